@@ -10,7 +10,8 @@ use fractal_explorer::core::{
             generate_fractal_parallel_rayon,
         },
         generate_pixel_buffer::generate_pixel_buffer::{
-            generate_pixel_buffer,
+            generate_pixel_buffer_counts, generate_pixel_buffer_lut, generate_pixel_buffer_parallel,
+            generate_pixel_buffer_sequential,
         },
         render_pixel_buffer::render_pixel_buffer_parallel_rayon,
     },
@@ -137,12 +138,56 @@ fn bench_colour_mapping(c: &mut Criterion) {
 
         group.throughput(Throughput::Elements(pixel_count));
         group.bench_with_input(
-            BenchmarkId::new("fire_gradient", params.label),
+            BenchmarkId::new("default", params.label),
             &iterations,
             |b, iters: &Vec<u32>| {
                 b.iter_batched(
                     || iters.clone(),
-                    |input| generate_pixel_buffer(input, colour_map.as_ref(), pixel_rect).unwrap(),
+                    |input| {
+                        generate_pixel_buffer_counts(input, colour_map.as_ref(), pixel_rect)
+                            .unwrap()
+                    },
+                    BatchSize::SmallInput,
+                );
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("sequential", params.label),
+            &iterations,
+            |b, iters: &Vec<u32>| {
+                b.iter_batched(
+                    || iters.clone(),
+                    |input| {
+                        generate_pixel_buffer_sequential(input, colour_map.as_ref(), pixel_rect)
+                            .unwrap()
+                    },
+                    BatchSize::SmallInput,
+                );
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("parallel", params.label),
+            &iterations,
+            |b, iters: &Vec<u32>| {
+                b.iter_batched(
+                    || iters.clone(),
+                    |input| {
+                        generate_pixel_buffer_parallel(input, colour_map.as_ref(), pixel_rect)
+                            .unwrap()
+                    },
+                    BatchSize::SmallInput,
+                );
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("lut", params.label),
+            &iterations,
+            |b, iters: &Vec<u32>| {
+                b.iter_batched(
+                    || iters.clone(),
+                    |input| {
+                        generate_pixel_buffer_lut(input, colour_map.as_ref(), pixel_rect).unwrap()
+                    },
                     BatchSize::SmallInput,
                 );
             },
@@ -1,6 +1,173 @@
 fn main() {
-    let presenter_factory = fractal_explorer::PixelsPresenterFactory::new();
-    let command = fractal_explorer::RunGuiCommand::new(presenter_factory);
+    let present_mode = present_mode_from_args(std::env::args().skip(1));
+    let presenter_factory = fractal_explorer::PixelsPresenterFactory::new()
+        .with_present_mode(present_mode)
+        .with_max_frame_age(max_frame_age_from_args(std::env::args().skip(1)));
+    let on_demand = std::env::args().skip(1).any(|arg| arg == "--on-demand");
+    let pipelined = std::env::args().skip(1).any(|arg| arg == "--pipelined");
+    let even_buffer = std::env::args().skip(1).any(|arg| arg == "--even-buffer");
+    let gpu = std::env::args().skip(1).any(|arg| arg == "--gpu");
+    let mut command = fractal_explorer::RunGuiCommand::new(presenter_factory)
+        .with_pipelined_colouring(pipelined)
+        .with_gpu_direct_rendering(gpu)
+        .with_even_buffer(even_buffer);
+    if let Some(config) = explore_config_from_args(std::env::args().skip(1)) {
+        command = command.with_explore_config(config);
+    }
+    if on_demand {
+        command = command.with_continuous(false);
+    }
+    if let Some(max_buffer_dimension) = max_buffer_dimension_from_args(std::env::args().skip(1)) {
+        command = command.with_max_buffer_dimension(max_buffer_dimension);
+    }
+    if let Some(ceiling) = max_iterations_ceiling_from_args(std::env::args().skip(1)) {
+        command = command.with_max_iterations_ceiling(ceiling);
+    }
+    if let Some(delay) = idle_render_delay_from_args(std::env::args().skip(1)) {
+        command = command.with_idle_render_delay(delay);
+    }
+    if let Some(window_count) = window_count_from_args(std::env::args().skip(1)) {
+        command = command.with_window_count(window_count);
+    }
+    if let Some(target) = frame_budget_from_args(std::env::args().skip(1)) {
+        command = command.with_frame_budget(target);
+    }
 
     command.execute();
 }
+
+/// Reads `--present-mode <vsync|no-vsync>`; anything else keeps vsync.
+fn present_mode_from_args(mut args: impl Iterator<Item = String>) -> fractal_explorer::PresentMode {
+    while let Some(arg) = args.next() {
+        if arg == "--present-mode" {
+            if let Some(mode) = args.next().as_deref().and_then(fractal_explorer::PresentMode::from_arg) {
+                return mode;
+            }
+            eprintln!("--present-mode expects vsync or no-vsync; using vsync");
+        }
+    }
+
+    fractal_explorer::PresentMode::default()
+}
+
+/// Reads `--config <path>`. A missing or invalid file exits before any
+/// window opens.
+#[cfg(feature = "config")]
+fn explore_config_from_args(mut args: impl Iterator<Item = String>) -> Option<fractal_explorer::ExploreConfig> {
+    while let Some(arg) = args.next() {
+        if arg == "--config" {
+            let Some(path) = args.next() else {
+                eprintln!("--config needs a file path");
+                std::process::exit(1);
+            };
+            return match fractal_explorer::ExploreConfig::load(&path) {
+                Ok(config) => Some(config),
+                Err(e) => {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            };
+        }
+    }
+
+    None
+}
+
+#[cfg(not(feature = "config"))]
+fn explore_config_from_args(mut args: impl Iterator<Item = String>) -> Option<fractal_explorer::ExploreConfig> {
+    if args.any(|arg| arg == "--config") {
+        eprintln!("--config needs the `config` feature");
+        std::process::exit(1);
+    }
+
+    None
+}
+
+/// Reads `--max-buffer-dimension <pixels>`; a missing or invalid value keeps
+/// the default cap.
+fn max_buffer_dimension_from_args(mut args: impl Iterator<Item = String>) -> Option<u32> {
+    while let Some(arg) = args.next() {
+        if arg == "--max-buffer-dimension" {
+            match args.next().as_deref().map(str::parse::<u32>) {
+                Some(Ok(value)) if value > 0 => return Some(value),
+                _ => eprintln!("--max-buffer-dimension expects a positive pixel count; using the default"),
+            }
+        }
+    }
+
+    None
+}
+
+/// Reads `--max-iterations-ceiling <iterations>`; a missing or invalid
+/// value keeps the default ceiling.
+fn max_iterations_ceiling_from_args(mut args: impl Iterator<Item = String>) -> Option<u32> {
+    while let Some(arg) = args.next() {
+        if arg == "--max-iterations-ceiling" {
+            match args.next().as_deref().map(str::parse::<u32>) {
+                Some(Ok(value)) if value > 0 => return Some(value),
+                _ => eprintln!("--max-iterations-ceiling expects a positive iteration count; using the default"),
+            }
+        }
+    }
+
+    None
+}
+
+/// Reads `--max-frame-age-ms <milliseconds>`; a missing or invalid value
+/// presents frames however late they arrive.
+fn max_frame_age_from_args(mut args: impl Iterator<Item = String>) -> Option<std::time::Duration> {
+    while let Some(arg) = args.next() {
+        if arg == "--max-frame-age-ms" {
+            match args.next().as_deref().map(str::parse::<u64>) {
+                Some(Ok(value)) => return Some(std::time::Duration::from_millis(value)),
+                _ => eprintln!("--max-frame-age-ms expects a number of milliseconds; keeping every frame"),
+            }
+        }
+    }
+
+    None
+}
+
+/// Reads `--idle-render-ms <milliseconds>`; `0` turns the idle render off,
+/// and a missing or invalid value keeps the default delay.
+fn idle_render_delay_from_args(mut args: impl Iterator<Item = String>) -> Option<std::time::Duration> {
+    while let Some(arg) = args.next() {
+        if arg == "--idle-render-ms" {
+            match args.next().as_deref().map(str::parse::<u64>) {
+                Some(Ok(value)) => return Some(std::time::Duration::from_millis(value)),
+                _ => eprintln!("--idle-render-ms expects a number of milliseconds; using the default"),
+            }
+        }
+    }
+
+    None
+}
+
+/// Reads `--windows <count>`; a missing or invalid value opens one window.
+fn window_count_from_args(mut args: impl Iterator<Item = String>) -> Option<usize> {
+    while let Some(arg) = args.next() {
+        if arg == "--windows" {
+            match args.next().as_deref().map(str::parse::<usize>) {
+                Some(Ok(value)) if value > 0 => return Some(value),
+                _ => eprintln!("--windows expects a positive window count; opening one"),
+            }
+        }
+    }
+
+    None
+}
+
+/// Reads `--frame-budget-ms <milliseconds>`; a missing or invalid value
+/// leaves the iteration count to the side panel.
+fn frame_budget_from_args(mut args: impl Iterator<Item = String>) -> Option<std::time::Duration> {
+    while let Some(arg) = args.next() {
+        if arg == "--frame-budget-ms" {
+            match args.next().as_deref().map(str::parse::<u64>) {
+                Some(Ok(value)) if value > 0 => return Some(std::time::Duration::from_millis(value)),
+                _ => eprintln!("--frame-budget-ms expects a positive number of milliseconds; ignoring it"),
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,383 @@
+use crate::controllers::cli::test::cli_test::render_explore_config;
+use crate::controllers::config::explore_config::ExploreConfig;
+use crate::controllers::ports::file_presenter::FilePresenterPort;
+use crate::core::data::complex::Complex;
+use crate::core::data::complex_rect::ComplexRect;
+use crate::core::fractals::fractal_kinds::FractalKinds;
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// The setting a [`BatchSweep`] varies, with its first and last values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SweepAxis {
+    /// The selected fractal's iteration limit, stepped linearly and
+    /// rounded to whole iterations.
+    MaxIterations { start: u32, end: u32 },
+    /// Magnification of the base view about its centre, stepped
+    /// geometrically so each frame zooms by the same factor.
+    Zoom { start: f64, end: f64 },
+    /// The Julia constant `c`, moved in a straight line. Only a Julia
+    /// view has one to sweep.
+    JuliaC { start: Complex, end: Complex },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BatchSweepError {
+    ZeroSteps,
+    ZeroMaxIterations,
+    /// A zoom that is not positive and finite.
+    InvalidZoom { zoom: f64 },
+    /// The zoomed view can't be represented, e.g. an extent that underflows.
+    InvalidRegion { zoom: f64 },
+    /// A Julia constant with a part that is not finite.
+    InvalidJuliaC { c: Complex },
+    /// A Julia constant sweep from a view of another fractal.
+    NotJulia { fractal: FractalKinds },
+}
+
+impl fmt::Display for BatchSweepError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroSteps => write!(f, "a sweep needs at least one step"),
+            Self::ZeroMaxIterations => write!(f, "max_iterations must be greater than zero"),
+            Self::InvalidZoom { zoom } => {
+                write!(f, "zoom must be positive and finite, got {zoom}")
+            }
+            Self::InvalidRegion { zoom } => {
+                write!(f, "the view zoomed {zoom} times is not a valid region")
+            }
+            Self::InvalidJuliaC { c } => {
+                write!(f, "the Julia constant must be finite, got {} + {}i", c.real, c.imag)
+            }
+            Self::NotJulia { fractal } => {
+                write!(f, "only a Julia view has a constant to sweep, not {fractal}")
+            }
+        }
+    }
+}
+
+impl Error for BatchSweepError {}
+
+/// Renders a series of frames from `base`, stepping one setting from its
+/// start to its end value, and hands each to a file presenter as
+/// `NNNN.ppm`, numbered from zero.
+pub struct BatchSweep<P: FilePresenterPort> {
+    base: ExploreConfig,
+    axis: SweepAxis,
+    steps: u32,
+    presenter: P,
+}
+
+impl<P: FilePresenterPort> BatchSweep<P> {
+    /// A sweep of `steps` frames. One step renders just the start value;
+    /// more include both ends.
+    pub fn new(
+        base: ExploreConfig,
+        axis: SweepAxis,
+        steps: u32,
+        presenter: P,
+    ) -> Result<Self, BatchSweepError> {
+        if steps == 0 {
+            return Err(BatchSweepError::ZeroSteps);
+        }
+
+        match axis {
+            SweepAxis::MaxIterations { start, end } if start == 0 || end == 0 => {
+                return Err(BatchSweepError::ZeroMaxIterations);
+            }
+            SweepAxis::Zoom { start, end } => {
+                for zoom in [start, end] {
+                    if !(zoom.is_finite() && zoom > 0.0) {
+                        return Err(BatchSweepError::InvalidZoom { zoom });
+                    }
+                }
+            }
+            SweepAxis::JuliaC { start, end } => {
+                if base.fractal != FractalKinds::Julia {
+                    return Err(BatchSweepError::NotJulia { fractal: base.fractal });
+                }
+                for c in [start, end] {
+                    if !(c.real.is_finite() && c.imag.is_finite()) {
+                        return Err(BatchSweepError::InvalidJuliaC { c });
+                    }
+                }
+            }
+            SweepAxis::MaxIterations { .. } => {}
+        }
+
+        Ok(Self {
+            base,
+            axis,
+            steps,
+            presenter,
+        })
+    }
+
+    /// The config for each frame, in order.
+    pub fn configs(&self) -> Result<Vec<ExploreConfig>, BatchSweepError> {
+        (0..self.steps)
+            .map(|index| self.config_at(self.fraction(index)))
+            .collect()
+    }
+
+    /// The file frame `index` is written to, under `directory`.
+    #[must_use]
+    pub fn frame_path(directory: impl AsRef<Path>, index: usize) -> PathBuf {
+        directory.as_ref().join(format!("{index:04}.ppm"))
+    }
+
+    /// Renders every frame into `directory`, creating it if needed, and
+    /// returns the files written.
+    pub fn run(&self, directory: impl AsRef<Path>) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+        let directory = directory.as_ref();
+        std::fs::create_dir_all(directory)?;
+
+        let mut written = Vec::new();
+        for (index, config) in self.configs()?.iter().enumerate() {
+            let path = Self::frame_path(directory, index);
+            self.presenter.present(&render_explore_config(config)?, &path)?;
+            written.push(path);
+        }
+
+        Ok(written)
+    }
+
+    fn fraction(&self, index: u32) -> f64 {
+        if self.steps > 1 {
+            f64::from(index) / f64::from(self.steps - 1)
+        } else {
+            0.0
+        }
+    }
+
+    fn config_at(&self, fraction: f64) -> Result<ExploreConfig, BatchSweepError> {
+        let mut config = self.base.clone();
+
+        match self.axis {
+            SweepAxis::MaxIterations { start, end } => {
+                let value = f64::from(start) + (f64::from(end) - f64::from(start)) * fraction;
+                let max_iterations = value.round() as u32;
+                match config.fractal {
+                    FractalKinds::Mandelbrot => config.mandelbrot.max_iterations = max_iterations,
+                    FractalKinds::Julia => config.julia.max_iterations = max_iterations,
+                    FractalKinds::BurningShip => {
+                        config.burning_ship.max_iterations = max_iterations;
+                    }
+                    FractalKinds::Tricorn => config.tricorn.max_iterations = max_iterations,
+                }
+            }
+            SweepAxis::Zoom { start, end } => {
+                let zoom = start * (end / start).powf(fraction);
+                match config.fractal {
+                    FractalKinds::Mandelbrot => {
+                        let region = &config.mandelbrot.region;
+                        config.mandelbrot.region = region
+                            .with_extent(region.width() / zoom, region.height() / zoom)
+                            .map_err(|_| BatchSweepError::InvalidRegion { zoom })?
+                            .normalised();
+                    }
+                    FractalKinds::Julia => {
+                        config.julia.region = zoomed_rect(config.julia.region, zoom)?;
+                    }
+                    FractalKinds::BurningShip => {
+                        config.burning_ship.region = zoomed_rect(config.burning_ship.region, zoom)?;
+                    }
+                    FractalKinds::Tricorn => {
+                        config.tricorn.region = zoomed_rect(config.tricorn.region, zoom)?;
+                    }
+                }
+            }
+            SweepAxis::JuliaC { start, end } => {
+                config.julia.c = Complex {
+                    real: start.real + (end.real - start.real) * fraction,
+                    imag: start.imag + (end.imag - start.imag) * fraction,
+                };
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// `region` shrunk `zoom` times about its centre.
+fn zoomed_rect(region: ComplexRect, zoom: f64) -> Result<ComplexRect, BatchSweepError> {
+    let centre = Complex {
+        real: region.top_left().real + region.width() / 2.0,
+        imag: region.top_left().imag + region.height() / 2.0,
+    };
+
+    ComplexRect::from_centre_size(centre, region.width() / zoom, region.height() / zoom)
+        .map_err(|_| BatchSweepError::InvalidRegion { zoom })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controllers::config::explore_config::WindowOptions;
+    use crate::core::data::pixel_buffer::PixelBuffer;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    struct RecordingPresenter {
+        calls: RefCell<Vec<(PathBuf, u32)>>,
+    }
+
+    impl FilePresenterPort for RecordingPresenter {
+        fn present(&self, buffer: &PixelBuffer, filepath: impl AsRef<Path>) -> std::io::Result<()> {
+            self.calls
+                .borrow_mut()
+                .push((filepath.as_ref().to_path_buf(), buffer.pixel_rect().width()));
+            Ok(())
+        }
+    }
+
+    fn small_base(fractal: FractalKinds) -> ExploreConfig {
+        ExploreConfig {
+            fractal,
+            window: WindowOptions {
+                width: Some(16),
+                height: Some(12),
+                ..WindowOptions::default()
+            },
+            ..ExploreConfig::default()
+        }
+    }
+
+    fn output_directory(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("fractal_explorer_sweep_{name}_{}", std::process::id()))
+    }
+
+    #[test]
+    fn five_step_sweep_renders_five_distinct_frames_in_order() {
+        let sweep = BatchSweep::new(
+            small_base(FractalKinds::Mandelbrot),
+            SweepAxis::MaxIterations { start: 10, end: 500 },
+            5,
+            RecordingPresenter::default(),
+        )
+        .unwrap();
+
+        let configs = sweep.configs().unwrap();
+        let iterations: Vec<u32> = configs.iter().map(|c| c.mandelbrot.max_iterations).collect();
+        assert_eq!(iterations, [10, 133, 255, 378, 500]);
+        for (i, a) in configs.iter().enumerate() {
+            for b in &configs[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+
+        let directory = output_directory("iterations");
+        let written = sweep.run(&directory).unwrap();
+
+        let expected: Vec<PathBuf> = ["0000.ppm", "0001.ppm", "0002.ppm", "0003.ppm", "0004.ppm"]
+            .iter()
+            .map(|name| directory.join(name))
+            .collect();
+        assert_eq!(written, expected);
+        assert_eq!(
+            *sweep.presenter.calls.borrow(),
+            expected.into_iter().map(|path| (path, 16)).collect::<Vec<_>>()
+        );
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn zoom_sweep_steps_geometrically_about_the_centre() {
+        let base = small_base(FractalKinds::Julia);
+        let sweep = BatchSweep::new(
+            base.clone(),
+            SweepAxis::Zoom { start: 1.0, end: 100.0 },
+            3,
+            RecordingPresenter::default(),
+        )
+        .unwrap();
+
+        let configs = sweep.configs().unwrap();
+        let widths: Vec<f64> = configs.iter().map(|c| c.julia.region.width()).collect();
+        let base_width = base.julia.region.width();
+
+        for (width, zoom) in widths.iter().zip([1.0, 10.0, 100.0]) {
+            assert!((width - base_width / zoom).abs() < 1e-12, "width {width} at zoom {zoom}");
+        }
+        let centre = |region: ComplexRect| region.top_left().real + region.width() / 2.0;
+        assert!((centre(configs[2].julia.region) - centre(base.julia.region)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn julia_c_sweep_moves_the_constant_in_a_straight_line() {
+        let sweep = BatchSweep::new(
+            small_base(FractalKinds::Julia),
+            SweepAxis::JuliaC {
+                start: Complex { real: -0.8, imag: 0.0 },
+                end: Complex { real: 0.0, imag: 0.8 },
+            },
+            3,
+            RecordingPresenter::default(),
+        )
+        .unwrap();
+
+        let configs = sweep.configs().unwrap();
+        let cs: Vec<(f64, f64)> = configs.iter().map(|c| (c.julia.c.real, c.julia.c.imag)).collect();
+        assert_eq!(cs, [(-0.8, 0.0), (-0.4, 0.4), (0.0, 0.8)]);
+
+        let directory = output_directory("julia_c");
+        assert_eq!(sweep.run(&directory).unwrap().len(), 3);
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn single_step_renders_the_start_value() {
+        let sweep = BatchSweep::new(
+            small_base(FractalKinds::Julia),
+            SweepAxis::MaxIterations { start: 64, end: 512 },
+            1,
+            RecordingPresenter::default(),
+        )
+        .unwrap();
+
+        let configs = sweep.configs().unwrap();
+
+        assert_eq!(configs.len(), 1);
+        assert_eq!(configs[0].julia.max_iterations, 64);
+    }
+
+    #[test]
+    fn rejects_empty_or_invalid_sweeps() {
+        let build = |axis, steps| {
+            BatchSweep::new(small_base(FractalKinds::Mandelbrot), axis, steps, RecordingPresenter::default())
+                .err()
+        };
+
+        assert_eq!(
+            build(SweepAxis::MaxIterations { start: 10, end: 20 }, 0),
+            Some(BatchSweepError::ZeroSteps)
+        );
+        assert_eq!(
+            build(SweepAxis::MaxIterations { start: 0, end: 20 }, 3),
+            Some(BatchSweepError::ZeroMaxIterations)
+        );
+        assert_eq!(
+            build(SweepAxis::Zoom { start: 1.0, end: -2.0 }, 3),
+            Some(BatchSweepError::InvalidZoom { zoom: -2.0 })
+        );
+
+        let c = Complex { real: -0.8, imag: 0.156 };
+        assert_eq!(
+            build(SweepAxis::JuliaC { start: c, end: c }, 3),
+            Some(BatchSweepError::NotJulia { fractal: FractalKinds::Mandelbrot })
+        );
+        let nan = Complex { real: f64::NAN, imag: 0.0 };
+        assert!(matches!(
+            BatchSweep::new(
+                small_base(FractalKinds::Julia),
+                SweepAxis::JuliaC { start: c, end: nan },
+                3,
+                RecordingPresenter::default(),
+            )
+            .err(),
+            Some(BatchSweepError::InvalidJuliaC { .. })
+        ));
+    }
+}
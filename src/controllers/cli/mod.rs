@@ -1 +1,4 @@
+pub mod batch_sweep;
+pub mod oneshot;
+pub mod self_test;
 pub mod test;
@@ -0,0 +1,194 @@
+use crate::controllers::cli::test::cli_test::explore_config_request;
+use crate::controllers::config::explore_config::ExploreConfig;
+use crate::controllers::interactive::InteractiveController;
+use crate::controllers::interactive::events::render::RenderEvent;
+use crate::controllers::interactive::ports::presenter::InteractiveControllerPresenterPort;
+use crate::controllers::ports::file_presenter::FilePresenterPort;
+use crate::core::data::pixel_rect::PixelRectError;
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// How long [`render_oneshot`] waits for the frame by default.
+pub const DEFAULT_ONESHOT_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+pub enum OneshotError {
+    PixelRect(PixelRectError),
+    /// The controller reported the render as failed.
+    Render(String),
+    Write(std::io::Error),
+    /// No frame arrived within the timeout.
+    TimedOut(Duration),
+}
+
+impl fmt::Display for OneshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PixelRect(e) => write!(f, "pixel rect error: {}", e),
+            Self::Render(message) => write!(f, "render error: {}", message),
+            Self::Write(e) => write!(f, "could not write the frame: {}", e),
+            Self::TimedOut(timeout) => write!(f, "no frame arrived within {:?}", timeout),
+        }
+    }
+}
+
+impl Error for OneshotError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::PixelRect(e) => Some(e),
+            Self::Write(e) => Some(e),
+            Self::Render(_) | Self::TimedOut(_) => None,
+        }
+    }
+}
+
+/// An [`InteractiveControllerPresenterPort`] that writes each frame to
+/// `path` with a file presenter and reports the outcome on a channel, so
+/// the controller's output can be captured without a window.
+pub struct FileFrameSink<P: FilePresenterPort> {
+    presenter: P,
+    path: PathBuf,
+    written: Mutex<Sender<Result<PathBuf, OneshotError>>>,
+}
+
+impl<P: FilePresenterPort> FileFrameSink<P> {
+    /// The sink, and the receiving end of its outcomes: the path for each
+    /// frame written, or why it wasn't.
+    pub fn new(
+        presenter: P,
+        path: impl AsRef<Path>,
+    ) -> (Self, Receiver<Result<PathBuf, OneshotError>>) {
+        let (sender, receiver) = mpsc::channel();
+        let sink = Self {
+            presenter,
+            path: path.as_ref().to_path_buf(),
+            written: Mutex::new(sender),
+        };
+
+        (sink, receiver)
+    }
+}
+
+impl<P: FilePresenterPort + Send + Sync> InteractiveControllerPresenterPort for FileFrameSink<P> {
+    fn present(&self, event: RenderEvent) {
+        let outcome = match event {
+            RenderEvent::Frame(frame) => self
+                .presenter
+                .present(&frame.pixel_buffer, &self.path)
+                .map(|()| self.path.clone())
+                .map_err(OneshotError::Write),
+            RenderEvent::Error(error) => Err(OneshotError::Render(error.message)),
+        };
+
+        // The receiver is gone once `render_oneshot` has given up waiting.
+        let _ = self
+            .written
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .send(outcome);
+    }
+}
+
+/// Renders the view from a config file once through the interactive
+/// controller, as the GUI would, writes the frame to `path` and shuts the
+/// controller down. For exercising the controller headlessly, e.g. under a
+/// profiler; the GPU path is not used.
+pub fn render_oneshot<P: FilePresenterPort + Send + Sync + 'static>(
+    config: &ExploreConfig,
+    presenter: P,
+    path: impl AsRef<Path>,
+    timeout: Duration,
+) -> Result<PathBuf, OneshotError> {
+    let (_, request) = explore_config_request(config).map_err(OneshotError::PixelRect)?;
+    let (sink, written) = FileFrameSink::new(presenter, path);
+
+    let mut controller = InteractiveController::new(Arc::new(sink), None);
+    controller.submit_request(Arc::new(request));
+    let outcome = match written.recv_timeout(timeout) {
+        Ok(outcome) => outcome,
+        Err(RecvTimeoutError::Timeout | RecvTimeoutError::Disconnected) => {
+            Err(OneshotError::TimedOut(timeout))
+        }
+    };
+    controller.shutdown();
+
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controllers::config::explore_config::WindowOptions;
+    use crate::core::fractals::fractal_kinds::FractalKinds;
+    use crate::presenters::file::ppm::PpmFilePresenter;
+
+    fn small_config(fractal: FractalKinds) -> ExploreConfig {
+        ExploreConfig {
+            fractal,
+            window: WindowOptions {
+                width: Some(24),
+                height: Some(18),
+                ..WindowOptions::default()
+            },
+            ..ExploreConfig::default()
+        }
+    }
+
+    #[test]
+    fn writes_one_frame_at_the_configured_size() {
+        let path = std::env::temp_dir()
+            .join(format!("fractal_explorer_oneshot_{}.ppm", std::process::id()));
+
+        let written = render_oneshot(
+            &small_config(FractalKinds::Julia),
+            PpmFilePresenter::new(),
+            &path,
+            DEFAULT_ONESHOT_TIMEOUT,
+        )
+        .unwrap();
+
+        let bytes = std::fs::read(&written).unwrap();
+        std::fs::remove_file(&written).unwrap();
+        assert_eq!(written, path);
+        assert!(bytes.starts_with(b"P6\n24 18\n255\n"));
+        assert_eq!(bytes.len(), b"P6\n24 18\n255\n".len() + 24 * 18 * 3);
+    }
+
+    #[test]
+    fn unwritable_path_is_reported() {
+        let path = std::env::temp_dir()
+            .join(format!("fractal_explorer_oneshot_missing_{}", std::process::id()))
+            .join("frame.ppm");
+
+        let result = render_oneshot(
+            &small_config(FractalKinds::Mandelbrot),
+            PpmFilePresenter::new(),
+            &path,
+            DEFAULT_ONESHOT_TIMEOUT,
+        );
+
+        assert!(matches!(result, Err(OneshotError::Write(_))));
+    }
+
+    #[test]
+    fn window_too_large_for_a_pixel_rect_is_reported() {
+        let mut config = small_config(FractalKinds::Mandelbrot);
+        config.window.width = Some(u32::MAX);
+
+        let result = render_oneshot(
+            &config,
+            PpmFilePresenter::new(),
+            std::env::temp_dir().join("fractal_explorer_oneshot_unused.ppm"),
+            DEFAULT_ONESHOT_TIMEOUT,
+        );
+
+        assert!(matches!(
+            result,
+            Err(OneshotError::PixelRect(PixelRectError::TooLarge { width: u32::MAX, height: 18 }))
+        ));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::core::actions::render_pixel_buffer::{
+    render_pixel_buffer_parallel_rayon, RenderPixelBufferError,
+};
+use crate::core::data::complex::Complex;
+use crate::core::data::complex_rect::{ComplexRect, ComplexRectError};
+use crate::core::data::pixel_buffer::PixelBuffer;
+use crate::core::data::pixel_rect::{PixelRect, PixelRectError};
+use crate::core::data::point::Point;
+use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
+use crate::core::fractals::mandelbrot::colour_mapping::maps::fire::MandelbrotFireColourMap;
+use crate::core::fractals::mandelbrot::errors::mandelbrot::MandelbrotError;
+use crate::core::util::pixel_to_complex_coords::PixelToComplexCoordsError;
+use std::error::Error;
+use std::fmt;
+
+const SIZE: i32 = 33;
+const MAX_ITERATIONS: u32 = 64;
+
+#[derive(Debug)]
+pub enum SelfTestError {
+    PixelRect(PixelRectError),
+    ComplexRect(ComplexRectError),
+    Algorithm(MandelbrotError),
+    Render(RenderPixelBufferError<PixelToComplexCoordsError>),
+    CentreNotBlack { rgb: [u8; 3] },
+    CornerBlack { corner: Point },
+}
+
+impl fmt::Display for SelfTestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PixelRect(e) => write!(f, "pixel rect error: {}", e),
+            Self::ComplexRect(e) => write!(f, "complex rect error: {}", e),
+            Self::Algorithm(e) => write!(f, "algorithm error: {}", e),
+            Self::Render(e) => write!(f, "render error: {}", e),
+            Self::CentreNotBlack { rgb } => write!(
+                f,
+                "self-test failed: centre pixel should be black, got rgb({}, {}, {})",
+                rgb[0], rgb[1], rgb[2]
+            ),
+            Self::CornerBlack { corner } => write!(
+                f,
+                "self-test failed: corner pixel (x: {}, y: {}) should not be black",
+                corner.x, corner.y
+            ),
+        }
+    }
+}
+
+impl Error for SelfTestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::PixelRect(e) => Some(e),
+            Self::ComplexRect(e) => Some(e),
+            Self::Algorithm(e) => Some(e),
+            Self::Render(e) => Some(e),
+            Self::CentreNotBlack { .. } | Self::CornerBlack { .. } => None,
+        }
+    }
+}
+
+/// Renders a tiny Mandelbrot headlessly and checks that the centre
+/// (`c = -0.5`, inside the main cardioid) is black and that every corner
+/// (well outside the set) is not.
+pub fn run_self_test() -> Result<(), SelfTestError> {
+    let pixel_rect = PixelRect::new(
+        Point { x: 0, y: 0 },
+        Point {
+            x: SIZE - 1,
+            y: SIZE - 1,
+        },
+    )
+    .map_err(SelfTestError::PixelRect)?;
+
+    let complex_rect = ComplexRect::new(
+        Complex {
+            real: -2.0,
+            imag: -1.5,
+        },
+        Complex {
+            real: 1.0,
+            imag: 1.5,
+        },
+    )
+    .map_err(SelfTestError::ComplexRect)?;
+
+    let algorithm = MandelbrotAlgorithm::new(pixel_rect, complex_rect, MAX_ITERATIONS)
+        .map_err(SelfTestError::Algorithm)?;
+    let colour_map = MandelbrotFireColourMap::new(MAX_ITERATIONS);
+
+    let buffer = render_pixel_buffer_parallel_rayon(pixel_rect, &algorithm, &colour_map)
+        .map_err(SelfTestError::Render)?;
+
+    check_invariants(&buffer)
+}
+
+fn check_invariants(buffer: &PixelBuffer) -> Result<(), SelfTestError> {
+    let rect = buffer.pixel_rect();
+    let top_left = rect.top_left();
+    let bottom_right = rect.bottom_right();
+
+    let centre = Point {
+        x: top_left.x + (rect.width() / 2) as i32,
+        y: top_left.y + (rect.height() / 2) as i32,
+    };
+    let rgb = rgb_at(buffer, centre);
+    if rgb != [0, 0, 0] {
+        return Err(SelfTestError::CentreNotBlack { rgb });
+    }
+
+    let corners = [
+        top_left,
+        Point {
+            x: bottom_right.x,
+            y: top_left.y,
+        },
+        Point {
+            x: top_left.x,
+            y: bottom_right.y,
+        },
+        bottom_right,
+    ];
+    for corner in corners {
+        if rgb_at(buffer, corner) == [0, 0, 0] {
+            return Err(SelfTestError::CornerBlack { corner });
+        }
+    }
+
+    Ok(())
+}
+
+fn rgb_at(buffer: &PixelBuffer, point: Point) -> [u8; 3] {
+    let rect = buffer.pixel_rect();
+    let x = (point.x - rect.top_left().x) as usize;
+    let y = (point.y - rect.top_left().y) as usize;
+    let base = (y * rect.width() as usize + x) * PixelBuffer::BYTES_PER_PIXEL;
+    let pixel = &buffer.buffer()[base..base + 3];
+
+    [pixel[0], pixel[1], pixel[2]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::colour::Colour;
+
+    fn rect(size: i32) -> PixelRect {
+        PixelRect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: size - 1,
+                y: size - 1,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn self_test_passes() {
+        assert!(run_self_test().is_ok());
+    }
+
+    #[test]
+    fn all_black_buffer_fails_on_a_corner() {
+        let buffer = PixelBuffer::new(rect(5));
+
+        assert!(matches!(
+            check_invariants(&buffer),
+            Err(SelfTestError::CornerBlack {
+                corner: Point { x: 0, y: 0 }
+            })
+        ));
+    }
+
+    #[test]
+    fn lit_centre_fails() {
+        let mut buffer = PixelBuffer::new(rect(5));
+        buffer
+            .set_pixel(Point { x: 2, y: 2 }, Colour { r: 9, g: 0, b: 0 })
+            .unwrap();
+
+        assert!(matches!(
+            check_invariants(&buffer),
+            Err(SelfTestError::CentreNotBlack { rgb: [9, 0, 0] })
+        ));
+    }
+}
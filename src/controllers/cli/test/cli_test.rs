@@ -1,6 +1,27 @@
 use std::{path::Path, time::Instant};
 
-use crate::{controllers::ports::file_presenter::FilePresenterPort, core::{actions::render_pixel_buffer::render_pixel_buffer_parallel_rayon, data::{complex::Complex, complex_rect::ComplexRect, pixel_buffer::PixelBuffer, pixel_rect::PixelRect, point::Point}, fractals::mandelbrot::{algorithm::MandelbrotAlgorithm, colour_mapping::maps::fire::MandelbrotFireColourMap}}};
+use crate::controllers::config::explore_config::ExploreConfig;
+use crate::controllers::interactive::data::fractal_config::FractalConfig;
+use crate::core::data::pixel_rect::PixelRectError;
+use crate::core::actions::cancellation::NeverCancel;
+use crate::core::actions::contrast_stretch::ContrastStretchColourMap;
+use crate::core::actions::cost_heatmap::render_cost_heatmap;
+use crate::core::actions::downscale_area::downscale_area;
+use crate::core::actions::extract_contours::extract_contours;
+use crate::core::actions::generate_fractal::generate_fractal_parallel_rayon::generate_fractal_parallel_rayon;
+use crate::core::actions::generate_pixel_buffer::generate_pixel_buffer::{
+    generate_pixel_buffer_counts, generate_pixel_buffer_histogram,
+};
+use crate::core::fractals::fractal_kinds::FractalKinds;
+use crate::core::actions::render_boundary_only::render_boundary_only;
+use crate::core::actions::render_tile::{TileRequest, render_tile};
+use crate::controllers::ports::streaming_file_presenter::StreamingFilePresenterPort;
+use crate::presenters::file::pam::PamFilePresenter;
+use crate::presenters::file::pgm::PgmFilePresenter;
+use crate::presenters::file::streaming_ppm::StreamingPpmPresenter;
+use crate::presenters::file::svg::SvgContourPresenter;
+
+use crate::{controllers::ports::file_presenter::FilePresenterPort, core::{actions::{render_palette_strip::render_palette_strip, render_pixel_buffer::render_pixel_buffer_parallel_rayon}, data::{complex::Complex, complex_rect::{ComplexRect, ComplexRectError}, pixel_buffer::PixelBuffer, pixel_rect::PixelRect, point::Point}, fractals::mandelbrot::{algorithm::{DEFAULT_POWER, MandelbrotAlgorithm}, colour_mapping::maps::fire::MandelbrotFireColourMap}}};
 
 pub struct CliTestController<P: FilePresenterPort> {
     presenter: P,
@@ -16,6 +37,12 @@ impl<P: FilePresenterPort> CliTestController<P> {
     }
 
     pub fn generate(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.generate_with_power(DEFAULT_POWER)
+    }
+
+    /// `generate` for `z^power + c`: the Mandelbrot set for power 2, a
+    /// Multibrot set above it.
+    pub fn generate_with_power(&mut self, power: u32) -> Result<(), Box<dyn std::error::Error>> {
         let width: i32 = 800;
         let height: i32 = 600;
         let max_iterations: u32 = 256;
@@ -42,8 +69,9 @@ impl<P: FilePresenterPort> CliTestController<P> {
         println!("Rendering Mandelbrot set...");
         println!("Image size: {}x{}", width, height);
         println!("Max iterations: {}", max_iterations);
+        println!("Power: {}", power);
 
-        let algorithm = MandelbrotAlgorithm::new(pixel_rect, complex_rect, max_iterations)?;
+        let algorithm = MandelbrotAlgorithm::new(pixel_rect, complex_rect, max_iterations)?.with_power(power)?;
         let colour_map = MandelbrotFireColourMap::new(max_iterations);
 
         let start = Instant::now();
@@ -59,6 +87,133 @@ impl<P: FilePresenterPort> CliTestController<P> {
         Ok(())
     }
 
+    /// Renders `generate`'s view at three times the size in each direction
+    /// and area-averages it back down to 800x600, for a smooth still.
+    pub fn generate_antialiased(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        const SCALE: i32 = 3;
+        let max_iterations: u32 = 256;
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 800 * SCALE - 1, y: 600 * SCALE - 1 })?;
+
+        let algorithm = MandelbrotAlgorithm::new(pixel_rect, demo_region()?, max_iterations)?;
+        let colour_map = MandelbrotFireColourMap::new(max_iterations);
+        let large = render_pixel_buffer_parallel_rayon(pixel_rect, &algorithm, &colour_map)?;
+        self.buffer = Some(downscale_area(&large, 800, 600));
+
+        Ok(())
+    }
+
+    /// Renders the view from a config file at its window size, 800x600
+    /// unless the file sets one.
+    pub fn generate_from_config(
+        &mut self,
+        config: &ExploreConfig,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        println!("Rendering {} set...", config.fractal);
+        println!(
+            "Image size: {}x{}",
+            config.window.width.unwrap_or(800),
+            config.window.height.unwrap_or(600)
+        );
+
+        let start = Instant::now();
+        self.buffer = Some(render_explore_config(config)?);
+        let duration = start.elapsed();
+
+        println!("Duration:   {:?}", duration);
+
+        Ok(())
+    }
+
+    /// Renders the demo render's colour map on its own as a 256x20 strip.
+    pub fn generate_palette_strip(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let max_iterations: u32 = 256;
+        let colour_map = MandelbrotFireColourMap::new(max_iterations);
+
+        self.buffer = Some(render_palette_strip(&colour_map, max_iterations, 256, 20)?);
+
+        Ok(())
+    }
+
+    /// Traces the demo render's iteration field at doubling levels from 2
+    /// to 128 and writes the bands' outlines as an SVG.
+    pub fn write_contours_svg(&self, filepath: impl AsRef<Path>) -> Result<(), Box<dyn std::error::Error>> {
+        let (iterations, pixel_rect, _) = Self::demo_iterations()?;
+        let levels: Vec<u32> = (1..=7).map(|power| 1 << power).collect();
+        let contours = extract_contours(&iterations, pixel_rect, &levels)?;
+
+        println!("Traced {} contours", contours.len());
+        SvgContourPresenter::new().present(&contours, pixel_rect, filepath)?;
+
+        Ok(())
+    }
+
+    /// Writes the demo render's raw iteration counts as a greyscale PGM.
+    pub fn write_iterations_pgm(&self, filepath: impl AsRef<Path>) -> Result<(), Box<dyn std::error::Error>> {
+        let (iterations, pixel_rect, max_iterations) = Self::demo_iterations()?;
+        PgmFilePresenter::new().present(&iterations, pixel_rect, max_iterations, filepath)?;
+
+        Ok(())
+    }
+
+    /// Writes the demo render's iteration counts, a proxy for per-pixel
+    /// cost, as a cold-to-hot heatmap.
+    pub fn write_cost_heatmap(&self, filepath: impl AsRef<Path>) -> Result<(), Box<dyn std::error::Error>> {
+        let (iterations, pixel_rect, max_iterations) = Self::demo_iterations()?;
+        let buffer = render_cost_heatmap(iterations, pixel_rect, max_iterations)?;
+        self.presenter.present(&buffer, filepath)?;
+
+        Ok(())
+    }
+
+    /// Writes just the outline of the demo render's set, in its Fire
+    /// colours, as a PAM with everything else transparent.
+    pub fn write_boundary_pam(&self, filepath: impl AsRef<Path>) -> Result<(), Box<dyn std::error::Error>> {
+        let (iterations, pixel_rect, max_iterations) = Self::demo_iterations()?;
+        let colour_map = MandelbrotFireColourMap::new(max_iterations);
+        let buffer = render_boundary_only(iterations, pixel_rect, max_iterations, &colour_map)?;
+        PamFilePresenter::new().present(&buffer, filepath)?;
+
+        Ok(())
+    }
+
+    /// Renders `generate`'s view in 64-row bands, writing each to a PPM at
+    /// `filepath` as soon as it is done, so only one band is in memory.
+    pub fn write_streaming_ppm(&self, filepath: impl AsRef<Path>) -> Result<(), Box<dyn std::error::Error>> {
+        const BAND_ROWS: i32 = 64;
+        let max_iterations: u32 = 256;
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 799, y: 599 })?;
+        let complex_rect = demo_region()?;
+        let colour_map = MandelbrotFireColourMap::new(max_iterations);
+
+        let mut presenter = StreamingPpmPresenter::create(pixel_rect, filepath)?;
+        let bottom = pixel_rect.bottom_right().y;
+        for y in (pixel_rect.top_left().y..=bottom).step_by(BAND_ROWS as usize) {
+            let band_rect = PixelRect::new(
+                Point { x: pixel_rect.top_left().x, y },
+                Point {
+                    x: pixel_rect.bottom_right().x,
+                    y: (y + BAND_ROWS - 1).min(bottom),
+                },
+            )?;
+            let request = TileRequest::new(pixel_rect, band_rect, complex_rect, max_iterations)?;
+            presenter.write_band(&render_tile(&request, MandelbrotAlgorithm::new, &colour_map)?)?;
+        }
+        presenter.finish()?;
+
+        Ok(())
+    }
+
+    /// The iteration field behind `generate`, with its pixel rect and
+    /// iteration limit.
+    fn demo_iterations() -> Result<(Vec<u32>, PixelRect, u32), Box<dyn std::error::Error>> {
+        let max_iterations: u32 = 256;
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 799, y: 599 })?;
+        let algorithm = MandelbrotAlgorithm::new(pixel_rect, demo_region()?, max_iterations)?;
+        let iterations = generate_fractal_parallel_rayon(pixel_rect, &algorithm)?;
+
+        Ok((iterations, pixel_rect, max_iterations))
+    }
+
     pub fn write(&self, filepath: impl AsRef<Path>) -> std::io::Result<()> {
         if let Some(buffer) = &self.buffer {
             self.presenter.present(buffer, filepath)?
@@ -67,3 +222,74 @@ impl<P: FilePresenterPort> CliTestController<P> {
         Ok(())
     }
 }
+
+/// The region `generate` renders: the whole Mandelbrot set.
+fn demo_region() -> Result<ComplexRect, ComplexRectError> {
+    ComplexRect::new(
+        Complex {
+            real: -2.5,
+            imag: -1.0,
+        },
+        Complex {
+            real: 1.0,
+            imag: 1.0,
+        },
+    )
+}
+
+/// The request for the view in a config file at its window size, 800x600
+/// unless the file sets one, with the pixel rect it covers.
+pub(crate) fn explore_config_request(
+    config: &ExploreConfig,
+) -> Result<(PixelRect, FractalConfig), PixelRectError> {
+    let width = config.window.width.unwrap_or(800);
+    let height = config.window.height.unwrap_or(600);
+    let too_large = |_| PixelRectError::TooLarge { width, height };
+
+    let pixel_rect = PixelRect::new(
+        Point { x: 0, y: 0 },
+        Point {
+            x: i32::try_from(width).map_err(too_large)? - 1,
+            y: i32::try_from(height).map_err(too_large)? - 1,
+        },
+    )?;
+
+    let request = match config.fractal {
+        FractalKinds::Mandelbrot => config.mandelbrot.build_render_request(pixel_rect),
+        FractalKinds::Julia => config.julia.build_render_request(pixel_rect),
+        FractalKinds::BurningShip => config.burning_ship.build_render_request(pixel_rect),
+        FractalKinds::Tricorn => config.tricorn.build_render_request(pixel_rect),
+    };
+
+    Ok((pixel_rect, request))
+}
+
+/// Renders the view from a config file at its window size (see
+/// [`explore_config_request`]), equalised or colour-stretched if the file
+/// asks.
+pub(crate) fn render_explore_config(
+    config: &ExploreConfig,
+) -> Result<PixelBuffer, Box<dyn std::error::Error>> {
+    let (pixel_rect, request) = explore_config_request(config)?;
+
+    request.prepare(&NeverCancel)?;
+    let iterations = generate_fractal_parallel_rayon(pixel_rect, request.algorithm())?;
+
+    Ok(match (request.histogram(), request.contrast_stretch()) {
+        (Some(max_iterations), _) => generate_pixel_buffer_histogram(
+            iterations,
+            request.colour_map(),
+            pixel_rect,
+            max_iterations,
+        )?,
+        (None, Some(max_iterations)) => {
+            let colour_map = ContrastStretchColourMap::for_field(
+                request.colour_map(),
+                &iterations,
+                max_iterations,
+            );
+            generate_pixel_buffer_counts(iterations, &colour_map, pixel_rect)?
+        }
+        (None, None) => generate_pixel_buffer_counts(iterations, request.colour_map(), pixel_rect)?,
+    })
+}
@@ -0,0 +1,51 @@
+use crate::core::flight::FlightLimits;
+use crate::core::fractals::burning_ship::burning_ship_config::BurningShipConfig;
+use crate::core::fractals::fractal_kinds::FractalKinds;
+use crate::core::fractals::julia::julia_config::JuliaConfig;
+use crate::core::fractals::mandelbrot::mandelbrot_config::MandelbrotConfig;
+use crate::core::fractals::tricorn::tricorn_config::TricornConfig;
+#[cfg(feature = "config")]
+use serde::Deserialize;
+
+/// Window settings from a config file; unset fields keep the command's
+/// defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "config", derive(Deserialize), serde(default, deny_unknown_fields))]
+pub struct WindowOptions {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub continuous: Option<bool>,
+    pub max_buffer_dimension: Option<u32>,
+}
+
+/// A saved view and settings to start from, read with
+/// `ExploreConfig::load` (the `config` feature).
+///
+/// The file is JSON; every field is optional:
+///
+/// ```json
+/// {
+///   "fractal": "Mandelbrot",
+///   "centre": [-0.743643887, 0.131825904],
+///   "extent": [0.0035, 0.002],
+///   "max_iterations": 2000,
+///   "colour_map": "Fire",
+///   "contrast_stretch": false,
+///   "colour_scheme": "Linear",
+///   "flight": { "zoom_mode": "direct", "max_speed_abs_world_per_sec": 3.0 },
+///   "window": { "width": 1280, "height": 720 }
+/// }
+/// ```
+///
+/// The view, iteration limit and colour map apply to the selected fractal;
+/// the others keep their defaults.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ExploreConfig {
+    pub fractal: FractalKinds,
+    pub mandelbrot: MandelbrotConfig,
+    pub julia: JuliaConfig,
+    pub burning_ship: BurningShipConfig,
+    pub tricorn: TricornConfig,
+    pub flight_limits: FlightLimits,
+    pub window: WindowOptions,
+}
@@ -0,0 +1,391 @@
+use crate::controllers::config::explore_config::{ExploreConfig, WindowOptions};
+use crate::core::actions::generate_pixel_buffer::histogram::ColourSchemeKind;
+use crate::core::data::complex::Complex;
+use crate::core::data::complex_rect::ComplexRect;
+use crate::core::data::deep_complex::DeepComplex;
+use crate::core::data::deep_region::DeepRegion;
+use crate::core::flight::{FlightLimits, FlightLimitsError};
+use crate::core::fractals::fractal_kinds::FractalKinds;
+use crate::core::fractals::parse_kind::ParseKindError;
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum ExploreConfigError {
+    Read { path: PathBuf, source: std::io::Error },
+    Parse(serde_json::Error),
+    Kind(ParseKindError),
+    ZeroMaxIterations,
+    ZeroWindowSize,
+    InvalidView { centre: [f64; 2], extent: [f64; 2] },
+    InvalidFlightLimit { field: &'static str },
+}
+
+impl fmt::Display for ExploreConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read { path, source } => {
+                write!(f, "cannot read config file {}: {}", path.display(), source)
+            }
+            Self::Parse(e) => write!(f, "invalid config file: {}", e),
+            Self::Kind(e) => write!(f, "invalid config file: {}", e),
+            Self::ZeroMaxIterations => {
+                write!(f, "invalid config file: max_iterations must be greater than zero")
+            }
+            Self::ZeroWindowSize => {
+                write!(f, "invalid config file: window width, height and max_buffer_dimension must be greater than zero")
+            }
+            Self::InvalidView { centre, extent } => write!(
+                f,
+                "invalid config file: view centred on ({}, {}) with extent {}x{} is not a valid region",
+                centre[0], centre[1], extent[0], extent[1]
+            ),
+            Self::InvalidFlightLimit { field } => {
+                write!(f, "invalid config file: flight.{} must be positive and finite", field)
+            }
+        }
+    }
+}
+
+impl Error for ExploreConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Read { source, .. } => Some(source),
+            Self::Parse(e) => Some(e),
+            Self::Kind(e) => Some(e),
+            Self::ZeroMaxIterations
+            | Self::ZeroWindowSize
+            | Self::InvalidView { .. }
+            | Self::InvalidFlightLimit { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawExploreConfig {
+    fractal: Option<String>,
+    centre: Option<[f64; 2]>,
+    extent: Option<[f64; 2]>,
+    max_iterations: Option<u32>,
+    colour_map: Option<String>,
+    contrast_stretch: Option<bool>,
+    colour_scheme: Option<String>,
+    flight: FlightLimits,
+    window: WindowOptions,
+}
+
+impl ExploreConfig {
+    /// Reads and validates the config file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ExploreConfigError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|source| ExploreConfigError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        Self::from_json(&text)
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, ExploreConfigError> {
+        let raw: RawExploreConfig =
+            serde_json::from_str(text).map_err(ExploreConfigError::Parse)?;
+
+        let mut config = Self {
+            fractal: match &raw.fractal {
+                Some(name) => name.parse().map_err(ExploreConfigError::Kind)?,
+                None => FractalKinds::default(),
+            },
+            flight_limits: raw.flight,
+            window: raw.window,
+            ..Self::default()
+        };
+
+        raw.flight
+            .validate()
+            .map_err(|FlightLimitsError::InvalidLimit { field }| ExploreConfigError::InvalidFlightLimit { field })?;
+        if raw.max_iterations == Some(0) {
+            return Err(ExploreConfigError::ZeroMaxIterations);
+        }
+        let window = raw.window;
+        if [window.width, window.height, window.max_buffer_dimension].contains(&Some(0)) {
+            return Err(ExploreConfigError::ZeroWindowSize);
+        }
+
+        let colour_scheme: Option<ColourSchemeKind> = raw
+            .colour_scheme
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .map_err(ExploreConfigError::Kind)?;
+
+        match config.fractal {
+            FractalKinds::Mandelbrot => {
+                let mandelbrot = &mut config.mandelbrot;
+                mandelbrot.region = mandelbrot_region(&mandelbrot.region, raw.centre, raw.extent)?;
+                mandelbrot.max_iterations = raw.max_iterations.unwrap_or(mandelbrot.max_iterations);
+                if let Some(name) = &raw.colour_map {
+                    mandelbrot.colour_map_kind = name.parse().map_err(ExploreConfigError::Kind)?;
+                }
+                mandelbrot.contrast_stretch =
+                    raw.contrast_stretch.unwrap_or(mandelbrot.contrast_stretch);
+                mandelbrot.colour_scheme = colour_scheme.unwrap_or(mandelbrot.colour_scheme);
+            }
+            FractalKinds::Julia => {
+                let julia = &mut config.julia;
+                julia.region = rect_region(julia.region, raw.centre, raw.extent)?;
+                julia.max_iterations = raw.max_iterations.unwrap_or(julia.max_iterations);
+                if let Some(name) = &raw.colour_map {
+                    julia.colour_map_kind = name.parse().map_err(ExploreConfigError::Kind)?;
+                }
+                julia.contrast_stretch = raw.contrast_stretch.unwrap_or(julia.contrast_stretch);
+                julia.colour_scheme = colour_scheme.unwrap_or(julia.colour_scheme);
+            }
+            FractalKinds::BurningShip => {
+                let ship = &mut config.burning_ship;
+                ship.region = rect_region(ship.region, raw.centre, raw.extent)?;
+                ship.max_iterations = raw.max_iterations.unwrap_or(ship.max_iterations);
+                if let Some(name) = &raw.colour_map {
+                    ship.colour_map_kind = name.parse().map_err(ExploreConfigError::Kind)?;
+                }
+                ship.contrast_stretch = raw.contrast_stretch.unwrap_or(ship.contrast_stretch);
+                ship.colour_scheme = colour_scheme.unwrap_or(ship.colour_scheme);
+            }
+            FractalKinds::Tricorn => {
+                let tricorn = &mut config.tricorn;
+                tricorn.region = rect_region(tricorn.region, raw.centre, raw.extent)?;
+                tricorn.max_iterations = raw.max_iterations.unwrap_or(tricorn.max_iterations);
+                if let Some(name) = &raw.colour_map {
+                    tricorn.colour_map_kind = name.parse().map_err(ExploreConfigError::Kind)?;
+                }
+                tricorn.contrast_stretch =
+                    raw.contrast_stretch.unwrap_or(tricorn.contrast_stretch);
+                tricorn.colour_scheme = colour_scheme.unwrap_or(tricorn.colour_scheme);
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+fn mandelbrot_region(
+    default: &DeepRegion,
+    centre: Option<[f64; 2]>,
+    extent: Option<[f64; 2]>,
+) -> Result<DeepRegion, ExploreConfigError> {
+    if centre.is_none() && extent.is_none() {
+        return Ok(default.clone());
+    }
+
+    let centre = centre.unwrap_or_else(|| {
+        let (re, im) = default.centre().to_f64();
+        [re, im]
+    });
+    let extent = extent.unwrap_or([default.width(), default.height()]);
+
+    let deep_centre = DeepComplex::from_f64(centre[0], centre[1])
+        .ok_or_else(|| invalid_view(centre, extent))?;
+
+    DeepRegion::new(deep_centre, extent[0], extent[1]).map_err(|_| invalid_view(centre, extent))
+}
+
+/// An f64 view (Julia, Burning Ship or Tricorn) from `centre` and `extent`, each
+/// defaulting to `default`'s.
+fn rect_region(
+    default: ComplexRect,
+    centre: Option<[f64; 2]>,
+    extent: Option<[f64; 2]>,
+) -> Result<ComplexRect, ExploreConfigError> {
+    if centre.is_none() && extent.is_none() {
+        return Ok(default);
+    }
+
+    let top_left = default.top_left();
+    let centre = centre.unwrap_or([
+        top_left.real + default.width() / 2.0,
+        top_left.imag + default.height() / 2.0,
+    ]);
+    let extent = extent.unwrap_or([default.width(), default.height()]);
+
+    if !(centre[0].is_finite() && centre[1].is_finite()) {
+        return Err(invalid_view(centre, extent));
+    }
+
+    ComplexRect::from_centre_size(
+        Complex {
+            real: centre[0],
+            imag: centre[1],
+        },
+        extent[0],
+        extent[1],
+    )
+    .map_err(|_| invalid_view(centre, extent))
+}
+
+fn invalid_view(centre: [f64; 2], extent: [f64; 2]) -> ExploreConfigError {
+    ExploreConfigError::InvalidView { centre, extent }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::flight::{BoundaryBehavior, ZoomControlMode};
+    use crate::core::fractals::burning_ship::burning_ship_config::BurningShipConfig;
+    use crate::core::fractals::julia::colour_mapping::kinds::JuliaColourMapKinds;
+    use crate::core::fractals::julia::julia_config::JuliaConfig;
+    use crate::core::fractals::mandelbrot::colour_mapping::kinds::MandelbrotColourMapKinds;
+    use crate::core::fractals::mandelbrot::mandelbrot_config::MandelbrotConfig;
+    use crate::core::fractals::tricorn::tricorn_config::TricornConfig;
+
+    #[test]
+    fn empty_config_keeps_every_default() {
+        assert_eq!(ExploreConfig::from_json("{}").unwrap(), ExploreConfig::default());
+    }
+
+    #[test]
+    fn loads_a_mandelbrot_view_and_settings() {
+        let config = ExploreConfig::from_json(
+            r#"{
+                "fractal": "mandelbrot",
+                "centre": [-0.75, 0.1],
+                "extent": [0.5, 0.25],
+                "max_iterations": 2000,
+                "colour_map": "Ice",
+                "contrast_stretch": true,
+                "colour_scheme": "histogram",
+                "flight": { "zoom_mode": "Direct", "max_speed_abs_world_per_sec": 3.0, "boundary": "bounce" },
+                "window": { "width": 1280, "height": 720, "continuous": false }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.fractal, FractalKinds::Mandelbrot);
+        assert_eq!(config.mandelbrot.region.centre().to_f64(), (-0.75, 0.1));
+        assert_eq!(config.mandelbrot.region.width(), 0.5);
+        assert_eq!(config.mandelbrot.region.height(), 0.25);
+        assert_eq!(config.mandelbrot.max_iterations, 2000);
+        assert_eq!(config.mandelbrot.colour_map_kind, MandelbrotColourMapKinds::BlueWhiteGradient);
+        assert!(config.mandelbrot.contrast_stretch);
+        assert_eq!(config.mandelbrot.colour_scheme, ColourSchemeKind::Histogram);
+        assert_eq!(config.julia, JuliaConfig::default());
+        assert_eq!(config.flight_limits.zoom_mode, ZoomControlMode::Direct);
+        assert_eq!(config.flight_limits.boundary, BoundaryBehavior::Bounce);
+        assert_eq!(config.flight_limits.max_speed_abs_world_per_sec, 3.0);
+        assert_eq!(config.flight_limits.tick_hz, FlightLimits::default().tick_hz);
+        assert_eq!(
+            config.window,
+            WindowOptions {
+                width: Some(1280),
+                height: Some(720),
+                continuous: Some(false),
+                max_buffer_dimension: None,
+            }
+        );
+    }
+
+    #[test]
+    fn julia_view_applies_to_julia_only() {
+        let config = ExploreConfig::from_json(
+            r#"{ "fractal": "Julia", "centre": [0.0, 0.0], "extent": [2.0, 1.0], "colour_map": "Fire" }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.fractal, FractalKinds::Julia);
+        assert_eq!(
+            config.julia.region,
+            ComplexRect::from_centre_size(Complex { real: 0.0, imag: 0.0 }, 2.0, 1.0).unwrap()
+        );
+        assert_eq!(config.julia.colour_map_kind, JuliaColourMapKinds::FireGradient);
+        assert_eq!(config.mandelbrot, MandelbrotConfig::default());
+    }
+
+    #[test]
+    fn burning_ship_takes_mandelbrot_colour_maps() {
+        let config = ExploreConfig::from_json(
+            r#"{ "fractal": "burning ship", "centre": [-1.75, -0.03], "extent": [0.05, 0.05], "colour_map": "Ice" }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.fractal, FractalKinds::BurningShip);
+        assert_eq!(
+            config.burning_ship.region,
+            ComplexRect::from_centre_size(Complex { real: -1.75, imag: -0.03 }, 0.05, 0.05).unwrap()
+        );
+        assert_eq!(
+            config.burning_ship.colour_map_kind,
+            MandelbrotColourMapKinds::BlueWhiteGradient
+        );
+        assert_eq!(config.julia, JuliaConfig::default());
+    }
+
+    #[test]
+    fn tricorn_keeps_its_own_view() {
+        let config =
+            ExploreConfig::from_json(r#"{ "fractal": "Tricorn", "max_iterations": 500 }"#).unwrap();
+
+        assert_eq!(config.fractal, FractalKinds::Tricorn);
+        assert_eq!(config.tricorn.max_iterations, 500);
+        assert_eq!(config.tricorn.region, TricornConfig::default().region);
+        assert_eq!(config.burning_ship, BurningShipConfig::default());
+    }
+
+    #[test]
+    fn rejects_malformed_json_and_unknown_fields() {
+        assert!(matches!(
+            ExploreConfig::from_json(r#"{ "fractal": "Mandelbrot", "#),
+            Err(ExploreConfigError::Parse(_))
+        ));
+        assert!(matches!(
+            ExploreConfig::from_json(r#"{ "max_iteration": 10 }"#),
+            Err(ExploreConfigError::Parse(_))
+        ));
+        assert!(matches!(
+            ExploreConfig::from_json(r#"{ "max_iterations": -1 }"#),
+            Err(ExploreConfigError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        assert!(matches!(
+            ExploreConfig::from_json(r#"{ "fractal": "Newton" }"#),
+            Err(ExploreConfigError::Kind(_))
+        ));
+        assert!(matches!(
+            ExploreConfig::from_json(r#"{ "colour_map": "Sepia" }"#),
+            Err(ExploreConfigError::Kind(_))
+        ));
+        assert!(matches!(
+            ExploreConfig::from_json(r#"{ "max_iterations": 0 }"#),
+            Err(ExploreConfigError::ZeroMaxIterations)
+        ));
+        assert!(matches!(
+            ExploreConfig::from_json(r#"{ "window": { "height": 0 } }"#),
+            Err(ExploreConfigError::ZeroWindowSize)
+        ));
+        assert!(matches!(
+            ExploreConfig::from_json(r#"{ "extent": [0.0, 1.0] }"#),
+            Err(ExploreConfigError::InvalidView { .. })
+        ));
+        assert!(matches!(
+            ExploreConfig::from_json(r#"{ "flight": { "zoom_base": -2.0 } }"#),
+            Err(ExploreConfigError::InvalidFlightLimit { field: "zoom_base" })
+        ));
+        let unknown_mode = ExploreConfig::from_json(r#"{ "flight": { "zoom_mode": "warp" } }"#).unwrap_err();
+        assert!(matches!(unknown_mode, ExploreConfigError::Parse(_)));
+        assert!(unknown_mode.to_string().contains("unknown zoom mode 'warp'"));
+        let unknown_boundary =
+            ExploreConfig::from_json(r#"{ "flight": { "boundary": "teleport" } }"#).unwrap_err();
+        assert!(unknown_boundary.to_string().contains("unknown boundary 'teleport'"));
+    }
+
+    #[test]
+    fn missing_file_names_the_path() {
+        let error = ExploreConfig::load("does/not/exist.json").unwrap_err();
+
+        assert!(matches!(error, ExploreConfigError::Read { .. }));
+        assert!(error.to_string().contains("does/not/exist.json"));
+    }
+}
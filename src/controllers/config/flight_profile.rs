@@ -0,0 +1,179 @@
+use crate::core::flight::{FlightLimits, FlightLimitsError};
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum FlightProfileError {
+    Read { path: PathBuf, source: std::io::Error },
+    Write { path: PathBuf, source: std::io::Error },
+    Parse(serde_json::Error),
+    InvalidLimit { field: &'static str },
+}
+
+impl fmt::Display for FlightProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read { path, source } => {
+                write!(f, "cannot read flight profile {}: {}", path.display(), source)
+            }
+            Self::Write { path, source } => {
+                write!(f, "cannot write flight profile {}: {}", path.display(), source)
+            }
+            Self::Parse(e) => write!(f, "invalid flight profile: {}", e),
+            Self::InvalidLimit { field } => {
+                write!(f, "invalid flight profile: {} must be positive and finite", field)
+            }
+        }
+    }
+}
+
+impl Error for FlightProfileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Read { source, .. } | Self::Write { source, .. } => Some(source),
+            Self::Parse(e) => Some(e),
+            Self::InvalidLimit { .. } => None,
+        }
+    }
+}
+
+/// Reads and validates the flight profile at `path`, ready for
+/// `FlightSimulator::set_limits`.
+///
+/// A profile is `FlightLimits` as JSON, as [`save_flight_profile`] writes
+/// it. Missing fields keep their defaults, so a hand-written profile can
+/// set just the ones being tuned:
+///
+/// ```json
+/// { "steer_strength": 0.8, "zoom_mode": "direct", "boundary": "wrap" }
+/// ```
+///
+/// Mode names ignore case and surrounding whitespace, as in a config
+/// file's `flight` section, which is read the same way.
+pub fn load_flight_profile(path: impl AsRef<Path>) -> Result<FlightLimits, FlightProfileError> {
+    let path = path.as_ref();
+    let text = std::fs::read_to_string(path).map_err(|source| FlightProfileError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    flight_profile_from_json(&text)
+}
+
+/// Writes `limits` to `path` as pretty-printed JSON.
+pub fn save_flight_profile(limits: &FlightLimits, path: impl AsRef<Path>) -> Result<(), FlightProfileError> {
+    let path = path.as_ref();
+    std::fs::write(path, flight_profile_to_json(limits)).map_err(|source| FlightProfileError::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+pub fn flight_profile_from_json(text: &str) -> Result<FlightLimits, FlightProfileError> {
+    let limits: FlightLimits = serde_json::from_str(text).map_err(FlightProfileError::Parse)?;
+    limits
+        .validate()
+        .map_err(|FlightLimitsError::InvalidLimit { field }| FlightProfileError::InvalidLimit { field })?;
+    Ok(limits)
+}
+
+#[must_use]
+pub fn flight_profile_to_json(limits: &FlightLimits) -> String {
+    // Every field is a number or a unit enum, which always serialize.
+    serde_json::to_string_pretty(limits).expect("flight limits serialize to JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::util::temp_file_path::temp_file_path;
+    use crate::controllers::interactive::flight::simulator::FlightSimulator;
+    use crate::core::flight::{BoundaryBehavior, ZoomControlMode};
+
+    fn custom_limits() -> FlightLimits {
+        FlightLimits {
+            tick_hz: 120,
+            base_accel_world_per_sec2: 0.75,
+            max_speed_abs_world_per_sec: 3.5,
+            steer_strength: 0.8,
+            boundary: BoundaryBehavior::Wrap,
+            zoom_mode: ZoomControlMode::Direct,
+            direct_zoom_speed_world_per_sec: 1.25,
+            ..FlightLimits::default()
+        }
+    }
+
+    #[test]
+    fn custom_limits_round_trip_through_json() {
+        let json = flight_profile_to_json(&custom_limits());
+
+        assert!(json.contains("\"zoom_mode\": \"direct\""));
+        assert!(json.contains("\"boundary\": \"wrap\""));
+        assert_eq!(flight_profile_from_json(&json).unwrap(), custom_limits());
+    }
+
+    #[test]
+    fn a_saved_profile_loads_and_applies_to_the_simulator() {
+        let path = temp_file_path("flight_profile", "json");
+        save_flight_profile(&custom_limits(), &path).unwrap();
+
+        let loaded = load_flight_profile(&path);
+        std::fs::remove_file(&path).unwrap();
+        let mut simulator = FlightSimulator::new(FlightLimits::default());
+        simulator.set_limits(loaded.unwrap());
+
+        assert_eq!(*simulator.limits(), custom_limits());
+    }
+
+    #[test]
+    fn missing_fields_keep_their_defaults() {
+        let limits = flight_profile_from_json(r#"{ "steer_strength": 0.8 }"#).unwrap();
+
+        assert_eq!(
+            limits,
+            FlightLimits {
+                steer_strength: 0.8,
+                ..FlightLimits::default()
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_profiles_are_rejected() {
+        assert!(matches!(
+            flight_profile_from_json(r#"{ "tick_hz": 0 }"#),
+            Err(FlightProfileError::InvalidLimit { field: "tick_hz" })
+        ));
+        assert!(matches!(
+            flight_profile_from_json(r#"{ "zoom_base": -2.0 }"#),
+            Err(FlightProfileError::InvalidLimit { field: "zoom_base" })
+        ));
+        assert!(matches!(
+            flight_profile_from_json(r#"{ "steer": 0.8 }"#),
+            Err(FlightProfileError::Parse(_))
+        ));
+        assert!(matches!(
+            flight_profile_from_json(r#"{ "boundary": "teleport" }"#),
+            Err(FlightProfileError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn mode_names_ignore_case_as_in_config_files() {
+        let limits = flight_profile_from_json(r#"{ "zoom_mode": " Direct ", "boundary": "WRAP" }"#).unwrap();
+
+        assert_eq!(limits.zoom_mode, ZoomControlMode::Direct);
+        assert_eq!(limits.boundary, BoundaryBehavior::Wrap);
+    }
+
+    #[test]
+    fn a_missing_file_is_a_read_error() {
+        let path = temp_file_path("missing_flight_profile", "json");
+
+        assert!(matches!(
+            load_flight_profile(&path),
+            Err(FlightProfileError::Read { .. })
+        ));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod explore_config;
+#[cfg(feature = "config")]
+pub mod explore_config_file;
+#[cfg(feature = "serde")]
+pub mod flight_profile;
@@ -1,31 +1,77 @@
 use crate::controllers::interactive::data::fractal_config::FractalConfig;
 use crate::controllers::interactive::data::frame_data::FrameData;
+use crate::controllers::interactive::data::frame_metrics::{FrameMetrics, FrameMetricsCallback};
+#[cfg(feature = "frame-checksum")]
+use crate::controllers::interactive::data::frame_data::buffer_checksum;
 use crate::controllers::interactive::errors::render::RenderError;
 use crate::controllers::interactive::events::render::RenderEvent;
+use crate::controllers::interactive::generation::next_generation;
+use crate::controllers::interactive::detail_budget::DetailBudget;
+use crate::controllers::interactive::load_tracker::LoadTracker;
 use crate::controllers::interactive::ports::gpu_renderer::GpuFractalRendererPort;
 use crate::controllers::interactive::ports::presenter::InteractiveControllerPresenterPort;
 use crate::core::actions::cancellation::CancelToken;
-use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::actions::contrast_stretch::ContrastStretchColourMap;
+use crate::core::actions::diff_iterations::diff_iterations_cancelable;
+use crate::core::actions::generate_fractal::generate_fractal_parallel_rayon::{
+    GenerateFractalError, generate_fractal_parallel_rayon_cancelable,
+};
 use crate::core::actions::generate_pixel_buffer::generate_pixel_buffer::{
-    GeneratePixelBufferCancelableError, generate_pixel_buffer_cancelable,
+    GeneratePixelBufferCancelableError, generate_pixel_buffer_counts_cancelable,
+    generate_pixel_buffer_histogram_cancelable, generate_pixel_buffer_parallel_cancelable,
 };
+use crate::core::actions::generate_pixel_buffer::histogram::HistogramColourMap;
 use crate::core::actions::render_pixel_buffer::{
     RenderPixelBufferCancelableError, render_pixel_buffer_parallel_rayon_cancelable,
 };
+use crate::core::actions::smooth_colour::SmoothColourMap;
 use crate::core::data::pixel_buffer::PixelBuffer;
+use crate::core::data::pixel_rect::PixelRect;
 use crate::core::fractals::mandelbrot::render_path::MandelbrotRenderPath;
+use crate::core::fractals::smooth_escape::{SmoothAlgorithm, SmoothEscape};
+use crate::core::util::pixel_to_complex_coords::PixelToComplexCoordsError;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::{Arc, Condvar, Mutex};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
 use std::thread::{self, JoinHandle};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 struct SharedState {
+    /// The newest generation issued, advanced by `next_generation`.
     generation: AtomicU64,
     last_completed_generation: AtomicU64,
-    latest_request: Mutex<Option<(u64, Arc<FractalConfig>)>>,
+    /// The newest request, its generation and when it was submitted.
+    latest_request: Mutex<Option<(u64, Arc<FractalConfig>, Instant)>>,
     wake: Condvar,
     shutdown: AtomicBool,
+    /// Colour each frame on a second thread while the next is computed;
+    /// see `with_pipelined_colouring`.
+    pipelined: AtomicBool,
+    /// Send shallow (direct) Mandelbrot renders to the GPU renderer too;
+    /// see `set_gpu_direct_rendering`.
+    gpu_direct: AtomicBool,
+    /// Whether the worker is rendering the newest request; see
+    /// `is_rendering`.
+    rendering: AtomicBool,
+    load: Mutex<LoadTracker>,
+    /// Fed every freshly rendered frame's duration; see
+    /// `with_detail_budget`.
+    detail_budget: Mutex<Option<DetailBudget>>,
+    /// The last successfully rendered request and its frame. An identical
+    /// follow-up request re-emits this under its own generation instead of
+    /// recomputing it. The buffer is the one the presenter was handed, not
+    /// a copy.
+    last_frame: Mutex<Option<(Arc<FractalConfig>, Arc<PixelBuffer>)>>,
+    /// With `Some`, the worker drops `last_frame` after waiting this long
+    /// without a request; see `with_idle_cleanup`.
+    idle_timeout: Mutex<Option<Duration>>,
+    /// Cleared by `shutdown` before the worker is joined; held while
+    /// presenting so no `present` call can start once output is closed.
+    accepting_output: Mutex<bool>,
     presenter_port: Arc<dyn InteractiveControllerPresenterPort>,
+    metrics_callback: Mutex<Option<FrameMetricsCallback>>,
 }
 
 pub struct InteractiveController {
@@ -44,7 +90,16 @@ impl InteractiveController {
             latest_request: Mutex::new(None),
             wake: Condvar::new(),
             shutdown: AtomicBool::new(false),
+            pipelined: AtomicBool::new(false),
+            gpu_direct: AtomicBool::new(false),
+            rendering: AtomicBool::new(false),
+            load: Mutex::new(LoadTracker::default()),
+            detail_budget: Mutex::new(None),
+            last_frame: Mutex::new(None),
+            idle_timeout: Mutex::new(None),
+            accepting_output: Mutex::new(true),
             presenter_port,
+            metrics_callback: Mutex::new(None),
         });
 
         let worker_shared = Arc::clone(&shared);
@@ -59,12 +114,116 @@ impl InteractiveController {
         }
     }
 
+    /// Splits rendering into two stages on separate threads: the worker
+    /// computes a frame's iteration field and hands it to a colour stage,
+    /// then starts on the next request while that field is coloured. Off
+    /// by default. Staleness is unchanged: a field whose request has been
+    /// superseded is dropped, mid-colour if need be, and never presented.
+    #[must_use]
+    pub fn with_pipelined_colouring(self, enabled: bool) -> Self {
+        self.shared.pipelined.store(enabled, Ordering::Relaxed);
+        self
+    }
+
+    /// Starts with GPU rendering of shallow Mandelbrot views on or off; see
+    /// `set_gpu_direct_rendering`.
+    #[must_use]
+    pub fn with_gpu_direct_rendering(self, enabled: bool) -> Self {
+        self.set_gpu_direct_rendering(enabled);
+        self
+    }
+
+    /// Switches shallow (direct) Mandelbrot renders between the CPU and the
+    /// GPU renderer's compute shader, from the next request on. Deep zooms
+    /// use the GPU whenever a renderer is available, as before. Off by
+    /// default; the CPU path is the reference, and renders the GPU declines
+    /// (or any render without a GPU renderer) fall back to it. Changing the
+    /// setting drops the cached last frame so an identical request is
+    /// rendered again rather than re-emitted.
+    pub fn set_gpu_direct_rendering(&self, enabled: bool) {
+        if self.shared.gpu_direct.swap(enabled, Ordering::Relaxed) != enabled {
+            self.clear_caches();
+        }
+    }
+
+    #[must_use]
+    pub fn gpu_direct_rendering(&self) -> bool {
+        self.shared.gpu_direct.load(Ordering::Relaxed)
+    }
+
+    /// Drops the cached last frame once the worker has waited `timeout`
+    /// without a new request, freeing its buffer while the app sits idle.
+    /// The next request renders from scratch, even if it repeats the last
+    /// one. Off by default.
+    #[must_use]
+    pub fn with_idle_cleanup(self, timeout: Duration) -> Self {
+        *lock(&self.shared.idle_timeout) = Some(timeout);
+        self
+    }
+
+    /// Tunes the iteration budget toward `budget`'s target frame time from
+    /// the duration of every frame actually rendered (cached re-emits and
+    /// cancelled renders are not measured). Callers read the result from
+    /// `detail_iterations` when building requests. Off by default.
+    #[must_use]
+    pub fn with_detail_budget(self, budget: DetailBudget) -> Self {
+        *lock(&self.shared.detail_budget) = Some(budget);
+        self
+    }
+
+    /// The iteration budget for the next request under
+    /// `with_detail_budget`, or `None` when no budget is set.
+    #[must_use]
+    pub fn detail_iterations(&self) -> Option<u32> {
+        lock(&self.shared.detail_budget)
+            .as_ref()
+            .map(DetailBudget::iterations)
+    }
+
+    /// Starts the generation counter at `generation`, to exercise the
+    /// counter wrapping.
+    #[cfg(test)]
+    fn with_generation(self, generation: u64) -> Self {
+        self.shared.generation.store(generation, Ordering::SeqCst);
+        self
+    }
+
+    /// Drops the cached last frame now; see `with_idle_cleanup`.
+    pub fn clear_caches(&self) {
+        *lock(&self.shared.last_frame) = None;
+    }
+
+    /// Registers `callback` to receive a [`FrameMetrics`] for every frame
+    /// presented from now on, replacing any earlier callback. It runs on
+    /// the render worker straight after `present`, so it should be quick;
+    /// like `present`, it is never called once `shutdown` has returned.
+    pub fn set_metrics_callback(&self, callback: FrameMetricsCallback) {
+        *lock(&self.shared.metrics_callback) = Some(callback);
+    }
+
+    /// Queues `request` as the newest render. The render in progress (if
+    /// any) is superseded and stops at its next cancellation check, in
+    /// either the compute or the colour pass; callers that want to avoid
+    /// that decide when to submit (see `RenderScheduler`). A request equal
+    /// to the last one rendered re-emits that frame without recomputing it.
+    ///
+    /// Returns the request's generation, newer (see `generation::is_newer`)
+    /// than every one returned before it, even across the counter wrapping.
     pub fn submit_request(&self, request: Arc<FractalConfig>) -> u64 {
-        let generation = self.shared.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let previous = self
+            .shared
+            .generation
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
+                Some(next_generation(current))
+            })
+            .expect("the generation update never declines");
+        let generation = next_generation(previous);
+        let requested_at = Instant::now();
+        lock(&self.shared.load).record_submission(requested_at);
 
         {
-            let mut guard = self.shared.latest_request.lock().unwrap();
-            *guard = Some((generation, request));
+            let mut guard = lock(&self.shared.latest_request);
+            *guard = Some((generation, request, requested_at));
         }
 
         self.shared.wake.notify_one();
@@ -72,7 +231,13 @@ impl InteractiveController {
         generation
     }
 
+    /// Stops the render worker, in a fixed order: output is closed first
+    /// (no `present` call starts after that), then the worker is woken and
+    /// joined. Once this returns the controller never touches the presenter
+    /// port again, so the presenter can be dropped safely. Idempotent; also
+    /// called on drop.
     pub fn shutdown(&mut self) {
+        *lock(&self.shared.accepting_output) = false;
         self.shared.shutdown.store(true, Ordering::Release);
         self.shared.wake.notify_one();
 
@@ -81,6 +246,24 @@ impl InteractiveController {
         }
     }
 
+    /// Quality factor in `(0, 1]` for the next request: below one while
+    /// renders have been falling behind the rate requests arrive (see
+    /// [`LoadTracker`]). Callers scale their iteration budget by it.
+    #[must_use]
+    pub fn load_quality(&self) -> f64 {
+        lock(&self.shared.load).quality(Instant::now())
+    }
+
+    /// Whether a render of the newest request is in flight: true from when
+    /// the worker picks a request up until just before its frame (or error)
+    /// is presented. Superseded renders winding down don't count, so this
+    /// is false once the newest frame has arrived. For UI affordances such
+    /// as a spinner.
+    #[must_use]
+    pub fn is_rendering(&self) -> bool {
+        self.shared.rendering.load(Ordering::Acquire)
+    }
+
     #[must_use]
     pub fn last_completed_generation(&self) -> u64 {
         self.shared
@@ -92,72 +275,303 @@ impl InteractiveController {
         shared: &Arc<SharedState>,
         mut gpu_renderer: Option<Box<dyn GpuFractalRendererPort>>,
     ) {
-        loop {
-            let (job_generation, request) = {
-                let mut guard = shared.latest_request.lock().unwrap();
-                loop {
-                    if shared.shutdown.load(Ordering::Acquire) {
-                        return;
+        thread::scope(|scope| {
+            // Rendezvous channel to the colour stage, started on the first
+            // pipelined job: at most one field is being coloured while the
+            // next is computed. Dropped when this closure returns, which
+            // ends the colour stage before the scope joins it.
+            let mut colour_stage: Option<SyncSender<ColourJob>> = None;
+
+            loop {
+                let (job_generation, request, requested_at) = {
+                    let mut guard = lock(&shared.latest_request);
+                    let idle_since = Instant::now();
+                    let mut idle_cleanup = *lock(&shared.idle_timeout);
+                    loop {
+                        if shared.shutdown.load(Ordering::Acquire) {
+                            return;
+                        }
+
+                        if let Some(req) = guard.take() {
+                            shared.rendering.store(true, Ordering::Release);
+                            break req;
+                        }
+
+                        let Some(timeout) = idle_cleanup else {
+                            guard = shared.wake.wait(guard).unwrap_or_else(PoisonError::into_inner);
+                            continue;
+                        };
+
+                        let remaining = timeout.saturating_sub(idle_since.elapsed());
+                        if remaining.is_zero() {
+                            *lock(&shared.last_frame) = None;
+                            idle_cleanup = None;
+                            continue;
+                        }
+
+                        guard = shared
+                            .wake
+                            .wait_timeout(guard, remaining)
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .0;
                     }
-
-                    if let Some(req) = guard.take() {
-                        break req;
+                };
+
+                let start = Instant::now();
+                let job = panic::catch_unwind(AssertUnwindSafe(|| {
+                    Self::start_job(shared, job_generation, &request, requested_at, start, gpu_renderer.as_deref_mut())
+                }));
+                let iterations = match job {
+                    Ok(Some(iterations)) => iterations,
+                    Ok(None) => continue,
+                    Err(payload) => {
+                        Self::report_panic(shared, job_generation, requested_at, payload.as_ref(), start);
+                        continue;
                     }
+                };
 
-                    guard = shared.wake.wait(guard).unwrap();
+                let sender = colour_stage.get_or_insert_with(|| {
+                    let (sender, receiver) = mpsc::sync_channel(0);
+                    scope.spawn(move || Self::colour_stage_loop(shared, &receiver));
+                    sender
+                });
+                let job = ColourJob {
+                    generation: job_generation,
+                    request,
+                    iterations,
+                    requested_at,
+                    start,
+                };
+                if sender.send(job).is_err() {
+                    return;
                 }
-            };
+            }
+        });
+    }
+
+    /// Second pipeline stage: colours computed fields in order, dropping
+    /// any whose generation has been superseded.
+    fn colour_stage_loop(
+        shared: &SharedState,
+        receiver: &Receiver<ColourJob>,
+    ) {
+        for job in receiver {
+            let (generation, requested_at, start) = (job.generation, job.requested_at, job.start);
+            let coloured = panic::catch_unwind(AssertUnwindSafe(|| {
+                let cancel_token = || Self::is_superseded(shared, job.generation);
+                let pixel_rect = job.request.algorithm().pixel_rect();
+
+                let result =
+                    Self::colour_iterations(job.iterations, &job.request, pixel_rect, &cancel_token)
+                        .map(Arc::new)
+                        .inspect(|pixel_buffer| {
+                            *lock(&shared.last_frame) =
+                                Some((Arc::clone(&job.request), Arc::clone(pixel_buffer)));
+                            Self::record_detail(shared, job.start.elapsed(), &job.request);
+                        });
+
+                Self::emit_result(shared, job.generation, job.requested_at, result, job.start.elapsed(), false);
+            }));
+
+            if let Err(payload) = coloured {
+                Self::report_panic(shared, generation, requested_at, payload.as_ref(), start);
+            }
+        }
+    }
 
-            let cancel_token = || {
-                shared.shutdown.load(Ordering::Relaxed)
-                    || job_generation != shared.generation.load(Ordering::Relaxed)
-            };
+    /// Serves `request` from the frame cache, or renders and emits it, or
+    /// (pipelined) computes its field and returns it for the colour stage.
+    fn start_job(
+        shared: &SharedState,
+        job_generation: u64,
+        request: &Arc<FractalConfig>,
+        requested_at: Instant,
+        start: Instant,
+        gpu_renderer: Option<&mut (dyn GpuFractalRendererPort + 'static)>,
+    ) -> Option<Vec<u32>> {
+        let cancel_token = || Self::is_superseded(shared, job_generation);
+
+        let cached = lock(&shared.last_frame)
+            .as_ref()
+            .filter(|(config, _)| Arc::ptr_eq(config, request) || **config == **request)
+            .map(|(_, pixel_buffer)| Arc::clone(pixel_buffer));
+        if let Some(pixel_buffer) = cached {
+            Self::emit_result(shared, job_generation, requested_at, Ok(pixel_buffer), start.elapsed(), true);
+            return None;
+        }
 
-            let start = Instant::now();
-            let result =
-                Self::render_request(&request, &cancel_token, gpu_renderer.as_deref_mut());
-            let render_duration = start.elapsed();
+        let gpu_direct = shared.gpu_direct.load(Ordering::Relaxed);
+        // Smooth frames colour f64 counts, which the colour stage doesn't
+        // carry, so they always render in one stage.
+        if !shared.pipelined.load(Ordering::Relaxed) || request.smooth_algorithm().is_some() {
+            let result = Self::render_request(request, &cancel_token, gpu_renderer, gpu_direct)
+                .map(Arc::new)
+                .inspect(|pixel_buffer| {
+                    *lock(&shared.last_frame) = Some((Arc::clone(request), Arc::clone(pixel_buffer)));
+                    Self::record_detail(shared, start.elapsed(), request);
+                });
+            Self::emit_result(shared, job_generation, requested_at, result, start.elapsed(), false);
+            return None;
+        }
 
-            match result {
-                Ok(pixel_buffer) => {
-                    let current_gen = shared.generation.load(Ordering::Acquire);
+        match Self::compute_iterations(request, &cancel_token, gpu_renderer, gpu_direct) {
+            Ok(iterations) => Some(iterations),
+            Err(outcome) => {
+                Self::emit_result(shared, job_generation, requested_at, Err(outcome), start.elapsed(), false);
+                None
+            }
+        }
+    }
 
-                    if job_generation != current_gen {
-                        continue;
-                    }
+    /// Reports a job that panicked as a render error, so the worker can
+    /// carry on with the next request. The panic may have come from the
+    /// presenter itself, so a second one while reporting is swallowed. A
+    /// panic after the job's frame was presented (in the metrics callback,
+    /// say) reports nothing: the generation already has its outcome.
+    fn report_panic(
+        shared: &SharedState,
+        job_generation: u64,
+        requested_at: Instant,
+        payload: &(dyn Any + Send),
+        start: Instant,
+    ) {
+        if shared.last_completed_generation.load(Ordering::Acquire) == job_generation {
+            return;
+        }
+
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+            Self::emit_result(
+                shared,
+                job_generation,
+                requested_at,
+                Err(RenderOutcome::Error(format!("render panicked: {message}"))),
+                start.elapsed(),
+                false,
+            );
+        }));
+    }
+
+    fn record_detail(shared: &SharedState, render_duration: Duration, request: &FractalConfig) {
+        if let Some(budget) = lock(&shared.detail_budget).as_mut() {
+            budget.record_frame(render_duration, request.max_iterations());
+        }
+    }
 
-                    shared.presenter_port.present(RenderEvent::Frame(FrameData {
+    /// Feeds a job's outcome to the load tracker. Cache hits are left out,
+    /// as in [`Self::record_detail`]: their near-zero durations would read
+    /// as spare capacity the renders don't have.
+    fn record_load(
+        load: &mut LoadTracker,
+        result: &Result<Arc<PixelBuffer>, RenderOutcome>,
+        render_duration: Duration,
+        cached: bool,
+    ) {
+        match result {
+            _ if cached => {}
+            Err(RenderOutcome::Cancelled) => load.record_superseded(),
+            _ => load.record_completion(render_duration),
+        }
+    }
+
+    /// A job is stale once any newer generation has been issued. Only
+    /// equality with the newest is tested, which stays correct when the
+    /// counter wraps.
+    fn is_superseded(shared: &SharedState, job_generation: u64) -> bool {
+        shared.shutdown.load(Ordering::Relaxed)
+            || job_generation != shared.generation.load(Ordering::Relaxed)
+    }
+
+    /// Reports a job's outcome. `cached` marks a frame re-emitted from the
+    /// frame cache, which took no rendering.
+    fn emit_result(
+        shared: &SharedState,
+        job_generation: u64,
+        requested_at: Instant,
+        result: Result<Arc<PixelBuffer>, RenderOutcome>,
+        render_duration: Duration,
+        cached: bool,
+    ) {
+        Self::finish_rendering(shared, job_generation);
+        Self::record_load(&mut lock(&shared.load), &result, render_duration, cached);
+
+        match result {
+            Ok(pixel_buffer) => {
+                let single_colour = pixel_buffer.is_single_colour();
+                #[cfg(feature = "frame-checksum")]
+                let checksum = buffer_checksum(&pixel_buffer);
+                Self::emit(
+                    shared,
+                    job_generation,
+                    RenderEvent::Frame(FrameData {
                         generation: job_generation,
                         pixel_buffer,
                         render_duration,
-                    }));
+                        single_colour,
+                        requested_at,
+                        submitted_at: Instant::now(),
+                        #[cfg(feature = "frame-checksum")]
+                        checksum,
+                    }),
+                );
+            }
+            Err(RenderOutcome::Cancelled) => {}
+            Err(RenderOutcome::Error(message)) => {
+                Self::emit(
+                    shared,
+                    job_generation,
+                    RenderEvent::Error(RenderError {
+                        generation: job_generation,
+                        message,
+                    }),
+                );
+            }
+        }
+    }
 
-                    shared
-                        .last_completed_generation
-                        .store(job_generation, Ordering::Release);
-                }
-                Err(RenderOutcome::Cancelled) => {
-                    continue;
-                }
-                Err(RenderOutcome::Error(message)) => {
-                    let current_gen = shared.generation.load(Ordering::Acquire);
+    /// Clears `rendering` once the newest request is done. Checked under the
+    /// request lock, where the worker sets it, so a request submitted
+    /// meanwhile (including while the colour stage finishes an older one)
+    /// keeps it set.
+    fn finish_rendering(shared: &SharedState, job_generation: u64) {
+        let pending = lock(&shared.latest_request);
 
-                    if job_generation != current_gen {
-                        continue;
-                    }
+        if pending.is_none() && job_generation == shared.generation.load(Ordering::Acquire) {
+            shared.rendering.store(false, Ordering::Release);
+        }
+    }
 
-                    shared
-                        .presenter_port
-                        .present(RenderEvent::Error(RenderError {
-                            generation: job_generation,
-                            message,
-                        }));
+    /// Presents the outcome of `job_generation` unless a newer request has
+    /// superseded it or `shutdown` has closed output.
+    fn emit(shared: &SharedState, job_generation: u64, event: RenderEvent) {
+        let accepting_output = lock(&shared.accepting_output);
 
-                    shared
-                        .last_completed_generation
-                        .store(job_generation, Ordering::Release);
-                }
-            }
+        if !*accepting_output || job_generation != shared.generation.load(Ordering::Acquire) {
+            return;
+        }
+
+        let metrics = match &event {
+            RenderEvent::Frame(frame) => Some(FrameMetrics {
+                generation: frame.generation,
+                render_duration: frame.render_duration,
+                pixel_count: frame.pixel_buffer.pixel_rect().size(),
+            }),
+            RenderEvent::Error(_) => None,
+        };
+
+        shared.presenter_port.present(event);
+
+        shared
+            .last_completed_generation
+            .store(job_generation, Ordering::Release);
+
+        if let (Some(metrics), Some(callback)) = (metrics, &*lock(&shared.metrics_callback)) {
+            callback(metrics);
         }
     }
 
@@ -165,62 +579,214 @@ impl InteractiveController {
         request: &FractalConfig,
         cancel: &C,
         gpu_renderer: Option<&mut (dyn GpuFractalRendererPort + 'static)>,
+        gpu_direct: bool,
     ) -> Result<PixelBuffer, RenderOutcome> {
-        // Resolve the perturbation reference orbit (if any) before the
-        // pixel pass; this is the only potentially slow per-frame setup.
-        if request.prepare(cancel).is_err() {
+        Self::prepare(request, cancel)?;
+
+        let pixel_rect = request.algorithm().pixel_rect();
+
+        if let Some(algorithm) = request.smooth_algorithm() {
+            return Self::render_smooth(algorithm, request, pixel_rect, cancel);
+        }
+
+        if let Some(iterations) = Self::gpu_iterations(request, cancel, gpu_renderer, gpu_direct)? {
+            return Self::colour_iterations(iterations, request, pixel_rect, cancel);
+        }
+
+        // Diffs, contrast stretching and histogram colouring need the whole
+        // field before anything can be coloured, so they can't use the fused
+        // compute-and-colour pass.
+        if matches!(request, FractalConfig::MandelbrotReferenceDiff { .. })
+            || request.contrast_stretch().is_some()
+            || request.histogram().is_some()
+        {
+            let iterations = Self::cpu_iterations(request, cancel)?;
+
+            return Self::colour_iterations(iterations, request, pixel_rect, cancel);
+        }
+
+        render_pixel_buffer_parallel_rayon_cancelable(
+            pixel_rect,
+            request.algorithm(),
+            request.colour_map(),
+            cancel,
+        )
+        .map_err(|e| match e {
+            RenderPixelBufferCancelableError::Cancelled(_) => RenderOutcome::Cancelled,
+            RenderPixelBufferCancelableError::Algorithm(err) => RenderOutcome::Error(err.to_string()),
+            RenderPixelBufferCancelableError::ColourMap(err) => RenderOutcome::Error(err.to_string()),
+            RenderPixelBufferCancelableError::PixelBuffer(err) => {
+                RenderOutcome::Error(err.to_string())
+            }
+        })
+    }
+
+    /// The compute half of `render_request`, for the pipelined worker: the
+    /// request's iteration field, left for the colour stage to map.
+    fn compute_iterations<C: CancelToken>(
+        request: &FractalConfig,
+        cancel: &C,
+        gpu_renderer: Option<&mut (dyn GpuFractalRendererPort + 'static)>,
+        gpu_direct: bool,
+    ) -> Result<Vec<u32>, RenderOutcome> {
+        Self::prepare(request, cancel)?;
+
+        match Self::gpu_iterations(request, cancel, gpu_renderer, gpu_direct)? {
+            Some(iterations) => Ok(iterations),
+            None => Self::cpu_iterations(request, cancel),
+        }
+    }
+
+    /// Resolves the perturbation reference orbit (if any) before the pixel
+    /// pass; this is the only potentially slow per-frame setup.
+    fn prepare<C: CancelToken>(request: &FractalConfig, cancel: &C) -> Result<(), RenderOutcome> {
+        request.prepare(cancel).map_err(|_| RenderOutcome::Cancelled)
+    }
+
+    /// Deep-zoom Mandelbrot frames go to the GPU when a renderer is
+    /// available and accepts the request, and so do direct ones with
+    /// `gpu_direct`. `None` means the CPU should render it (including a
+    /// declined GPU render).
+    fn gpu_iterations<C: CancelToken>(
+        request: &FractalConfig,
+        cancel: &C,
+        gpu_renderer: Option<&mut (dyn GpuFractalRendererPort + 'static)>,
+        gpu_direct: bool,
+    ) -> Result<Option<Vec<u32>>, RenderOutcome> {
+        let (Some(gpu), FractalConfig::Mandelbrot { algorithm, .. }) = (gpu_renderer, request) else {
+            return Ok(None);
+        };
+
+        let iterations = match algorithm {
+            MandelbrotRenderPath::Perturbation(perturbation) => gpu.render_iterations(perturbation),
+            MandelbrotRenderPath::Direct(direct) if gpu_direct => gpu.render_direct_iterations(direct),
+            MandelbrotRenderPath::Direct(_) => return Ok(None),
+            #[cfg(feature = "bigfloat")]
+            MandelbrotRenderPath::BigFloat(_) => return Ok(None),
+        };
+
+        if let Some(iterations) = iterations {
+            return Ok(Some(iterations));
+        }
+
+        if cancel.is_cancelled() {
             return Err(RenderOutcome::Cancelled);
         }
 
-        // Deep-zoom Mandelbrot frames go to the GPU when a renderer is
-        // available and accepts the request; anything else (including a
-        // declined GPU render) takes the CPU path below.
-        if let (
-            Some(gpu),
-            FractalConfig::Mandelbrot {
-                algorithm: MandelbrotRenderPath::Perturbation(perturbation),
+        Ok(None)
+    }
+
+    fn cpu_iterations<C: CancelToken>(
+        request: &FractalConfig,
+        cancel: &C,
+    ) -> Result<Vec<u32>, RenderOutcome> {
+        let pixel_rect = request.algorithm().pixel_rect();
+        let iterations = match request {
+            FractalConfig::MandelbrotReferenceDiff {
+                algorithm,
+                reference,
                 ..
-            },
-        ) = (gpu_renderer, request)
-        {
-            if let Some(iterations) = gpu.render_iterations(perturbation) {
-                return generate_pixel_buffer_cancelable(
-                    iterations,
+            } => diff_iterations_cancelable(pixel_rect, algorithm, reference, cancel),
+            _ => generate_fractal_parallel_rayon_cancelable(pixel_rect, request.algorithm(), cancel),
+        };
+
+        iterations.map_err(|e| match e {
+            GenerateFractalError::Cancelled(_) => RenderOutcome::Cancelled,
+            GenerateFractalError::Algorithm(err) => RenderOutcome::Error(err.to_string()),
+        })
+    }
+
+    /// Colours a computed iteration field with the request's colour map,
+    /// equalised over the field's histogram or stretched over its escaped
+    /// range when the request asks.
+    fn colour_iterations<C: CancelToken>(
+        iterations: Vec<u32>,
+        request: &FractalConfig,
+        pixel_rect: PixelRect,
+        cancel: &C,
+    ) -> Result<PixelBuffer, RenderOutcome> {
+        let result = match (request.histogram(), request.contrast_stretch()) {
+            (Some(max_iterations), _) => generate_pixel_buffer_histogram_cancelable(
+                iterations,
+                request.colour_map(),
+                pixel_rect,
+                max_iterations,
+                cancel,
+            ),
+            (None, Some(max_iterations)) => {
+                let colour_map = ContrastStretchColourMap::for_field(
                     request.colour_map(),
-                    perturbation.pixel_rect(),
-                    cancel,
-                )
-                .map_err(|e| match e {
-                    GeneratePixelBufferCancelableError::Cancelled(_) => RenderOutcome::Cancelled,
-                    other => RenderOutcome::Error(other.to_string()),
-                });
+                    &iterations,
+                    max_iterations,
+                );
+                generate_pixel_buffer_counts_cancelable(iterations, &colour_map, pixel_rect, cancel)
             }
-
-            if cancel.is_cancelled() {
-                return Err(RenderOutcome::Cancelled);
+            (None, None) => {
+                generate_pixel_buffer_counts_cancelable(iterations, request.colour_map(), pixel_rect, cancel)
             }
-        }
+        };
 
-        let algorithm = request.algorithm();
-        let colour_map = request.colour_map();
-        let pixel_rect = algorithm.pixel_rect();
+        result.map_err(|e| match e {
+            GeneratePixelBufferCancelableError::Cancelled(_) => RenderOutcome::Cancelled,
+            other => RenderOutcome::Error(other.to_string()),
+        })
+    }
 
-        render_pixel_buffer_parallel_rayon_cancelable(pixel_rect, algorithm, colour_map, cancel)
+    /// Renders continuous escape counts and colours them by blending the
+    /// request's colour map between whole counts, equalised or stretched
+    /// over the whole counts as in `colour_iterations`.
+    fn render_smooth<C: CancelToken>(
+        algorithm: &(dyn SmoothEscape<Failure = PixelToComplexCoordsError> + Sync),
+        request: &FractalConfig,
+        pixel_rect: PixelRect,
+        cancel: &C,
+    ) -> Result<PixelBuffer, RenderOutcome> {
+        let counts = generate_fractal_parallel_rayon_cancelable(pixel_rect, &SmoothAlgorithm(algorithm), cancel)
             .map_err(|e| match e {
-                RenderPixelBufferCancelableError::Cancelled(_) => RenderOutcome::Cancelled,
-                RenderPixelBufferCancelableError::Algorithm(err) => {
-                    RenderOutcome::Error(err.to_string())
-                }
-                RenderPixelBufferCancelableError::ColourMap(err) => {
-                    RenderOutcome::Error(err.to_string())
-                }
-                RenderPixelBufferCancelableError::PixelBuffer(err) => {
-                    RenderOutcome::Error(err.to_string())
-                }
-            })
+                GenerateFractalError::Cancelled(_) => RenderOutcome::Cancelled,
+                GenerateFractalError::Algorithm(err) => RenderOutcome::Error(err.to_string()),
+            })?;
+        let max_iterations = algorithm.max_iterations();
+
+        let whole_counts = || counts.iter().map(|&count| count as u32).collect::<Vec<u32>>();
+        let result = match (request.histogram(), request.contrast_stretch()) {
+            (Some(_), _) => {
+                let equalised =
+                    HistogramColourMap::for_field(request.colour_map(), &whole_counts(), max_iterations);
+                let colour_map = SmoothColourMap::new(&equalised, max_iterations);
+                generate_pixel_buffer_parallel_cancelable(counts, &colour_map, pixel_rect, cancel)
+            }
+            (None, Some(_)) => {
+                let stretched = ContrastStretchColourMap::for_field(
+                    request.colour_map(),
+                    &whole_counts(),
+                    max_iterations,
+                );
+                let colour_map = SmoothColourMap::new(&stretched, max_iterations);
+                generate_pixel_buffer_parallel_cancelable(counts, &colour_map, pixel_rect, cancel)
+            }
+            (None, None) => {
+                let colour_map = SmoothColourMap::new(request.colour_map(), max_iterations);
+                generate_pixel_buffer_parallel_cancelable(counts, &colour_map, pixel_rect, cancel)
+            }
+        };
+
+        result.map_err(|e| match e {
+            GeneratePixelBufferCancelableError::Cancelled(_) => RenderOutcome::Cancelled,
+            other => RenderOutcome::Error(other.to_string()),
+        })
     }
 }
 
+/// A computed field waiting for the colour stage.
+struct ColourJob {
+    generation: u64,
+    request: Arc<FractalConfig>,
+    iterations: Vec<u32>,
+    requested_at: Instant,
+    start: Instant,
+}
+
 enum RenderOutcome {
     Cancelled,
     Error(String),
@@ -232,9 +798,19 @@ impl Drop for InteractiveController {
     }
 }
 
+/// Locks `mutex` even if a panic poisoned it. Every value guarded in
+/// `SharedState` is left consistent between statements, so a render that
+/// panicked mid-frame must not take the caller down with it.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::controllers::interactive::generation::is_newer;
+    use crate::core::actions::generate_pixel_buffer::histogram::ColourSchemeKind;
+    use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
     use std::sync::Mutex;
     use std::thread;
     use std::time::{Duration, Instant};
@@ -247,10 +823,13 @@ mod tests {
     use crate::core::fractals::mandelbrot::colour_mapping::factory::mandelbrot_colour_map_factory;
     use crate::core::fractals::mandelbrot::colour_mapping::kinds::MandelbrotColourMapKinds;
     use crate::core::fractals::mandelbrot::render_path::MandelbrotRenderPath;
+    use crate::core::fractals::optimization_flags::OptimizationFlags;
 
     #[derive(Default)]
     struct MockPresenterPort {
         events: Mutex<Vec<RenderEvent>>,
+        /// Panic instead of recording the next frame.
+        fail_next_frame: AtomicBool,
     }
 
     impl MockPresenterPort {
@@ -262,6 +841,9 @@ mod tests {
 
     impl InteractiveControllerPresenterPort for MockPresenterPort {
         fn present(&self, event: RenderEvent) {
+            if matches!(event, RenderEvent::Frame(_)) && self.fail_next_frame.swap(false, Ordering::Relaxed) {
+                panic!("presenter failed");
+            }
             self.events.lock().unwrap().push(event);
         }
     }
@@ -287,6 +869,15 @@ mod tests {
 
             self.serve_iterations.map(|value| vec![value; pixels])
         }
+
+        fn render_direct_iterations(&mut self, algorithm: &MandelbrotAlgorithm) -> Option<Vec<u32>> {
+            *self.calls.lock().unwrap() += 1;
+
+            let pixel_rect = algorithm.pixel_rect();
+            let pixels = (pixel_rect.width() * pixel_rect.height()) as usize;
+
+            self.serve_iterations.map(|value| vec![value; pixels])
+        }
     }
 
     fn create_perturbation_request(pixel_rect: PixelRect) -> FractalConfig {
@@ -348,6 +939,9 @@ mod tests {
         FractalConfig::Mandelbrot {
             colour_map,
             algorithm: MandelbrotRenderPath::Direct(algorithm),
+            contrast_stretch: false,
+            smooth: false,
+            colour_scheme: ColourSchemeKind::Linear,
         }
     }
 
@@ -373,113 +967,734 @@ mod tests {
         FractalConfig::Mandelbrot {
             colour_map,
             algorithm: MandelbrotRenderPath::Direct(algorithm),
+            contrast_stretch: false,
+            smooth: false,
+            colour_scheme: ColourSchemeKind::Linear,
+        }
+    }
+
+    #[test]
+    fn test_submit_request_emits_frame() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        );
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+        let request = Arc::new(create_test_request(pixel_rect));
+
+        let generation = controller.submit_request(Arc::clone(&request));
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+        assert!(!events.is_empty(), "expected a render event");
+
+        let mut saw_frame = false;
+        for event in events {
+            match event {
+                RenderEvent::Frame(frame) => {
+                    assert_eq!(frame.generation, generation);
+                    assert!(generation > 0, "generation should be non-zero");
+                    assert_eq!(frame.pixel_buffer.pixel_rect(), pixel_rect);
+                    assert_eq!(
+                        frame.pixel_buffer.buffer().len(),
+                        (pixel_rect.width() * pixel_rect.height()) as usize
+                            * PixelBuffer::BYTES_PER_PIXEL
+                    );
+                    #[cfg(feature = "frame-checksum")]
+                    assert_eq!(frame.checksum, buffer_checksum(&frame.pixel_buffer));
+                    saw_frame = true;
+                }
+                RenderEvent::Error(error) => {
+                    panic!("unexpected render error: {}", error.message);
+                }
+            }
+        }
+
+        assert!(saw_frame, "expected a frame event");
+        controller.shutdown();
+    }
+
+    #[test]
+    fn worker_panic_is_reported_and_the_controller_keeps_rendering() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        presenter_port.fail_next_frame.store(true, Ordering::Relaxed);
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        );
+
+        // Panics on the worker while `emit` holds `accepting_output`,
+        // poisoning it, and before the frame counts as presented.
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+        let first = controller.submit_request(Arc::new(create_test_request(pixel_rect)));
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+        assert!(
+            matches!(events.as_slice(), [RenderEvent::Error(error)]
+                if error.generation == first && error.message.contains("presenter failed")),
+            "expected the panic as a render error, got {events:?}"
+        );
+
+        let second = controller.submit_request(Arc::new(create_test_request(
+            PixelRect::new(Point { x: 0, y: 0 }, Point { x: 5, y: 5 }).unwrap(),
+        )));
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+        assert!(
+            matches!(events.as_slice(), [RenderEvent::Frame(frame)] if frame.generation == second),
+            "expected a frame after the panic, got {events:?}"
+        );
+        assert_eq!(controller.last_completed_generation(), second);
+
+        controller.shutdown();
+    }
+
+    #[test]
+    fn a_panic_after_the_frame_is_presented_reports_nothing_more() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        );
+        let panicked = AtomicBool::new(false);
+        controller.set_metrics_callback(Box::new(move |_| {
+            if !panicked.swap(true, Ordering::Relaxed) {
+                panic!("metrics callback failed");
+            }
+        }));
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+        let first = controller.submit_request(Arc::new(create_test_request(pixel_rect)));
+        let mut events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+        // Anything reported for the panic would follow the frame promptly.
+        thread::sleep(Duration::from_millis(100));
+        events.extend(presenter_port.take_events());
+        assert!(
+            matches!(events.as_slice(), [RenderEvent::Frame(frame)] if frame.generation == first),
+            "expected only the frame, got {events:?}"
+        );
+
+        let second = controller.submit_request(Arc::new(create_test_request(
+            PixelRect::new(Point { x: 0, y: 0 }, Point { x: 5, y: 5 }).unwrap(),
+        )));
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+        assert!(
+            matches!(events.as_slice(), [RenderEvent::Frame(frame)] if frame.generation == second),
+            "expected a frame after the panic, got {events:?}"
+        );
+
+        controller.shutdown();
+    }
+
+    #[test]
+    fn metrics_callback_fires_for_each_completed_frame() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        );
+        let recorded = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&recorded);
+        controller.set_metrics_callback(Box::new(move |metrics| sink.lock().unwrap().push(metrics)));
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 4, y: 2 }).unwrap();
+        let generation = controller.submit_request(Arc::new(create_test_request(pixel_rect)));
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+        controller.shutdown();
+
+        let [RenderEvent::Frame(frame)] = events.as_slice() else {
+            panic!("expected one frame, got {events:?}");
+        };
+        assert_eq!(
+            *recorded.lock().unwrap(),
+            vec![FrameMetrics {
+                generation,
+                render_duration: frame.render_duration,
+                pixel_count: 15,
+            }]
+        );
+    }
+
+    #[test]
+    fn detail_budget_measures_only_fresh_renders() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        // No real render meets a 1 ns target, so every measured frame sets
+        // the budget to half its own iterations: the 10 of the test
+        // request, not the 1000 budgeted.
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        )
+        .with_detail_budget(
+            DetailBudget::new(Duration::from_nanos(1), 1000).with_iteration_range(1, 1000),
+        );
+        assert_eq!(controller.detail_iterations(), Some(1000));
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+        let request = Arc::new(create_test_request(pixel_rect));
+
+        controller.submit_request(Arc::clone(&request));
+        assert_eq!(wait_for_events(presenter_port.as_ref(), Duration::from_secs(2)).len(), 1);
+        assert_eq!(controller.detail_iterations(), Some(5));
+
+        // The repeat is a cached re-emit and is not measured.
+        controller.submit_request(request);
+        assert_eq!(wait_for_events(presenter_port.as_ref(), Duration::from_secs(2)).len(), 1);
+        assert_eq!(controller.detail_iterations(), Some(5));
+
+        controller.shutdown();
+    }
+
+    #[test]
+    fn metrics_callback_skips_render_errors() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        );
+        let calls = Arc::new(Mutex::new(0u32));
+        let counter = Arc::clone(&calls);
+        controller.set_metrics_callback(Box::new(move |_| *counter.lock().unwrap() += 1));
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+        controller.submit_request(Arc::new(create_error_request(pixel_rect)));
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+        controller.shutdown();
+
+        assert!(matches!(events.as_slice(), [RenderEvent::Error(_)]));
+        assert_eq!(*calls.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn reference_diff_request_presents_black_frame_when_algorithms_agree() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        );
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 15, y: 11 }).unwrap();
+        let request = crate::core::fractals::mandelbrot::mandelbrot_config::MandelbrotConfig::default()
+            .build_reference_diff_request(pixel_rect)
+            .expect("default view renders directly");
+
+        controller.submit_request(Arc::new(request));
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+
+        match events.as_slice() {
+            [RenderEvent::Frame(frame)] => {
+                assert!(frame
+                    .pixel_buffer
+                    .buffer()
+                    .chunks_exact(PixelBuffer::BYTES_PER_PIXEL)
+                    .all(|pixel| pixel[..3] == [0, 0, 0]));
+            }
+            other => panic!("expected one frame, got {other:?}"),
+        }
+
+        controller.shutdown();
+    }
+
+    #[test]
+    fn gpu_renderer_serves_deep_zoom_frames() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let calls = Arc::new(Mutex::new(0u32));
+        let gpu = MockGpuRenderer {
+            calls: Arc::clone(&calls),
+            serve_iterations: Some(1),
+        };
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            Some(Box::new(gpu)),
+        );
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+        let request = Arc::new(create_perturbation_request(pixel_rect));
+
+        controller.submit_request(request);
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+
+        let frame = events
+            .iter()
+            .find_map(|e| match e {
+                RenderEvent::Frame(frame) => Some(frame),
+                RenderEvent::Error(err) => panic!("unexpected render error: {}", err.message),
+            })
+            .expect("expected a frame from the GPU path");
+
+        assert_eq!(*calls.lock().unwrap(), 1, "GPU renderer should be asked once");
+
+        // Every pixel was served iteration count 1, so the frame must be a
+        // single uniform colour.
+        let buffer = frame.pixel_buffer.buffer();
+        let first_pixel = &buffer[0..PixelBuffer::BYTES_PER_PIXEL];
+        for pixel in buffer.chunks_exact(PixelBuffer::BYTES_PER_PIXEL) {
+            assert_eq!(pixel, first_pixel);
+        }
+
+        controller.shutdown();
+    }
+
+    #[test]
+    fn declined_gpu_render_falls_back_to_cpu() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let calls = Arc::new(Mutex::new(0u32));
+        let gpu = MockGpuRenderer {
+            calls: Arc::clone(&calls),
+            serve_iterations: None,
+        };
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            Some(Box::new(gpu)),
+        );
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+        let request = Arc::new(create_perturbation_request(pixel_rect));
+
+        controller.submit_request(request);
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+
+        let saw_frame = events.iter().any(|e| matches!(e, RenderEvent::Frame(_)));
+
+        assert_eq!(*calls.lock().unwrap(), 1, "GPU renderer should be offered the frame");
+        assert!(saw_frame, "CPU fallback must still produce a frame");
+
+        controller.shutdown();
+    }
+
+    #[test]
+    fn direct_renders_use_the_gpu_only_when_switched_on() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let calls = Arc::new(Mutex::new(0u32));
+        let gpu = MockGpuRenderer {
+            calls: Arc::clone(&calls),
+            serve_iterations: Some(1),
+        };
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            Some(Box::new(gpu)),
+        );
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+        let request = Arc::new(create_test_request(pixel_rect));
+
+        controller.submit_request(Arc::clone(&request));
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+        assert!(events.iter().any(|e| matches!(e, RenderEvent::Frame(_))));
+        assert_eq!(*calls.lock().unwrap(), 0, "the CPU renders direct views by default");
+
+        // Switching renders the same request again rather than re-emitting
+        // the cached CPU frame.
+        controller.set_gpu_direct_rendering(true);
+        controller.submit_request(request);
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+        assert!(events.iter().any(|e| matches!(e, RenderEvent::Frame(_))));
+        assert_eq!(*calls.lock().unwrap(), 1, "the GPU renderer should serve the frame");
+
+        controller.shutdown();
+    }
+
+    #[test]
+    fn identical_consecutive_request_reuses_the_cached_frame() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let calls = Arc::new(Mutex::new(0u32));
+        let gpu = MockGpuRenderer {
+            calls: Arc::clone(&calls),
+            serve_iterations: Some(1),
+        };
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            Some(Box::new(gpu)),
+        );
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+
+        let first_generation =
+            controller.submit_request(Arc::new(create_perturbation_request(pixel_rect)));
+        let first = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+
+        let second_generation =
+            controller.submit_request(Arc::new(create_perturbation_request(pixel_rect)));
+        let second = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+
+        let frame = |events: &[RenderEvent]| match events {
+            [RenderEvent::Frame(frame)] => (frame.generation, frame.pixel_buffer.buffer().to_vec()),
+            other => panic!("expected one frame, got {other:?}"),
+        };
+        let (first_frame_generation, first_buffer) = frame(&first);
+        let (second_frame_generation, second_buffer) = frame(&second);
+
+        assert_eq!(*calls.lock().unwrap(), 1, "identical request should not re-render");
+        assert_eq!(first_frame_generation, first_generation);
+        assert_eq!(second_frame_generation, second_generation);
+        assert_eq!(first_buffer, second_buffer);
+
+        controller.shutdown();
+    }
+
+    #[test]
+    fn cache_hits_are_left_out_of_the_load_tracker() {
+        let mut load = LoadTracker::default();
+        let start = Instant::now();
+        load.record_submission(start);
+        load.record_submission(start + Duration::from_millis(10));
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+        let frame = Ok(Arc::new(PixelBuffer::new(pixel_rect)));
+
+        let before = load.clone();
+        InteractiveController::record_load(&mut load, &frame, Duration::ZERO, true);
+        assert_eq!(load, before, "a cache hit should not count as a render");
+
+        InteractiveController::record_load(&mut load, &frame, Duration::from_millis(1), false);
+        assert_eq!(load.load(), Some(0.1));
+    }
+
+    /// Renders the same deep-zoom request twice through a counting mock
+    /// GPU, running `between` after the first frame. Returns the GPU call
+    /// count and both frames' bytes.
+    fn render_twice_with(
+        configure: impl FnOnce(InteractiveController) -> InteractiveController,
+        between: impl FnOnce(&InteractiveController),
+    ) -> (u32, Vec<u8>, Vec<u8>) {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let calls = Arc::new(Mutex::new(0u32));
+        let gpu = MockGpuRenderer {
+            calls: Arc::clone(&calls),
+            serve_iterations: Some(1),
+        };
+        let mut controller = configure(InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            Some(Box::new(gpu)),
+        ));
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+        let frame = |events: Vec<RenderEvent>| match events.as_slice() {
+            [RenderEvent::Frame(frame)] => frame.pixel_buffer.buffer().to_vec(),
+            other => panic!("expected one frame, got {other:?}"),
+        };
+
+        controller.submit_request(Arc::new(create_perturbation_request(pixel_rect)));
+        let first = frame(wait_for_events(presenter_port.as_ref(), Duration::from_secs(2)));
+
+        between(&controller);
+
+        controller.submit_request(Arc::new(create_perturbation_request(pixel_rect)));
+        let second = frame(wait_for_events(presenter_port.as_ref(), Duration::from_secs(2)));
+
+        controller.shutdown();
+        let calls = *calls.lock().unwrap();
+
+        (calls, first, second)
+    }
+
+    #[test]
+    fn idle_cleanup_drops_the_cached_frame_and_renders_afresh() {
+        let (calls, first, second) = render_twice_with(
+            |controller| controller.with_idle_cleanup(Duration::from_millis(50)),
+            |_| thread::sleep(Duration::from_millis(250)),
+        );
+
+        assert_eq!(calls, 2, "the cache should have been dropped while idle");
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn repeat_within_the_idle_timeout_still_uses_the_cache() {
+        let (calls, first, second) = render_twice_with(
+            |controller| controller.with_idle_cleanup(Duration::from_secs(60)),
+            |_| thread::sleep(Duration::from_millis(50)),
+        );
+
+        assert_eq!(calls, 1);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn clear_caches_forces_the_next_request_to_render() {
+        let (calls, first, second) =
+            render_twice_with(|controller| controller, InteractiveController::clear_caches);
+
+        assert_eq!(calls, 2);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn contrast_stretched_request_colours_the_observed_range() {
+        use crate::core::actions::generate_fractal::generate_fractal_parallel_rayon::generate_fractal_parallel_rayon;
+        use crate::core::actions::generate_pixel_buffer::generate_pixel_buffer::generate_pixel_buffer;
+
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        );
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 15, y: 15 }).unwrap();
+        let mut request = create_test_request(pixel_rect);
+        if let FractalConfig::Mandelbrot {
+            contrast_stretch, ..
+        } = &mut request
+        {
+            *contrast_stretch = true;
+        }
+        let max_iterations = request.contrast_stretch().expect("request is stretched");
+
+        let field = generate_fractal_parallel_rayon(pixel_rect, request.algorithm()).unwrap();
+        let colour_map =
+            ContrastStretchColourMap::for_field(request.colour_map(), &field, max_iterations);
+        let expected = generate_pixel_buffer(field.clone(), &colour_map, pixel_rect).unwrap();
+        let unstretched = generate_pixel_buffer(field, request.colour_map(), pixel_rect).unwrap();
+
+        controller.submit_request(Arc::new(request));
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+
+        match events.as_slice() {
+            [RenderEvent::Frame(frame)] => {
+                assert_eq!(frame.pixel_buffer.buffer(), expected.buffer());
+                assert_ne!(frame.pixel_buffer.buffer(), unstretched.buffer());
+            }
+            other => panic!("expected one frame, got {other:?}"),
+        }
+
+        controller.shutdown();
+    }
+
+    #[test]
+    fn histogram_request_equalises_the_field() {
+        use crate::core::actions::generate_fractal::generate_fractal_parallel_rayon::generate_fractal_parallel_rayon;
+        use crate::core::actions::generate_pixel_buffer::generate_pixel_buffer::{
+            generate_pixel_buffer, generate_pixel_buffer_histogram,
+        };
+
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        );
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 15, y: 15 }).unwrap();
+        let mut request = create_test_request(pixel_rect);
+        if let FractalConfig::Mandelbrot { colour_scheme, .. } = &mut request {
+            *colour_scheme = ColourSchemeKind::Histogram;
+        }
+        let max_iterations = request.histogram().expect("request is equalised");
+
+        let field = generate_fractal_parallel_rayon(pixel_rect, request.algorithm()).unwrap();
+        let expected =
+            generate_pixel_buffer_histogram(field.clone(), request.colour_map(), pixel_rect, max_iterations)
+                .unwrap();
+        let linear = generate_pixel_buffer(field, request.colour_map(), pixel_rect).unwrap();
+
+        controller.submit_request(Arc::new(request));
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+
+        match events.as_slice() {
+            [RenderEvent::Frame(frame)] => {
+                assert_eq!(frame.pixel_buffer.buffer(), expected.buffer());
+                assert_ne!(frame.pixel_buffer.buffer(), linear.buffer());
+            }
+            other => panic!("expected one frame, got {other:?}"),
         }
+
+        controller.shutdown();
     }
 
     #[test]
-    fn test_submit_request_emits_frame() {
+    fn smooth_request_colours_continuous_counts() {
+        use crate::core::actions::generate_fractal::generate_fractal_parallel_rayon::generate_fractal_parallel_rayon;
+        use crate::core::actions::generate_pixel_buffer::generate_pixel_buffer::{
+            generate_pixel_buffer, generate_pixel_buffer_parallel,
+        };
+
         let presenter_port = Arc::new(MockPresenterPort::default());
         let mut controller = InteractiveController::new(
             Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
             None,
-        );
+        )
+        .with_pipelined_colouring(true);
 
-        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
-        let request = Arc::new(create_test_request(pixel_rect));
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 15, y: 15 }).unwrap();
+        let mut request = create_test_request(pixel_rect);
+        if let FractalConfig::Mandelbrot { smooth, .. } = &mut request {
+            *smooth = true;
+        }
+        let algorithm = request.smooth_algorithm().expect("request is smooth");
+        let max_iterations = algorithm.max_iterations();
 
-        let generation = controller.submit_request(Arc::clone(&request));
+        let counts = generate_fractal_parallel_rayon(pixel_rect, &SmoothAlgorithm(algorithm)).unwrap();
+        let colour_map = SmoothColourMap::new(request.colour_map(), max_iterations);
+        let expected = generate_pixel_buffer_parallel(counts, &colour_map, pixel_rect).unwrap();
+        let field = generate_fractal_parallel_rayon(pixel_rect, request.algorithm()).unwrap();
+        let banded = generate_pixel_buffer(field, request.colour_map(), pixel_rect).unwrap();
+
+        controller.submit_request(Arc::new(request));
         let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
-        assert!(!events.is_empty(), "expected a render event");
 
-        let mut saw_frame = false;
-        for event in events {
-            match event {
-                RenderEvent::Frame(frame) => {
+        match events.as_slice() {
+            [RenderEvent::Frame(frame)] => {
+                assert_eq!(frame.pixel_buffer.buffer(), expected.buffer());
+                assert_ne!(frame.pixel_buffer.buffer(), banded.buffer());
+            }
+            other => panic!("expected one frame, got {other:?}"),
+        }
+
+        controller.shutdown();
+    }
+
+    fn create_iterations_request(pixel_rect: PixelRect, max_iterations: u32) -> FractalConfig {
+        use crate::core::fractals::mandelbrot::mandelbrot_config::MandelbrotConfig;
+
+        let config = MandelbrotConfig {
+            max_iterations,
+            ..MandelbrotConfig::default()
+        };
+
+        config.build_render_request(pixel_rect)
+    }
+
+    /// Renders each request in turn, waiting for its frame before
+    /// submitting the next, and returns the frames' buffers.
+    fn render_stream(pipelined: bool, requests: Vec<FractalConfig>) -> Vec<Vec<u8>> {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        )
+        .with_pipelined_colouring(pipelined);
+
+        let mut buffers = Vec::new();
+        for request in requests {
+            let generation = controller.submit_request(Arc::new(request));
+            for event in wait_for_events(presenter_port.as_ref(), Duration::from_secs(5)) {
+                if let RenderEvent::Frame(frame) = event {
                     assert_eq!(frame.generation, generation);
-                    assert!(generation > 0, "generation should be non-zero");
-                    assert_eq!(frame.pixel_buffer.pixel_rect(), pixel_rect);
-                    assert_eq!(
-                        frame.pixel_buffer.buffer().len(),
-                        (pixel_rect.width() * pixel_rect.height()) as usize
-                            * PixelBuffer::BYTES_PER_PIXEL
-                    );
-                    saw_frame = true;
-                }
-                RenderEvent::Error(error) => {
-                    panic!("unexpected render error: {}", error.message);
+                    buffers.push(frame.pixel_buffer.buffer().to_vec());
                 }
             }
         }
 
-        assert!(saw_frame, "expected a frame event");
         controller.shutdown();
+        buffers
     }
 
     #[test]
-    fn gpu_renderer_serves_deep_zoom_frames() {
-        let presenter_port = Arc::new(MockPresenterPort::default());
-        let calls = Arc::new(Mutex::new(0u32));
+    fn pipelined_stream_matches_single_stage_output() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 31, y: 23 }).unwrap();
+        let requests = || {
+            (1..=6)
+                .map(|i| create_iterations_request(pixel_rect, i * 20))
+                .collect::<Vec<_>>()
+        };
+
+        let single_stage = render_stream(false, requests());
+        let pipelined = render_stream(true, requests());
+
+        assert_eq!(single_stage.len(), 6);
+        assert_eq!(pipelined, single_stage);
+    }
+
+    /// Mock presenter that reports each frame's generation and then blocks
+    /// inside `present` until released. Dropping the release sender lets
+    /// every later frame through.
+    struct BlockingPresenterPort {
+        presenting: Mutex<std::sync::mpsc::Sender<u64>>,
+        release: Mutex<std::sync::mpsc::Receiver<()>>,
+    }
+
+    impl InteractiveControllerPresenterPort for BlockingPresenterPort {
+        fn present(&self, event: RenderEvent) {
+            if let RenderEvent::Frame(frame) = event {
+                let _ = self.presenting.lock().unwrap().send(frame.generation);
+                let _ = self.release.lock().unwrap().recv();
+            }
+        }
+    }
+
+    /// Whether the worker computes a second request while the first
+    /// request's frame is still inside `present`.
+    fn computes_while_presenting(pipelined: bool) -> bool {
+        let (presenting_tx, presenting_rx) = std::sync::mpsc::channel();
+        let (release_tx, release_rx) = std::sync::mpsc::channel();
+        let presenter_port = Arc::new(BlockingPresenterPort {
+            presenting: Mutex::new(presenting_tx),
+            release: Mutex::new(release_rx),
+        });
+        let calls = Arc::new(Mutex::new(0));
         let gpu = MockGpuRenderer {
             calls: Arc::clone(&calls),
             serve_iterations: Some(1),
         };
         let mut controller = InteractiveController::new(
-            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            presenter_port as Arc<dyn InteractiveControllerPresenterPort>,
             Some(Box::new(gpu)),
-        );
-
-        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
-        let request = Arc::new(create_perturbation_request(pixel_rect));
-
-        controller.submit_request(request);
-        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
-
-        let frame = events
-            .iter()
-            .find_map(|e| match e {
-                RenderEvent::Frame(frame) => Some(frame),
-                RenderEvent::Error(err) => panic!("unexpected render error: {}", err.message),
-            })
-            .expect("expected a frame from the GPU path");
+        )
+        .with_pipelined_colouring(pipelined);
 
-        assert_eq!(*calls.lock().unwrap(), 1, "GPU renderer should be asked once");
+        let first_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 7, y: 7 }).unwrap();
+        let second_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 15, y: 7 }).unwrap();
+        let first = controller.submit_request(Arc::new(create_perturbation_request(first_rect)));
+        assert_eq!(presenting_rx.recv_timeout(Duration::from_secs(5)), Ok(first));
 
-        // Every pixel was served iteration count 1, so the frame must be a
-        // single uniform colour.
-        let buffer = frame.pixel_buffer.buffer();
-        let first_pixel = &buffer[0..PixelBuffer::BYTES_PER_PIXEL];
-        for pixel in buffer.chunks_exact(PixelBuffer::BYTES_PER_PIXEL) {
-            assert_eq!(pixel, first_pixel);
+        let second = controller.submit_request(Arc::new(create_perturbation_request(second_rect)));
+        let deadline = Instant::now() + Duration::from_millis(500);
+        while *calls.lock().unwrap() < 2 && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(1));
         }
+        let overlapped = *calls.lock().unwrap() == 2;
 
+        drop(release_tx);
+        assert_eq!(presenting_rx.recv_timeout(Duration::from_secs(5)), Ok(second));
         controller.shutdown();
+        overlapped
     }
 
     #[test]
-    fn declined_gpu_render_falls_back_to_cpu() {
+    fn pipelined_worker_computes_the_next_field_while_a_frame_is_presented() {
+        assert!(
+            computes_while_presenting(true),
+            "pipelined worker should compute while the colour stage presents"
+        );
+        assert!(
+            !computes_while_presenting(false),
+            "single-stage worker presents before it computes again"
+        );
+    }
+
+    #[test]
+    fn pipelined_burst_presents_only_the_newest_request() {
         let presenter_port = Arc::new(MockPresenterPort::default());
-        let calls = Arc::new(Mutex::new(0u32));
-        let gpu = MockGpuRenderer {
-            calls: Arc::clone(&calls),
-            serve_iterations: None,
-        };
         let mut controller = InteractiveController::new(
             Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
-            Some(Box::new(gpu)),
-        );
-
-        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
-        let request = Arc::new(create_perturbation_request(pixel_rect));
+            None,
+        )
+        .with_pipelined_colouring(true);
 
-        controller.submit_request(request);
-        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(2));
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 63, y: 63 }).unwrap();
+        let mut last_generation = 0;
+        for max_iterations in (100..=1000).step_by(100) {
+            last_generation = controller
+                .submit_request(Arc::new(create_iterations_request(pixel_rect, max_iterations)));
+        }
 
-        let saw_frame = events.iter().any(|e| matches!(e, RenderEvent::Frame(_)));
+        let start = Instant::now();
+        while controller.last_completed_generation() != last_generation {
+            assert!(start.elapsed() < Duration::from_secs(5), "burst never finished");
+            thread::sleep(Duration::from_millis(10));
+        }
+        controller.shutdown();
 
-        assert_eq!(*calls.lock().unwrap(), 1, "GPU renderer should be offered the frame");
-        assert!(saw_frame, "CPU fallback must still produce a frame");
+        let frames: Vec<(u64, Vec<u8>)> = presenter_port
+            .take_events()
+            .into_iter()
+            .map(|event| match event {
+                RenderEvent::Frame(frame) => (frame.generation, frame.pixel_buffer.buffer().to_vec()),
+                RenderEvent::Error(error) => panic!("unexpected error: {}", error.message),
+            })
+            .collect();
+        assert!(frames.windows(2).all(|pair| pair[0].0 < pair[1].0));
 
-        controller.shutdown();
+        let (newest_generation, newest_buffer) = frames.last().expect("the newest frame is presented");
+        let expected = render_stream(false, vec![create_iterations_request(pixel_rect, 1000)]);
+        assert_eq!(*newest_generation, last_generation);
+        assert_eq!(vec![newest_buffer.clone()], expected);
     }
 
     #[test]
@@ -515,6 +1730,44 @@ mod tests {
         controller.shutdown();
     }
 
+    #[test]
+    fn generations_stay_ordered_across_the_counter_wrapping() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        )
+        .with_generation(u64::MAX - 1);
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 63, y: 63 }).unwrap();
+        let before_wrap = controller.submit_request(Arc::new(create_iterations_request(pixel_rect, 1000)));
+        let after_wrap = controller.submit_request(Arc::new(create_iterations_request(pixel_rect, 200)));
+
+        assert_eq!(before_wrap, u64::MAX);
+        assert_eq!(after_wrap, 1);
+        assert!(is_newer(after_wrap, before_wrap));
+
+        let start = Instant::now();
+        while controller.last_completed_generation() != after_wrap {
+            assert!(start.elapsed() < Duration::from_secs(5), "the newest request never completed");
+            thread::sleep(Duration::from_millis(10));
+        }
+        controller.shutdown();
+
+        // The request from before the wrap is stale: if it is presented at
+        // all, it comes first, and the newest frame is the one after it.
+        let generations: Vec<u64> = presenter_port
+            .take_events()
+            .into_iter()
+            .map(|event| match event {
+                RenderEvent::Frame(frame) => frame.generation,
+                RenderEvent::Error(error) => panic!("unexpected error: {}", error.message),
+            })
+            .collect();
+        assert!(generations.windows(2).all(|pair| is_newer(pair[1], pair[0])));
+        assert_eq!(generations.last(), Some(&after_wrap));
+    }
+
     fn extract_generation(events: &[RenderEvent]) -> u64 {
         events
             .iter()
@@ -823,4 +2076,266 @@ mod tests {
 
         controller.shutdown();
     }
+
+    /// A brute-force render of the cardioid interior: every pixel runs to
+    /// `max_iterations`, so a full frame takes far longer than any test.
+    fn create_long_request(pixel_rect: PixelRect) -> FractalConfig {
+        let region = ComplexRect::new(
+            Complex {
+                real: -0.5,
+                imag: -0.25,
+            },
+            Complex {
+                real: 0.0,
+                imag: 0.25,
+            },
+        )
+        .expect("test region is valid");
+
+        let max_iterations = 500_000;
+        let algorithm = MandelbrotAlgorithm::new(pixel_rect, region, max_iterations)
+            .expect("test algorithm params are valid")
+            .with_optimizations(OptimizationFlags::NONE);
+        let colour_map = mandelbrot_colour_map_factory(
+            MandelbrotColourMapKinds::BlueWhiteGradient,
+            max_iterations,
+        );
+
+        FractalConfig::Mandelbrot {
+            colour_map,
+            algorithm: MandelbrotRenderPath::Direct(algorithm),
+            contrast_stretch: false,
+            smooth: false,
+            colour_scheme: ColourSchemeKind::Linear,
+        }
+    }
+
+    #[test]
+    fn resize_during_long_render_cancels_it_promptly() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        );
+
+        let long_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 63, y: 255 }).unwrap();
+        let long_generation = controller.submit_request(Arc::new(create_long_request(long_rect)));
+        thread::sleep(Duration::from_millis(100));
+
+        let resized_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+        let submitted_at = Instant::now();
+        let resized_generation =
+            controller.submit_request(Arc::new(create_test_request(resized_rect)));
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(10));
+        let latency = submitted_at.elapsed();
+
+        match events.as_slice() {
+            [RenderEvent::Frame(frame)] => {
+                assert_eq!(frame.generation, resized_generation);
+                assert_ne!(frame.generation, long_generation);
+                assert_eq!(frame.pixel_buffer.pixel_rect(), resized_rect);
+            }
+            other => panic!("expected only the resized frame, got {other:?}"),
+        }
+        assert!(
+            latency < Duration::from_secs(5),
+            "resize waited {latency:?} for the superseded render"
+        );
+
+        controller.shutdown();
+    }
+
+    #[test]
+    fn preempt_request_supersedes_the_in_flight_render_during_flight() {
+        use crate::controllers::interactive::flight::{RenderScheduler, RequestPriority, SchedulerAction};
+
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        );
+        let mut scheduler = RenderScheduler::new();
+        let update = |scheduler: &mut RenderScheduler, request: FractalConfig, priority| {
+            scheduler.update(
+                Arc::new(request),
+                priority,
+                true,
+                controller.last_completed_generation(),
+                |request| controller.submit_request(request),
+            )
+        };
+
+        let long_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 63, y: 255 }).unwrap();
+        let SchedulerAction::Submitted { generation: long_generation } =
+            update(&mut scheduler, create_long_request(long_rect), RequestPriority::Normal)
+        else {
+            panic!("nothing in flight, so the first request submits");
+        };
+        thread::sleep(Duration::from_millis(100));
+
+        let small_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+        let coalesced = update(&mut scheduler, create_test_request(small_rect), RequestPriority::Normal);
+        assert_eq!(coalesced, SchedulerAction::Coalesced);
+
+        let resized_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 5, y: 5 }).unwrap();
+        let SchedulerAction::Submitted { generation: resized_generation } =
+            update(&mut scheduler, create_test_request(resized_rect), RequestPriority::Preempt)
+        else {
+            panic!("a preempting request submits while another is in flight");
+        };
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(5));
+
+        match events.as_slice() {
+            [RenderEvent::Frame(frame)] => {
+                assert_eq!(frame.generation, resized_generation);
+                assert_ne!(frame.generation, long_generation);
+                assert_eq!(frame.pixel_buffer.pixel_rect(), resized_rect);
+            }
+            other => panic!("expected only the preempting frame, got {other:?}"),
+        }
+        assert_eq!(scheduler.in_flight_generation(), Some(resized_generation));
+
+        controller.shutdown();
+    }
+
+    #[test]
+    fn is_rendering_while_the_newest_request_is_computed() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        );
+        assert!(!controller.is_rendering());
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 7, y: 7 }).unwrap();
+        let generation = controller.submit_request(Arc::new(create_long_request(pixel_rect)));
+
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while !controller.is_rendering() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(1));
+        }
+        assert!(controller.is_rendering());
+
+        let events = wait_for_events(presenter_port.as_ref(), Duration::from_secs(60));
+        assert_eq!(extract_generation(&events), generation);
+        assert!(!controller.is_rendering());
+
+        controller.shutdown();
+    }
+
+    /// Mock GPU renderer that blocks inside `render_iterations` until
+    /// released, so a test can shut down while a render is in flight.
+    struct BlockingGpuRenderer {
+        started: std::sync::mpsc::Sender<()>,
+        release: std::sync::mpsc::Receiver<()>,
+    }
+
+    impl GpuFractalRendererPort for BlockingGpuRenderer {
+        fn render_iterations(
+            &mut self,
+            algorithm: &crate::core::fractals::mandelbrot::perturbation::algorithm::MandelbrotPerturbationAlgorithm,
+        ) -> Option<Vec<u32>> {
+            let _ = self.started.send(());
+            let _ = self.release.recv();
+
+            let pixel_rect = algorithm.pixel_rect();
+            Some(vec![1; (pixel_rect.width() * pixel_rect.height()) as usize])
+        }
+    }
+
+    /// Mock presenter standing in for one whose event loop is gone once
+    /// `closed` is set: any later `present` is counted as a violation.
+    /// When `hold` is set, the first `present` reports on its sender and
+    /// then blocks until its receiver is signalled.
+    #[derive(Default)]
+    struct ClosingPresenterPort {
+        closed: AtomicBool,
+        late_presents: AtomicU64,
+        hold: Mutex<Option<(std::sync::mpsc::Sender<()>, std::sync::mpsc::Receiver<()>)>>,
+    }
+
+    impl InteractiveControllerPresenterPort for ClosingPresenterPort {
+        fn present(&self, _event: RenderEvent) {
+            if let Some((entered, resume)) = lock(&self.hold).take() {
+                entered.send(()).unwrap();
+                let _ = resume.recv();
+            }
+
+            if self.closed.load(Ordering::SeqCst) {
+                self.late_presents.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    #[test]
+    fn shutdown_during_in_flight_render_presents_nothing() {
+        let presenter_port = Arc::new(MockPresenterPort::default());
+        let (started_tx, started_rx) = std::sync::mpsc::channel();
+        let (release_tx, release_rx) = std::sync::mpsc::channel();
+        let gpu = BlockingGpuRenderer {
+            started: started_tx,
+            release: release_rx,
+        };
+
+        let mut controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            Some(Box::new(gpu)),
+        );
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 7, y: 7 }).unwrap();
+        controller.submit_request(Arc::new(create_perturbation_request(pixel_rect)));
+        started_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("worker should start the GPU render");
+
+        // Release the in-flight render only after shutdown has begun.
+        let releaser = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            let _ = release_tx.send(());
+        });
+
+        controller.shutdown();
+        releaser.join().unwrap();
+
+        assert!(presenter_port.take_events().is_empty());
+
+        drop(controller);
+        assert_eq!(
+            Arc::strong_count(&presenter_port),
+            1,
+            "worker should release the presenter once shut down"
+        );
+    }
+
+    #[test]
+    fn dropping_controller_before_presenter_never_presents_late() {
+        let (entered_tx, entered_rx) = std::sync::mpsc::channel();
+        let (resume_tx, resume_rx) = std::sync::mpsc::channel();
+        let presenter_port = Arc::new(ClosingPresenterPort {
+            hold: Mutex::new(Some((entered_tx, resume_rx))),
+            ..ClosingPresenterPort::default()
+        });
+        let controller = InteractiveController::new(
+            Arc::clone(&presenter_port) as Arc<dyn InteractiveControllerPresenterPort>,
+            None,
+        );
+
+        // The first frame parks inside `present`, and a second request is
+        // queued behind it, so output is still live when the drop begins.
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 3 }).unwrap();
+        controller.submit_request(Arc::new(create_test_request(pixel_rect)));
+        entered_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("worker should present the first frame");
+        controller.submit_request(Arc::new(create_test_request(pixel_rect)));
+
+        // Shutdown order: controller first (closes output, joins the
+        // worker), then the presenter's event loop goes away.
+        resume_tx.send(()).unwrap();
+        drop(controller);
+        presenter_port.closed.store(true, Ordering::SeqCst);
+
+        assert_eq!(presenter_port.late_presents.load(Ordering::SeqCst), 0);
+        assert_eq!(Arc::strong_count(&presenter_port), 1);
+    }
 }
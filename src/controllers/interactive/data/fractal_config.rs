@@ -1,30 +1,179 @@
 use crate::core::actions::cancellation::{CancelToken, Cancelled};
+use crate::core::actions::diff_iterations::IterationDiffColourMap;
 use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::actions::generate_pixel_buffer::histogram::ColourSchemeKind;
 use crate::core::actions::generate_pixel_buffer::ports::colour_map::ColourMap;
+use crate::core::data::complex::Complex;
+use crate::core::data::complex_rect::{ComplexRect, ComplexRectError};
+use crate::core::data::deep_region::DeepRegion;
+use crate::core::data::pixel_rect::PixelRect;
+use crate::core::data::point::Point;
 use crate::core::fractals::{
-    julia::{algorithm::JuliaAlgorithm, colour_mapping::map::JuliaColourMap},
-    mandelbrot::{colour_mapping::map::MandelbrotColourMap, render_path::MandelbrotRenderPath},
+    burning_ship::algorithm::BurningShipAlgorithm,
+    julia::{
+        algorithm::JuliaAlgorithm,
+        colour_mapping::{kinds::JuliaColourMapKinds, map::JuliaColourMap},
+        julia_config::JuliaConfig,
+        split_view::SplitViewAlgorithm,
+    },
+    mandelbrot::{
+        algorithm::MandelbrotAlgorithm,
+        colour_mapping::{kinds::MandelbrotColourMapKinds, map::MandelbrotColourMap},
+        mandelbrot_config::MandelbrotConfig,
+        render_path::MandelbrotRenderPath,
+    },
+    smooth_escape::SmoothEscape,
+    tricorn::algorithm::TricornAlgorithm,
 };
-use crate::core::util::pixel_to_complex_coords::PixelToComplexCoordsError;
+use crate::core::util::pixel_to_complex_coords::{PixelToComplexCoordsError, check_pixel_steps};
+use std::error::Error;
+use std::fmt;
+
+/// Why [`FractalConfig::mandelbrot`] or [`FractalConfig::julia`] rejected
+/// their parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FractalConfigError {
+    /// The image has no pixels, or is too large to address.
+    InvalidImageSize { width: u32, height: u32 },
+    /// The bounds are empty or inverted.
+    InvalidBounds(ComplexRectError),
+    /// The bounds are not finite, or too narrow to give each pixel its own
+    /// coordinate.
+    UnresolvableBounds(PixelToComplexCoordsError),
+    ZeroMaxIterations,
+}
+
+impl fmt::Display for FractalConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidImageSize { width, height } => {
+                write!(f, "invalid image size {}x{}", width, height)
+            }
+            Self::InvalidBounds(err) => write!(f, "invalid bounds: {}", err),
+            Self::UnresolvableBounds(err) => write!(f, "unresolvable bounds: {}", err),
+            Self::ZeroMaxIterations => write!(f, "max iterations must be greater than zero"),
+        }
+    }
+}
+
+impl Error for FractalConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidBounds(err) => Some(err),
+            Self::UnresolvableBounds(err) => Some(err),
+            Self::InvalidImageSize { .. } | Self::ZeroMaxIterations => None,
+        }
+    }
+}
 
 pub enum FractalConfig {
     Mandelbrot {
         colour_map: Box<dyn MandelbrotColourMap>,
         algorithm: MandelbrotRenderPath,
+        /// Colour against the escaped iteration range present in the frame
+        /// rather than `0..max_iterations`.
+        contrast_stretch: bool,
+        /// Colour by continuous escape counts instead of whole ones; only
+        /// honoured on the direct path.
+        smooth: bool,
+        colour_scheme: ColourSchemeKind,
     },
     Julia {
         colour_map: Box<dyn JuliaColourMap>,
         algorithm: JuliaAlgorithm,
+        contrast_stretch: bool,
+        smooth: bool,
+        colour_scheme: ColourSchemeKind,
+    },
+    /// The Mandelbrot set beside the Julia set for the chosen `c`, both
+    /// coloured with the Julia colour map; smooth shading and the colour
+    /// schemes are not applied.
+    JuliaSplitView {
+        colour_map: Box<dyn JuliaColourMap>,
+        algorithm: SplitViewAlgorithm,
+    },
+    /// Coloured with the Mandelbrot colour maps, whose escape counts it
+    /// shares.
+    BurningShip {
+        colour_map: Box<dyn MandelbrotColourMap>,
+        algorithm: BurningShipAlgorithm,
+        contrast_stretch: bool,
+        smooth: bool,
+        colour_scheme: ColourSchemeKind,
+    },
+    /// Coloured with the Mandelbrot colour maps, like the Burning Ship.
+    Tricorn {
+        colour_map: Box<dyn MandelbrotColourMap>,
+        algorithm: TricornAlgorithm,
+        contrast_stretch: bool,
+        smooth: bool,
+        colour_scheme: ColourSchemeKind,
+    },
+    /// Debug comparison of the direct Mandelbrot algorithm against its
+    /// brute-force `reference`: the frame shows where iteration counts
+    /// differ, and is all black when they agree.
+    MandelbrotReferenceDiff {
+        colour_map: IterationDiffColourMap,
+        algorithm: MandelbrotAlgorithm,
+        reference: MandelbrotAlgorithm,
     },
 }
 
 impl FractalConfig {
+    /// A Mandelbrot request for a `width`x`height` image of the region
+    /// `bounds`, given as `[re_min, im_min, re_max, im_max]`, validating
+    /// everything up front. Views too deep for f64 pixel coordinates render through
+    /// perturbation, as in the GUI.
+    pub fn mandelbrot(
+        width: u32,
+        height: u32,
+        bounds: [f64; 4],
+        max_iterations: u32,
+        colour_map_kind: MandelbrotColourMapKinds,
+    ) -> Result<Self, FractalConfigError> {
+        let (pixel_rect, region) = validate_view(width, height, bounds, max_iterations)?;
+
+        let config = MandelbrotConfig {
+            region: DeepRegion::from_complex_rect(&region),
+            max_iterations,
+            colour_map_kind,
+            ..MandelbrotConfig::default()
+        };
+
+        Ok(config.build_render_request(pixel_rect))
+    }
+
+    /// The Julia equivalent of [`Self::mandelbrot`].
+    pub fn julia(
+        width: u32,
+        height: u32,
+        bounds: [f64; 4],
+        max_iterations: u32,
+        colour_map_kind: JuliaColourMapKinds,
+    ) -> Result<Self, FractalConfigError> {
+        let (pixel_rect, region) = validate_view(width, height, bounds, max_iterations)?;
+
+        let config = JuliaConfig {
+            region,
+            max_iterations,
+            colour_map_kind,
+            contrast_stretch: false,
+            ..JuliaConfig::default()
+        };
+
+        Ok(config.build_render_request(pixel_rect))
+    }
+
     pub fn algorithm(
         &self,
     ) -> &(dyn FractalAlgorithm<Success = u32, Failure = PixelToComplexCoordsError> + Sync) {
         match self {
             FractalConfig::Mandelbrot { algorithm, .. } => algorithm,
             FractalConfig::Julia { algorithm, .. } => algorithm,
+            FractalConfig::JuliaSplitView { algorithm, .. } => algorithm,
+            FractalConfig::BurningShip { algorithm, .. } => algorithm,
+            FractalConfig::Tricorn { algorithm, .. } => algorithm,
+            FractalConfig::MandelbrotReferenceDiff { algorithm, .. } => algorithm,
         }
     }
 
@@ -32,6 +181,110 @@ impl FractalConfig {
         match self {
             FractalConfig::Mandelbrot { colour_map, .. } => colour_map.as_ref(),
             FractalConfig::Julia { colour_map, .. } => colour_map.as_ref(),
+            FractalConfig::JuliaSplitView { colour_map, .. } => colour_map.as_ref(),
+            FractalConfig::BurningShip { colour_map, .. } => colour_map.as_ref(),
+            FractalConfig::Tricorn { colour_map, .. } => colour_map.as_ref(),
+            FractalConfig::MandelbrotReferenceDiff { colour_map, .. } => colour_map,
+        }
+    }
+
+    /// The iteration limit the frame renders with.
+    #[must_use]
+    pub fn max_iterations(&self) -> u32 {
+        match self {
+            FractalConfig::Mandelbrot { algorithm, .. } => algorithm.max_iterations(),
+            FractalConfig::Julia { algorithm, .. } => algorithm.max_iterations(),
+            FractalConfig::JuliaSplitView { algorithm, .. } => algorithm.max_iterations(),
+            FractalConfig::BurningShip { algorithm, .. } => algorithm.max_iterations(),
+            FractalConfig::Tricorn { algorithm, .. } => algorithm.max_iterations(),
+            FractalConfig::MandelbrotReferenceDiff { algorithm, .. } => algorithm.max_iterations(),
+        }
+    }
+
+    /// `Some(max_iterations)` when the frame should be coloured through a
+    /// `ContrastStretchColourMap` over its escaped iteration range.
+    pub fn contrast_stretch(&self) -> Option<u32> {
+        match self {
+            FractalConfig::Mandelbrot {
+                algorithm,
+                contrast_stretch: true,
+                ..
+            } => Some(algorithm.max_iterations()),
+            FractalConfig::Julia {
+                algorithm,
+                contrast_stretch: true,
+                ..
+            } => Some(algorithm.max_iterations()),
+            FractalConfig::BurningShip {
+                algorithm,
+                contrast_stretch: true,
+                ..
+            } => Some(algorithm.max_iterations()),
+            FractalConfig::Tricorn {
+                algorithm,
+                contrast_stretch: true,
+                ..
+            } => Some(algorithm.max_iterations()),
+            _ => None,
+        }
+    }
+
+    /// `Some(max_iterations)` when the frame should be coloured through a
+    /// `HistogramColourMap` over its escaped counts. Takes precedence over
+    /// contrast stretching, which equalising already implies.
+    pub fn histogram(&self) -> Option<u32> {
+        match self {
+            FractalConfig::Mandelbrot {
+                algorithm,
+                colour_scheme: ColourSchemeKind::Histogram,
+                ..
+            } => Some(algorithm.max_iterations()),
+            FractalConfig::Julia {
+                algorithm,
+                colour_scheme: ColourSchemeKind::Histogram,
+                ..
+            } => Some(algorithm.max_iterations()),
+            FractalConfig::BurningShip {
+                algorithm,
+                colour_scheme: ColourSchemeKind::Histogram,
+                ..
+            } => Some(algorithm.max_iterations()),
+            FractalConfig::Tricorn {
+                algorithm,
+                colour_scheme: ColourSchemeKind::Histogram,
+                ..
+            } => Some(algorithm.max_iterations()),
+            _ => None,
+        }
+    }
+
+    /// The algorithm to render continuous escape counts with, when the
+    /// request asks for smooth shading and its algorithm can provide them.
+    pub fn smooth_algorithm(
+        &self,
+    ) -> Option<&(dyn SmoothEscape<Failure = PixelToComplexCoordsError> + Sync)> {
+        match self {
+            FractalConfig::Mandelbrot {
+                algorithm: MandelbrotRenderPath::Direct(algorithm),
+                smooth: true,
+                ..
+            } => Some(algorithm),
+            FractalConfig::Julia {
+                algorithm,
+                smooth: true,
+                ..
+            } => Some(algorithm),
+            FractalConfig::BurningShip {
+                algorithm,
+                smooth: true,
+                ..
+            } => Some(algorithm),
+            FractalConfig::Tricorn {
+                algorithm,
+                smooth: true,
+                ..
+            } => Some(algorithm),
+            _ => None,
         }
     }
 
@@ -41,7 +294,11 @@ impl FractalConfig {
     pub fn prepare<C: CancelToken + ?Sized>(&self, cancel: &C) -> Result<(), Cancelled> {
         match self {
             FractalConfig::Mandelbrot { algorithm, .. } => algorithm.prepare(cancel),
-            FractalConfig::Julia { .. } => Ok(()),
+            FractalConfig::Julia { .. }
+            | FractalConfig::JuliaSplitView { .. }
+            | FractalConfig::BurningShip { .. }
+            | FractalConfig::Tricorn { .. }
+            | FractalConfig::MandelbrotReferenceDiff { .. } => Ok(()),
         }
     }
 }
@@ -50,14 +307,217 @@ impl PartialEq for FractalConfig {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (
-                FractalConfig::Mandelbrot { colour_map: cmap1, algorithm: alg1 },
-                FractalConfig::Mandelbrot { colour_map: cmap2, algorithm: alg2 },
-            ) => cmap1.kind() == cmap2.kind() && alg1 == alg2,
+                FractalConfig::Mandelbrot {
+                    colour_map: cmap1,
+                    algorithm: alg1,
+                    contrast_stretch: cs1,
+                    smooth: s1,
+                    colour_scheme: scheme1,
+                },
+                FractalConfig::Mandelbrot {
+                    colour_map: cmap2,
+                    algorithm: alg2,
+                    contrast_stretch: cs2,
+                    smooth: s2,
+                    colour_scheme: scheme2,
+                },
+            ) => cmap1.kind() == cmap2.kind() && alg1 == alg2 && cs1 == cs2 && s1 == s2 && scheme1 == scheme2,
             (
-                FractalConfig::Julia { colour_map: cmap1, algorithm: alg1 },
-                FractalConfig::Julia { colour_map: cmap2, algorithm: alg2 },
+                FractalConfig::Julia {
+                    colour_map: cmap1,
+                    algorithm: alg1,
+                    contrast_stretch: cs1,
+                    smooth: s1,
+                    colour_scheme: scheme1,
+                },
+                FractalConfig::Julia {
+                    colour_map: cmap2,
+                    algorithm: alg2,
+                    contrast_stretch: cs2,
+                    smooth: s2,
+                    colour_scheme: scheme2,
+                },
+            ) => cmap1.kind() == cmap2.kind() && alg1 == alg2 && cs1 == cs2 && s1 == s2 && scheme1 == scheme2,
+            (
+                FractalConfig::JuliaSplitView { colour_map: cmap1, algorithm: alg1 },
+                FractalConfig::JuliaSplitView { colour_map: cmap2, algorithm: alg2 },
             ) => cmap1.kind() == cmap2.kind() && alg1 == alg2,
+            (
+                FractalConfig::BurningShip {
+                    colour_map: cmap1,
+                    algorithm: alg1,
+                    contrast_stretch: cs1,
+                    smooth: s1,
+                    colour_scheme: scheme1,
+                },
+                FractalConfig::BurningShip {
+                    colour_map: cmap2,
+                    algorithm: alg2,
+                    contrast_stretch: cs2,
+                    smooth: s2,
+                    colour_scheme: scheme2,
+                },
+            ) => cmap1.kind() == cmap2.kind() && alg1 == alg2 && cs1 == cs2 && s1 == s2 && scheme1 == scheme2,
+            (
+                FractalConfig::Tricorn {
+                    colour_map: cmap1,
+                    algorithm: alg1,
+                    contrast_stretch: cs1,
+                    smooth: s1,
+                    colour_scheme: scheme1,
+                },
+                FractalConfig::Tricorn {
+                    colour_map: cmap2,
+                    algorithm: alg2,
+                    contrast_stretch: cs2,
+                    smooth: s2,
+                    colour_scheme: scheme2,
+                },
+            ) => cmap1.kind() == cmap2.kind() && alg1 == alg2 && cs1 == cs2 && s1 == s2 && scheme1 == scheme2,
+            (
+                FractalConfig::MandelbrotReferenceDiff { algorithm: alg1, reference: ref1, .. },
+                FractalConfig::MandelbrotReferenceDiff { algorithm: alg2, reference: ref2, .. },
+            ) => alg1 == alg2 && ref1 == ref2,
             _ => false,
         }
     }
 }
+
+fn validate_view(
+    width: u32,
+    height: u32,
+    bounds: [f64; 4],
+    max_iterations: u32,
+) -> Result<(PixelRect, ComplexRect), FractalConfigError> {
+    let [re_min, im_min, re_max, im_max] = bounds;
+    let invalid_size = FractalConfigError::InvalidImageSize { width, height };
+    let (Ok(right), Ok(bottom)) = (i32::try_from(width), i32::try_from(height)) else {
+        return Err(invalid_size);
+    };
+    if width == 0 || height == 0 {
+        return Err(invalid_size);
+    }
+    let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: right - 1, y: bottom - 1 })
+        .map_err(|_| invalid_size)?;
+
+    let region = ComplexRect::new(
+        Complex {
+            real: re_min,
+            imag: im_min,
+        },
+        Complex {
+            real: re_max,
+            imag: im_max,
+        },
+    )
+    .map_err(FractalConfigError::InvalidBounds)?;
+    check_pixel_steps(pixel_rect, region).map_err(FractalConfigError::UnresolvableBounds)?;
+
+    if max_iterations == 0 {
+        return Err(FractalConfigError::ZeroMaxIterations);
+    }
+
+    Ok((pixel_rect, region))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mandelbrot(width: u32, height: u32, bounds: [f64; 4]) -> Result<FractalConfig, FractalConfigError> {
+        FractalConfig::mandelbrot(width, height, bounds, 256, MandelbrotColourMapKinds::default())
+    }
+
+    const OVERVIEW: [f64; 4] = [-2.5, -1.0, 1.0, 1.0];
+
+    #[test]
+    fn valid_parameters_build_requests() {
+        let request = mandelbrot(64, 48, OVERVIEW).unwrap();
+        assert!(matches!(
+            request,
+            FractalConfig::Mandelbrot {
+                algorithm: MandelbrotRenderPath::Direct(_),
+                ..
+            }
+        ));
+        assert_eq!(request.algorithm().pixel_rect().width(), 64);
+        assert_eq!(request.algorithm().pixel_rect().height(), 48);
+
+        let julia =
+            FractalConfig::julia(64, 48, [-1.5, -1.0, 1.5, 1.0], 128, JuliaColourMapKinds::default())
+                .unwrap();
+        assert!(matches!(julia, FractalConfig::Julia { .. }));
+    }
+
+    #[test]
+    fn every_colour_map_kind_reaches_the_controller_and_back() {
+        for &kind in MandelbrotColourMapKinds::ALL {
+            let request = FractalConfig::mandelbrot(8, 8, OVERVIEW, 64, kind).unwrap();
+            let FractalConfig::Mandelbrot { colour_map, .. } = &request else {
+                panic!("expected a Mandelbrot request");
+            };
+            assert_eq!(colour_map.kind(), kind);
+            assert_eq!(request.colour_map().display_name(), kind.display_name());
+        }
+
+        for &kind in JuliaColourMapKinds::ALL {
+            let request = FractalConfig::julia(8, 8, [-1.5, -1.0, 1.5, 1.0], 64, kind).unwrap();
+            let FractalConfig::Julia { colour_map, .. } = &request else {
+                panic!("expected a Julia request");
+            };
+            assert_eq!(colour_map.kind(), kind);
+            assert_eq!(request.colour_map().display_name(), kind.display_name());
+        }
+    }
+
+    #[test]
+    fn deep_bounds_use_perturbation() {
+        let request = mandelbrot(16, 16, [-0.75, 0.1, -0.75 + 1e-12, 0.1 + 1e-12]).unwrap();
+
+        assert!(matches!(
+            request,
+            FractalConfig::Mandelbrot {
+                algorithm: MandelbrotRenderPath::Perturbation(_),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn invalid_dimensions_are_rejected() {
+        for (width, height) in [(0, 10), (10, 0), (u32::MAX, 10)] {
+            assert!(matches!(
+                mandelbrot(width, height, OVERVIEW),
+                Err(FractalConfigError::InvalidImageSize { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn invalid_bounds_are_rejected() {
+        assert!(matches!(
+            mandelbrot(10, 10, [1.0, -1.0, -2.5, 1.0]),
+            Err(FractalConfigError::InvalidBounds(_))
+        ));
+        assert!(matches!(
+            mandelbrot(10, 10, [-2.5, 1.0, 1.0, 1.0]),
+            Err(FractalConfigError::InvalidBounds(_))
+        ));
+        assert!(matches!(
+            mandelbrot(10, 10, [f64::NAN, -1.0, 1.0, 1.0]),
+            Err(FractalConfigError::UnresolvableBounds(_))
+        ));
+        assert!(matches!(
+            mandelbrot(10, 10, [-2.5, -1.0, f64::INFINITY, 1.0]),
+            Err(FractalConfigError::UnresolvableBounds(_))
+        ));
+    }
+
+    #[test]
+    fn zero_iterations_are_rejected() {
+        let result =
+            FractalConfig::julia(10, 10, [-1.5, -1.0, 1.5, 1.0], 0, JuliaColourMapKinds::default());
+
+        assert!(matches!(result, Err(FractalConfigError::ZeroMaxIterations)));
+    }
+}
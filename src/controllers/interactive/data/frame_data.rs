@@ -1,9 +1,77 @@
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use crate::core::data::pixel_buffer::PixelBuffer;
 
 #[derive(Debug)]
 pub struct FrameData {
     pub generation: u64,
-    pub pixel_buffer: PixelBuffer,
+    /// Shared with the controller's cache of the last frame.
+    pub pixel_buffer: Arc<PixelBuffer>,
     pub render_duration: Duration,
+    /// Every pixel has the same colour; see `PixelBuffer::is_single_colour`.
+    pub single_colour: bool,
+    /// When `submit_request` was called for this frame's generation.
+    pub requested_at: Instant,
+    /// When the controller submitted the frame to the presenter, which is
+    /// where the presenter's maximum frame age is measured from.
+    pub submitted_at: Instant,
+    /// `buffer_checksum` of `pixel_buffer` when the frame was emitted.
+    #[cfg(feature = "frame-checksum")]
+    pub checksum: u64,
+}
+
+#[cfg(feature = "frame-checksum")]
+impl FrameData {
+    /// True if the buffer is byte-for-byte what the controller emitted.
+    #[must_use]
+    pub fn checksum_matches(&self) -> bool {
+        buffer_checksum(&self.pixel_buffer) == self.checksum
+    }
+}
+
+/// 64-bit FNV-1a over the buffer bytes. Fast and order-sensitive, for
+/// catching accidental mutation rather than for security.
+#[cfg(feature = "frame-checksum")]
+#[must_use]
+pub fn buffer_checksum(buffer: &PixelBuffer) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    buffer
+        .buffer()
+        .iter()
+        .fold(OFFSET_BASIS, |hash, &byte| (hash ^ u64::from(byte)).wrapping_mul(PRIME))
+}
+
+#[cfg(all(test, feature = "frame-checksum"))]
+mod tests {
+    use super::*;
+    use crate::core::data::colour::Colour;
+    use crate::core::data::pixel_rect::PixelRect;
+    use crate::core::data::point::Point;
+
+    fn frame(pixel_buffer: PixelBuffer) -> FrameData {
+        FrameData {
+            generation: 1,
+            checksum: buffer_checksum(&pixel_buffer),
+            pixel_buffer: Arc::new(pixel_buffer),
+            render_duration: Duration::ZERO,
+            single_colour: false,
+            requested_at: Instant::now(),
+            submitted_at: Instant::now(),
+        }
+    }
+
+    #[test]
+    fn checksum_detects_a_mutated_pixel() {
+        let rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 2 }).unwrap();
+        let mut frame = frame(PixelBuffer::new(rect));
+        assert!(frame.checksum_matches());
+
+        Arc::make_mut(&mut frame.pixel_buffer)
+            .set_pixel(Point { x: 1, y: 1 }, Colour { r: 1, g: 0, b: 0 })
+            .unwrap();
+
+        assert!(!frame.checksum_matches());
+    }
 }
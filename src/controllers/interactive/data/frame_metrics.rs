@@ -0,0 +1,13 @@
+use std::time::Duration;
+
+/// Summary of one emitted frame, passed to the controller's metrics
+/// callback; see `InteractiveController::set_metrics_callback`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameMetrics {
+    pub generation: u64,
+    pub render_duration: Duration,
+    pub pixel_count: u64,
+}
+
+/// Callback receiving a [`FrameMetrics`] for every emitted frame.
+pub type FrameMetricsCallback = Box<dyn Fn(FrameMetrics) + Send + Sync>;
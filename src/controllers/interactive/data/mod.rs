@@ -1,2 +1,3 @@
 pub mod frame_data;
+pub mod frame_metrics;
 pub mod fractal_config;
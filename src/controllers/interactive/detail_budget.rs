@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+/// Tunes `max_iterations` so frames render in about `target`.
+///
+/// Render time grows roughly in proportion to the iteration budget, so
+/// after each frame the budget becomes that frame's `max_iterations` scaled
+/// by `target / render_duration`. Scaling the frame's own limit rather
+/// than the budget keeps frames rendered below it (a flight's reduced
+/// iterations, say) from inflating it.
+/// Frames within `tolerance` of the target (as a fraction of it) leave the
+/// budget alone, so it settles instead of hunting, and one frame moves it
+/// by at most a factor of `max_step` either way, so a single outlier (a
+/// view change, a stall) cannot swing it far. The budget stays within
+/// `min_iterations..=max_iterations`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetailBudget {
+    pub target: Duration,
+    pub tolerance: f64,
+    pub max_step: f64,
+    pub min_iterations: u32,
+    pub max_iterations: u32,
+    iterations: u32,
+}
+
+impl DetailBudget {
+    /// A budget aiming at `target` per frame, starting from
+    /// `initial_iterations`.
+    #[must_use]
+    pub fn new(target: Duration, initial_iterations: u32) -> Self {
+        let mut budget = Self {
+            target,
+            tolerance: 0.2,
+            max_step: 2.0,
+            min_iterations: 32,
+            max_iterations: 1 << 16,
+            iterations: 0,
+        };
+        budget.iterations = budget.clamp(f64::from(initial_iterations));
+        budget
+    }
+
+    #[must_use]
+    pub fn with_iteration_range(mut self, min_iterations: u32, max_iterations: u32) -> Self {
+        self.min_iterations = min_iterations.max(1);
+        self.max_iterations = max_iterations.max(self.min_iterations);
+        self.iterations = self.clamp(f64::from(self.iterations));
+        self
+    }
+
+    /// The budget for the next frame.
+    #[must_use]
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// A frame rendered with `max_iterations` took `render_duration`;
+    /// returns the adjusted budget.
+    pub fn record_frame(&mut self, render_duration: Duration, max_iterations: u32) -> u32 {
+        let target = self.target.as_secs_f64();
+        let measured = render_duration.as_secs_f64();
+
+        if target <= 0.0 {
+            return self.iterations;
+        }
+        if ((measured - target) / target).abs() <= self.tolerance {
+            self.iterations = self.clamp(f64::from(max_iterations));
+            return self.iterations;
+        }
+
+        let max_step = self.max_step.max(1.0);
+        let factor = if measured > 0.0 {
+            (target / measured).clamp(1.0 / max_step, max_step)
+        } else {
+            max_step
+        };
+
+        self.iterations = self.clamp(f64::from(max_iterations) * factor);
+        self.iterations
+    }
+
+    fn clamp(&self, iterations: f64) -> u32 {
+        let min = self.min_iterations.max(1);
+        let max = self.max_iterations.max(min);
+
+        (iterations.round() as u32).clamp(min, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TARGET: Duration = Duration::from_millis(16);
+
+    #[test]
+    fn over_budget_frames_lower_iterations_in_proportion() {
+        let mut budget = DetailBudget::new(TARGET, 1000);
+
+        assert_eq!(budget.record_frame(Duration::from_millis(20), budget.iterations()), 800);
+        assert_eq!(budget.iterations(), 800);
+    }
+
+    #[test]
+    fn under_budget_frames_raise_iterations_in_proportion() {
+        let mut budget = DetailBudget::new(TARGET, 1000);
+
+        assert_eq!(budget.record_frame(Duration::from_millis(10), budget.iterations()), 1600);
+    }
+
+    #[test]
+    fn frames_near_the_target_leave_the_budget_alone() {
+        let mut budget = DetailBudget::new(TARGET, 1000);
+
+        assert_eq!(budget.record_frame(Duration::from_millis(18), budget.iterations()), 1000);
+        assert_eq!(budget.record_frame(Duration::from_millis(14), budget.iterations()), 1000);
+    }
+
+    #[test]
+    fn one_frame_moves_the_budget_at_most_one_step() {
+        let mut budget = DetailBudget::new(TARGET, 1000);
+
+        assert_eq!(budget.record_frame(Duration::from_millis(500), budget.iterations()), 500);
+        assert_eq!(budget.record_frame(Duration::ZERO, budget.iterations()), 1000);
+        assert_eq!(budget.record_frame(Duration::from_micros(100), budget.iterations()), 2000);
+    }
+
+    #[test]
+    fn frames_below_the_budget_scale_from_their_own_iterations() {
+        let mut budget = DetailBudget::new(TARGET, 1000);
+
+        // A quarter-budget frame at a quarter of the target: 250 iterations
+        // would fit four times over, but the step limit allows double.
+        assert_eq!(budget.record_frame(Duration::from_millis(4), 250), 500);
+        // On target at 500 iterations, so 500 is the budget.
+        assert_eq!(budget.record_frame(TARGET, 500), 500);
+    }
+
+    #[test]
+    fn budget_stays_within_its_range() {
+        let mut budget = DetailBudget::new(TARGET, 100).with_iteration_range(64, 150);
+
+        assert_eq!(budget.record_frame(Duration::from_millis(4), budget.iterations()), 150);
+        for _ in 0..10 {
+            budget.record_frame(Duration::from_secs(1), budget.iterations());
+        }
+        assert_eq!(budget.iterations(), 64);
+
+        assert_eq!(DetailBudget::new(TARGET, 5).iterations(), 32);
+    }
+
+    #[test]
+    fn repeated_feedback_settles_on_the_target() {
+        // Simulated renderer: 20 µs per iteration, so 800 iterations hit
+        // the target exactly.
+        let render_time = |iterations: u32| Duration::from_micros(20 * u64::from(iterations));
+        let mut budget = DetailBudget::new(TARGET, 100);
+
+        for _ in 0..10 {
+            let iterations = budget.iterations();
+            budget.record_frame(render_time(iterations), iterations);
+        }
+
+        let settled = render_time(budget.iterations()).as_secs_f64();
+        assert!((settled / TARGET.as_secs_f64() - 1.0).abs() <= 0.2, "settled at {settled}s");
+    }
+}
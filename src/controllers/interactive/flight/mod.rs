@@ -1,5 +1,7 @@
 pub mod scheduler;
+pub mod scripted;
 pub mod simulator;
 
-pub use scheduler::{RenderScheduler, SchedulerAction};
+pub use scheduler::{RenderScheduler, RequestPriority, SchedulerAction};
+pub use scripted::ScriptedControls;
 pub use simulator::FlightSimulator;
@@ -1,9 +1,24 @@
 use crate::controllers::interactive::data::fractal_config::FractalConfig;
+use crate::controllers::interactive::generation::is_newer;
 use std::sync::Arc;
 
 pub struct RenderScheduler {
     pending_request: Option<Arc<FractalConfig>>,
     in_flight_generation: Option<u64>,
+    submitted_count: u64,
+    coalesced_count: u64,
+}
+
+/// How urgently a desired request should replace the one in flight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RequestPriority {
+    /// May be coalesced behind the in-flight render during flight.
+    #[default]
+    Normal,
+    /// Submitted immediately, superseding (and so cancelling) the in-flight
+    /// render. Used when the viewport size changes: a stale-sized frame
+    /// would be discarded anyway.
+    Preempt,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,12 +34,15 @@ impl RenderScheduler {
         Self {
             pending_request: None,
             in_flight_generation: None,
+            submitted_count: 0,
+            coalesced_count: 0,
         }
     }
 
     pub fn update(
         &mut self,
         desired: Arc<FractalConfig>,
+        priority: RequestPriority,
         flight_active: bool,
         last_completed_gen: u64,
         submit: impl FnOnce(Arc<FractalConfig>) -> u64,
@@ -32,16 +50,22 @@ impl RenderScheduler {
         self.mark_completed(last_completed_gen);
         self.pending_request = Some(desired);
 
-        if self.in_flight_generation.is_none() || !flight_active {
+        if self.in_flight_generation.is_none()
+            || !flight_active
+            || priority == RequestPriority::Preempt
+        {
             return self.submit_pending(submit);
         }
 
+        self.coalesced_count += 1;
         SchedulerAction::Coalesced
     }
 
     pub fn reset(&mut self) {
         self.pending_request = None;
         self.in_flight_generation = None;
+        self.submitted_count = 0;
+        self.coalesced_count = 0;
     }
 
     pub fn observe_completion(&mut self, last_completed_gen: u64) {
@@ -58,10 +82,24 @@ impl RenderScheduler {
         self.in_flight_generation
     }
 
+    /// Requests handed to the controller since the last `reset`.
+    #[must_use]
+    pub fn submitted_count(&self) -> u64 {
+        self.submitted_count
+    }
+
+    /// Updates held back behind an in-flight render since the last `reset`.
+    /// Each was superseded by a later request, so a high ratio to
+    /// `submitted_count` means flight is skipping many intermediate frames.
+    #[must_use]
+    pub fn coalesced_count(&self) -> u64 {
+        self.coalesced_count
+    }
+
     fn mark_completed(&mut self, last_completed_gen: u64) {
         if self
             .in_flight_generation
-            .is_some_and(|generation| last_completed_gen >= generation)
+            .is_some_and(|generation| !is_newer(generation, last_completed_gen))
         {
             self.in_flight_generation = None;
         }
@@ -77,6 +115,7 @@ impl RenderScheduler {
 
         let generation = submit(request);
         self.in_flight_generation = Some(generation);
+        self.submitted_count += 1;
 
         SchedulerAction::Submitted { generation }
     }
@@ -90,7 +129,7 @@ impl Default for RenderScheduler {
 
 #[cfg(test)]
 mod tests {
-    use super::{RenderScheduler, SchedulerAction};
+    use super::{RenderScheduler, RequestPriority, SchedulerAction};
     use crate::{
         controllers::interactive::data::fractal_config::FractalConfig,
         core::{
@@ -114,7 +153,7 @@ mod tests {
     fn submits_immediately_when_nothing_is_in_flight() {
         let mut scheduler = RenderScheduler::new();
 
-        let action = scheduler.update(request(10), true, 0, |_| 1);
+        let action = scheduler.update(request(10), RequestPriority::Normal, true, 0, |_| 1);
 
         assert_eq!(action, SchedulerAction::Submitted { generation: 1 });
         assert_eq!(scheduler.in_flight_generation(), Some(1));
@@ -124,9 +163,9 @@ mod tests {
     #[test]
     fn submits_immediately_when_in_flight_and_flight_inactive() {
         let mut scheduler = RenderScheduler::new();
-        let _ = scheduler.update(request(10), true, 0, |_| 1);
+        let _ = scheduler.update(request(10), RequestPriority::Normal, true, 0, |_| 1);
 
-        let action = scheduler.update(request(11), false, 0, |_| 2);
+        let action = scheduler.update(request(11), RequestPriority::Normal, false, 0, |_| 2);
 
         assert_eq!(action, SchedulerAction::Submitted { generation: 2 });
         assert_eq!(scheduler.in_flight_generation(), Some(2));
@@ -136,11 +175,11 @@ mod tests {
     #[test]
     fn coalesces_when_in_flight_and_flight_active() {
         let mut scheduler = RenderScheduler::new();
-        let _ = scheduler.update(request(10), true, 0, |_| 1);
+        let _ = scheduler.update(request(10), RequestPriority::Normal, true, 0, |_| 1);
 
         let mut submitted = false;
         let next = request(11);
-        let action = scheduler.update(Arc::clone(&next), true, 0, |_| {
+        let action = scheduler.update(Arc::clone(&next), RequestPriority::Normal, true, 0, |_| {
             submitted = true;
             2
         });
@@ -158,13 +197,13 @@ mod tests {
     #[test]
     fn multiple_coalesced_updates_keep_only_the_newest_pending_request() {
         let mut scheduler = RenderScheduler::new();
-        let _ = scheduler.update(request(10), true, 0, |_| 1);
+        let _ = scheduler.update(request(10), RequestPriority::Normal, true, 0, |_| 1);
 
         let second = request(11);
         let third = request(12);
 
-        let _ = scheduler.update(Arc::clone(&second), true, 0, |_| panic!("must not submit"));
-        let _ = scheduler.update(Arc::clone(&third), true, 0, |_| panic!("must not submit"));
+        let _ = scheduler.update(Arc::clone(&second), RequestPriority::Normal, true, 0, |_| panic!("must not submit"));
+        let _ = scheduler.update(Arc::clone(&third), RequestPriority::Normal, true, 0, |_| panic!("must not submit"));
 
         assert!(Arc::ptr_eq(
             scheduler.pending_request.as_ref().expect("pending exists"),
@@ -175,12 +214,12 @@ mod tests {
     #[test]
     fn completion_allows_pending_request_to_submit() {
         let mut scheduler = RenderScheduler::new();
-        let _ = scheduler.update(request(10), true, 0, |_| 1);
-        let _ = scheduler.update(request(11), true, 0, |_| panic!("must not submit"));
+        let _ = scheduler.update(request(10), RequestPriority::Normal, true, 0, |_| 1);
+        let _ = scheduler.update(request(11), RequestPriority::Normal, true, 0, |_| panic!("must not submit"));
 
         let newest = request(12);
         let mut submitted_request: Option<Arc<FractalConfig>> = None;
-        let action = scheduler.update(Arc::clone(&newest), true, 1, |request| {
+        let action = scheduler.update(Arc::clone(&newest), RequestPriority::Normal, true, 1, |request| {
             submitted_request = Some(request);
             2
         });
@@ -199,9 +238,9 @@ mod tests {
     #[test]
     fn completion_mismatch_keeps_in_flight_generation() {
         let mut scheduler = RenderScheduler::new();
-        let _ = scheduler.update(request(10), true, 0, |_| 5);
+        let _ = scheduler.update(request(10), RequestPriority::Normal, true, 0, |_| 5);
 
-        let action = scheduler.update(request(11), true, 4, |_| panic!("must not submit"));
+        let action = scheduler.update(request(11), RequestPriority::Normal, true, 4, |_| panic!("must not submit"));
 
         assert_eq!(action, SchedulerAction::Coalesced);
         assert_eq!(scheduler.in_flight_generation(), Some(5));
@@ -211,8 +250,8 @@ mod tests {
     #[test]
     fn reset_clears_pending_and_in_flight_state() {
         let mut scheduler = RenderScheduler::new();
-        let _ = scheduler.update(request(10), true, 0, |_| 1);
-        let _ = scheduler.update(request(11), true, 0, |_| panic!("must not submit"));
+        let _ = scheduler.update(request(10), RequestPriority::Normal, true, 0, |_| 1);
+        let _ = scheduler.update(request(11), RequestPriority::Normal, true, 0, |_| panic!("must not submit"));
 
         scheduler.reset();
 
@@ -220,10 +259,57 @@ mod tests {
         assert_eq!(scheduler.in_flight_generation(), None);
     }
 
+    #[test]
+    fn counts_submitted_and_coalesced_updates() {
+        let mut scheduler = RenderScheduler::new();
+
+        let _ = scheduler.update(request(10), RequestPriority::Normal, true, 0, |_| 1);
+        for max_iterations in 11..=13 {
+            let _ = scheduler.update(request(max_iterations), RequestPriority::Normal, true, 0, |_| panic!("must not submit"));
+        }
+        let _ = scheduler.update(request(14), RequestPriority::Normal, true, 1, |_| 2);
+        let _ = scheduler.update(request(15), RequestPriority::Preempt, true, 1, |_| 3);
+        let _ = scheduler.update(request(16), RequestPriority::Normal, false, 1, |_| 4);
+
+        assert_eq!(scheduler.submitted_count(), 4);
+        assert_eq!(scheduler.coalesced_count(), 3);
+    }
+
+    #[test]
+    fn reset_clears_the_counters() {
+        let mut scheduler = RenderScheduler::new();
+        let _ = scheduler.update(request(10), RequestPriority::Normal, true, 0, |_| 1);
+        let _ = scheduler.update(request(11), RequestPriority::Normal, true, 0, |_| panic!("must not submit"));
+
+        scheduler.reset();
+
+        assert_eq!(scheduler.submitted_count(), 0);
+        assert_eq!(scheduler.coalesced_count(), 0);
+    }
+
+    #[test]
+    fn completion_tracking_survives_the_generation_counter_wrapping() {
+        let mut scheduler = RenderScheduler::new();
+        let _ = scheduler.update(request(10), RequestPriority::Normal, true, 0, |_| 1);
+
+        // Completions from just before the wrap don't finish generation 1.
+        scheduler.observe_completion(u64::MAX);
+        assert_eq!(scheduler.in_flight_generation(), Some(1));
+
+        scheduler.observe_completion(1);
+        assert_eq!(scheduler.in_flight_generation(), None);
+
+        let _ = scheduler.update(request(11), RequestPriority::Normal, true, u64::MAX - 1, |_| u64::MAX);
+        scheduler.observe_completion(u64::MAX - 1);
+        assert_eq!(scheduler.in_flight_generation(), Some(u64::MAX));
+        scheduler.observe_completion(2);
+        assert_eq!(scheduler.in_flight_generation(), None);
+    }
+
     #[test]
     fn observe_completion_clears_in_flight_when_done() {
         let mut scheduler = RenderScheduler::new();
-        let _ = scheduler.update(request(10), true, 0, |_| 7);
+        let _ = scheduler.update(request(10), RequestPriority::Normal, true, 0, |_| 7);
 
         scheduler.observe_completion(6);
         assert_eq!(scheduler.in_flight_generation(), Some(7));
@@ -237,25 +323,25 @@ mod tests {
         let mut scheduler = RenderScheduler::new();
         let same = request(10);
 
-        let first = scheduler.update(Arc::clone(&same), true, 0, |_| 1);
+        let first = scheduler.update(Arc::clone(&same), RequestPriority::Normal, true, 0, |_| 1);
         assert_eq!(first, SchedulerAction::Submitted { generation: 1 });
 
-        let second = scheduler.update(Arc::clone(&same), true, 0, |_| panic!("must not submit"));
+        let second = scheduler.update(Arc::clone(&same), RequestPriority::Normal, true, 0, |_| panic!("must not submit"));
         assert_eq!(second, SchedulerAction::Coalesced);
 
-        let third = scheduler.update(Arc::clone(&same), true, 1, |_| 2);
+        let third = scheduler.update(Arc::clone(&same), RequestPriority::Normal, true, 1, |_| 2);
         assert_eq!(third, SchedulerAction::Submitted { generation: 2 });
     }
 
     #[test]
     fn rapid_updates_during_flight_leave_only_last_pending_request() {
         let mut scheduler = RenderScheduler::new();
-        let _ = scheduler.update(request(10), true, 0, |_| 1);
+        let _ = scheduler.update(request(10), RequestPriority::Normal, true, 0, |_| 1);
 
         let mut last = request(11);
         for max_iterations in 12..=20 {
             let next = request(max_iterations);
-            let _ = scheduler.update(Arc::clone(&next), true, 0, |_| panic!("must not submit"));
+            let _ = scheduler.update(Arc::clone(&next), RequestPriority::Normal, true, 0, |_| panic!("must not submit"));
             last = next;
         }
 
@@ -264,4 +350,16 @@ mod tests {
             &last
         ));
     }
+
+    #[test]
+    fn preempt_submits_immediately_during_flight() {
+        let mut scheduler = RenderScheduler::new();
+        let _ = scheduler.update(request(10), RequestPriority::Normal, true, 0, |_| 1);
+
+        let action = scheduler.update(request(11), RequestPriority::Preempt, true, 0, |_| 2);
+
+        assert_eq!(action, SchedulerAction::Submitted { generation: 2 });
+        assert_eq!(scheduler.in_flight_generation(), Some(2));
+        assert!(!scheduler.has_pending());
+    }
 }
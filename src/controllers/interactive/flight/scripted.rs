@@ -0,0 +1,148 @@
+use crate::core::flight::FlightControlsSnapshot;
+use std::collections::VecDeque;
+
+/// A predetermined sequence of control snapshots, one per simulation tick,
+/// for deterministic flight tests and demos.
+///
+/// Feed it to [`FlightSimulator::advance`](super::FlightSimulator::advance)
+/// in place of live input with `|| script.next_snapshot()`. Once the queue
+/// runs out every tick sees released controls, so the flight coasts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptedControls {
+    queue: VecDeque<FlightControlsSnapshot>,
+}
+
+impl ScriptedControls {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `controls`, held for `ticks` ticks.
+    #[must_use]
+    pub fn with_hold(mut self, controls: FlightControlsSnapshot, ticks: u32) -> Self {
+        self.push(controls, ticks);
+        self
+    }
+
+    /// Appends `controls`, held for `ticks` ticks.
+    pub fn push(&mut self, controls: FlightControlsSnapshot, ticks: u32) {
+        self.queue.extend(std::iter::repeat_n(controls, ticks as usize));
+    }
+
+    /// The snapshot for the next tick, or released controls once the script
+    /// has run out.
+    pub fn next_snapshot(&mut self) -> FlightControlsSnapshot {
+        self.queue.pop_front().unwrap_or_default()
+    }
+
+    /// Ticks left before the script runs out.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.queue.len()
+    }
+
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+impl FromIterator<FlightControlsSnapshot> for ScriptedControls {
+    fn from_iter<I: IntoIterator<Item = FlightControlsSnapshot>>(snapshots: I) -> Self {
+        Self {
+            queue: snapshots.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScriptedControls;
+    use crate::controllers::interactive::flight::FlightSimulator;
+    use crate::core::flight::{FlightControlsSnapshot, FlightLimits, FlightUpdateReport};
+    use std::time::Duration;
+
+    const EPSILON: f64 = 1e-12;
+
+    const UNPAUSE: FlightControlsSnapshot = FlightControlsSnapshot {
+        w: false,
+        a: false,
+        s: false,
+        d: false,
+        accelerate: false,
+        decelerate: false,
+        zoom_in: false,
+        zoom_out: false,
+        pause_toggle_edge: true,
+    };
+
+    const ACCELERATE: FlightControlsSnapshot = FlightControlsSnapshot {
+        pause_toggle_edge: false,
+        accelerate: true,
+        ..UNPAUSE
+    };
+
+    /// Runs `script` to completion at 60 Hz, returning the speed after
+    /// every tick.
+    fn speed_profile(mut script: ScriptedControls) -> Vec<f64> {
+        let mut simulator = FlightSimulator::new(FlightLimits::default());
+        let mut speeds = Vec::new();
+
+        while !script.is_finished() {
+            let _ = simulator.advance(
+                Duration::from_secs_f64(1.0 / 60.0),
+                || script.next_snapshot(),
+                |motion, _, _| {
+                    speeds.push(motion.speed_world_per_sec);
+                    FlightUpdateReport::default()
+                },
+            );
+        }
+
+        speeds
+    }
+
+    #[test]
+    fn accelerate_then_coast_holds_the_reached_speed() {
+        let script = ScriptedControls::new()
+            .with_hold(UNPAUSE, 1)
+            .with_hold(ACCELERATE, 30)
+            .with_hold(FlightControlsSnapshot::default(), 30);
+
+        let speeds = speed_profile(script);
+
+        // Default limits accelerate at 0.5 world units/s² over 1/60 s ticks.
+        let per_tick = 0.5 / 60.0;
+        assert_eq!(speeds.len(), 61);
+        assert_eq!(speeds[0], 0.0);
+        for (tick, speed) in speeds[1..=30].iter().enumerate() {
+            let expected = per_tick * (tick + 1) as f64;
+            assert!((speed - expected).abs() < EPSILON, "tick {tick}: {speed} != {expected}");
+        }
+        for speed in &speeds[31..] {
+            assert!((speed - 0.25).abs() < EPSILON, "coasting at {speed}");
+        }
+    }
+
+    #[test]
+    fn snapshots_are_served_in_order_one_per_tick() {
+        let mut script: ScriptedControls = [UNPAUSE, ACCELERATE].into_iter().collect();
+        script.push(FlightControlsSnapshot::default(), 2);
+
+        assert_eq!(script.remaining(), 4);
+        assert_eq!(script.next_snapshot(), UNPAUSE);
+        assert_eq!(script.next_snapshot(), ACCELERATE);
+        assert_eq!(script.next_snapshot(), FlightControlsSnapshot::default());
+        assert_eq!(script.remaining(), 1);
+    }
+
+    #[test]
+    fn exhausted_script_releases_the_controls() {
+        let mut script = ScriptedControls::new().with_hold(ACCELERATE, 1);
+
+        assert_eq!(script.next_snapshot(), ACCELERATE);
+        assert!(script.is_finished());
+        assert_eq!(script.next_snapshot(), FlightControlsSnapshot::default());
+    }
+}
@@ -9,6 +9,8 @@ pub struct FlightSimulator {
     limits: FlightLimits,
     accumulator_secs: f64,
     status: FlightStatus,
+    /// The motion a glide returns to when it ends; `Some` while gliding.
+    resting_motion: Option<MotionState>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -29,9 +31,47 @@ impl FlightSimulator {
             limits,
             accumulator_secs: 0.0,
             status: FlightStatus::default(),
+            resting_motion: None,
         }
     }
 
+    /// Lets the view coast on `motion`, as built by
+    /// [`crate::core::flight::glide_motion`] when a drag-pan is released.
+    /// The glide ends, restoring the motion it replaced, once its heading
+    /// has decayed to zero or any flight control is used.
+    pub fn glide(&mut self, motion: MotionState) {
+        if self.resting_motion.is_none() {
+            self.resting_motion = Some(self.motion);
+        }
+        self.motion = motion;
+    }
+
+    #[must_use]
+    pub fn is_gliding(&self) -> bool {
+        self.resting_motion.is_some()
+    }
+
+    /// Ends any glide where it is.
+    pub fn stop_glide(&mut self) {
+        if let Some(resting) = self.resting_motion.take() {
+            self.motion = resting;
+            self.status.paused = resting.paused;
+            self.status.speed = resting.speed_world_per_sec;
+            self.status.heading = resting.heading;
+        }
+    }
+
+    #[must_use]
+    pub fn limits(&self) -> &FlightLimits {
+        &self.limits
+    }
+
+    /// Swaps in new limits, such as a loaded flight profile. The motion
+    /// carries on and is held to them from the next tick.
+    pub fn set_limits(&mut self, limits: FlightLimits) {
+        self.limits = limits;
+    }
+
     pub fn advance<C, U>(
         &mut self,
         elapsed: Duration,
@@ -65,24 +105,7 @@ impl FlightSimulator {
 
         for _ in 0..ticks_run {
             let controls = controls_fn();
-            let previous_motion = self.motion;
-            let previous_status = self.status.clone();
-
-            let motion_report = step_motion(&mut self.motion, controls, dt, &self.limits);
-            let update_report = update_fractal(&self.motion, dt, &self.limits);
-
-            self.status.paused = self.motion.paused;
-            self.status.speed = self.motion.speed_world_per_sec;
-            self.status.heading = self.motion.heading;
-            self.status.last_warning = update_report.warning.or(motion_report.warning);
-
-            if previous_motion != self.motion
-                || self.status != previous_status
-                || motion_report.view_should_update
-                || update_report.clamped
-            {
-                state_changed = true;
-            }
+            state_changed |= self.run_tick(controls, dt, &mut update_fractal);
         }
 
         if dropped_excess {
@@ -101,6 +124,84 @@ impl FlightSimulator {
         }
     }
 
+    /// Runs exactly one tick, ignoring the accumulator, for single-stepping
+    /// while debugging. A paused simulation is advanced as if unpaused and
+    /// stays paused afterwards; the pause-toggle edge in `controls_fn`'s
+    /// snapshot is ignored.
+    pub fn step_once<C, U>(&mut self, mut controls_fn: C, mut update_fractal: U) -> SimulationResult
+    where
+        C: FnMut() -> FlightControlsSnapshot,
+        U: FnMut(&MotionState, f64, &FlightLimits) -> FlightUpdateReport,
+    {
+        let dt = self.limits.dt();
+        if !dt.is_finite() || dt <= 0.0 {
+            return SimulationResult {
+                state_changed: false,
+                ticks_run: 0,
+                status: self.status.clone(),
+            };
+        }
+
+        let controls = FlightControlsSnapshot {
+            pause_toggle_edge: false,
+            ..controls_fn()
+        };
+
+        let was_paused = self.motion.paused;
+        self.motion.paused = false;
+        let state_changed = self.run_tick(controls, dt, &mut update_fractal);
+        self.motion.paused = was_paused;
+        self.status.paused = was_paused;
+
+        SimulationResult {
+            state_changed,
+            ticks_run: 1,
+            status: self.status.clone(),
+        }
+    }
+
+    fn run_tick<U>(&mut self, controls: FlightControlsSnapshot, dt: f64, update_fractal: &mut U) -> bool
+    where
+        U: FnMut(&MotionState, f64, &FlightLimits) -> FlightUpdateReport,
+    {
+        let previous_motion = self.motion;
+        let previous_status = self.status.clone();
+
+        if self.is_gliding() && controls != FlightControlsSnapshot::default() {
+            self.stop_glide();
+        }
+
+        let motion_report = step_motion(&mut self.motion, controls, dt, &self.limits);
+        let update_report = update_fractal(&self.motion, dt, &self.limits);
+        for (heading, bounced) in self.motion.heading.iter_mut().zip(update_report.bounced) {
+            if bounced {
+                *heading = -*heading;
+            }
+        }
+
+        if self.is_gliding() && self.motion.heading == [0.0, 0.0] {
+            self.stop_glide();
+        }
+
+        self.status.paused = self.motion.paused;
+        self.status.speed = self.motion.speed_world_per_sec;
+        self.status.heading = self.motion.heading;
+        self.status.last_warning = update_report.warning.or(motion_report.warning);
+
+        let changed = previous_motion != self.motion
+            || self.status != previous_status
+            || motion_report.view_should_update
+            || update_report.clamped;
+
+        // Counted after the comparison so a repeating warning alone does not
+        // read as a state change.
+        for warning in [motion_report.warning, update_report.warning].into_iter().flatten() {
+            self.status.warning_counts.record(warning);
+        }
+
+        changed
+    }
+
     pub fn reset_motion(&mut self) {
         self.motion = MotionState {
             paused: true,
@@ -108,6 +209,7 @@ impl FlightSimulator {
         };
         self.status = FlightStatus::default();
         self.accumulator_secs = 0.0;
+        self.resting_motion = None;
     }
 
     #[must_use]
@@ -117,8 +219,7 @@ impl FlightSimulator {
 
     #[must_use]
     pub fn is_active(&self) -> bool {
-        !self.motion.paused
-            && (self.motion.speed_world_per_sec != 0.0 || self.motion.accel_world_per_sec2 != 0.0)
+        self.motion.moves_view() || (!self.motion.paused && self.motion.accel_world_per_sec2 != 0.0)
     }
 }
 
@@ -127,6 +228,7 @@ mod tests {
     use super::FlightSimulator;
     use crate::core::flight::{
         FlightControlsSnapshot, FlightLimits, FlightUpdateReport, FlightWarning, MotionState,
+        ZoomControlMode,
     };
     use std::time::Duration;
 
@@ -161,6 +263,24 @@ mod tests {
         assert_eq!(fractal_calls, 1);
     }
 
+    #[test]
+    fn set_limits_changes_the_tick_rate_from_the_next_advance() {
+        let mut simulator = FlightSimulator::new(test_limits());
+        simulator.set_limits(FlightLimits {
+            tick_hz: 120,
+            ..test_limits()
+        });
+
+        let result = simulator.advance(
+            Duration::from_secs_f64(1.0 / 60.0),
+            FlightControlsSnapshot::default,
+            |_, _, _| FlightUpdateReport::default(),
+        );
+
+        assert_eq!(simulator.limits().tick_hz, 120);
+        assert_eq!(result.ticks_run, 2);
+    }
+
     #[test]
     fn multiple_ticks_run_for_larger_elapsed() {
         let mut simulator = FlightSimulator::new(test_limits());
@@ -215,6 +335,46 @@ mod tests {
         assert_eq!(controls_calls, 1);
     }
 
+    #[test]
+    fn many_small_advances_run_the_ticks_their_total_time_allows() {
+        // Deterministic xorshift stream of frame times from 0.1 ms to 20 ms.
+        let mut seed = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next_elapsed = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            Duration::from_micros(100 + seed % 19_901)
+        };
+
+        for tick_hz in [7, 60, 144, 1000] {
+            let mut simulator = FlightSimulator::new(FlightLimits {
+                tick_hz,
+                max_ticks_per_redraw: u32::MAX,
+                ..test_limits()
+            });
+            let mut total = Duration::ZERO;
+            let mut ticks = 0u64;
+
+            for _ in 0..50_000 {
+                let elapsed = next_elapsed();
+                total += elapsed;
+                let result = simulator.advance(
+                    elapsed,
+                    FlightControlsSnapshot::default,
+                    |_, _, _| FlightUpdateReport::default(),
+                );
+                ticks += u64::from(result.ticks_run);
+            }
+
+            // Exact in integers: whole ticks in the total elapsed time.
+            let expected = (total.as_nanos() * u128::from(tick_hz) / 1_000_000_000) as u64;
+            assert!(
+                ticks.abs_diff(expected) <= 1,
+                "{tick_hz} Hz: ran {ticks} ticks over {total:?}, expected {expected}"
+            );
+        }
+    }
+
     #[test]
     fn zero_elapsed_runs_no_ticks() {
         let mut simulator = FlightSimulator::new(test_limits());
@@ -279,6 +439,30 @@ mod tests {
         assert!(result.status.paused);
     }
 
+    #[test]
+    fn bounced_axes_reverse_the_heading() {
+        let mut simulator = FlightSimulator::new(test_limits());
+        let mut heading_seen = [0.0; 2];
+
+        let result = simulator.step_once(
+            || FlightControlsSnapshot {
+                d: true,
+                s: true,
+                ..FlightControlsSnapshot::default()
+            },
+            |motion, _, _| {
+                heading_seen = motion.heading;
+                FlightUpdateReport {
+                    bounced: [true, false],
+                    ..FlightUpdateReport::default()
+                }
+            },
+        );
+
+        assert!(heading_seen[0] > 0.0 && heading_seen[1] > 0.0);
+        assert_eq!(result.status.heading, [-heading_seen[0], heading_seen[1]]);
+    }
+
     #[test]
     fn reset_motion_restores_defaults() {
         let mut simulator = FlightSimulator::new(test_limits());
@@ -292,6 +476,7 @@ mod tests {
             |_, _, _| FlightUpdateReport {
                 clamped: true,
                 warning: Some(FlightWarning::CenterClamped),
+                ..FlightUpdateReport::default()
             },
         );
 
@@ -301,9 +486,37 @@ mod tests {
         assert_eq!(simulator.status().speed, 0.0);
         assert_eq!(simulator.status().heading, [0.0, 0.0]);
         assert_eq!(simulator.status().last_warning, None);
+        assert_eq!(simulator.status().warning_counts.get(FlightWarning::CenterClamped), 0);
         assert!(!simulator.is_active());
     }
 
+    #[test]
+    fn repeated_clamps_increment_their_own_counter() {
+        let mut simulator = FlightSimulator::new(test_limits());
+
+        let result = simulator.advance(
+            Duration::from_secs_f64(3.0 / 60.0),
+            FlightControlsSnapshot::default,
+            |_, _, _| FlightUpdateReport {
+                clamped: true,
+                warning: Some(FlightWarning::ExtentClamped),
+                ..FlightUpdateReport::default()
+            },
+        );
+        let _ = simulator.step_once(FlightControlsSnapshot::default, |_, _, _| FlightUpdateReport {
+            clamped: true,
+            warning: Some(FlightWarning::CenterClamped),
+            ..FlightUpdateReport::default()
+        });
+
+        let counts = simulator.status().warning_counts;
+        assert_eq!(result.status.warning_counts.get(FlightWarning::ExtentClamped), 3);
+        assert_eq!(counts.get(FlightWarning::ExtentClamped), 3);
+        assert_eq!(counts.get(FlightWarning::CenterClamped), 1);
+        assert_eq!(counts.get(FlightWarning::SpeedClamped), 0);
+        assert_eq!(counts.get(FlightWarning::NonFiniteReset), 0);
+    }
+
     #[test]
     fn is_active_true_when_speed_nonzero_and_unpaused() {
         let mut simulator = FlightSimulator::new(test_limits());
@@ -345,6 +558,7 @@ mod tests {
                     FlightUpdateReport {
                         clamped: true,
                         warning: Some(FlightWarning::ExtentClamped),
+                        ..FlightUpdateReport::default()
                     }
                 } else {
                     FlightUpdateReport::default()
@@ -361,4 +575,181 @@ mod tests {
         );
         assert!(result.state_changed);
     }
+
+    #[test]
+    fn step_once_runs_exactly_one_tick_without_elapsed_time() {
+        let mut simulator = FlightSimulator::new(test_limits());
+        let mut controls_calls = 0;
+        let mut fractal_calls = 0;
+
+        let result = simulator.step_once(
+            || {
+                controls_calls += 1;
+                FlightControlsSnapshot::default()
+            },
+            |_, _, _| {
+                fractal_calls += 1;
+                FlightUpdateReport::default()
+            },
+        );
+
+        assert_eq!(result.ticks_run, 1);
+        assert_eq!(controls_calls, 1);
+        assert_eq!(fractal_calls, 1);
+    }
+
+    #[test]
+    fn step_once_advances_paused_motion_and_stays_paused() {
+        let mut simulator = FlightSimulator::new(test_limits());
+        let limits = test_limits();
+
+        let result = simulator.step_once(
+            || FlightControlsSnapshot {
+                accelerate: true,
+                ..FlightControlsSnapshot::default()
+            },
+            |_, _, _| FlightUpdateReport::default(),
+        );
+
+        let expected_speed = limits.base_accel_world_per_sec2 * limits.dt();
+        assert!(result.state_changed);
+        assert!(result.status.paused);
+        assert_eq!(result.status.speed, expected_speed);
+        assert_eq!(simulator.status(), &result.status);
+        assert!(!simulator.is_active());
+    }
+
+    #[test]
+    fn step_once_ignores_pause_toggle_edge() {
+        let mut simulator = FlightSimulator::new(test_limits());
+
+        let result = simulator.step_once(
+            || FlightControlsSnapshot {
+                pause_toggle_edge: true,
+                ..FlightControlsSnapshot::default()
+            },
+            |_, _, _| FlightUpdateReport::default(),
+        );
+
+        assert!(result.status.paused);
+    }
+
+    #[test]
+    fn step_once_leaves_accumulator_untouched() {
+        let mut limits = test_limits();
+        limits.tick_hz = 2;
+
+        let mut simulator = FlightSimulator::new(limits);
+
+        let partial = simulator.advance(
+            Duration::from_secs_f64(0.25),
+            FlightControlsSnapshot::default,
+            |_, _, _| FlightUpdateReport::default(),
+        );
+        let _ = simulator.step_once(FlightControlsSnapshot::default, |_, _, _| {
+            FlightUpdateReport::default()
+        });
+        let completed = simulator.advance(
+            Duration::from_secs_f64(0.25),
+            FlightControlsSnapshot::default,
+            |_, _, _| FlightUpdateReport::default(),
+        );
+
+        assert_eq!(partial.ticks_run, 0);
+        assert_eq!(completed.ticks_run, 1);
+    }
+
+    fn glide_motion() -> MotionState {
+        MotionState {
+            paused: false,
+            heading: [1.0, 0.0],
+            speed_world_per_sec: 0.0,
+            accel_world_per_sec2: 0.0,
+            gliding: true,
+        }
+    }
+
+    #[test]
+    fn glide_decays_to_a_stop_and_restores_the_resting_motion() {
+        let mut simulator = FlightSimulator::new(test_limits());
+        simulator.glide(glide_motion());
+        assert!(simulator.is_active());
+        assert!(simulator.is_gliding());
+
+        let mut headings = Vec::new();
+        for _ in 0..60 {
+            simulator.advance(
+                Duration::from_secs_f64(1.0 / 60.0),
+                FlightControlsSnapshot::default,
+                |motion, _, _| {
+                    if !motion.paused {
+                        headings.push(motion.heading[0]);
+                    }
+                    FlightUpdateReport::default()
+                },
+            );
+        }
+
+        assert!(headings.len() > 1);
+        assert!(headings.windows(2).all(|pair| pair[1] < pair[0]));
+        assert!(!simulator.is_gliding());
+        assert!(!simulator.is_active());
+        assert!(simulator.status().paused);
+    }
+
+    #[test]
+    fn glide_steers_without_zooming_in_either_zoom_mode() {
+        for &zoom_mode in ZoomControlMode::ALL {
+            let mut simulator = FlightSimulator::new(FlightLimits {
+                zoom_mode,
+                ..test_limits()
+            });
+            simulator.glide(glide_motion());
+
+            let result = simulator.advance(
+                Duration::from_secs_f64(1.0 / 60.0),
+                FlightControlsSnapshot::default,
+                |motion, _, _| {
+                    assert_eq!(motion.speed_world_per_sec, 0.0);
+                    assert!(motion.heading[0] > 0.0);
+                    FlightUpdateReport::default()
+                },
+            );
+
+            assert!(result.state_changed);
+            assert!(simulator.is_gliding());
+            assert!(simulator.is_active());
+        }
+    }
+
+    #[test]
+    fn any_control_ends_the_glide() {
+        let mut simulator = FlightSimulator::new(test_limits());
+        simulator.glide(glide_motion());
+
+        simulator.advance(
+            Duration::from_secs_f64(1.0 / 60.0),
+            || FlightControlsSnapshot {
+                w: true,
+                ..FlightControlsSnapshot::default()
+            },
+            |_, _, _| FlightUpdateReport::default(),
+        );
+
+        assert!(!simulator.is_gliding());
+        assert!(!simulator.is_active());
+    }
+
+    #[test]
+    fn stop_glide_restores_the_resting_motion() {
+        let mut simulator = FlightSimulator::new(test_limits());
+        simulator.glide(glide_motion());
+        simulator.glide(glide_motion());
+
+        simulator.stop_glide();
+
+        assert!(!simulator.is_gliding());
+        assert!(!simulator.is_active());
+        assert!(simulator.status().paused);
+    }
 }
@@ -0,0 +1,61 @@
+/// Half the generation space: generations closer than this compare by
+/// their wrapped distance.
+const HALF_RANGE: u64 = 1 << 63;
+
+/// The generation issued after `current`. The counter wraps past
+/// `u64::MAX`, unreachable in practice, and skips zero, which means
+/// "nothing yet".
+#[must_use]
+pub fn next_generation(current: u64) -> u64 {
+    let next = match current.wrapping_add(1) {
+        0 => 1,
+        next => next,
+    };
+    debug_assert!(is_newer(next, current), "generation {next} must be newer than {current}");
+
+    next
+}
+
+/// Whether `generation` was issued after `than`, by serial-number
+/// arithmetic so a generation issued just after a wrap is still newer than
+/// one issued just before it.
+///
+/// Staleness checks, the scheduler and the presenter all order generations
+/// through this; the invariant is that the two are never more than `2^63`
+/// submissions apart.
+#[must_use]
+pub fn is_newer(generation: u64, than: u64) -> bool {
+    generation != than && generation.wrapping_sub(than) < HALF_RANGE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_generation_counts_up_and_skips_zero_on_wrap() {
+        assert_eq!(next_generation(0), 1);
+        assert_eq!(next_generation(41), 42);
+        assert_eq!(next_generation(u64::MAX - 1), u64::MAX);
+        assert_eq!(next_generation(u64::MAX), 1);
+    }
+
+    #[test]
+    fn ordering_matches_plain_comparison_away_from_the_wrap() {
+        assert!(is_newer(2, 1));
+        assert!(is_newer(1, 0));
+        assert!(!is_newer(1, 2));
+        assert!(!is_newer(7, 7));
+    }
+
+    #[test]
+    fn generations_after_the_wrap_are_newer_than_those_before() {
+        let before = u64::MAX - 1;
+        let after = next_generation(next_generation(before));
+
+        assert_eq!(after, 1);
+        assert!(is_newer(after, before));
+        assert!(is_newer(u64::MAX, before));
+        assert!(!is_newer(before, after));
+    }
+}
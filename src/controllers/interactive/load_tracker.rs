@@ -0,0 +1,254 @@
+use std::time::{Duration, Instant};
+
+/// Weight of the newest sample in the running averages.
+const SMOOTHING: f64 = 0.5;
+
+/// Tracks whether renders keep up with the rate requests arrive, and lowers
+/// a quality factor while they don't.
+///
+/// Load is the average render duration over the average interval between
+/// submissions; near one the worker is never idle, which is also what a
+/// caller that coalesces requests behind the in-flight render sees. After
+/// `sustain` consecutive overloaded renders (load above
+/// `overload_ratio`, or superseded before finishing) quality drops by
+/// `step`, down to `min_quality`. After `sustain` consecutive renders below
+/// `recovered_ratio` it climbs back by the same step, up to one. A gap of
+/// `idle_reset` without submissions means the worker has caught up: quality
+/// reads as one again and the averages start afresh.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadTracker {
+    pub overload_ratio: f64,
+    pub recovered_ratio: f64,
+    pub sustain: u32,
+    pub step: f64,
+    pub min_quality: f64,
+    pub idle_reset: Duration,
+    last_submission: Option<Instant>,
+    mean_interval: Option<f64>,
+    mean_render: Option<f64>,
+    overloaded_streak: u32,
+    recovered_streak: u32,
+    quality: f64,
+}
+
+impl Default for LoadTracker {
+    fn default() -> Self {
+        Self {
+            overload_ratio: 0.9,
+            recovered_ratio: 0.5,
+            sustain: 4,
+            step: 0.75,
+            min_quality: 0.25,
+            idle_reset: Duration::from_secs(1),
+            last_submission: None,
+            mean_interval: None,
+            mean_render: None,
+            overloaded_streak: 0,
+            recovered_streak: 0,
+            quality: 1.0,
+        }
+    }
+}
+
+impl LoadTracker {
+    pub fn record_submission(&mut self, now: Instant) {
+        if let Some(last) = self.last_submission {
+            let interval = now.saturating_duration_since(last);
+
+            if interval >= self.idle_reset {
+                self.reset();
+            } else {
+                self.mean_interval = Some(smooth(self.mean_interval, interval.as_secs_f64()));
+            }
+        }
+
+        self.last_submission = Some(now);
+    }
+
+    /// A render finished in `render_duration`.
+    pub fn record_completion(&mut self, render_duration: Duration) {
+        self.mean_render = Some(smooth(self.mean_render, render_duration.as_secs_f64()));
+
+        match self.load() {
+            Some(load) if load > self.overload_ratio => self.observe_overloaded(),
+            Some(load) if load < self.recovered_ratio => self.observe_recovered(),
+            _ => {
+                self.overloaded_streak = 0;
+                self.recovered_streak = 0;
+            }
+        }
+    }
+
+    /// A render was cancelled because a newer request arrived first: the
+    /// clearest sign that renders are not keeping up.
+    pub fn record_superseded(&mut self) {
+        self.observe_overloaded();
+    }
+
+    /// Average render duration over average submission interval; `None`
+    /// until both have been measured.
+    #[must_use]
+    pub fn load(&self) -> Option<f64> {
+        match (self.mean_render, self.mean_interval) {
+            (Some(render), Some(interval)) if interval > 0.0 => Some(render / interval),
+            (Some(_), Some(_)) => Some(f64::INFINITY),
+            _ => None,
+        }
+    }
+
+    /// The quality factor in `[min_quality, 1]` for a request made at
+    /// `now`.
+    #[must_use]
+    pub fn quality(&self, now: Instant) -> f64 {
+        let idle = self
+            .last_submission
+            .is_none_or(|last| now.saturating_duration_since(last) >= self.idle_reset);
+
+        if idle { 1.0 } else { self.quality }
+    }
+
+    /// `max_iterations` reduced by `quality`, never below one.
+    #[must_use]
+    pub fn scale_iterations(max_iterations: u32, quality: f64) -> u32 {
+        ((f64::from(max_iterations) * quality.clamp(0.0, 1.0)).round() as u32).max(1)
+    }
+
+    fn observe_overloaded(&mut self) {
+        self.recovered_streak = 0;
+        self.overloaded_streak += 1;
+
+        if self.overloaded_streak >= self.sustain {
+            self.overloaded_streak = 0;
+            self.quality = (self.quality * self.step).max(self.min_quality);
+        }
+    }
+
+    fn observe_recovered(&mut self) {
+        self.overloaded_streak = 0;
+        self.recovered_streak += 1;
+
+        if self.recovered_streak >= self.sustain {
+            self.recovered_streak = 0;
+            self.quality = (self.quality / self.step).min(1.0);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.mean_interval = None;
+        self.mean_render = None;
+        self.overloaded_streak = 0;
+        self.recovered_streak = 0;
+        self.quality = 1.0;
+    }
+}
+
+fn smooth(mean: Option<f64>, sample: f64) -> f64 {
+    mean.map_or(sample, |mean| mean + SMOOTHING * (sample - mean))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUBMIT_INTERVAL: Duration = Duration::from_millis(16);
+
+    /// Submits `frames` requests `SUBMIT_INTERVAL` apart, each finishing in
+    /// `render`, starting at `*now`.
+    fn run(tracker: &mut LoadTracker, now: &mut Instant, frames: u32, render: Duration) {
+        for _ in 0..frames {
+            tracker.record_submission(*now);
+            tracker.record_completion(render);
+            *now += SUBMIT_INTERVAL;
+        }
+    }
+
+    #[test]
+    fn renders_that_keep_up_hold_full_quality() {
+        let mut tracker = LoadTracker::default();
+        let mut now = Instant::now();
+
+        run(&mut tracker, &mut now, 50, Duration::from_millis(10));
+
+        assert_eq!(tracker.quality(now), 1.0);
+    }
+
+    #[test]
+    fn sustained_slow_renders_lower_quality_step_by_step() {
+        let mut tracker = LoadTracker::default();
+        let mut now = Instant::now();
+
+        // The first completion has no interval yet, so overload starts
+        // counting on the second.
+        run(&mut tracker, &mut now, 1 + 4, Duration::from_millis(40));
+        assert_eq!(tracker.quality(now), 0.75);
+
+        run(&mut tracker, &mut now, 4, Duration::from_millis(40));
+        assert_eq!(tracker.quality(now), 0.5625);
+
+        run(&mut tracker, &mut now, 40, Duration::from_millis(40));
+        assert_eq!(tracker.quality(now), 0.25);
+    }
+
+    #[test]
+    fn a_brief_spike_is_not_sustained_load() {
+        let mut tracker = LoadTracker::default();
+        let mut now = Instant::now();
+
+        run(&mut tracker, &mut now, 10, Duration::from_millis(4));
+        run(&mut tracker, &mut now, 2, Duration::from_millis(60));
+        run(&mut tracker, &mut now, 10, Duration::from_millis(4));
+
+        assert_eq!(tracker.quality(now), 1.0);
+    }
+
+    #[test]
+    fn quality_recovers_once_renders_catch_up() {
+        let mut tracker = LoadTracker::default();
+        let mut now = Instant::now();
+
+        run(&mut tracker, &mut now, 20, Duration::from_millis(40));
+        let degraded = tracker.quality(now);
+        assert!(degraded < 1.0);
+
+        run(&mut tracker, &mut now, 60, Duration::from_millis(2));
+
+        assert_eq!(tracker.quality(now), 1.0);
+    }
+
+    #[test]
+    fn superseded_renders_count_as_overload() {
+        let mut tracker = LoadTracker::default();
+        let mut now = Instant::now();
+
+        for _ in 0..4 {
+            tracker.record_submission(now);
+            tracker.record_superseded();
+            now += SUBMIT_INTERVAL;
+        }
+
+        assert_eq!(tracker.quality(now), 0.75);
+    }
+
+    #[test]
+    fn idle_gap_restores_full_quality() {
+        let mut tracker = LoadTracker::default();
+        let mut now = Instant::now();
+
+        run(&mut tracker, &mut now, 20, Duration::from_millis(40));
+        assert!(tracker.quality(now) < 1.0);
+
+        now += Duration::from_secs(1);
+        assert_eq!(tracker.quality(now), 1.0);
+
+        tracker.record_submission(now);
+        assert_eq!(tracker.quality(now), 1.0);
+        assert_eq!(tracker.load(), None);
+    }
+
+    #[test]
+    fn scaled_iterations_never_reach_zero() {
+        assert_eq!(LoadTracker::scale_iterations(800, 1.0), 800);
+        assert_eq!(LoadTracker::scale_iterations(800, 0.25), 200);
+        assert_eq!(LoadTracker::scale_iterations(1, 0.25), 1);
+    }
+}
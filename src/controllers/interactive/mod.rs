@@ -1,8 +1,11 @@
 mod controller;
 pub mod data;
+pub mod detail_budget;
 pub mod errors;
 pub mod events;
 pub mod flight;
+pub mod generation;
+pub mod load_tracker;
 pub mod ports;
 
 pub use controller::InteractiveController;
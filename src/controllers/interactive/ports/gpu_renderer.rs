@@ -1,6 +1,9 @@
+use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
 use crate::core::fractals::mandelbrot::perturbation::algorithm::MandelbrotPerturbationAlgorithm;
 
-/// Driven port for offloading the perturbation delta iteration to a GPU.
+/// Driven port for offloading Mandelbrot iteration to a GPU: the
+/// perturbation delta iteration for deep zooms and, when the controller has
+/// GPU rendering switched on, the direct iteration for shallow views.
 ///
 /// Implementations return the per-pixel iteration counts in row-major order
 /// for the algorithm's pixel rect, or `None` when the GPU path is
@@ -12,4 +15,11 @@ pub trait GpuFractalRendererPort: Send {
         &mut self,
         algorithm: &MandelbrotPerturbationAlgorithm,
     ) -> Option<Vec<u32>>;
+
+    /// The direct (non-perturbation) iteration counts for `algorithm`, in
+    /// the same layout as `render_iterations`. The CPU path stays the
+    /// correctness reference; the default declines, leaving it to the CPU.
+    fn render_direct_iterations(&mut self, _algorithm: &MandelbrotAlgorithm) -> Option<Vec<u32>> {
+        None
+    }
 }
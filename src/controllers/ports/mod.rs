@@ -1 +1,2 @@
 pub mod file_presenter;
+pub mod streaming_file_presenter;
@@ -0,0 +1,17 @@
+use crate::core::data::pixel_buffer::PixelBuffer;
+
+/// Receives an image as row bands, top to bottom, and writes each as it
+/// arrives, so images too big to hold in memory can be rendered band by
+/// band (see `TileRequest`) straight to a file.
+///
+/// Unlike [`FilePresenterPort`](super::file_presenter::FilePresenterPort),
+/// which takes the whole image at once, an implementation is one open
+/// output: it is created for a given image size, fed bands, then finished.
+pub trait StreamingFilePresenterPort {
+    /// Appends `band`, which must span the image's full width and start on
+    /// the row after the previous band.
+    fn write_band(&mut self, band: &PixelBuffer) -> std::io::Result<()>;
+
+    /// Checks that every row has been written and flushes the output.
+    fn finish(self) -> std::io::Result<()>;
+}
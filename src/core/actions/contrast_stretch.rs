@@ -0,0 +1,120 @@
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{ColourMap, ColourMapError};
+use crate::core::data::colour::Colour;
+
+/// Smallest and largest escaped iteration count in `field`, ignoring the
+/// interior (`>= max_iterations`). `None` when no pixel escaped.
+#[must_use]
+pub fn escaped_iteration_range(field: &[u32], max_iterations: u32) -> Option<(u32, u32)> {
+    field
+        .iter()
+        .copied()
+        .filter(|&iterations| iterations < max_iterations)
+        .fold(None, |range, iterations| match range {
+            None => Some((iterations, iterations)),
+            Some((min, max)) => Some((min.min(iterations), max.max(iterations))),
+        })
+}
+
+/// Wraps a colour map so the escaped iteration counts actually present in a
+/// frame use its whole range.
+///
+/// Counts in `min..=max` are remapped onto `[0, 1]` and then onto
+/// `0..max_iterations`, so `min` gets the inner map's first colour and `max`
+/// its last escaped colour. Interior points (`>= max_iterations`) pass
+/// through unchanged, as do all counts when `min == max`.
+#[derive(Debug)]
+pub struct ContrastStretchColourMap<'a, CMap: ?Sized> {
+    inner: &'a CMap,
+    min: u32,
+    max: u32,
+    max_iterations: u32,
+}
+
+impl<'a, CMap: ColourMap<u32> + ?Sized> ContrastStretchColourMap<'a, CMap> {
+    #[must_use]
+    pub fn new(inner: &'a CMap, min: u32, max: u32, max_iterations: u32) -> Self {
+        Self {
+            inner,
+            min: min.min(max),
+            max: min.max(max),
+            max_iterations,
+        }
+    }
+
+    /// Stretches `inner` over the escaped range observed in `field`.
+    #[must_use]
+    pub fn for_field(inner: &'a CMap, field: &[u32], max_iterations: u32) -> Self {
+        let (min, max) = escaped_iteration_range(field, max_iterations).unwrap_or((0, 0));
+
+        Self::new(inner, min, max, max_iterations)
+    }
+
+    fn stretch(&self, iterations: u32) -> u32 {
+        if iterations >= self.max_iterations || self.min == self.max {
+            return iterations;
+        }
+
+        let t = f64::from(iterations.clamp(self.min, self.max) - self.min)
+            / f64::from(self.max - self.min);
+
+        (t * f64::from(self.max_iterations - 1)).round() as u32
+    }
+}
+
+impl<CMap: ColourMap<u32> + ?Sized> ColourMap<u32> for ContrastStretchColourMap<'_, CMap> {
+    fn map(&self, iterations: u32) -> Result<Colour, ColourMapError> {
+        self.inner.map(self.stretch(iterations))
+    }
+
+    fn display_name(&self) -> &str {
+        self.inner.display_name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fractals::mandelbrot::colour_mapping::maps::fire::MandelbrotFireColourMap;
+
+    const MAX_ITERATIONS: u32 = 1000;
+
+    fn rgb<CMap: ColourMap<u32> + ?Sized>(map: &CMap, iterations: u32) -> (u8, u8, u8) {
+        let colour = map.map(iterations).unwrap();
+        (colour.r, colour.g, colour.b)
+    }
+
+    #[test]
+    fn range_ignores_interior_points() {
+        let field = [MAX_ITERATIONS, 412, 405, MAX_ITERATIONS, 418];
+
+        assert_eq!(escaped_iteration_range(&field, MAX_ITERATIONS), Some((405, 418)));
+        assert_eq!(escaped_iteration_range(&[MAX_ITERATIONS; 3], MAX_ITERATIONS), None);
+    }
+
+    #[test]
+    fn narrow_band_spans_the_full_colour_range() {
+        let inner = MandelbrotFireColourMap::new(MAX_ITERATIONS);
+        let field: Vec<u32> = (400..=420).collect();
+
+        let stretched = ContrastStretchColourMap::for_field(&inner, &field, MAX_ITERATIONS);
+
+        assert_eq!(rgb(&stretched, 400), rgb(&inner, 0));
+        assert_eq!(rgb(&stretched, 410), rgb(&inner, 500));
+        assert_eq!(rgb(&stretched, 420), rgb(&inner, MAX_ITERATIONS - 1));
+
+        let colours: std::collections::HashSet<_> =
+            field.iter().map(|&v| rgb(&stretched, v)).collect();
+        assert_eq!(colours.len(), field.len());
+    }
+
+    #[test]
+    fn interior_and_flat_fields_pass_through() {
+        let inner = MandelbrotFireColourMap::new(MAX_ITERATIONS);
+
+        let stretched = ContrastStretchColourMap::new(&inner, 400, 420, MAX_ITERATIONS);
+        assert_eq!(rgb(&stretched, MAX_ITERATIONS), rgb(&inner, MAX_ITERATIONS));
+
+        let flat = ContrastStretchColourMap::for_field(&inner, &[7, 7, 7], MAX_ITERATIONS);
+        assert_eq!(rgb(&flat, 7), rgb(&inner, 7));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::core::actions::generate_pixel_buffer::generate_pixel_buffer::{
+    GeneratePixelBufferError, generate_pixel_buffer_counts,
+};
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{ColourMap, ColourMapError};
+use crate::core::data::colour::Colour;
+use crate::core::data::pixel_buffer::PixelBuffer;
+use crate::core::data::pixel_rect::PixelRect;
+
+/// Cold to hot, evenly spaced: dark blue, cyan, yellow, red.
+const HEATMAP_STOPS: [Colour; 4] = [
+    Colour { r: 0, g: 0, b: 128 },
+    Colour { r: 0, g: 255, b: 255 },
+    Colour { r: 255, g: 255, b: 0 },
+    Colour { r: 255, g: 0, b: 0 },
+];
+
+/// Colours an iteration field by how much work each pixel took, using the
+/// iteration count as a proxy for cost.
+///
+/// Counts are normalised against `max_iterations` onto a cold-to-hot
+/// gradient: 0 is dark blue, and `max_iterations` (the interior, which runs
+/// the full loop) is red. Counts above the limit clamp to red.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CostHeatmapColourMap {
+    max_iterations: u32,
+}
+
+impl CostHeatmapColourMap {
+    /// A zero `max_iterations` is treated as 1.
+    #[must_use]
+    pub fn new(max_iterations: u32) -> Self {
+        Self {
+            max_iterations: max_iterations.max(1),
+        }
+    }
+}
+
+impl ColourMap<u32> for CostHeatmapColourMap {
+    fn map(&self, iterations: u32) -> Result<Colour, ColourMapError> {
+        let t = f64::from(iterations.min(self.max_iterations)) / f64::from(self.max_iterations);
+        let segments = (HEATMAP_STOPS.len() - 1) as f64;
+        let position = t * segments;
+        let index = (position.floor() as usize).min(HEATMAP_STOPS.len() - 2);
+
+        Ok(HEATMAP_STOPS[index].lerp(HEATMAP_STOPS[index + 1], position - index as f64))
+    }
+
+    fn display_name(&self) -> &str {
+        "Cost heatmap"
+    }
+}
+
+/// Renders `iterations` as a cost heatmap, showing where a render spends
+/// its time.
+pub fn render_cost_heatmap(
+    iterations: Vec<u32>,
+    pixel_rect: PixelRect,
+    max_iterations: u32,
+) -> Result<PixelBuffer, GeneratePixelBufferError> {
+    generate_pixel_buffer_counts(iterations, &CostHeatmapColourMap::new(max_iterations), pixel_rect)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::point::Point;
+
+    const MAX_ITERATIONS: u32 = 256;
+
+    fn rgb(map: &CostHeatmapColourMap, iterations: u32) -> (u8, u8, u8) {
+        let Colour { r, g, b } = map.map(iterations).unwrap();
+        (r, g, b)
+    }
+
+    #[test]
+    fn high_iterations_are_hot_and_low_are_cold() {
+        let map = CostHeatmapColourMap::new(MAX_ITERATIONS);
+
+        assert_eq!(rgb(&map, 0), (0, 0, 128));
+        assert_eq!(rgb(&map, MAX_ITERATIONS), (255, 0, 0));
+        assert_eq!(rgb(&map, MAX_ITERATIONS * 10), (255, 0, 0));
+
+        let (r_low, _, b_low) = rgb(&map, 10);
+        let (r_high, _, b_high) = rgb(&map, MAX_ITERATIONS - 10);
+        assert!(b_low > r_low, "low counts should be blue, got r={r_low} b={b_low}");
+        assert!(r_high > b_high, "high counts should be red, got r={r_high} b={b_high}");
+    }
+
+    #[test]
+    fn passes_through_each_stop() {
+        let map = CostHeatmapColourMap::new(300);
+
+        assert_eq!(rgb(&map, 100), (0, 255, 255));
+        assert_eq!(rgb(&map, 200), (255, 255, 0));
+    }
+
+    #[test]
+    fn renders_a_field_pixel_for_pixel() {
+        let rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 1, y: 0 }).unwrap();
+
+        let buffer = render_cost_heatmap(vec![0, MAX_ITERATIONS], rect, MAX_ITERATIONS).unwrap();
+
+        assert_eq!(buffer.buffer()[..3], [0, 0, 128]);
+        assert_eq!(buffer.buffer()[4..7], [255, 0, 0]);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::core::actions::cancellation::{CancelToken, NeverCancel};
+use crate::core::actions::generate_fractal::generate_fractal_parallel_rayon::{
+    GenerateFractalError, generate_fractal_parallel_rayon_cancelable_impl,
+};
+use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{ColourMap, ColourMapError};
+use crate::core::data::colour::Colour;
+use crate::core::data::pixel_rect::PixelRect;
+
+/// Per-pixel absolute difference between the iteration counts of an
+/// optimized algorithm and a brute-force reference over the same pixels.
+/// All zeros means the optimization changed nothing.
+pub fn diff_iterations<A, R>(
+    pixel_rect: PixelRect,
+    optimized: &A,
+    reference: &R,
+) -> Result<Vec<u32>, A::Failure>
+where
+    A: FractalAlgorithm<Success = u32> + Sync + ?Sized,
+    A::Failure: Send,
+    R: FractalAlgorithm<Success = u32, Failure = A::Failure> + Sync + ?Sized,
+{
+    diff_iterations_cancelable_impl(pixel_rect, optimized, reference, &NeverCancel).map_err(|e| {
+        match e {
+            GenerateFractalError::Algorithm(alg_err) => alg_err,
+            GenerateFractalError::Cancelled(_) => {
+                unreachable!("NeverCancel token should never signal cancellation")
+            }
+        }
+    })
+}
+
+pub fn diff_iterations_cancelable<A, R, C>(
+    pixel_rect: PixelRect,
+    optimized: &A,
+    reference: &R,
+    cancel: &C,
+) -> Result<Vec<u32>, GenerateFractalError<A::Failure>>
+where
+    A: FractalAlgorithm<Success = u32> + Sync + ?Sized,
+    A::Failure: Send,
+    R: FractalAlgorithm<Success = u32, Failure = A::Failure> + Sync + ?Sized,
+    C: CancelToken,
+{
+    diff_iterations_cancelable_impl(pixel_rect, optimized, reference, cancel)
+}
+
+fn diff_iterations_cancelable_impl<A, R, C>(
+    pixel_rect: PixelRect,
+    optimized: &A,
+    reference: &R,
+    cancel: &C,
+) -> Result<Vec<u32>, GenerateFractalError<A::Failure>>
+where
+    A: FractalAlgorithm<Success = u32> + Sync + ?Sized,
+    A::Failure: Send,
+    R: FractalAlgorithm<Success = u32, Failure = A::Failure> + Sync + ?Sized,
+    C: CancelToken,
+{
+    let optimized = generate_fractal_parallel_rayon_cancelable_impl(pixel_rect, optimized, cancel)?;
+    let reference = generate_fractal_parallel_rayon_cancelable_impl(pixel_rect, reference, cancel)?;
+
+    Ok(optimized
+        .iter()
+        .zip(&reference)
+        .map(|(&a, &b)| a.abs_diff(b))
+        .collect())
+}
+
+/// Colours an iteration diff: black where the fields agree, red where they
+/// differ (brighter for larger differences).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IterationDiffColourMap;
+
+impl ColourMap<u32> for IterationDiffColourMap {
+    fn map(&self, value: u32) -> Result<Colour, ColourMapError> {
+        if value == 0 {
+            return Ok(Colour { r: 0, g: 0, b: 0 });
+        }
+
+        Ok(Colour {
+            r: 128 + value.min(127) as u8,
+            g: 0,
+            b: 0,
+        })
+    }
+
+    fn display_name(&self) -> &str {
+        "Iteration diff"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::complex::Complex;
+    use crate::core::data::complex_rect::ComplexRect;
+    use crate::core::data::point::Point;
+    use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
+    use crate::core::util::pixel_to_complex_coords::PixelToComplexCoordsError;
+
+    #[derive(Debug)]
+    struct ConstantAlgorithm {
+        value: u32,
+        pixel_rect: PixelRect,
+    }
+
+    impl FractalAlgorithm for ConstantAlgorithm {
+        type Success = u32;
+        type Failure = PixelToComplexCoordsError;
+
+        fn compute(&self, _: Point) -> Result<Self::Success, Self::Failure> {
+            Ok(self.value)
+        }
+
+        fn pixel_rect(&self) -> PixelRect {
+            self.pixel_rect
+        }
+    }
+
+    fn pixel_rect(width: i32, height: i32) -> PixelRect {
+        PixelRect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: width - 1,
+                y: height - 1,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn cardioid_and_bulb_checks_match_brute_force() {
+        let rect = pixel_rect(96, 64);
+        let max_iterations = 200;
+        // Covers the main cardioid, the period-2 bulb and their boundaries.
+        let region = ComplexRect::new(
+            Complex {
+                real: -1.6,
+                imag: -0.9,
+            },
+            Complex {
+                real: 0.5,
+                imag: 0.9,
+            },
+        )
+        .unwrap();
+
+        let optimized = MandelbrotAlgorithm::new(rect, region, max_iterations).unwrap();
+        let brute_force = optimized.clone().with_interior_checks(false);
+
+        let field = generate_fractal_parallel_rayon_cancelable_impl(rect, &optimized, &NeverCancel)
+            .ok()
+            .unwrap();
+        assert!(
+            field.iter().filter(|&&n| n == max_iterations).count() > 1000,
+            "test region should be dominated by interior pixels"
+        );
+
+        let diff = diff_iterations(rect, &optimized, &brute_force).unwrap();
+
+        assert_eq!(diff.len(), rect.size() as usize);
+        assert!(diff.iter().all(|&d| d == 0));
+    }
+
+    #[test]
+    fn reports_absolute_per_pixel_differences() {
+        let rect = pixel_rect(3, 2);
+        let low = ConstantAlgorithm {
+            value: 4,
+            pixel_rect: rect,
+        };
+        let high = ConstantAlgorithm {
+            value: 10,
+            pixel_rect: rect,
+        };
+
+        assert_eq!(diff_iterations(rect, &low, &high).unwrap(), vec![6; 6]);
+        assert_eq!(diff_iterations(rect, &high, &low).unwrap(), vec![6; 6]);
+    }
+
+    #[test]
+    fn cancelable_returns_cancelled() {
+        let rect = pixel_rect(3, 2);
+        let algorithm = ConstantAlgorithm {
+            value: 1,
+            pixel_rect: rect,
+        };
+        let cancel_token = || true;
+
+        assert!(matches!(
+            diff_iterations_cancelable(rect, &algorithm, &algorithm, &cancel_token),
+            Err(GenerateFractalError::Cancelled(_))
+        ));
+    }
+
+    #[test]
+    fn diff_colour_map_is_black_only_for_zero() {
+        let map = IterationDiffColourMap;
+
+        let rgb = |value| {
+            let Colour { r, g, b } = map.map(value).unwrap();
+            (r, g, b)
+        };
+
+        assert_eq!(rgb(0), (0, 0, 0));
+        assert_eq!(rgb(1), (129, 0, 0));
+        assert_eq!(rgb(5000), (255, 0, 0));
+    }
+}
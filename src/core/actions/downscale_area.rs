@@ -0,0 +1,153 @@
+use crate::core::data::pixel_buffer::PixelBuffer;
+use crate::core::data::pixel_rect::PixelRect;
+use crate::core::data::point::Point;
+
+/// Resizes `src` to `out_width` x `out_height` with a box filter: each
+/// output pixel is the average of the source pixels under it, weighted by
+/// how much of each it covers, so a render at a higher internal resolution
+/// comes out anti-aliased. Ratios need not be whole numbers.
+///
+/// Colours are averaged weighted by alpha, so transparent pixels do not
+/// darken their neighbours. The result starts at the origin; sizes below 1
+/// are treated as 1.
+#[must_use]
+pub fn downscale_area(src: &PixelBuffer, out_width: u32, out_height: u32) -> PixelBuffer {
+    let src_rect = src.pixel_rect();
+    let src_width = src_rect.width() as usize;
+    let out_width = out_width.max(1);
+    let out_height = out_height.max(1);
+
+    let columns = coverage(src_rect.width(), out_width);
+    let rows = coverage(src_rect.height(), out_height);
+    let pixels = src.as_rgba();
+
+    let mut data = Vec::with_capacity(out_width as usize * out_height as usize * PixelBuffer::BYTES_PER_PIXEL);
+    for row in &rows {
+        for column in &columns {
+            // Premultiplied red, green, blue, then alpha.
+            let mut sums = [0.0f64; 4];
+
+            for &(y, y_weight) in row {
+                for &(x, x_weight) in column {
+                    let offset = (y * src_width + x) * PixelBuffer::BYTES_PER_PIXEL;
+                    let pixel = &pixels[offset..offset + PixelBuffer::BYTES_PER_PIXEL];
+                    let weight = x_weight * y_weight;
+                    let alpha = f64::from(pixel[3]);
+
+                    for channel in 0..3 {
+                        sums[channel] += weight * alpha * f64::from(pixel[channel]);
+                    }
+                    sums[3] += weight * alpha;
+                }
+            }
+
+            let alpha = sums[3];
+            for sum in &sums[..3] {
+                let value = if alpha > 0.0 { sum / alpha } else { 0.0 };
+                data.push(to_byte(value));
+            }
+            data.push(to_byte(alpha));
+        }
+    }
+
+    let out_rect = PixelRect::new(
+        Point { x: 0, y: 0 },
+        Point {
+            x: out_width as i32 - 1,
+            y: out_height as i32 - 1,
+        },
+    )
+    .expect("output size is at least 1x1");
+
+    PixelBuffer::from_data_rgba(out_rect, data).expect("buffer is sized to the output rect")
+}
+
+/// For each of `out_len` output pixels along an axis, the source pixels
+/// (as offsets from the source's first) it covers and what fraction of
+/// the output pixel each makes up. The fractions sum to 1.
+fn coverage(src_len: u32, out_len: u32) -> Vec<Vec<(usize, f64)>> {
+    let scale = f64::from(src_len) / f64::from(out_len);
+
+    (0..out_len)
+        .map(|i| {
+            let start = f64::from(i) * scale;
+            let end = f64::from(i + 1) * scale;
+            let first = start.floor() as usize;
+            let last = (end.ceil() as usize).min(src_len as usize);
+
+            (first..last)
+                .filter_map(|j| {
+                    let overlap = end.min(j as f64 + 1.0) - start.max(j as f64);
+                    (overlap > 0.0).then_some((j, overlap / scale))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn to_byte(value: f64) -> u8 {
+    value.round().clamp(0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(width: i32, height: i32, pixels: &[[u8; 4]]) -> PixelBuffer {
+        let rect = PixelRect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: width - 1,
+                y: height - 1,
+            },
+        )
+        .unwrap();
+
+        PixelBuffer::from_data_rgba(rect, pixels.concat()).unwrap()
+    }
+
+    fn pixels(buffer: &PixelBuffer) -> Vec<[u8; 4]> {
+        buffer
+            .as_rgba()
+            .chunks_exact(PixelBuffer::BYTES_PER_PIXEL)
+            .map(|pixel| pixel.try_into().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn solid_colour_is_preserved() {
+        let src = buffer(4, 4, &[[12, 200, 77, 255]; 16]);
+
+        let out = downscale_area(&src, 2, 2);
+
+        assert_eq!(out.pixel_rect().width(), 2);
+        assert_eq!(out.pixel_rect().height(), 2);
+        assert_eq!(pixels(&out), [[12, 200, 77, 255]; 4]);
+    }
+
+    #[test]
+    fn gradient_pixels_are_averaged() {
+        let src = buffer(2, 1, &[[0, 100, 255, 255], [255, 200, 0, 255]]);
+
+        assert_eq!(pixels(&downscale_area(&src, 1, 1)), [[128, 150, 128, 255]]);
+    }
+
+    #[test]
+    fn fractional_ratios_weight_partly_covered_pixels() {
+        // Three pixels into two: each output takes one whole pixel and
+        // half of the middle one.
+        let src = buffer(3, 1, &[[0, 0, 0, 255], [90, 90, 90, 255], [240, 240, 240, 255]]);
+
+        assert_eq!(
+            pixels(&downscale_area(&src, 2, 1)),
+            [[30, 30, 30, 255], [190, 190, 190, 255]]
+        );
+    }
+
+    #[test]
+    fn transparent_pixels_do_not_darken_the_average() {
+        let src = buffer(2, 1, &[[200, 100, 50, 255], [0, 0, 0, 0]]);
+
+        assert_eq!(pixels(&downscale_area(&src, 1, 1)), [[200, 100, 50, 128]]);
+    }
+}
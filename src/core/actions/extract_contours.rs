@@ -0,0 +1,325 @@
+use crate::core::data::pixel_rect::PixelRect;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+/// One iso-line through an iteration field: the boundary between pixels
+/// below `level` and pixels at or above it.
+///
+/// Points are in pixel coordinates relative to the field's top-left pixel,
+/// with pixel centres on whole numbers, so they lie in
+/// `0..=width - 1` by `0..=height - 1`. A closed contour's last point joins
+/// back to its first, which is not repeated. Contours that leave the field
+/// are open and start and end on its edge.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contour {
+    pub level: u32,
+    pub points: Vec<(f64, f64)>,
+    pub closed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtractContoursError {
+    FieldSizeMismatch { expected: u64, actual: usize },
+}
+
+impl fmt::Display for ExtractContoursError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FieldSizeMismatch { expected, actual } => write!(
+                f,
+                "iteration field has {actual} values but the pixel rect holds {expected}"
+            ),
+        }
+    }
+}
+
+impl Error for ExtractContoursError {}
+
+/// A cell edge, named by the corner it starts from: `Horizontal` runs
+/// right from `(x, y)`, `Vertical` runs down from it. Neighbouring cells
+/// name a shared edge the same way, which is how segments are stitched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Edge {
+    Horizontal(usize, usize),
+    Vertical(usize, usize),
+}
+
+/// Traces the iso-lines of a row-major iteration `field` covering
+/// `pixel_rect` at each of `levels`, by marching squares over the grid of
+/// pixel centres.
+///
+/// Crossings are placed by linear interpolation at `level - 0.5`, halfway
+/// between the last count below the level and the first at it. Saddle
+/// cells are resolved by the mean of their corners, so a band whose centre
+/// is above the level stays connected. Contours come out grouped by level,
+/// in the order `levels` lists them.
+pub fn extract_contours(
+    field: &[u32],
+    pixel_rect: PixelRect,
+    levels: &[u32],
+) -> Result<Vec<Contour>, ExtractContoursError> {
+    let width = pixel_rect.width() as usize;
+    let height = pixel_rect.height() as usize;
+
+    if field.len() as u64 != pixel_rect.size() {
+        return Err(ExtractContoursError::FieldSizeMismatch {
+            expected: pixel_rect.size(),
+            actual: field.len(),
+        });
+    }
+
+    let mut contours = Vec::new();
+    for &level in levels {
+        let segments = march(field, width, height, level);
+
+        contours.extend(
+            stitch(&segments)
+                .into_iter()
+                .map(|(edges, closed)| Contour {
+                    level,
+                    points: edges
+                        .into_iter()
+                        .map(|edge| crossing(field, width, edge, level))
+                        .collect(),
+                    closed,
+                }),
+        );
+    }
+
+    Ok(contours)
+}
+
+/// The crossing segments of every cell, as pairs of the edges they join.
+fn march(field: &[u32], width: usize, height: usize, level: u32) -> Vec<(Edge, Edge)> {
+    let mut segments = Vec::new();
+    if width < 2 || height < 2 {
+        return segments;
+    }
+
+    let value = |x: usize, y: usize| field[y * width + x];
+
+    for y in 0..height - 1 {
+        for x in 0..width - 1 {
+            let corners = [value(x, y), value(x + 1, y), value(x + 1, y + 1), value(x, y + 1)];
+            let [top_left, top_right, bottom_right, bottom_left] = corners.map(|v| v >= level);
+
+            let top = Edge::Horizontal(x, y);
+            let right = Edge::Vertical(x + 1, y);
+            let bottom = Edge::Horizontal(x, y + 1);
+            let left = Edge::Vertical(x, y);
+
+            if top_left == bottom_right && top_right == bottom_left && top_left != top_right {
+                let mean = corners.iter().map(|&v| f64::from(v)).sum::<f64>() / 4.0;
+                let centre_inside = mean >= f64::from(level) - 0.5;
+
+                if centre_inside == top_left {
+                    // The diagonal through the top-left corner is joined, so
+                    // the other two corners are cut off on their own.
+                    segments.push((top, right));
+                    segments.push((bottom, left));
+                } else {
+                    segments.push((top, left));
+                    segments.push((right, bottom));
+                }
+                continue;
+            }
+
+            let crossed: Vec<Edge> = [
+                (top, top_left != top_right),
+                (right, top_right != bottom_right),
+                (bottom, bottom_left != bottom_right),
+                (left, top_left != bottom_left),
+            ]
+            .into_iter()
+            .filter_map(|(edge, crossed)| crossed.then_some(edge))
+            .collect();
+
+            if let [from, to] = crossed[..] {
+                segments.push((from, to));
+            }
+        }
+    }
+
+    segments
+}
+
+/// Chains segments sharing an edge into polylines, returning each one's
+/// edges in order and whether it closes on itself.
+///
+/// Every edge touches at most two segments, one per cell either side, so
+/// a chain either runs from the field's border back to it or loops.
+fn stitch(segments: &[(Edge, Edge)]) -> Vec<(Vec<Edge>, bool)> {
+    let mut by_edge: HashMap<Edge, Vec<usize>> = HashMap::new();
+    for (index, &(from, to)) in segments.iter().enumerate() {
+        by_edge.entry(from).or_default().push(index);
+        by_edge.entry(to).or_default().push(index);
+    }
+
+    let mut used = vec![false; segments.len()];
+    let mut chains = Vec::new();
+
+    let follow = |start: usize, start_edge: Edge, used: &mut Vec<bool>| {
+        let mut edges = vec![start_edge];
+        let mut current = start;
+        let mut edge = start_edge;
+
+        loop {
+            used[current] = true;
+            let (from, to) = segments[current];
+            edge = if from == edge { to } else { from };
+
+            let next = by_edge[&edge].iter().copied().find(|&other| !used[other]);
+            match next {
+                Some(next) => {
+                    edges.push(edge);
+                    current = next;
+                }
+                None => {
+                    let closed = edge == start_edge;
+                    if !closed {
+                        edges.push(edge);
+                    }
+                    return (edges, closed);
+                }
+            }
+        }
+    };
+
+    // Open chains first, from an edge on the border that only one segment
+    // touches, so they are not started halfway along.
+    for (index, &(from, to)) in segments.iter().enumerate() {
+        if used[index] {
+            continue;
+        }
+        for end in [from, to] {
+            if by_edge[&end].len() == 1 {
+                chains.push(follow(index, end, &mut used));
+                break;
+            }
+        }
+    }
+
+    for (index, &(from, _)) in segments.iter().enumerate() {
+        if !used[index] {
+            chains.push(follow(index, from, &mut used));
+        }
+    }
+
+    chains
+}
+
+/// Where the contour at `level` crosses `edge`.
+fn crossing(field: &[u32], width: usize, edge: Edge, level: u32) -> (f64, f64) {
+    let (x, y, dx, dy) = match edge {
+        Edge::Horizontal(x, y) => (x, y, 1, 0),
+        Edge::Vertical(x, y) => (x, y, 0, 1),
+    };
+
+    let a = f64::from(field[y * width + x]);
+    let b = f64::from(field[(y + dy) * width + x + dx]);
+    let t = ((f64::from(level) - 0.5 - a) / (b - a)).clamp(0.0, 1.0);
+
+    (x as f64 + t * dx as f64, y as f64 + t * dy as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::point::Point;
+
+    fn pixel_rect(width: i32, height: i32) -> PixelRect {
+        PixelRect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: width - 1,
+                y: height - 1,
+            },
+        )
+        .unwrap()
+    }
+
+    /// Counts falling off by one per pixel of distance from `centre`, from
+    /// `peak` there.
+    fn radial_field(width: i32, height: i32, centre: (f64, f64), peak: f64) -> Vec<u32> {
+        (0..height)
+            .flat_map(|y| {
+                (0..width).map(move |x| {
+                    let distance = (f64::from(x) - centre.0).hypot(f64::from(y) - centre.1);
+                    (peak - distance).max(0.0).round() as u32
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn radial_gradient_has_one_closed_ring_per_level() {
+        let field = radial_field(41, 41, (20.0, 20.0), 20.0);
+
+        let contours = extract_contours(&field, pixel_rect(41, 41), &[5, 10, 15]).unwrap();
+
+        assert_eq!(contours.len(), 3);
+        for (contour, level) in contours.iter().zip([5, 10, 15]) {
+            assert_eq!(contour.level, level);
+            assert!(contour.closed, "level {level} is open");
+
+            // The ring sits where the count crosses `level - 0.5`, i.e.
+            // about 20.5 - level pixels out, give or take the rounding.
+            let expected_radius = 20.5 - f64::from(level);
+            for &(x, y) in &contour.points {
+                let radius = (x - 20.0).hypot(y - 20.0);
+                assert!((radius - expected_radius).abs() < 1.0, "level {level}: radius {radius}");
+            }
+        }
+    }
+
+    #[test]
+    fn separate_peaks_give_separate_rings() {
+        let left = radial_field(60, 30, (15.0, 15.0), 12.0);
+        let right = radial_field(60, 30, (45.0, 15.0), 12.0);
+        let field: Vec<u32> = left.iter().zip(&right).map(|(&a, &b)| a.max(b)).collect();
+
+        let contours = extract_contours(&field, pixel_rect(60, 30), &[6]).unwrap();
+
+        assert_eq!(contours.len(), 2);
+        assert!(contours.iter().all(|contour| contour.closed));
+    }
+
+    #[test]
+    fn levels_outside_the_field_have_no_contours() {
+        let field = radial_field(21, 21, (10.0, 10.0), 8.0);
+
+        let contours = extract_contours(&field, pixel_rect(21, 21), &[0, 50]).unwrap();
+
+        assert!(contours.is_empty());
+    }
+
+    #[test]
+    fn a_band_leaving_the_field_is_open_and_ends_on_the_border() {
+        // Counts rise left to right: the level 3 line is a vertical cut.
+        let field: Vec<u32> = (0..5).flat_map(|_| 0..6).collect();
+
+        let contours = extract_contours(&field, pixel_rect(6, 5), &[3]).unwrap();
+
+        assert_eq!(contours.len(), 1);
+        let contour = &contours[0];
+        assert!(!contour.closed);
+        assert_eq!(contour.points.len(), 5);
+        assert!(contour.points.iter().all(|&(x, _)| (x - 2.5).abs() < 1e-12));
+        let ends = [contour.points[0].1, contour.points[4].1];
+        assert!(ends.contains(&0.0) && ends.contains(&4.0), "ends at {ends:?}");
+    }
+
+    #[test]
+    fn field_must_fill_the_pixel_rect() {
+        let result = extract_contours(&[1, 2, 3], pixel_rect(2, 2), &[1]);
+
+        assert_eq!(
+            result,
+            Err(ExtractContoursError::FieldSizeMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+}
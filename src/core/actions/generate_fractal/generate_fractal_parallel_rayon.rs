@@ -35,7 +35,7 @@ pub fn generate_fractal_parallel_rayon<Alg>(
 ) -> Result<Vec<Alg::Success>, Alg::Failure>
 where
     Alg: FractalAlgorithm + Sync + ?Sized,
-    Alg::Success: Send,
+    Alg::Success: Clone + Send,
     Alg::Failure: Send,
 {
     generate_fractal_parallel_rayon_cancelable_impl(pixel_rect, algorithm, &NeverCancel)
@@ -55,7 +55,7 @@ pub fn generate_fractal_parallel_rayon_cancelable<Alg, C>(
 ) -> Result<Vec<Alg::Success>, GenerateFractalError<Alg::Failure>>
 where
     Alg: FractalAlgorithm + Sync + ?Sized,
-    Alg::Success: Send,
+    Alg::Success: Clone + Send,
     Alg::Failure: Send,
     C: CancelToken,
 {
@@ -70,7 +70,7 @@ pub(crate) fn generate_fractal_parallel_rayon_cancelable_impl<Alg, C>(
 ) -> Result<Vec<Alg::Success>, GenerateFractalError<Alg::Failure>>
 where
     Alg: FractalAlgorithm + Sync + ?Sized,
-    Alg::Success: Send,
+    Alg::Success: Clone + Send,
     Alg::Failure: Send,
     C: CancelToken,
 {
@@ -78,10 +78,18 @@ where
     let x_start = pixel_rect.top_left().x;
     let x_end = pixel_rect.bottom_right().x;
     let row_width = (x_end - x_start + 1) as usize;
+    let mirror = |y: i32| {
+        algorithm
+            .mirror_row(y)
+            .filter(|m| (pixel_rect.top_left().y..=pixel_rect.bottom_right().y).contains(m))
+    };
 
     let rows: Result<Vec<Vec<Alg::Success>>, GenerateFractalError<Alg::Failure>> = y_range
         .into_par_iter()
         .map(|y| {
+            if mirror(y).is_some() {
+                return Ok(Vec::new());
+            }
             let mut row = Vec::with_capacity(row_width);
             if cancel.is_cancelled() {
                 return Err(GenerateFractalError::Cancelled(Cancelled));
@@ -95,7 +103,15 @@ where
         })
         .collect();
 
-    rows.map(|r| r.into_iter().flatten().collect())
+    let mut rows = rows?;
+    let top_y = pixel_rect.top_left().y;
+    for y in top_y..=pixel_rect.bottom_right().y {
+        if let Some(m) = mirror(y) {
+            rows[(y - top_y) as usize] = rows[(m - top_y) as usize].clone();
+        }
+    }
+
+    Ok(rows.into_iter().flatten().collect())
 }
 
 #[cfg(test)]
@@ -160,6 +176,30 @@ mod tests {
         assert_eq!(rayon_results, sequential_results);
     }
 
+    #[test]
+    fn test_rayon_covers_the_last_row_and_column() {
+        let algorithm = StubSuccessAlgorithm {};
+
+        for pixel_rect in [
+            PixelRect::new(Point { x: 0, y: 0 }, Point { x: 10, y: 8 }).unwrap(),
+            PixelRect::new(Point { x: 3, y: 2 }, Point { x: 13, y: 10 }).unwrap(),
+        ] {
+            let sequential_results = generate_fractal_serial(pixel_rect, &algorithm).unwrap();
+            let rayon_results = generate_fractal_parallel_rayon(pixel_rect, &algorithm).unwrap();
+
+            // Corners are inclusive: 11 columns by 9 rows.
+            assert_eq!(rayon_results.len(), 11 * 9);
+            assert_eq!(rayon_results.len(), sequential_results.len());
+            assert_eq!(rayon_results.len() as u64, pixel_rect.size());
+
+            let bottom_right = pixel_rect.bottom_right();
+            assert_eq!(
+                rayon_results.last(),
+                Some(&((bottom_right.x + bottom_right.y) as u64))
+            );
+        }
+    }
+
     #[test]
     fn test_rayon_propagates_algorithm_failure() {
         let algorithm = StubFailureAlgorithm {};
@@ -1,7 +1,11 @@
 use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
 use crate::core::data::pixel_rect::PixelRect;
 
-#[allow(dead_code)]
+/// Single-threaded reference implementation: computes every pixel of
+/// `pixel_rect` (both corners inclusive) row by row in row-major order.
+///
+/// This is the ground truth for correctness. Every parallel generator must
+/// return exactly the same results in the same order for any rect.
 pub fn generate_fractal_serial<Alg: FractalAlgorithm>(
     pixel_rect: PixelRect,
     algorithm: &Alg,
@@ -20,9 +24,21 @@ pub fn generate_fractal_serial<Alg: FractalAlgorithm>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::actions::cancellation::NeverCancel;
+    use crate::core::actions::generate_fractal::generate_fractal_parallel_arc::generate_fractal_parallel_arc;
+    use crate::core::actions::generate_fractal::generate_fractal_parallel_rayon::{
+        GenerateFractalError, generate_fractal_parallel_rayon,
+        generate_fractal_parallel_rayon_cancelable,
+    };
+    use crate::core::actions::generate_fractal::generate_fractal_parallel_scoped_threads::generate_fractal_parallel_scoped_threads;
+    use crate::core::data::complex::Complex;
+    use crate::core::data::complex_rect::ComplexRect;
     use crate::core::data::pixel_rect::PixelRect;
     use crate::core::data::point::Point;
+    use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
     use std::error::Error;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[derive(Debug, PartialEq)]
     struct StubError {}
@@ -85,4 +101,126 @@ mod tests {
 
         assert_eq!(results, Err(StubError {}));
     }
+
+    #[test]
+    fn test_matches_rayon_on_offset_mandelbrot_rect() {
+        // Offset origin and odd sizes so an exclusive bound on either axis
+        // would drop a row or column and change the length.
+        let pixel_rect = PixelRect::new(Point { x: 7, y: -3 }, Point { x: 86, y: 57 }).unwrap();
+        let region = ComplexRect::new(
+            Complex {
+                real: -2.0,
+                imag: -1.2,
+            },
+            Complex {
+                real: 0.6,
+                imag: 1.2,
+            },
+        )
+        .unwrap();
+        let algorithm = MandelbrotAlgorithm::new(pixel_rect, region, 200).unwrap();
+
+        let serial = generate_fractal_serial(pixel_rect, &algorithm).unwrap();
+        let rayon = generate_fractal_parallel_rayon(pixel_rect, &algorithm).unwrap();
+
+        assert_eq!(serial.len(), pixel_rect.size() as usize);
+        assert_eq!(rayon, serial);
+    }
+
+    fn mandelbrot_for(pixel_rect: PixelRect) -> MandelbrotAlgorithm {
+        let region = ComplexRect::new(
+            Complex {
+                real: -2.0,
+                imag: -1.2,
+            },
+            Complex {
+                real: 0.6,
+                imag: 1.2,
+            },
+        )
+        .unwrap();
+
+        MandelbrotAlgorithm::new(pixel_rect, region, 200).unwrap()
+    }
+
+    /// Offset origins and odd sizes, so an exclusive bound on either axis
+    /// would drop a row or column; single rows and columns; and heights
+    /// below and above the thread count, so banding hits its remainder
+    /// and one-band cases.
+    fn cross_validation_rects() -> Vec<PixelRect> {
+        [
+            ((7, -3), (86, 57)),
+            ((-40, -25), (39, 24)),
+            ((0, 0), (0, 0)),
+            ((5, 5), (104, 5)),
+            ((-3, 0), (-3, 60)),
+            ((0, 0), (130, 2)),
+            ((100, 200), (116, 296)),
+        ]
+        .into_iter()
+        .map(|((x0, y0), (x1, y1))| {
+            PixelRect::new(Point { x: x0, y: y0 }, Point { x: x1, y: y1 }).unwrap()
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_all_generators_match_serial_on_mandelbrot_rects() {
+        for pixel_rect in cross_validation_rects() {
+            let algorithm = mandelbrot_for(pixel_rect);
+            let serial = generate_fractal_serial(pixel_rect, &algorithm).unwrap();
+            assert_eq!(serial.len(), pixel_rect.size() as usize, "{pixel_rect:?}");
+
+            let generators: [(&str, Vec<u32>); 4] = [
+                (
+                    "parallel_rayon",
+                    generate_fractal_parallel_rayon(pixel_rect, &algorithm).unwrap(),
+                ),
+                (
+                    "parallel_rayon_cancelable",
+                    generate_fractal_parallel_rayon_cancelable(pixel_rect, &algorithm, &NeverCancel)
+                        .unwrap(),
+                ),
+                (
+                    "parallel_arc",
+                    generate_fractal_parallel_arc(pixel_rect, Arc::new(algorithm.clone())).unwrap(),
+                ),
+                (
+                    "parallel_scoped_threads",
+                    generate_fractal_parallel_scoped_threads(pixel_rect, &algorithm).unwrap(),
+                ),
+            ];
+
+            for (name, results) in generators {
+                assert!(results == serial, "{name} diverged from serial on {pixel_rect:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_cancelled_generation_never_returns_partial_results() {
+        for pixel_rect in cross_validation_rects() {
+            let algorithm = mandelbrot_for(pixel_rect);
+            let serial = generate_fractal_serial(pixel_rect, &algorithm).unwrap();
+
+            // Cancel after a varying number of checks, including before the
+            // first row: either every row completed or the result is an
+            // error, never a short or reordered field.
+            for checks_before_cancel in [0, 1, 3, 10, 1000] {
+                let checks = AtomicUsize::new(0);
+                let cancel = || checks.fetch_add(1, Ordering::Relaxed) >= checks_before_cancel;
+
+                match generate_fractal_parallel_rayon_cancelable(pixel_rect, &algorithm, &cancel) {
+                    Ok(results) => assert!(
+                        results == serial,
+                        "completed after {checks_before_cancel} checks but diverged on {pixel_rect:?}"
+                    ),
+                    Err(GenerateFractalError::Cancelled(_)) => {
+                        assert!(checks_before_cancel < pixel_rect.height() as usize);
+                    }
+                    Err(GenerateFractalError::Algorithm(e)) => panic!("algorithm error: {e}"),
+                }
+            }
+        }
+    }
 }
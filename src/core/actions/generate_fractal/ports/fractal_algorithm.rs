@@ -9,6 +9,13 @@ pub trait FractalAlgorithm {
     fn compute(&self, pixel: Point) -> Result<Self::Success, Self::Failure>;
     fn pixel_rect(&self) -> PixelRect;
 
+    /// A row of `pixel_rect` holding exactly the values of row `y`, which
+    /// generators may copy instead of computing `y`. The row returned must
+    /// have no mirror of its own. `None`, the default, computes every row.
+    fn mirror_row(&self, _y: i32) -> Option<i32> {
+        None
+    }
+
     fn compute_row_segment_into(
         &self,
         y: i32,
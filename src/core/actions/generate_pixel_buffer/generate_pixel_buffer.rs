@@ -1,13 +1,53 @@
 use crate::core::actions::cancellation::{
     CancelToken, Cancelled, NeverCancel, CANCEL_CHECK_INTERVAL_PIXELS,
 };
-use crate::core::actions::generate_pixel_buffer::ports::colour_map::{ColourMap, ColourMapError};
-use crate::core::data::colour::Colour;
+use crate::core::actions::generate_pixel_buffer::histogram::HistogramColourMap;
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{
+    ColourMap, ColourMapError, ColourMapRgba,
+};
+use crate::core::data::colour::{Colour, ColourRgba};
 use crate::core::data::pixel_buffer::{PixelBuffer, PixelBufferData, PixelBufferError};
 use crate::core::data::pixel_rect::PixelRect;
+use rayon::prelude::*;
 use std::error::Error;
 use std::fmt;
 
+/// Inputs with at least this many pixels are colour mapped on rayon, through
+/// a prebuilt lookup table where the values allow one (see the
+/// `colour_mapping` group in `benches/render_pipeline.rs`). Below it the
+/// table build and rayon dispatch outweigh the per-pixel savings.
+pub const LUT_PIXEL_THRESHOLD: usize = 16 * 1024;
+
+/// Smallest number of pixels a single rayon task maps.
+const PARALLEL_MIN_PIXELS_PER_TASK: usize = 4 * 1024;
+
+/// Input values that can index a colour lookup table, such as iteration
+/// counts.
+pub trait LutIndex: Copy + Send + Sync {
+    fn to_lut_index(self) -> usize;
+    fn from_lut_index(index: usize) -> Self;
+}
+
+macro_rules! impl_lut_index {
+    ($($ty:ty),*) => {
+        $(
+            impl LutIndex for $ty {
+                #[inline]
+                fn to_lut_index(self) -> usize {
+                    self as usize
+                }
+
+                #[inline]
+                fn from_lut_index(index: usize) -> Self {
+                    index as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_lut_index!(u8, u16, u32);
+
 #[derive(Debug)]
 pub enum GeneratePixelBufferError {
     ColourMap(ColourMapError),
@@ -65,26 +105,148 @@ impl From<PixelBufferError> for GeneratePixelBufferError {
     }
 }
 
-pub fn generate_pixel_buffer<T, CMap: ColourMap<T> + ?Sized>(
+/// Maps `input` through `mapper`, on rayon from `LUT_PIXEL_THRESHOLD`
+/// pixels up and sequentially below; both produce identical output.
+pub fn generate_pixel_buffer<T: Send, CMap: ColourMap<T> + ?Sized>(
     input: Vec<T>,
     mapper: &CMap,
     pixel_rect: PixelRect,
 ) -> Result<PixelBuffer, GeneratePixelBufferError> {
-    generate_pixel_buffer_cancelable_impl(input, mapper, pixel_rect, &NeverCancel).map_err(|e| {
-        match e {
-            GeneratePixelBufferCancelableError::ColourMap(err) => {
-                GeneratePixelBufferError::ColourMap(err)
-            }
-            GeneratePixelBufferCancelableError::PixelBuffer(err) => {
-                GeneratePixelBufferError::PixelBuffer(err)
-            }
-            GeneratePixelBufferCancelableError::Cancelled(_) => {
-                unreachable!("NeverCancel token should never signal cancellation")
-            }
+    generate_pixel_buffer_cancelable_impl(input, mapper, pixel_rect, &NeverCancel)
+        .map_err(never_cancelled)
+}
+
+/// `generate_pixel_buffer` for values that can index a lookup table, such
+/// as iteration counts. From `LUT_PIXEL_THRESHOLD` pixels up this takes
+/// the lookup-table path, otherwise the sequential one; both produce
+/// identical output.
+pub fn generate_pixel_buffer_counts<T: LutIndex, CMap: ColourMap<T> + ?Sized>(
+    input: Vec<T>,
+    mapper: &CMap,
+    pixel_rect: PixelRect,
+) -> Result<PixelBuffer, GeneratePixelBufferError> {
+    generate_pixel_buffer_counts_cancelable(input, mapper, pixel_rect, &NeverCancel)
+        .map_err(never_cancelled)
+}
+
+/// Single-threaded reference implementation of `generate_pixel_buffer`.
+pub fn generate_pixel_buffer_sequential<T, CMap: ColourMap<T> + ?Sized>(
+    input: Vec<T>,
+    mapper: &CMap,
+    pixel_rect: PixelRect,
+) -> Result<PixelBuffer, GeneratePixelBufferError> {
+    generate_pixel_buffer_sequential_impl(input, mapper, pixel_rect, &NeverCancel)
+        .map_err(never_cancelled)
+}
+
+/// Rayon implementation calling the colour map once per pixel.
+pub fn generate_pixel_buffer_parallel<T: Send, CMap: ColourMap<T> + ?Sized>(
+    input: Vec<T>,
+    mapper: &CMap,
+    pixel_rect: PixelRect,
+) -> Result<PixelBuffer, GeneratePixelBufferError> {
+    generate_pixel_buffer_parallel_impl(input, mapper, pixel_rect, &NeverCancel)
+        .map_err(never_cancelled)
+}
+
+/// Rayon implementation that maps every value up to the input's maximum
+/// once, then fills pixels from that table. Falls back to the sequential
+/// path when the table would be larger than the image.
+pub fn generate_pixel_buffer_lut<T: LutIndex, CMap: ColourMap<T> + ?Sized>(
+    input: Vec<T>,
+    mapper: &CMap,
+    pixel_rect: PixelRect,
+) -> Result<PixelBuffer, GeneratePixelBufferError> {
+    generate_pixel_buffer_lut_impl(input, mapper, pixel_rect, &NeverCancel)
+        .map_err(never_cancelled)
+}
+
+/// Maps `input` through a colour map that picks its own alpha, keeping
+/// that alpha in the pixel buffer (and so in the presented frame) instead of
+/// forcing every pixel opaque.
+pub fn generate_pixel_buffer_rgba<T: Send, CMap: ColourMapRgba<T> + ?Sized>(
+    input: Vec<T>,
+    mapper: &CMap,
+    pixel_rect: PixelRect,
+) -> Result<PixelBuffer, GeneratePixelBufferError> {
+    let mut buffer: PixelBufferData = vec![0u8; input.len() * PixelBuffer::BYTES_PER_PIXEL];
+
+    buffer
+        .par_chunks_exact_mut(PixelBuffer::BYTES_PER_PIXEL)
+        .zip(input.into_par_iter())
+        .with_min_len(PARALLEL_MIN_PIXELS_PER_TASK)
+        .try_for_each(|(pixel, value)| -> Result<(), GeneratePixelBufferError> {
+            let ColourRgba { r, g, b, a } = mapper
+                .map_rgba(value)
+                .map_err(GeneratePixelBufferError::ColourMap)?;
+
+            pixel.copy_from_slice(&[r, g, b, a]);
+            Ok(())
+        })?;
+
+    Ok(PixelBuffer::from_data_rgba(pixel_rect, buffer)?)
+}
+
+/// Maps one field through each of `mappers`, returning a buffer per map
+/// in the same order. The field is borrowed rather than consumed, so
+/// comparing colour maps costs one render and a colouring per map. Each
+/// buffer matches what `generate_pixel_buffer_counts` gives for that map.
+pub fn generate_pixel_buffers<T: LutIndex>(
+    input: &[T],
+    mappers: &[&dyn ColourMap<T>],
+    pixel_rect: PixelRect,
+) -> Result<Vec<PixelBuffer>, GeneratePixelBufferError> {
+    let max_index = input
+        .par_iter()
+        .map(|value| value.to_lut_index())
+        .max()
+        .filter(|&max_index| input.len() >= LUT_PIXEL_THRESHOLD && max_index < input.len());
+
+    mappers
+        .par_iter()
+        .map(|&mapper| {
+            let lut = max_index.map(|max_index| colour_lut(mapper, max_index));
+            let mut buffer: PixelBufferData =
+                vec![0u8; input.len() * PixelBuffer::BYTES_PER_PIXEL];
+
+            buffer
+                .par_chunks_exact_mut(PixelBuffer::BYTES_PER_PIXEL)
+                .zip(input.par_iter())
+                .with_min_len(PARALLEL_MIN_PIXELS_PER_TASK)
+                .try_for_each(|(pixel, &value)| -> Result<(), GeneratePixelBufferError> {
+                    match lut.as_ref().and_then(|lut| lut[value.to_lut_index()]) {
+                        Some(rgba) => pixel.copy_from_slice(&rgba),
+                        None => {
+                            let Colour { r, g, b } = mapper
+                                .map(value)
+                                .map_err(GeneratePixelBufferError::ColourMap)?;
+                            pixel.copy_from_slice(&[r, g, b, PixelBuffer::ALPHA_OPAQUE]);
+                        }
+                    }
+
+                    Ok(())
+                })?;
+
+            Ok(PixelBuffer::from_data_opaque(pixel_rect, buffer)?)
+        })
+        .collect()
+}
+
+fn never_cancelled(err: GeneratePixelBufferCancelableError) -> GeneratePixelBufferError {
+    match err {
+        GeneratePixelBufferCancelableError::ColourMap(err) => {
+            GeneratePixelBufferError::ColourMap(err)
         }
-    })
+        GeneratePixelBufferCancelableError::PixelBuffer(err) => {
+            GeneratePixelBufferError::PixelBuffer(err)
+        }
+        GeneratePixelBufferCancelableError::Cancelled(_) => {
+            unreachable!("NeverCancel token should never signal cancellation")
+        }
+    }
 }
 
+/// `generate_pixel_buffer` with cancellation.
 #[allow(dead_code)]
 pub fn generate_pixel_buffer_cancelable<T, CMap, C>(
     input: Vec<T>,
@@ -93,19 +255,102 @@ pub fn generate_pixel_buffer_cancelable<T, CMap, C>(
     cancel: &C,
 ) -> Result<PixelBuffer, GeneratePixelBufferCancelableError>
 where
+    T: Send,
     CMap: ColourMap<T> + ?Sized,
     C: CancelToken,
 {
     generate_pixel_buffer_cancelable_impl(input, mapper, pixel_rect, cancel)
 }
 
-#[allow(dead_code)]
+/// `generate_pixel_buffer_counts` with cancellation.
+pub fn generate_pixel_buffer_counts_cancelable<T, CMap, C>(
+    input: Vec<T>,
+    mapper: &CMap,
+    pixel_rect: PixelRect,
+    cancel: &C,
+) -> Result<PixelBuffer, GeneratePixelBufferCancelableError>
+where
+    T: LutIndex,
+    CMap: ColourMap<T> + ?Sized,
+    C: CancelToken,
+{
+    if input.len() >= LUT_PIXEL_THRESHOLD {
+        generate_pixel_buffer_lut_impl(input, mapper, pixel_rect, cancel)
+    } else {
+        generate_pixel_buffer_sequential_impl(input, mapper, pixel_rect, cancel)
+    }
+}
+
+/// Maps iteration counts to a pixel buffer by histogram equalisation,
+/// which needs the whole field up front: each escaped count is coloured
+/// by its rank among the field's escaped counts rather than by its value.
+/// See [`HistogramColourMap`].
+pub fn generate_pixel_buffer_histogram<CMap: ColourMap<u32> + ?Sized>(
+    input: Vec<u32>,
+    mapper: &CMap,
+    pixel_rect: PixelRect,
+    max_iterations: u32,
+) -> Result<PixelBuffer, GeneratePixelBufferError> {
+    generate_pixel_buffer_histogram_cancelable(input, mapper, pixel_rect, max_iterations, &NeverCancel)
+        .map_err(never_cancelled)
+}
+
+pub fn generate_pixel_buffer_histogram_cancelable<CMap, C>(
+    input: Vec<u32>,
+    mapper: &CMap,
+    pixel_rect: PixelRect,
+    max_iterations: u32,
+    cancel: &C,
+) -> Result<PixelBuffer, GeneratePixelBufferCancelableError>
+where
+    CMap: ColourMap<u32> + ?Sized,
+    C: CancelToken,
+{
+    let equalised = HistogramColourMap::for_field(mapper, &input, max_iterations);
+
+    generate_pixel_buffer_counts_cancelable(input, &equalised, pixel_rect, cancel)
+}
+
+/// `generate_pixel_buffer_parallel` with cancellation, for values with no
+/// lookup table such as continuous escape counts.
+pub fn generate_pixel_buffer_parallel_cancelable<T, CMap, C>(
+    input: Vec<T>,
+    mapper: &CMap,
+    pixel_rect: PixelRect,
+    cancel: &C,
+) -> Result<PixelBuffer, GeneratePixelBufferCancelableError>
+where
+    T: Send,
+    CMap: ColourMap<T> + ?Sized,
+    C: CancelToken,
+{
+    generate_pixel_buffer_parallel_impl(input, mapper, pixel_rect, cancel)
+}
+
 pub(crate) fn generate_pixel_buffer_cancelable_impl<T, CMap, C>(
     input: Vec<T>,
     mapper: &CMap,
     pixel_rect: PixelRect,
     cancel: &C,
 ) -> Result<PixelBuffer, GeneratePixelBufferCancelableError>
+where
+    T: Send,
+    CMap: ColourMap<T> + ?Sized,
+    C: CancelToken,
+{
+    if input.len() >= LUT_PIXEL_THRESHOLD {
+        generate_pixel_buffer_parallel_impl(input, mapper, pixel_rect, cancel)
+    } else {
+        generate_pixel_buffer_sequential_impl(input, mapper, pixel_rect, cancel)
+    }
+}
+
+fn generate_pixel_buffer_sequential_impl<T, CMap, C>(
+    input: Vec<T>,
+    mapper: &CMap,
+    pixel_rect: PixelRect,
+    cancel: &C,
+) -> Result<PixelBuffer, GeneratePixelBufferCancelableError>
 where
     CMap: ColourMap<T> + ?Sized,
     C: CancelToken,
@@ -132,6 +377,111 @@ where
         .map_err(GeneratePixelBufferCancelableError::PixelBuffer)
 }
 
+fn generate_pixel_buffer_parallel_impl<T, CMap, C>(
+    input: Vec<T>,
+    mapper: &CMap,
+    pixel_rect: PixelRect,
+    cancel: &C,
+) -> Result<PixelBuffer, GeneratePixelBufferCancelableError>
+where
+    T: Send,
+    CMap: ColourMap<T> + ?Sized,
+    C: CancelToken,
+{
+    let mut buffer: PixelBufferData = vec![0u8; input.len() * PixelBuffer::BYTES_PER_PIXEL];
+
+    buffer
+        .par_chunks_exact_mut(PixelBuffer::BYTES_PER_PIXEL)
+        .zip(input.into_par_iter())
+        .enumerate()
+        .with_min_len(PARALLEL_MIN_PIXELS_PER_TASK)
+        .try_for_each(|(i, (pixel, value))| {
+            if i % CANCEL_CHECK_INTERVAL_PIXELS == 0 && cancel.is_cancelled() {
+                return Err(GeneratePixelBufferCancelableError::Cancelled(Cancelled));
+            }
+
+            let Colour { r, g, b } = mapper
+                .map(value)
+                .map_err(GeneratePixelBufferCancelableError::ColourMap)?;
+
+            pixel.copy_from_slice(&[r, g, b, PixelBuffer::ALPHA_OPAQUE]);
+            Ok(())
+        })?;
+
+    PixelBuffer::from_data_opaque(pixel_rect, buffer)
+        .map_err(GeneratePixelBufferCancelableError::PixelBuffer)
+}
+
+fn generate_pixel_buffer_lut_impl<T, CMap, C>(
+    input: Vec<T>,
+    mapper: &CMap,
+    pixel_rect: PixelRect,
+    cancel: &C,
+) -> Result<PixelBuffer, GeneratePixelBufferCancelableError>
+where
+    T: LutIndex,
+    CMap: ColourMap<T> + ?Sized,
+    C: CancelToken,
+{
+    let max_index = input.par_iter().map(|value| value.to_lut_index()).max();
+    let Some(max_index) = max_index.filter(|&max_index| max_index < input.len()) else {
+        return generate_pixel_buffer_sequential_impl(input, mapper, pixel_rect, cancel);
+    };
+
+    let lut = colour_lut(mapper, max_index);
+
+    let mut buffer: PixelBufferData = vec![0u8; input.len() * PixelBuffer::BYTES_PER_PIXEL];
+
+    buffer
+        .par_chunks_exact_mut(PixelBuffer::BYTES_PER_PIXEL)
+        .zip(input.par_iter())
+        .enumerate()
+        .with_min_len(PARALLEL_MIN_PIXELS_PER_TASK)
+        .try_for_each(|(i, (pixel, &value))| {
+            if i % CANCEL_CHECK_INTERVAL_PIXELS == 0 && cancel.is_cancelled() {
+                return Err(GeneratePixelBufferCancelableError::Cancelled(Cancelled));
+            }
+
+            match lut[value.to_lut_index()] {
+                Some(rgba) => pixel.copy_from_slice(&rgba),
+                None => {
+                    let Colour { r, g, b } = mapper
+                        .map(value)
+                        .map_err(GeneratePixelBufferCancelableError::ColourMap)?;
+                    pixel.copy_from_slice(&[r, g, b, PixelBuffer::ALPHA_OPAQUE]);
+                }
+            }
+
+            Ok(())
+        })?;
+
+    PixelBuffer::from_data_opaque(pixel_rect, buffer)
+        .map_err(GeneratePixelBufferCancelableError::PixelBuffer)
+}
+
+type ColourLut = Vec<Option<[u8; PixelBuffer::BYTES_PER_PIXEL]>>;
+
+/// Maps every value up to `max_index`, on rayon since the table can be
+/// nearly as large as the image. Values the colour map rejects get no
+/// entry; a pixel that hits one is mapped directly so the error it reports
+/// is the colour map's own.
+fn colour_lut<T, CMap>(mapper: &CMap, max_index: usize) -> ColourLut
+where
+    T: LutIndex,
+    CMap: ColourMap<T> + ?Sized,
+{
+    (0..max_index + 1)
+        .into_par_iter()
+        .with_min_len(PARALLEL_MIN_PIXELS_PER_TASK)
+        .map(|index| {
+            mapper
+                .map(T::from_lut_index(index))
+                .ok()
+                .map(|Colour { r, g, b }| [r, g, b, PixelBuffer::ALPHA_OPAQUE])
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,6 +490,9 @@ mod tests {
     use crate::core::data::pixel_buffer::{PixelBuffer, PixelBufferData, PixelBufferError};
     use crate::core::data::pixel_rect::PixelRect;
     use crate::core::data::point::Point;
+    use crate::core::fractals::mandelbrot::colour_mapping::maps::{
+        fire::MandelbrotFireColourMap, ice::MandelbrotIceColourMap,
+    };
     use std::sync::atomic::{AtomicBool, Ordering};
 
     fn expected_buffer_size(width: usize, height: usize) -> usize {
@@ -176,6 +529,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn histogram_spreads_crowded_counts_and_leaves_the_interior() {
+        struct GreyColourMap;
+
+        impl ColourMap<u32> for GreyColourMap {
+            fn map(&self, value: u32) -> Result<Colour, ColourMapError> {
+                let level = value.min(255) as u8;
+                Ok(Colour { r: level, g: level, b: 0 })
+            }
+
+            fn display_name(&self) -> &str {
+                "Grey"
+            }
+        }
+
+        let max_iterations = 256;
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 0 }).unwrap();
+
+        let buffer = generate_pixel_buffer_histogram(
+            vec![10, 10, 11, max_iterations],
+            &GreyColourMap,
+            pixel_rect,
+            max_iterations,
+        )
+        .unwrap();
+
+        let reds: Vec<u8> = buffer
+            .buffer()
+            .chunks(PixelBuffer::BYTES_PER_PIXEL)
+            .map(|pixel| pixel[0])
+            .collect();
+        assert_eq!(reds, [170, 170, 255, 255]);
+    }
+
     #[test]
     fn test_generates_pixel_buffer_correctly() {
         let input: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
@@ -215,6 +602,53 @@ mod tests {
         assert_eq!(results.buffer_size(), expected_results.buffer_size());
     }
 
+    #[test]
+    fn test_closure_colour_map_generates_pixel_buffer() {
+        let input: Vec<u32> = vec![0, 10, 20, 30];
+        let mapper = |iterations: u32| Colour {
+            r: iterations as u8,
+            g: 0,
+            b: 255 - iterations as u8,
+        };
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 1, y: 1 }).unwrap();
+        let expected_buffer: PixelBufferData = vec![
+            0,
+            0,
+            255,
+            PixelBuffer::ALPHA_OPAQUE,
+            10,
+            0,
+            245,
+            PixelBuffer::ALPHA_OPAQUE,
+            20,
+            0,
+            235,
+            PixelBuffer::ALPHA_OPAQUE,
+            30,
+            0,
+            225,
+            PixelBuffer::ALPHA_OPAQUE,
+        ];
+        let results = generate_pixel_buffer(input, &mapper, pixel_rect).unwrap();
+
+        assert_eq!(results.buffer(), &expected_buffer);
+    }
+
+    #[test]
+    fn test_closure_colour_map_matches_sequential_above_lut_threshold() {
+        let pixel_rect = large_pixel_rect();
+        let input: Vec<u32> = (0..pixel_rect.size() as u32).map(|i| i % 7).collect();
+        let mapper = |iterations: u32| Colour {
+            r: iterations as u8 * 30,
+            g: iterations as u8,
+            b: 0,
+        };
+        let parallel = generate_pixel_buffer_counts(input.clone(), &mapper, pixel_rect).unwrap();
+        let sequential = generate_pixel_buffer_sequential(input, &mapper, pixel_rect).unwrap();
+
+        assert_eq!(parallel.buffer(), sequential.buffer());
+    }
+
     #[test]
     fn test_propagates_colour_map_failure() {
         let input: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
@@ -360,4 +794,241 @@ mod tests {
             Err(GeneratePixelBufferCancelableError::Cancelled(_))
         ));
     }
+
+    fn create_pixel_rect(width: i32, height: i32) -> PixelRect {
+        PixelRect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: width - 1,
+                y: height - 1,
+            },
+        )
+        .unwrap()
+    }
+
+    /// Distinct colour per value; rejects values above `max_value`.
+    #[derive(Debug)]
+    struct StubU32ColourMap {
+        max_value: u32,
+    }
+
+    impl ColourMap<u32> for StubU32ColourMap {
+        fn map(&self, value: u32) -> Result<Colour, ColourMapError> {
+            if value > self.max_value {
+                return Err("value exceeds stub maximum".into());
+            }
+
+            Ok(Colour {
+                r: (value % 251) as u8,
+                g: (value / 251 % 251) as u8,
+                b: (value * 7 % 253) as u8,
+            })
+        }
+
+        fn display_name(&self) -> &str {
+            "Stub u32"
+        }
+    }
+
+    fn large_pixel_rect() -> PixelRect {
+        let pixel_rect = create_pixel_rect(256, 80);
+        assert!(pixel_rect.size() as usize >= LUT_PIXEL_THRESHOLD);
+        pixel_rect
+    }
+
+    fn iteration_field(pixel_rect: PixelRect, max_value: u32) -> Vec<u32> {
+        (0..pixel_rect.size()).map(|i| (i * 31 % u64::from(max_value + 1)) as u32).collect()
+    }
+
+    #[test]
+    fn test_default_matches_sequential_reference_above_lut_threshold() {
+        let pixel_rect = large_pixel_rect();
+        let input = iteration_field(pixel_rect, 1000);
+        let mapper = StubU32ColourMap { max_value: 1000 };
+
+        let reference = generate_pixel_buffer_sequential(input.clone(), &mapper, pixel_rect).unwrap();
+        let default = generate_pixel_buffer(input.clone(), &mapper, pixel_rect).unwrap();
+        let counts = generate_pixel_buffer_counts(input.clone(), &mapper, pixel_rect).unwrap();
+        let parallel = generate_pixel_buffer_parallel(input.clone(), &mapper, pixel_rect).unwrap();
+        let lut = generate_pixel_buffer_lut(input, &mapper, pixel_rect).unwrap();
+
+        assert_eq!(default.buffer(), reference.buffer());
+        assert_eq!(counts.buffer(), reference.buffer());
+        assert_eq!(parallel.buffer(), reference.buffer());
+        assert_eq!(lut.buffer(), reference.buffer());
+    }
+
+    #[test]
+    fn test_lut_falls_back_when_values_exceed_pixel_count() {
+        let pixel_rect = create_pixel_rect(4, 2);
+        let input: Vec<u32> = vec![0, 1, 2, 3, 4, 5, 6, 1_000_000];
+        let mapper = StubU32ColourMap {
+            max_value: u32::MAX,
+        };
+
+        let reference = generate_pixel_buffer_sequential(input.clone(), &mapper, pixel_rect).unwrap();
+        let lut = generate_pixel_buffer_lut(input, &mapper, pixel_rect).unwrap();
+
+        assert_eq!(lut.buffer(), reference.buffer());
+    }
+
+    #[test]
+    fn test_lut_propagates_colour_map_failure_for_rejected_pixel() {
+        let pixel_rect = large_pixel_rect();
+        let mut input = iteration_field(pixel_rect, 500);
+        input[100] = 600;
+        let mapper = StubU32ColourMap { max_value: 500 };
+
+        let results = generate_pixel_buffer_counts(input, &mapper, pixel_rect);
+
+        assert!(matches!(results, Err(GeneratePixelBufferError::ColourMap(_))));
+    }
+
+    #[test]
+    fn test_parallel_reports_size_mismatch_like_sequential() {
+        let input: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
+        let mapper = StubColourMapSuccess {};
+        let pixel_rect = create_pixel_rect(2, 2);
+        let results = generate_pixel_buffer_parallel(input, &mapper, pixel_rect);
+
+        assert!(matches!(
+            results,
+            Err(GeneratePixelBufferError::PixelBuffer(
+                PixelBufferError::BoundsMismatch { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_parallel_propagates_colour_map_failure() {
+        let input: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
+        let mapper = StubColourMapFailure {};
+        let pixel_rect = create_pixel_rect(3, 2);
+        let results = generate_pixel_buffer_parallel(input, &mapper, pixel_rect);
+
+        assert!(matches!(results, Err(GeneratePixelBufferError::ColourMap(_))));
+    }
+
+    #[test]
+    fn test_cancelable_large_input_returns_cancelled_when_token_is_cancelled() {
+        let pixel_rect = large_pixel_rect();
+        let input = iteration_field(pixel_rect, 10);
+        let mapper = StubU32ColourMap { max_value: 10 };
+        let cancel_token = || true;
+        let result =
+            generate_pixel_buffer_counts_cancelable(input.clone(), &mapper, pixel_rect, &cancel_token);
+        let default = generate_pixel_buffer_cancelable(input, &mapper, pixel_rect, &cancel_token);
+
+        assert!(matches!(
+            result,
+            Err(GeneratePixelBufferCancelableError::Cancelled(_))
+        ));
+        assert!(matches!(
+            default,
+            Err(GeneratePixelBufferCancelableError::Cancelled(_))
+        ));
+    }
+
+    #[derive(Debug)]
+    struct StubColourMapRgba {}
+
+    impl ColourMapRgba<u8> for StubColourMapRgba {
+        fn map_rgba(&self, value: u8) -> Result<ColourRgba, ColourMapError> {
+            Ok(ColourRgba {
+                r: value,
+                g: value,
+                b: value,
+                a: value * 10,
+            })
+        }
+
+        fn display_name(&self) -> &str {
+            "Stub Rgba"
+        }
+    }
+
+    #[test]
+    fn test_rgba_keeps_the_mapped_alpha() {
+        let input: Vec<u8> = vec![0, 1, 2, 3];
+        let pixel_rect = create_pixel_rect(2, 2);
+        let results = generate_pixel_buffer_rgba(input, &StubColourMapRgba {}, pixel_rect).unwrap();
+
+        assert_eq!(
+            results.as_rgba(),
+            &[0, 0, 0, 0, 1, 1, 1, 10, 2, 2, 2, 20, 3, 3, 3, 30]
+        );
+    }
+
+    #[test]
+    fn test_rgba_reports_size_mismatch() {
+        let input: Vec<u8> = vec![1, 2, 3];
+        let pixel_rect = create_pixel_rect(2, 2);
+        let results = generate_pixel_buffer_rgba(input, &StubColourMapRgba {}, pixel_rect);
+
+        assert!(matches!(
+            results,
+            Err(GeneratePixelBufferError::PixelBuffer(
+                PixelBufferError::BoundsMismatch { .. }
+            ))
+        ));
+    }
+
+    fn assert_each_matches_single_map(field: Vec<u32>, pixel_rect: PixelRect) {
+        let fire = MandelbrotFireColourMap::new(100);
+        let ice = MandelbrotIceColourMap::new(100);
+        let mappers: [&dyn ColourMap<u32>; 2] = [&fire, &ice];
+
+        let buffers = generate_pixel_buffers(&field, &mappers, pixel_rect).unwrap();
+
+        assert_eq!(buffers.len(), mappers.len());
+        for (buffer, mapper) in buffers.iter().zip(mappers) {
+            let single = generate_pixel_buffer_counts(field.clone(), mapper, pixel_rect).unwrap();
+            assert_eq!(buffer.buffer(), single.buffer());
+            assert_eq!(buffer.pixel_rect(), single.pixel_rect());
+        }
+    }
+
+    #[test]
+    fn test_multiple_maps_each_match_single_map_output() {
+        let pixel_rect = create_pixel_rect(4, 3);
+        assert_each_matches_single_map(iteration_field(pixel_rect, 100), pixel_rect);
+    }
+
+    #[test]
+    fn test_multiple_maps_each_match_single_map_output_above_lut_threshold() {
+        let pixel_rect = large_pixel_rect();
+        assert_each_matches_single_map(iteration_field(pixel_rect, 100), pixel_rect);
+    }
+
+    #[test]
+    fn test_multiple_maps_with_no_maps_returns_no_buffers() {
+        let pixel_rect = create_pixel_rect(2, 2);
+        let buffers = generate_pixel_buffers(&[1u32, 2, 3, 4], &[], pixel_rect).unwrap();
+
+        assert!(buffers.is_empty());
+    }
+
+    #[test]
+    fn test_multiple_maps_propagate_colour_map_failure() {
+        let pixel_rect = large_pixel_rect();
+        let field = iteration_field(pixel_rect, 100);
+        let accepting = StubU32ColourMap { max_value: 100 };
+        let rejecting = StubU32ColourMap { max_value: 50 };
+        let results = generate_pixel_buffers(&field, &[&accepting, &rejecting], pixel_rect);
+
+        assert!(matches!(results, Err(GeneratePixelBufferError::ColourMap(_))));
+    }
+
+    #[test]
+    fn test_multiple_maps_report_size_mismatch() {
+        let accepting = StubU32ColourMap { max_value: 100 };
+        let results = generate_pixel_buffers(&[1u32, 2, 3], &[&accepting], create_pixel_rect(2, 2));
+
+        assert!(matches!(
+            results,
+            Err(GeneratePixelBufferError::PixelBuffer(
+                PixelBufferError::BoundsMismatch { .. }
+            ))
+        ));
+    }
 }
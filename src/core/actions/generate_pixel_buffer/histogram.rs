@@ -0,0 +1,159 @@
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{ColourMap, ColourMapError};
+use crate::core::data::colour::Colour;
+use crate::core::fractals::parse_kind::{ParseKindError, parse_kind};
+use std::str::FromStr;
+
+/// How iteration counts are spread over a colour map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColourSchemeKind {
+    /// Each count is coloured as itself.
+    #[default]
+    Linear,
+    /// Each escaped count is coloured by its rank in the frame; see
+    /// [`HistogramColourMap`].
+    Histogram,
+}
+
+impl ColourSchemeKind {
+    pub const ALL: &'static [Self] = &[Self::Linear, Self::Histogram];
+
+    #[must_use]
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::Linear => "Linear",
+            Self::Histogram => "Histogram",
+        }
+    }
+}
+
+impl std::fmt::Display for ColourSchemeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str((*self).display_name())
+    }
+}
+
+/// Parses a display name case-insensitively, e.g. from a config file.
+impl FromStr for ColourSchemeKind {
+    type Err = ParseKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_kind(Self::ALL, Self::display_name, "colour scheme", s)
+    }
+}
+
+/// Wraps a colour map so colours are shared out by how many pixels have
+/// each escaped iteration count, not by the count itself.
+///
+/// Each count below `max_iterations` is coloured as its cumulative share
+/// of the escaped pixels in the frame, scaled onto `0..max_iterations`:
+/// the most common counts get the most colours, so a deep zoom whose
+/// counts crowd into a narrow band still uses the whole map. The interior
+/// (`>= max_iterations`) takes no part in the histogram and passes
+/// through unchanged.
+#[derive(Debug)]
+pub struct HistogramColourMap<'a, CMap: ?Sized> {
+    inner: &'a CMap,
+    /// The count each escaped count is coloured as, indexed by count.
+    equalised: Vec<u32>,
+    max_iterations: u32,
+}
+
+impl<'a, CMap: ColourMap<u32> + ?Sized> HistogramColourMap<'a, CMap> {
+    /// Equalises `inner` over the escaped counts in `field`.
+    #[must_use]
+    pub fn for_field(inner: &'a CMap, field: &[u32], max_iterations: u32) -> Self {
+        let mut histogram = vec![0u64; max_iterations as usize];
+        for &iterations in field {
+            if let Some(bucket) = histogram.get_mut(iterations as usize) {
+                *bucket += 1;
+            }
+        }
+
+        let escaped: u64 = histogram.iter().sum();
+        let top = f64::from(max_iterations.saturating_sub(1));
+        let mut cumulative = 0u64;
+        let equalised = histogram
+            .iter()
+            .map(|&pixels| {
+                cumulative += pixels;
+                if escaped == 0 {
+                    0
+                } else {
+                    (cumulative as f64 / escaped as f64 * top).round() as u32
+                }
+            })
+            .collect();
+
+        Self {
+            inner,
+            equalised,
+            max_iterations,
+        }
+    }
+}
+
+impl<CMap: ColourMap<u32> + ?Sized> ColourMap<u32> for HistogramColourMap<'_, CMap> {
+    fn map(&self, iterations: u32) -> Result<Colour, ColourMapError> {
+        match self.equalised.get(iterations as usize) {
+            Some(&equalised) if iterations < self.max_iterations => self.inner.map(equalised),
+            _ => self.inner.map(iterations),
+        }
+    }
+
+    fn display_name(&self) -> &str {
+        self.inner.display_name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fractals::mandelbrot::colour_mapping::maps::fire::MandelbrotFireColourMap;
+
+    const MAX_ITERATIONS: u32 = 100;
+
+    fn rgb<CMap: ColourMap<u32> + ?Sized>(map: &CMap, iterations: u32) -> (u8, u8, u8) {
+        let colour = map.map(iterations).unwrap();
+        (colour.r, colour.g, colour.b)
+    }
+
+    #[test]
+    fn counts_are_coloured_by_cumulative_rank() {
+        let inner = MandelbrotFireColourMap::new(MAX_ITERATIONS);
+        // Half the pixels at 40, a quarter each at 41 and 42.
+        let field = [40, 40, 41, 42];
+
+        let equalised = HistogramColourMap::for_field(&inner, &field, MAX_ITERATIONS);
+
+        assert_eq!(rgb(&equalised, 40), rgb(&inner, 50));
+        assert_eq!(rgb(&equalised, 41), rgb(&inner, 74));
+        assert_eq!(rgb(&equalised, 42), rgb(&inner, MAX_ITERATIONS - 1));
+    }
+
+    #[test]
+    fn interior_is_excluded_from_the_histogram() {
+        let inner = MandelbrotFireColourMap::new(MAX_ITERATIONS);
+        let field = [40, 40, 41, 42];
+        let with_interior = [40, MAX_ITERATIONS, 40, MAX_ITERATIONS, 41, MAX_ITERATIONS, 42];
+
+        let equalised = HistogramColourMap::for_field(&inner, &field, MAX_ITERATIONS);
+        let equalised_with_interior =
+            HistogramColourMap::for_field(&inner, &with_interior, MAX_ITERATIONS);
+
+        for iterations in [40, 41, 42] {
+            assert_eq!(rgb(&equalised_with_interior, iterations), rgb(&equalised, iterations));
+        }
+        assert_eq!(
+            rgb(&equalised_with_interior, MAX_ITERATIONS),
+            rgb(&inner, MAX_ITERATIONS)
+        );
+    }
+
+    #[test]
+    fn scheme_names_round_trip() {
+        for &kind in ColourSchemeKind::ALL {
+            assert_eq!(kind.to_string().parse::<ColourSchemeKind>(), Ok(kind));
+        }
+        assert!("rainbow".parse::<ColourSchemeKind>().is_err());
+    }
+}
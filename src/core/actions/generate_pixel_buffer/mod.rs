@@ -1,3 +1,4 @@
 #[allow(clippy::module_inception)]
 pub mod generate_pixel_buffer;
+pub mod histogram;
 pub mod ports;
@@ -1,4 +1,4 @@
-use crate::core::data::colour::Colour;
+use crate::core::data::colour::{Colour, ColourRgba};
 
 /// Error type for colour map operations. Uses `Send + Sync` bounds so that
 /// colour-map failures can safely propagate from rayon worker threads.
@@ -9,3 +9,28 @@ pub trait ColourMap<T>: Send + Sync {
     #[allow(dead_code)]
     fn display_name(&self) -> &str;
 }
+
+/// Lets any `Fn(u32) -> Colour` closure stand in for an iteration colour map,
+/// for quick experiments that don't warrant a named type.
+impl<F> ColourMap<u32> for F
+where
+    F: Fn(u32) -> Colour + Send + Sync,
+{
+    #[inline]
+    fn map(&self, value: u32) -> Result<Colour, ColourMapError> {
+        Ok(self(value))
+    }
+
+    fn display_name(&self) -> &str {
+        "Closure"
+    }
+}
+
+/// A colour map that also chooses each pixel's alpha. Rendered through
+/// `generate_pixel_buffer_rgba`, which keeps the alpha in the pixel buffer
+/// instead of forcing it opaque.
+pub trait ColourMapRgba<T>: Send + Sync {
+    fn map_rgba(&self, value: T) -> Result<ColourRgba, ColourMapError>;
+    #[allow(dead_code)]
+    fn display_name(&self) -> &str;
+}
@@ -1,4 +1,15 @@
 pub mod cancellation;
+pub mod contrast_stretch;
+pub mod cost_heatmap;
+pub mod diff_iterations;
+pub mod downscale_area;
+pub mod extract_contours;
 pub mod generate_fractal;
 pub mod generate_pixel_buffer;
+pub mod render_adaptive_aa;
+pub mod render_boundary_only;
+pub mod render_palette_strip;
 pub mod render_pixel_buffer;
+pub mod render_tile;
+pub mod seek_detail;
+pub mod smooth_colour;
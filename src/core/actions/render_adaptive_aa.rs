@@ -0,0 +1,412 @@
+use rayon::prelude::*;
+
+use crate::core::actions::generate_fractal::generate_fractal_parallel_rayon::generate_fractal_parallel_rayon;
+use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{ColourMap, ColourMapError};
+use crate::core::data::colour::Colour;
+use crate::core::data::complex::Complex;
+use crate::core::data::complex_rect::{ComplexRect, ComplexRectError};
+use crate::core::data::pixel_buffer::{PixelBuffer, PixelBufferData, PixelBufferError};
+use crate::core::data::pixel_rect::{PixelRect, PixelRectError};
+use crate::core::data::point::Point;
+use std::error::Error;
+use std::fmt;
+
+/// Upper bound on `samples_per_axis`; larger settings are clamped.
+pub const MAX_SAMPLES_PER_AXIS: u32 = 16;
+
+/// Adaptive supersampling settings.
+///
+/// A pixel is an edge when the iteration count of any of its four
+/// neighbours differs from its own by at least `edge_threshold`. Edge pixels
+/// are re-rendered on a `samples_per_axis` x `samples_per_axis` grid and
+/// their colours averaged; every other pixel keeps its 1x colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdaptiveAa {
+    pub samples_per_axis: u32,
+    pub edge_threshold: u32,
+}
+
+impl Default for AdaptiveAa {
+    fn default() -> Self {
+        Self {
+            samples_per_axis: 3,
+            edge_threshold: 1,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RenderAdaptiveAaError<BuildErr, AlgErr> {
+    Build(BuildErr),
+    Algorithm(AlgErr),
+    ColourMap(ColourMapError),
+    SampleRect(PixelRectError),
+    SampleRegion(ComplexRectError),
+    PixelBuffer(PixelBufferError),
+}
+
+impl<B: fmt::Display, E: fmt::Display> fmt::Display for RenderAdaptiveAaError<B, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Build(e) => write!(f, "algorithm build error: {}", e),
+            Self::Algorithm(e) => write!(f, "algorithm error: {}", e),
+            Self::ColourMap(e) => write!(f, "colour map error: {}", e),
+            Self::SampleRect(e) => write!(f, "supersample rect error: {}", e),
+            Self::SampleRegion(e) => write!(f, "supersample region error: {}", e),
+            Self::PixelBuffer(e) => write!(f, "pixel buffer error: {}", e),
+        }
+    }
+}
+
+impl<B: Error + 'static, E: Error + 'static> Error for RenderAdaptiveAaError<B, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Build(e) => Some(e),
+            Self::Algorithm(e) => Some(e),
+            Self::ColourMap(e) => Some(e.as_ref()),
+            Self::SampleRect(e) => Some(e),
+            Self::SampleRegion(e) => Some(e),
+            Self::PixelBuffer(e) => Some(e),
+        }
+    }
+}
+
+/// Failures from the parallel supersampling pass, which cannot carry the
+/// (possibly non-`Send`) build error.
+enum SampleError<AlgErr> {
+    Algorithm(AlgErr),
+    ColourMap(ColourMapError),
+}
+
+/// Marks the pixels of a `width` x `height` iteration field whose value
+/// differs from a horizontal or vertical neighbour by at least `threshold`.
+/// A zero threshold is treated as 1.
+#[must_use]
+pub fn detect_edges(iterations: &[u32], width: usize, height: usize, threshold: u32) -> Vec<bool> {
+    debug_assert_eq!(iterations.len(), width * height);
+    let threshold = threshold.max(1);
+    let mut edges = vec![false; iterations.len()];
+
+    for y in 0..height {
+        for x in 0..width {
+            let i = y * width + x;
+
+            if x + 1 < width && iterations[i].abs_diff(iterations[i + 1]) >= threshold {
+                edges[i] = true;
+                edges[i + 1] = true;
+            }
+            if y + 1 < height && iterations[i].abs_diff(iterations[i + width]) >= threshold {
+                edges[i] = true;
+                edges[i + width] = true;
+            }
+        }
+    }
+
+    edges
+}
+
+/// Renders `region` into `pixel_rect` with adaptive anti-aliasing.
+///
+/// A 1x pass computes every pixel's iteration count; pixels on an edge (see
+/// [`AdaptiveAa`]) are then supersampled. `build_algorithm` is called once
+/// for the 1x pass and once more, over a grid `samples_per_axis` times finer
+/// whose samples are centred on each pixel's own coordinate, for the
+/// supersampling pass. Constructors such as `MandelbrotAlgorithm::new` can
+/// be passed directly.
+pub fn render_adaptive_aa<Alg, CMap, F, BuildErr>(
+    pixel_rect: PixelRect,
+    region: ComplexRect,
+    max_iterations: u32,
+    settings: AdaptiveAa,
+    build_algorithm: F,
+    colour_map: &CMap,
+) -> Result<PixelBuffer, RenderAdaptiveAaError<BuildErr, Alg::Failure>>
+where
+    Alg: FractalAlgorithm<Success = u32> + Sync,
+    Alg::Failure: Send,
+    CMap: ColourMap<u32> + ?Sized,
+    F: Fn(PixelRect, ComplexRect, u32) -> Result<Alg, BuildErr>,
+{
+    let algorithm =
+        build_algorithm(pixel_rect, region, max_iterations).map_err(RenderAdaptiveAaError::Build)?;
+    let iterations = generate_fractal_parallel_rayon(pixel_rect, &algorithm)
+        .map_err(RenderAdaptiveAaError::Algorithm)?;
+
+    let mut buffer: PixelBufferData = vec![0u8; iterations.len() * PixelBuffer::BYTES_PER_PIXEL];
+    for (pixel, &value) in buffer
+        .chunks_exact_mut(PixelBuffer::BYTES_PER_PIXEL)
+        .zip(&iterations)
+    {
+        let colour = colour_map
+            .map(value)
+            .map_err(RenderAdaptiveAaError::ColourMap)?;
+        write_colour(pixel, colour);
+    }
+
+    let width = pixel_rect.width() as usize;
+    let height = pixel_rect.height() as usize;
+    let samples = settings.samples_per_axis.min(MAX_SAMPLES_PER_AXIS);
+
+    // Sub-pixel spacing is derived from the pixel spacing, which a single
+    // row or column does not have.
+    if samples > 1 && width > 1 && height > 1 {
+        let edges = detect_edges(&iterations, width, height, settings.edge_threshold);
+
+        if edges.contains(&true) {
+            let (sample_rect, sample_region) = sample_grid(pixel_rect, region, samples)?;
+            let sampler = build_algorithm(sample_rect, sample_region, max_iterations)
+                .map_err(RenderAdaptiveAaError::Build)?;
+
+            buffer
+                .par_chunks_mut(width * PixelBuffer::BYTES_PER_PIXEL)
+                .zip(edges.par_chunks(width))
+                .enumerate()
+                .try_for_each(|(y, (row, row_edges))| -> Result<(), SampleError<Alg::Failure>> {
+                    for x in (0..width).filter(|&x| row_edges[x]) {
+                        let colour = supersample(&sampler, colour_map, x, y, samples)?;
+                        let base = x * PixelBuffer::BYTES_PER_PIXEL;
+                        write_colour(&mut row[base..base + PixelBuffer::BYTES_PER_PIXEL], colour);
+                    }
+                    Ok(())
+                })
+                .map_err(|e| match e {
+                    SampleError::Algorithm(e) => RenderAdaptiveAaError::Algorithm(e),
+                    SampleError::ColourMap(e) => RenderAdaptiveAaError::ColourMap(e),
+                })?;
+        }
+    }
+
+    PixelBuffer::from_data_opaque(pixel_rect, buffer).map_err(RenderAdaptiveAaError::PixelBuffer)
+}
+
+/// The pixel rect and region of the supersampling grid.
+///
+/// Grid pixel `(x * n + i, y * n + j)` lands at offset
+/// `((i + 0.5) / n - 0.5, (j + 0.5) / n - 0.5)` pixel steps from pixel
+/// `(x, y)`, so each pixel's samples are spread evenly around its 1x
+/// coordinate.
+fn sample_grid<BuildErr, AlgErr>(
+    pixel_rect: PixelRect,
+    region: ComplexRect,
+    samples: u32,
+) -> Result<(PixelRect, ComplexRect), RenderAdaptiveAaError<BuildErr, AlgErr>> {
+    let step_re = region.width() / f64::from(pixel_rect.width() - 1);
+    let step_im = region.height() / f64::from(pixel_rect.height() - 1);
+    let inset = f64::from(samples - 1) / f64::from(2 * samples);
+
+    let sample_region = ComplexRect::new(
+        Complex {
+            real: region.top_left().real - step_re * inset,
+            imag: region.top_left().imag - step_im * inset,
+        },
+        Complex {
+            real: region.bottom_right().real + step_re * inset,
+            imag: region.bottom_right().imag + step_im * inset,
+        },
+    )
+    .map_err(RenderAdaptiveAaError::SampleRegion)?;
+
+    let sample_rect = PixelRect::new(
+        Point { x: 0, y: 0 },
+        Point {
+            x: pixel_rect.width().saturating_mul(samples) as i32 - 1,
+            y: pixel_rect.height().saturating_mul(samples) as i32 - 1,
+        },
+    )
+    .map_err(RenderAdaptiveAaError::SampleRect)?;
+
+    Ok((sample_rect, sample_region))
+}
+
+/// Average colour of the `samples` x `samples` grid for pixel `(x, y)`,
+/// relative to the 1x pixel rect's top-left.
+fn supersample<Alg, CMap>(
+    sampler: &Alg,
+    colour_map: &CMap,
+    x: usize,
+    y: usize,
+    samples: u32,
+) -> Result<Colour, SampleError<Alg::Failure>>
+where
+    Alg: FractalAlgorithm<Success = u32> + ?Sized,
+    CMap: ColourMap<u32> + ?Sized,
+{
+    let n = samples as usize;
+    let (mut r, mut g, mut b) = (0u32, 0u32, 0u32);
+
+    for j in 0..n {
+        for i in 0..n {
+            let point = Point {
+                x: (x * n + i) as i32,
+                y: (y * n + j) as i32,
+            };
+            let value = sampler.compute(point).map_err(SampleError::Algorithm)?;
+            let colour = colour_map.map(value).map_err(SampleError::ColourMap)?;
+
+            r += u32::from(colour.r);
+            g += u32::from(colour.g);
+            b += u32::from(colour.b);
+        }
+    }
+
+    let count = (n * n) as u32;
+    let average = |sum: u32| ((sum + count / 2) / count) as u8;
+
+    Ok(Colour {
+        r: average(r),
+        g: average(g),
+        b: average(b),
+    })
+}
+
+fn write_colour(pixel: &mut [u8], colour: Colour) {
+    pixel[0] = colour.r;
+    pixel[1] = colour.g;
+    pixel[2] = colour.b;
+    pixel[3] = PixelBuffer::ALPHA_OPAQUE;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::actions::render_pixel_buffer::render_pixel_buffer_parallel_rayon;
+    use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
+    use crate::core::fractals::mandelbrot::colour_mapping::maps::fire::MandelbrotFireColourMap;
+    use crate::core::fractals::mandelbrot::errors::mandelbrot::MandelbrotError;
+
+    fn pixel_rect(width: i32, height: i32) -> PixelRect {
+        PixelRect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: width - 1,
+                y: height - 1,
+            },
+        )
+        .unwrap()
+    }
+
+    fn region() -> ComplexRect {
+        ComplexRect::new(
+            Complex {
+                real: -2.0,
+                imag: -1.2,
+            },
+            Complex {
+                real: 0.6,
+                imag: 1.2,
+            },
+        )
+        .unwrap()
+    }
+
+    fn pixels(buffer: &PixelBuffer) -> Vec<&[u8]> {
+        buffer
+            .buffer()
+            .chunks_exact(PixelBuffer::BYTES_PER_PIXEL)
+            .collect()
+    }
+
+    #[test]
+    fn flat_field_has_no_edges() {
+        assert!(detect_edges(&[7; 12], 4, 3, 1).iter().all(|&e| !e));
+    }
+
+    #[test]
+    fn step_marks_pixels_on_both_sides() {
+        #[rustfmt::skip]
+        let field = [
+            1, 1, 5, 5,
+            1, 1, 5, 5,
+            1, 1, 1, 1,
+        ];
+
+        #[rustfmt::skip]
+        let expected = [
+            false, true, true, false,
+            false, true, true, true,
+            false, false, true, true,
+        ];
+
+        assert_eq!(detect_edges(&field, 4, 3, 1), expected);
+    }
+
+    #[test]
+    fn threshold_ignores_small_differences() {
+        let field = [1, 2, 2, 9];
+
+        assert_eq!(detect_edges(&field, 4, 1, 3), [false, false, true, true]);
+    }
+
+    #[test]
+    fn flat_pixels_are_untouched_and_edges_change() {
+        let rect = pixel_rect(24, 20);
+        let max_iterations = 64;
+        let colour_map = MandelbrotFireColourMap::new(max_iterations);
+
+        let algorithm = MandelbrotAlgorithm::new(rect, region(), max_iterations).unwrap();
+        let plain = render_pixel_buffer_parallel_rayon(rect, &algorithm, &colour_map).unwrap();
+        let iterations = generate_fractal_parallel_rayon(rect, &algorithm).unwrap();
+        let edges = detect_edges(&iterations, 24, 20, 1);
+
+        let aa = render_adaptive_aa(
+            rect,
+            region(),
+            max_iterations,
+            AdaptiveAa::default(),
+            MandelbrotAlgorithm::new,
+            &colour_map,
+        )
+        .unwrap();
+
+        let (plain, aa) = (pixels(&plain), pixels(&aa));
+        let mut interior_checked = 0;
+        let mut changed_edges = 0;
+
+        for i in 0..edges.len() {
+            if edges[i] {
+                changed_edges += usize::from(plain[i] != aa[i]);
+            } else {
+                assert_eq!(plain[i], aa[i], "flat pixel {i} changed");
+                interior_checked += usize::from(iterations[i] == max_iterations);
+            }
+        }
+
+        assert!(interior_checked > 0, "expected flat interior pixels in the test view");
+        assert!(changed_edges > 0, "expected supersampling to change some edge pixels");
+    }
+
+    #[test]
+    fn single_sample_matches_plain_render() {
+        let rect = pixel_rect(16, 12);
+        let colour_map = MandelbrotFireColourMap::new(50);
+        let algorithm = MandelbrotAlgorithm::new(rect, region(), 50).unwrap();
+        let plain = render_pixel_buffer_parallel_rayon(rect, &algorithm, &colour_map).unwrap();
+
+        let settings = AdaptiveAa {
+            samples_per_axis: 1,
+            ..AdaptiveAa::default()
+        };
+        let aa = render_adaptive_aa(rect, region(), 50, settings, MandelbrotAlgorithm::new, &colour_map)
+            .unwrap();
+
+        assert_eq!(aa.buffer(), plain.buffer());
+    }
+
+    #[test]
+    fn propagates_algorithm_build_error() {
+        let result = render_adaptive_aa(
+            pixel_rect(4, 4),
+            region(),
+            0,
+            AdaptiveAa::default(),
+            MandelbrotAlgorithm::new,
+            &MandelbrotFireColourMap::new(1),
+        );
+
+        assert!(matches!(
+            result,
+            Err(RenderAdaptiveAaError::Build(MandelbrotError::ZeroMaxIterationsError))
+        ));
+    }
+}
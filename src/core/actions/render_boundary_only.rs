@@ -0,0 +1,217 @@
+use crate::core::actions::generate_pixel_buffer::generate_pixel_buffer::{
+    GeneratePixelBufferError, generate_pixel_buffer_rgba,
+};
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{
+    ColourMap, ColourMapError, ColourMapRgba,
+};
+use crate::core::data::colour::ColourRgba;
+use crate::core::data::pixel_buffer::{PixelBuffer, PixelBufferError};
+use crate::core::data::pixel_rect::PixelRect;
+
+/// Marks the pixels of a row-major iteration `field` that lie on the set's
+/// boundary: interior pixels (`>= max_iterations`) with an escaped pixel
+/// directly above, below, left or right of them, and escaped pixels with
+/// an interior one there. Both sides of each transition are marked, so the
+/// outline is two pixels thick and closed. Pixels past the field's edge do
+/// not count as neighbours.
+///
+/// `field` must hold `width * height` values.
+#[must_use]
+pub fn boundary_mask(field: &[u32], width: usize, height: usize, max_iterations: u32) -> Vec<bool> {
+    debug_assert_eq!(field.len(), width * height);
+    let interior = |index: usize| field[index] >= max_iterations;
+
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let index = y * width + x;
+            let inside = interior(index);
+
+            (x > 0 && interior(index - 1) != inside)
+                || (x + 1 < width && interior(index + 1) != inside)
+                || (y > 0 && interior(index - width) != inside)
+                || (y + 1 < height && interior(index + width) != inside)
+        })
+        .collect()
+}
+
+/// Boundary-only colouring: boundary pixels (see [`boundary_mask`]) take
+/// their colour from an iteration-count map at full opacity, and every
+/// other pixel is fully transparent, so just the set's outline lands on
+/// whatever the image is composited over.
+#[derive(Debug)]
+pub struct BoundaryOnlyColourMap<'a, CMap: ?Sized> {
+    colours: &'a CMap,
+}
+
+impl<'a, CMap: ColourMap<u32> + ?Sized> BoundaryOnlyColourMap<'a, CMap> {
+    #[must_use]
+    pub fn new(colours: &'a CMap) -> Self {
+        Self { colours }
+    }
+}
+
+impl<CMap: ColourMap<u32> + ?Sized> ColourMapRgba<(u32, bool)> for BoundaryOnlyColourMap<'_, CMap> {
+    fn map_rgba(&self, (iterations, on_boundary): (u32, bool)) -> Result<ColourRgba, ColourMapError> {
+        if !on_boundary {
+            return Ok(ColourRgba::TRANSPARENT);
+        }
+
+        self.colours.map(iterations).map(ColourRgba::from)
+    }
+
+    fn display_name(&self) -> &str {
+        self.colours.display_name()
+    }
+}
+
+/// Renders only the boundary of the set in `field`, coloured by
+/// `colour_map`, with the rest of the image transparent.
+pub fn render_boundary_only<CMap: ColourMap<u32> + ?Sized>(
+    field: Vec<u32>,
+    pixel_rect: PixelRect,
+    max_iterations: u32,
+    colour_map: &CMap,
+) -> Result<PixelBuffer, GeneratePixelBufferError> {
+    if field.len() as u64 != pixel_rect.size() {
+        return Err(GeneratePixelBufferError::PixelBuffer(
+            PixelBufferError::BoundsMismatch {
+                pixel_rect_size: pixel_rect.size() as usize * PixelBuffer::BYTES_PER_PIXEL,
+                buffer_size: field.len() * PixelBuffer::BYTES_PER_PIXEL,
+            },
+        ));
+    }
+
+    let mask = boundary_mask(
+        &field,
+        pixel_rect.width() as usize,
+        pixel_rect.height() as usize,
+        max_iterations,
+    );
+    let input: Vec<(u32, bool)> = field.into_iter().zip(mask).collect();
+
+    generate_pixel_buffer_rgba(input, &BoundaryOnlyColourMap::new(colour_map), pixel_rect)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::colour::Colour;
+    use crate::core::data::point::Point;
+
+    const MAX: u32 = 10;
+
+    /// Every escaped pixel red, every interior one blue, so tests can tell
+    /// which side of the boundary a pixel came from.
+    struct SideColours;
+
+    impl ColourMap<u32> for SideColours {
+        fn map(&self, iterations: u32) -> Result<Colour, ColourMapError> {
+            Ok(if iterations >= MAX {
+                Colour { r: 0, g: 0, b: 255 }
+            } else {
+                Colour { r: 255, g: 0, b: 0 }
+            })
+        }
+
+        fn display_name(&self) -> &str {
+            "Sides"
+        }
+    }
+
+    fn pixel_rect(width: i32, height: i32) -> PixelRect {
+        PixelRect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: width - 1,
+                y: height - 1,
+            },
+        )
+        .unwrap()
+    }
+
+    /// Parses rows of `#` (interior) and `.` (escaped, with a count that
+    /// varies so only interior-ness can matter).
+    fn field(rows: &[&str]) -> Vec<u32> {
+        rows.iter()
+            .flat_map(|row| row.chars())
+            .enumerate()
+            .map(|(i, c)| if c == '#' { MAX } else { i as u32 % MAX })
+            .collect()
+    }
+
+    /// Renders `rows` and draws the result back as rows: `.` transparent,
+    /// `o` opaque exterior, `#` opaque interior.
+    fn render(rows: &[&str]) -> Vec<String> {
+        let width = rows[0].len();
+        let buffer = render_boundary_only(
+            field(rows),
+            pixel_rect(width as i32, rows.len() as i32),
+            MAX,
+            &SideColours,
+        )
+        .unwrap();
+
+        let cells: Vec<char> = buffer
+            .as_rgba()
+            .chunks_exact(PixelBuffer::BYTES_PER_PIXEL)
+            .map(|pixel| match pixel {
+                [_, _, _, 0] => '.',
+                [255, 0, 0, 255] => 'o',
+                [0, 0, 255, 255] => '#',
+                other => panic!("unexpected pixel {other:?}"),
+            })
+            .collect();
+
+        cells.chunks(width).map(|row| row.iter().collect()).collect()
+    }
+
+    #[test]
+    fn only_pixels_either_side_of_a_transition_are_opaque() {
+        let rows = [
+            ".......", //
+            ".......",
+            "..###..",
+            "..###..",
+            "..###..",
+            ".......",
+            ".......",
+        ];
+
+        assert_eq!(
+            render(&rows),
+            [
+                ".......", //
+                "..ooo..",
+                ".o###o.",
+                ".o#.#o.",
+                ".o###o.",
+                "..ooo..",
+                ".......",
+            ]
+        );
+    }
+
+    #[test]
+    fn uniform_fields_have_no_boundary() {
+        assert!(render(&["....", "...."]).iter().all(|row| row == "...."));
+        assert!(render(&["####", "####"]).iter().all(|row| row == "...."));
+    }
+
+    #[test]
+    fn the_field_edge_is_not_a_boundary() {
+        // Interior touching the left edge: only its right side is a
+        // transition.
+        assert_eq!(render(&["##..", "##.."]), [".#o.", ".#o."]);
+    }
+
+    #[test]
+    fn field_must_fill_the_pixel_rect() {
+        let result = render_boundary_only(vec![0; 5], pixel_rect(2, 2), MAX, &SideColours);
+
+        assert!(matches!(
+            result,
+            Err(GeneratePixelBufferError::PixelBuffer(PixelBufferError::BoundsMismatch { .. }))
+        ));
+    }
+}
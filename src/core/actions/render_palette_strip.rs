@@ -0,0 +1,151 @@
+use crate::core::actions::generate_pixel_buffer::generate_pixel_buffer::{
+    GeneratePixelBufferError, generate_pixel_buffer_counts,
+};
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::ColourMap;
+use crate::core::data::pixel_buffer::PixelBuffer;
+use crate::core::data::pixel_rect::PixelRect;
+use crate::core::data::point::Point;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum RenderPaletteStripError {
+    ZeroMaxIterations,
+    InvalidSize { width: u32, height: u32 },
+    PixelBuffer(GeneratePixelBufferError),
+}
+
+impl fmt::Display for RenderPaletteStripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroMaxIterations => write!(f, "max iterations must be greater than zero"),
+            Self::InvalidSize { width, height } => {
+                write!(f, "palette strip size is invalid: {}x{}", width, height)
+            }
+            Self::PixelBuffer(e) => write!(f, "pixel buffer error: {}", e),
+        }
+    }
+}
+
+impl Error for RenderPaletteStripError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::PixelBuffer(e) => Some(e),
+            Self::ZeroMaxIterations | Self::InvalidSize { .. } => None,
+        }
+    }
+}
+
+/// Renders `colour_map` as a `width`x`height` strip for documentation.
+///
+/// Columns sample the iteration range `0..max_iterations` evenly from left
+/// to right: the leftmost column is `map(0)` and the rightmost is
+/// `map(max_iterations - 1)`. Every row is identical.
+pub fn render_palette_strip<CMap: ColourMap<u32> + ?Sized>(
+    colour_map: &CMap,
+    max_iterations: u32,
+    width: u32,
+    height: u32,
+) -> Result<PixelBuffer, RenderPaletteStripError> {
+    if max_iterations == 0 {
+        return Err(RenderPaletteStripError::ZeroMaxIterations);
+    }
+
+    let invalid_size = RenderPaletteStripError::InvalidSize { width, height };
+    let (Ok(right), Ok(bottom)) = (
+        i32::try_from(width.wrapping_sub(1)),
+        i32::try_from(height.wrapping_sub(1)),
+    ) else {
+        return Err(invalid_size);
+    };
+    let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: right, y: bottom })
+        .map_err(|_| invalid_size)?;
+
+    let row: Vec<u32> = (0..u64::from(width))
+        .map(|x| column_iteration(x, u64::from(width), max_iterations))
+        .collect();
+    let iterations = row.repeat(height as usize);
+
+    generate_pixel_buffer_counts(iterations, colour_map, pixel_rect)
+        .map_err(RenderPaletteStripError::PixelBuffer)
+}
+
+fn column_iteration(x: u64, width: u64, max_iterations: u32) -> u32 {
+    if width <= 1 {
+        return 0;
+    }
+
+    (x * u64::from(max_iterations - 1) / (width - 1)) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fractals::mandelbrot::colour_mapping::maps::fire::MandelbrotFireColourMap;
+
+    fn column_rgba(buffer: &PixelBuffer, x: usize) -> Vec<[u8; 4]> {
+        let width = buffer.pixel_rect().width() as usize;
+
+        buffer
+            .buffer()
+            .chunks_exact(PixelBuffer::BYTES_PER_PIXEL)
+            .skip(x)
+            .step_by(width)
+            .map(|pixel| [pixel[0], pixel[1], pixel[2], pixel[3]])
+            .collect()
+    }
+
+    fn rgba(map: &MandelbrotFireColourMap, value: u32) -> [u8; 4] {
+        let colour = map.map(value).unwrap();
+        [colour.r, colour.g, colour.b, PixelBuffer::ALPHA_OPAQUE]
+    }
+
+    #[test]
+    fn strip_spans_the_iteration_range() {
+        let max_iterations = 256;
+        let map = MandelbrotFireColourMap::new(max_iterations);
+
+        let strip = render_palette_strip(&map, max_iterations, 256, 20).unwrap();
+
+        assert_eq!(strip.pixel_rect().width(), 256);
+        assert_eq!(strip.pixel_rect().height(), 20);
+        assert!(column_rgba(&strip, 0).iter().all(|&p| p == rgba(&map, 0)));
+        assert!(
+            column_rgba(&strip, 255)
+                .iter()
+                .all(|&p| p == rgba(&map, max_iterations - 1))
+        );
+    }
+
+    #[test]
+    fn narrow_strip_still_ends_on_the_last_iteration() {
+        let max_iterations = 1000;
+        let map = MandelbrotFireColourMap::new(max_iterations);
+
+        let strip = render_palette_strip(&map, max_iterations, 7, 2).unwrap();
+
+        assert!(column_rgba(&strip, 0).iter().all(|&p| p == rgba(&map, 0)));
+        assert!(
+            column_rgba(&strip, 6)
+                .iter()
+                .all(|&p| p == rgba(&map, max_iterations - 1))
+        );
+    }
+
+    #[test]
+    fn rejects_zero_size_and_zero_iterations() {
+        let map = MandelbrotFireColourMap::new(16);
+
+        assert!(matches!(
+            render_palette_strip(&map, 16, 0, 20),
+            Err(RenderPaletteStripError::InvalidSize {
+                width: 0,
+                height: 20
+            })
+        ));
+        assert!(matches!(
+            render_palette_strip(&map, 0, 256, 20),
+            Err(RenderPaletteStripError::ZeroMaxIterations)
+        ));
+    }
+}
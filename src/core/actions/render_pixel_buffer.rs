@@ -120,6 +120,11 @@ where
     let x_start = pixel_rect.top_left().x;
     let x_end = pixel_rect.bottom_right().x;
     let top_y = pixel_rect.top_left().y;
+    let mirror = |y: i32| {
+        algorithm
+            .mirror_row(y)
+            .filter(|m| (top_y..=pixel_rect.bottom_right().y).contains(m))
+    };
 
     let mut buffer: PixelBufferData =
         vec![0u8; width * pixel_rect.height() as usize * PixelBuffer::BYTES_PER_PIXEL];
@@ -134,6 +139,9 @@ where
                 }
 
                 let y = top_y + row_idx as i32;
+                if mirror(y).is_some() {
+                    return Ok(());
+                }
                 let mut iters = Vec::with_capacity(width);
                 algorithm
                     .compute_row_segment_into(y, x_start, x_end, &mut iters)
@@ -153,6 +161,13 @@ where
             },
         )?;
 
+    for (row_idx, y) in (top_y..=pixel_rect.bottom_right().y).enumerate() {
+        if let Some(m) = mirror(y) {
+            let source = (m - top_y) as usize * row_bytes;
+            buffer.copy_within(source..source + row_bytes, row_idx * row_bytes);
+        }
+    }
+
     PixelBuffer::from_data_opaque(pixel_rect, buffer)
         .map_err(RenderPixelBufferCancelableError::PixelBuffer)
 }
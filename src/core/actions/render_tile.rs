@@ -0,0 +1,276 @@
+use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{ColourMap, ColourMapError};
+use crate::core::actions::render_pixel_buffer::{
+    render_pixel_buffer_parallel_rayon, RenderPixelBufferError,
+};
+use crate::core::data::complex_rect::ComplexRect;
+use crate::core::data::pixel_buffer::{PixelBuffer, PixelBufferError};
+use crate::core::data::pixel_rect::PixelRect;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TileRequestError {
+    TileOutsideLogicalRect {
+        tile_rect: PixelRect,
+        logical_rect: PixelRect,
+    },
+}
+
+impl fmt::Display for TileRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TileOutsideLogicalRect {
+                tile_rect,
+                logical_rect,
+            } => write!(
+                f,
+                "tile (x: {}, y: {})-(x: {}, y: {}) is outside the logical image (x: {}, y: {})-(x: {}, y: {})",
+                tile_rect.top_left().x,
+                tile_rect.top_left().y,
+                tile_rect.bottom_right().x,
+                tile_rect.bottom_right().y,
+                logical_rect.top_left().x,
+                logical_rect.top_left().y,
+                logical_rect.bottom_right().x,
+                logical_rect.bottom_right().y
+            ),
+        }
+    }
+}
+
+impl Error for TileRequestError {}
+
+/// One tile of a larger logical image.
+///
+/// `region` spans the whole of `logical_rect`; only the pixels in
+/// `tile_rect` are rendered, each at the complex coordinate it would have in
+/// a full render. This lets a `W`x`H` image be split across workers or
+/// machines and reassembled exactly.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TileRequest {
+    logical_rect: PixelRect,
+    tile_rect: PixelRect,
+    region: ComplexRect,
+    max_iterations: u32,
+}
+
+impl TileRequest {
+    pub fn new(
+        logical_rect: PixelRect,
+        tile_rect: PixelRect,
+        region: ComplexRect,
+        max_iterations: u32,
+    ) -> Result<Self, TileRequestError> {
+        if !logical_rect.contains_point(tile_rect.top_left())
+            || !logical_rect.contains_point(tile_rect.bottom_right())
+        {
+            return Err(TileRequestError::TileOutsideLogicalRect {
+                tile_rect,
+                logical_rect,
+            });
+        }
+
+        Ok(Self {
+            logical_rect,
+            tile_rect,
+            region,
+            max_iterations,
+        })
+    }
+
+    #[must_use]
+    pub fn logical_rect(&self) -> PixelRect {
+        self.logical_rect
+    }
+
+    #[must_use]
+    pub fn tile_rect(&self) -> PixelRect {
+        self.tile_rect
+    }
+
+    #[must_use]
+    pub fn region(&self) -> ComplexRect {
+        self.region
+    }
+
+    #[must_use]
+    pub fn max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
+}
+
+#[derive(Debug)]
+pub enum RenderTileError<BuildErr, AlgErr> {
+    Build(BuildErr),
+    Algorithm(AlgErr),
+    ColourMap(ColourMapError),
+    PixelBuffer(PixelBufferError),
+}
+
+impl<B: fmt::Display, E: fmt::Display> fmt::Display for RenderTileError<B, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Build(e) => write!(f, "algorithm build error: {}", e),
+            Self::Algorithm(e) => write!(f, "algorithm error: {}", e),
+            Self::ColourMap(e) => write!(f, "colour map error: {}", e),
+            Self::PixelBuffer(e) => write!(f, "pixel buffer error: {}", e),
+        }
+    }
+}
+
+impl<B: Error + 'static, E: Error + 'static> Error for RenderTileError<B, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Build(e) => Some(e),
+            Self::Algorithm(e) => Some(e),
+            Self::ColourMap(e) => Some(e.as_ref()),
+            Self::PixelBuffer(e) => Some(e),
+        }
+    }
+}
+
+/// Renders the tile described by `request`.
+///
+/// `build_algorithm` receives the logical rect, region and iteration budget,
+/// so constructors such as `MandelbrotAlgorithm::new` can be passed
+/// directly. The returned buffer's `pixel_rect` is the tile rect, in the
+/// logical image's pixel coordinates.
+pub fn render_tile<Alg, CMap, F, BuildErr>(
+    request: &TileRequest,
+    build_algorithm: F,
+    colour_map: &CMap,
+) -> Result<PixelBuffer, RenderTileError<BuildErr, Alg::Failure>>
+where
+    Alg: FractalAlgorithm<Success = u32> + Sync,
+    Alg::Failure: Send,
+    CMap: ColourMap<u32> + ?Sized,
+    F: FnOnce(PixelRect, ComplexRect, u32) -> Result<Alg, BuildErr>,
+{
+    let algorithm = build_algorithm(request.logical_rect, request.region, request.max_iterations)
+        .map_err(RenderTileError::Build)?;
+
+    render_pixel_buffer_parallel_rayon(request.tile_rect, &algorithm, colour_map).map_err(|e| {
+        match e {
+            RenderPixelBufferError::Algorithm(e) => RenderTileError::Algorithm(e),
+            RenderPixelBufferError::ColourMap(e) => RenderTileError::ColourMap(e),
+            RenderPixelBufferError::PixelBuffer(e) => RenderTileError::PixelBuffer(e),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::complex::Complex;
+    use crate::core::data::point::Point;
+    use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
+    use crate::core::fractals::mandelbrot::colour_mapping::maps::fire::MandelbrotFireColourMap;
+    use crate::core::fractals::mandelbrot::errors::mandelbrot::MandelbrotError;
+
+    fn rect(x0: i32, y0: i32, x1: i32, y1: i32) -> PixelRect {
+        PixelRect::new(Point { x: x0, y: y0 }, Point { x: x1, y: y1 }).unwrap()
+    }
+
+    fn region() -> ComplexRect {
+        ComplexRect::new(
+            Complex {
+                real: -2.5,
+                imag: -1.0,
+            },
+            Complex {
+                real: 1.0,
+                imag: 1.0,
+            },
+        )
+        .unwrap()
+    }
+
+    /// Copies `tile` into a full-image RGBA buffer at its own pixel rect.
+    fn blit(image: &mut [u8], image_width: u32, tile: &PixelBuffer) {
+        let tile_rect = tile.pixel_rect();
+        let row_bytes = tile_rect.width() as usize * PixelBuffer::BYTES_PER_PIXEL;
+
+        for (row_idx, row) in tile.buffer().chunks_exact(row_bytes).enumerate() {
+            let y = tile_rect.top_left().y as usize + row_idx;
+            let start = (y * image_width as usize + tile_rect.top_left().x as usize)
+                * PixelBuffer::BYTES_PER_PIXEL;
+            image[start..start + row_bytes].copy_from_slice(row);
+        }
+    }
+
+    #[test]
+    fn rejects_tile_outside_logical_rect() {
+        let logical_rect = rect(0, 0, 9, 9);
+        let tile_rect = rect(5, 5, 10, 9);
+
+        assert_eq!(
+            TileRequest::new(logical_rect, tile_rect, region(), 10),
+            Err(TileRequestError::TileOutsideLogicalRect {
+                tile_rect,
+                logical_rect
+            })
+        );
+    }
+
+    #[test]
+    fn tile_buffer_covers_only_the_tile_rect() {
+        let tile_rect = rect(4, 2, 7, 3);
+        let request = TileRequest::new(rect(0, 0, 9, 9), tile_rect, region(), 10).unwrap();
+
+        let tile = render_tile(&request, MandelbrotAlgorithm::new, &MandelbrotFireColourMap::new(10))
+            .unwrap();
+
+        assert_eq!(tile.pixel_rect(), tile_rect);
+        assert_eq!(tile.buffer().len(), 4 * 2 * PixelBuffer::BYTES_PER_PIXEL);
+    }
+
+    #[test]
+    fn propagates_algorithm_build_error() {
+        let request = TileRequest::new(rect(0, 0, 9, 9), rect(0, 0, 4, 4), region(), 0).unwrap();
+
+        let result = render_tile(&request, MandelbrotAlgorithm::new, &MandelbrotFireColourMap::new(1));
+
+        assert!(matches!(
+            result,
+            Err(RenderTileError::Build(MandelbrotError::ZeroMaxIterationsError))
+        ));
+    }
+
+    #[test]
+    fn two_by_two_tiling_reassembles_into_the_full_render() {
+        let (width, height) = (33, 25);
+        let max_iterations = 100;
+        let logical_rect = rect(0, 0, width - 1, height - 1);
+        let colour_map = MandelbrotFireColourMap::new(max_iterations);
+
+        let full_algorithm = MandelbrotAlgorithm::new(logical_rect, region(), max_iterations).unwrap();
+        let full = render_pixel_buffer_parallel_rayon(logical_rect, &full_algorithm, &colour_map)
+            .unwrap();
+
+        // Uneven split so tiles differ in size.
+        let (split_x, split_y) = (13, 9);
+        let tiles = [
+            rect(0, 0, split_x - 1, split_y - 1),
+            rect(split_x, 0, width - 1, split_y - 1),
+            rect(0, split_y, split_x - 1, height - 1),
+            rect(split_x, split_y, width - 1, height - 1),
+        ];
+
+        let mut image = vec![0u8; full.buffer().len()];
+        for tile_rect in tiles {
+            let request = TileRequest::new(logical_rect, tile_rect, region(), max_iterations).unwrap();
+            let tile = render_tile(&request, MandelbrotAlgorithm::new, &colour_map).unwrap();
+            blit(&mut image, width as u32, &tile);
+        }
+
+        assert_eq!(&image, full.buffer());
+    }
+
+    #[test]
+    fn error_displays_colour_map_error() {
+        let err: RenderTileError<MandelbrotError, MandelbrotError> =
+            RenderTileError::ColourMap("bad map".into());
+        assert_eq!(format!("{}", err), "colour map error: bad map");
+    }
+}
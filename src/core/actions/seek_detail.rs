@@ -0,0 +1,237 @@
+use crate::core::data::pixel_rect::PixelRect;
+use crate::core::data::point::Point;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SeekDetailError {
+    FieldSizeMismatch { expected: u64, actual: usize },
+}
+
+impl fmt::Display for SeekDetailError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FieldSizeMismatch { expected, actual } => write!(
+                f,
+                "iteration field has {actual} values but the pixel rect holds {expected}"
+            ),
+        }
+    }
+}
+
+impl Error for SeekDetailError {}
+
+/// Suggests a new view centre near the current one where the row-major
+/// iteration `field` covering `pixel_rect` shows the most detail, measured
+/// as the variance of the counts in a square window around each pixel.
+///
+/// Gradient ascent from the centre pixel: at each window size, from a
+/// quarter of the shorter side down to a sixteenth, the point moves a
+/// window's radius at a time to whichever of its eight neighbours has the
+/// higher variance, until none does. The coarse windows see detail from
+/// further away, so the climb starts moving even in a flat area; the fine
+/// ones settle it on the busiest spot. The result is the local maximum
+/// reached, not the busiest spot in the whole field, and is the centre
+/// pixel itself when the field is flat.
+pub fn suggest_detail_centre(field: &[u32], pixel_rect: PixelRect) -> Result<Point, SeekDetailError> {
+    if field.len() as u64 != pixel_rect.size() {
+        return Err(SeekDetailError::FieldSizeMismatch {
+            expected: pixel_rect.size(),
+            actual: field.len(),
+        });
+    }
+
+    let width = pixel_rect.width() as usize;
+    let height = pixel_rect.height() as usize;
+    let variance = LocalVariance::new(field, width, height);
+
+    let shorter = width.min(height);
+    let finest = (shorter / 16).max(1);
+    let mut radius = (shorter / 4).max(1);
+    let mut position = (width / 2, height / 2);
+
+    loop {
+        position = climb(&variance, position, radius);
+        if radius <= finest {
+            break;
+        }
+        radius = (radius / 2).max(finest);
+    }
+
+    Ok(Point {
+        x: pixel_rect.top_left().x + position.0 as i32,
+        y: pixel_rect.top_left().y + position.1 as i32,
+    })
+}
+
+/// Moves from `start` in steps of `radius` to the neighbour with the
+/// highest variance at that radius until no neighbour beats the current
+/// point. Variance strictly rises with each step, so this terminates.
+fn climb(variance: &LocalVariance, start: (usize, usize), radius: usize) -> (usize, usize) {
+    let mut position = start;
+    let mut best = variance.at(position, radius);
+
+    loop {
+        let mut next = None;
+        for (dx, dy) in [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)] {
+            let Some(candidate) = variance.step(position, dx, dy, radius) else {
+                continue;
+            };
+            let candidate_variance = variance.at(candidate, radius);
+            if candidate_variance > best {
+                best = candidate_variance;
+                next = Some(candidate);
+            }
+        }
+
+        match next {
+            Some(candidate) => position = candidate,
+            None => return position,
+        }
+    }
+}
+
+/// Summed-area tables of the counts and their squares, so the variance of
+/// any window is a handful of lookups.
+struct LocalVariance {
+    width: usize,
+    height: usize,
+    sums: Vec<f64>,
+    squares: Vec<f64>,
+}
+
+impl LocalVariance {
+    fn new(field: &[u32], width: usize, height: usize) -> Self {
+        let stride = width + 1;
+        let mut sums = vec![0.0; stride * (height + 1)];
+        let mut squares = vec![0.0; stride * (height + 1)];
+
+        for y in 0..height {
+            for x in 0..width {
+                let value = f64::from(field[y * width + x]);
+                let index = (y + 1) * stride + x + 1;
+                sums[index] = value + sums[index - 1] + sums[index - stride] - sums[index - stride - 1];
+                squares[index] =
+                    value * value + squares[index - 1] + squares[index - stride] - squares[index - stride - 1];
+            }
+        }
+
+        Self {
+            width,
+            height,
+            sums,
+            squares,
+        }
+    }
+
+    /// Variance of the counts within `radius` of `(x, y)`, clipped to the
+    /// field.
+    fn at(&self, (x, y): (usize, usize), radius: usize) -> f64 {
+        let x0 = x.saturating_sub(radius);
+        let y0 = y.saturating_sub(radius);
+        let x1 = (x + radius + 1).min(self.width);
+        let y1 = (y + radius + 1).min(self.height);
+
+        let area = |table: &[f64]| {
+            let stride = self.width + 1;
+            table[y1 * stride + x1] - table[y0 * stride + x1] - table[y1 * stride + x0] + table[y0 * stride + x0]
+        };
+
+        let count = ((x1 - x0) * (y1 - y0)) as f64;
+        let mean = area(&self.sums) / count;
+
+        (area(&self.squares) / count - mean * mean).max(0.0)
+    }
+
+    /// `position` moved `radius` pixels in direction `(dx, dy)`, or `None`
+    /// if that leaves the field.
+    fn step(&self, (x, y): (usize, usize), dx: isize, dy: isize, radius: usize) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx * radius as isize)?;
+        let y = y.checked_add_signed(dy * radius as isize)?;
+
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDTH: i32 = 96;
+    const HEIGHT: i32 = 64;
+
+    fn pixel_rect() -> PixelRect {
+        PixelRect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: WIDTH - 1,
+                y: HEIGHT - 1,
+            },
+        )
+        .unwrap()
+    }
+
+    /// A flat field of 10s with a checkerboard of 0s and 200s, the most
+    /// varied counts possible, filling the square of `size` centred at
+    /// `centre`.
+    fn field_with_patch(centre: (i32, i32), size: i32) -> Vec<u32> {
+        let half = size / 2;
+
+        (0..HEIGHT)
+            .flat_map(|y| (0..WIDTH).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let in_patch = (x - centre.0).abs() <= half && (y - centre.1).abs() <= half;
+                match (in_patch, (x + y) % 2 == 0) {
+                    (false, _) => 10,
+                    (true, true) => 200,
+                    (true, false) => 0,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn suggestion_lands_on_a_nearby_patch_of_detail() {
+        let patch = (70, 20);
+        let centre = suggest_detail_centre(&field_with_patch(patch, 8), pixel_rect()).unwrap();
+
+        assert!(
+            (centre.x - patch.0).abs() <= 4 && (centre.y - patch.1).abs() <= 4,
+            "suggested {centre:?}, patch at {patch:?}"
+        );
+    }
+
+    #[test]
+    fn suggestion_points_toward_the_detail() {
+        for patch in [(15, 32), (80, 32), (48, 8), (48, 56)] {
+            let centre = suggest_detail_centre(&field_with_patch(patch, 6), pixel_rect()).unwrap();
+            let moved = (centre.x - WIDTH / 2, centre.y - HEIGHT / 2);
+            let towards = (patch.0 - WIDTH / 2, patch.1 - HEIGHT / 2);
+
+            assert!(
+                moved.0 * towards.0 + moved.1 * towards.1 > 0,
+                "moved {moved:?} for a patch at {patch:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn flat_field_keeps_the_centre() {
+        let rect = PixelRect::new(Point { x: 100, y: 50 }, Point { x: 139, y: 79 }).unwrap();
+
+        let centre = suggest_detail_centre(&vec![7; rect.size() as usize], rect).unwrap();
+
+        assert_eq!(centre, Point { x: 120, y: 65 });
+    }
+
+    #[test]
+    fn field_must_fill_the_pixel_rect() {
+        assert_eq!(
+            suggest_detail_centre(&[0; 5], pixel_rect()),
+            Err(SeekDetailError::FieldSizeMismatch {
+                expected: (WIDTH * HEIGHT) as u64,
+                actual: 5,
+            })
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{ColourMap, ColourMapError};
+use crate::core::data::colour::Colour;
+
+/// Colours continuous escape counts with an iteration colour map by
+/// blending the colours of the whole counts either side.
+///
+/// A count of `n + f` gets the inner map's colour for `n` moved `f` of the
+/// way towards its colour for `n + 1`, never past the last escaped count
+/// `max_iterations - 1`. Counts of `max_iterations` and above are the
+/// interior and take the inner map's interior colour unblended.
+#[derive(Debug)]
+pub struct SmoothColourMap<'a, CMap: ?Sized> {
+    inner: &'a CMap,
+    max_iterations: u32,
+}
+
+impl<'a, CMap: ColourMap<u32> + ?Sized> SmoothColourMap<'a, CMap> {
+    #[must_use]
+    pub fn new(inner: &'a CMap, max_iterations: u32) -> Self {
+        Self {
+            inner,
+            max_iterations,
+        }
+    }
+}
+
+impl<CMap: ColourMap<u32> + ?Sized> ColourMap<f64> for SmoothColourMap<'_, CMap> {
+    fn map(&self, count: f64) -> Result<Colour, ColourMapError> {
+        if count >= f64::from(self.max_iterations) {
+            return self.inner.map(self.max_iterations);
+        }
+
+        let count = count.max(0.0);
+        let whole = count.floor() as u32;
+        let next = (whole + 1).min(self.max_iterations.saturating_sub(1));
+
+        Ok(self.inner.map(whole)?.lerp(self.inner.map(next)?, count.fract()))
+    }
+
+    fn display_name(&self) -> &str {
+        self.inner.display_name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX_ITERATIONS: u32 = 10;
+
+    /// Grey ramp: count `n` is level `n * 20`, the interior is red.
+    struct RampColourMap;
+
+    impl ColourMap<u32> for RampColourMap {
+        fn map(&self, iterations: u32) -> Result<Colour, ColourMapError> {
+            if iterations >= MAX_ITERATIONS {
+                return Ok(Colour { r: 255, g: 0, b: 0 });
+            }
+            let level = (iterations * 20) as u8;
+            Ok(Colour { r: level, g: level, b: level })
+        }
+
+        fn display_name(&self) -> &str {
+            "Ramp"
+        }
+    }
+
+    fn red(map: &SmoothColourMap<'_, RampColourMap>, count: f64) -> u8 {
+        map.map(count).unwrap().r
+    }
+
+    #[test]
+    fn blends_between_neighbouring_counts() {
+        let map = SmoothColourMap::new(&RampColourMap, MAX_ITERATIONS);
+
+        assert_eq!(red(&map, 3.0), 60);
+        assert_eq!(red(&map, 3.5), 70);
+        assert_eq!(red(&map, 3.75), 75);
+    }
+
+    #[test]
+    fn last_escaped_count_does_not_blend_into_the_interior() {
+        let map = SmoothColourMap::new(&RampColourMap, MAX_ITERATIONS);
+
+        let last = map.map(9.9).unwrap();
+        let interior = map.map(10.0).unwrap();
+
+        assert_eq!((last.r, last.g, last.b), (180, 180, 180));
+        assert_eq!((interior.r, interior.g, interior.b), (255, 0, 0));
+    }
+}
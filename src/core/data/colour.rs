@@ -4,3 +4,129 @@ pub struct Colour {
     pub g: u8,
     pub b: u8,
 }
+
+impl Colour {
+    /// The colour `t` of the way from `self` to `other`, per channel and
+    /// rounded to the nearest value. `t` is clamped to `[0, 1]`.
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |from: u8, to: u8| (f64::from(from) + (f64::from(to) - f64::from(from)) * t).round() as u8;
+
+        Self {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+        }
+    }
+
+    /// Converts hue (degrees, wrapped into `[0, 360)`), saturation and value
+    /// (both clamped to `[0, 1]`) to RGB, rounding each channel.
+    #[must_use]
+    pub fn from_hsv(hue_degrees: f64, saturation: f64, value: f64) -> Self {
+        let hue = hue_degrees.rem_euclid(360.0) / 60.0;
+        let saturation = saturation.clamp(0.0, 1.0);
+        let value = value.clamp(0.0, 1.0);
+
+        let chroma = value * saturation;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let offset = value - chroma;
+        let channel = |c: f64| ((c + offset) * 255.0).round() as u8;
+
+        Self {
+            r: channel(r),
+            g: channel(g),
+            b: channel(b),
+        }
+    }
+}
+
+/// A [`Colour`] with a real alpha channel, for colour maps that leave part
+/// of the image transparent to composite over something else. Alpha is
+/// straight (not premultiplied); 0 is fully transparent.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ColourRgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl ColourRgba {
+    pub const TRANSPARENT: Self = Self {
+        r: 0,
+        g: 0,
+        b: 0,
+        a: 0,
+    };
+}
+
+impl From<Colour> for ColourRgba {
+    fn from(Colour { r, g, b }: Colour) -> Self {
+        Self { r, g, b, a: 255 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channels(colour: Colour) -> (u8, u8, u8) {
+        (colour.r, colour.g, colour.b)
+    }
+
+    #[test]
+    fn lerp_runs_from_self_to_other() {
+        let from = Colour { r: 0, g: 100, b: 255 };
+        let to = Colour { r: 255, g: 0, b: 255 };
+
+        assert_eq!(channels(from.lerp(to, 0.0)), (0, 100, 255));
+        assert_eq!(channels(from.lerp(to, 0.5)), (128, 50, 255));
+        assert_eq!(channels(from.lerp(to, 1.0)), (255, 0, 255));
+    }
+
+    #[test]
+    fn lerp_clamps_t() {
+        let from = Colour { r: 10, g: 20, b: 30 };
+        let to = Colour { r: 40, g: 50, b: 60 };
+
+        assert_eq!(channels(from.lerp(to, -1.0)), (10, 20, 30));
+        assert_eq!(channels(from.lerp(to, 2.0)), (40, 50, 60));
+    }
+
+    #[test]
+    fn from_hsv_gives_the_primaries() {
+        assert_eq!(channels(Colour::from_hsv(0.0, 1.0, 1.0)), (255, 0, 0));
+        assert_eq!(channels(Colour::from_hsv(120.0, 1.0, 1.0)), (0, 255, 0));
+        assert_eq!(channels(Colour::from_hsv(240.0, 1.0, 1.0)), (0, 0, 255));
+    }
+
+    #[test]
+    fn from_hsv_gives_the_secondaries() {
+        assert_eq!(channels(Colour::from_hsv(60.0, 1.0, 1.0)), (255, 255, 0));
+        assert_eq!(channels(Colour::from_hsv(180.0, 1.0, 1.0)), (0, 255, 255));
+        assert_eq!(channels(Colour::from_hsv(300.0, 1.0, 1.0)), (255, 0, 255));
+    }
+
+    #[test]
+    fn from_hsv_wraps_hue() {
+        assert_eq!(channels(Colour::from_hsv(360.0, 1.0, 1.0)), (255, 0, 0));
+        assert_eq!(channels(Colour::from_hsv(-120.0, 1.0, 1.0)), (0, 0, 255));
+    }
+
+    #[test]
+    fn from_hsv_saturation_and_value_scale_the_channels() {
+        assert_eq!(channels(Colour::from_hsv(0.0, 0.0, 1.0)), (255, 255, 255));
+        assert_eq!(channels(Colour::from_hsv(0.0, 0.5, 1.0)), (255, 128, 128));
+        assert_eq!(channels(Colour::from_hsv(120.0, 1.0, 0.5)), (0, 128, 0));
+        assert_eq!(channels(Colour::from_hsv(240.0, 1.0, 0.0)), (0, 0, 0));
+    }
+}
@@ -1,5 +1,10 @@
+use std::fmt;
 use std::ops::{Add, Mul};
 
+/// Decimal places used by `Complex`'s `Display` when the formatter gives no
+/// precision.
+pub const DEFAULT_DISPLAY_PRECISION: usize = 6;
+
 // implement Complex instead of using the num-complex trait for learning
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Complex {
@@ -12,6 +17,50 @@ impl Complex {
     pub fn magnitude_squared(&self) -> f64 {
         self.real * self.real + self.imag * self.imag
     }
+
+    /// The complex conjugate, `a - bi` for `a + bi`.
+    #[must_use]
+    pub fn conjugate(&self) -> Self {
+        Self {
+            real: self.real,
+            imag: -self.imag,
+        }
+    }
+
+    /// `self` raised to a whole `power` by repeated multiplication; `powi(0)`
+    /// is one.
+    #[must_use]
+    pub fn powi(&self, power: u32) -> Self {
+        let mut result = Self {
+            real: 1.0,
+            imag: 0.0,
+        };
+        for _ in 0..power {
+            result = result * *self;
+        }
+
+        result
+    }
+}
+
+/// Formats as `a + bi` or `a - bi`, with `DEFAULT_DISPLAY_PRECISION`
+/// decimal places unless the formatter specifies one (`{:.3}`). A negative
+/// zero imaginary part is shown as `+ 0`.
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(DEFAULT_DISPLAY_PRECISION);
+        let sign = if self.imag < 0.0 { '-' } else { '+' };
+
+        write!(
+            f,
+            "{:.*} {} {:.*}i",
+            precision,
+            self.real,
+            sign,
+            precision,
+            self.imag.abs()
+        )
+    }
 }
 
 impl Add for Complex {
@@ -40,6 +89,41 @@ impl Mul for Complex {
 mod tests {
     use super::*;
 
+    #[test]
+    fn conjugate_negates_the_imaginary_part() {
+        let c = Complex {
+            real: 3.0,
+            imag: 4.0,
+        };
+
+        assert_eq!(
+            c.conjugate(),
+            Complex {
+                real: 3.0,
+                imag: -4.0,
+            }
+        );
+        assert_eq!(c.conjugate().conjugate(), c);
+    }
+
+    #[test]
+    fn powi_multiplies_out_whole_powers() {
+        let i = Complex {
+            real: 0.0,
+            imag: 1.0,
+        };
+        let c = Complex {
+            real: 2.0,
+            imag: 3.0,
+        };
+
+        assert_eq!(c.powi(0), Complex { real: 1.0, imag: 0.0 });
+        assert_eq!(c.powi(1), c);
+        assert_eq!(c.powi(2), c * c);
+        assert_eq!(i.powi(3), Complex { real: 0.0, imag: -1.0 });
+        assert_eq!(i.powi(4), Complex { real: 1.0, imag: 0.0 });
+    }
+
     #[test]
     fn test_magnitude_squared() {
         let c = Complex {
@@ -173,4 +257,40 @@ mod tests {
         assert_eq!(result.real, -5.0);
         assert_eq!(result.imag, 12.0);
     }
+
+    #[test]
+    fn test_display_positive_imag() {
+        let c = Complex {
+            real: -0.75,
+            imag: 0.1,
+        };
+        assert_eq!(c.to_string(), "-0.750000 + 0.100000i");
+        assert_eq!(format!("{:.2}", c), "-0.75 + 0.10i");
+    }
+
+    #[test]
+    fn test_display_negative_imag() {
+        let c = Complex {
+            real: 0.375,
+            imag: -1.5,
+        };
+        assert_eq!(c.to_string(), "0.375000 - 1.500000i");
+        assert_eq!(format!("{:.1}", c), "0.4 - 1.5i");
+    }
+
+    #[test]
+    fn test_display_zero_imag() {
+        let c = Complex {
+            real: 2.0,
+            imag: 0.0,
+        };
+        assert_eq!(c.to_string(), "2.000000 + 0.000000i");
+        assert_eq!(format!("{:.0}", c), "2 + 0i");
+
+        let negative_zero = Complex {
+            real: 2.0,
+            imag: -0.0,
+        };
+        assert_eq!(format!("{:.3}", negative_zero), "2.000 + 0.000i");
+    }
 }
@@ -44,6 +44,29 @@ impl ComplexRect {
         })
     }
 
+    /// The `width`x`height` rect centred on `centre`. Both extents must be
+    /// positive and finite.
+    pub fn from_centre_size(
+        centre: Complex,
+        width: f64,
+        height: f64,
+    ) -> Result<Self, ComplexRectError> {
+        if !(width.is_finite() && height.is_finite()) {
+            return Err(ComplexRectError::InvalidSize { width, height });
+        }
+
+        Self::new(
+            Complex {
+                real: centre.real - width / 2.0,
+                imag: centre.imag - height / 2.0,
+            },
+            Complex {
+                real: centre.real + width / 2.0,
+                imag: centre.imag + height / 2.0,
+            },
+        )
+    }
+
     #[must_use]
     pub fn top_left(&self) -> Complex {
         self.top_left
@@ -65,6 +88,9 @@ impl ComplexRect {
         self.bottom_right.imag - self.top_left.imag
     }
 
+    /// True if `point` lies inside the rect or on any of its edges
+    /// (`[top_left, bottom_right]`). Points on a border shared by two
+    /// adjacent rects are contained by both.
     #[allow(dead_code)]
     #[must_use]
     pub fn contains_point(&self, point: Complex) -> bool {
@@ -74,6 +100,68 @@ impl ComplexRect {
             && self.bottom_right.imag >= point.imag
     }
 
+    /// Half-open containment (`[top_left, bottom_right)`): the top-left
+    /// edges are included and the bottom-right edges are not. Rects that
+    /// tile a region then claim every point exactly once.
+    #[allow(dead_code)]
+    #[must_use]
+    pub fn contains_point_half_open(&self, point: Complex) -> bool {
+        self.top_left.real <= point.real
+            && self.top_left.imag <= point.imag
+            && point.real < self.bottom_right.real
+            && point.imag < self.bottom_right.imag
+    }
+
+    /// The region for a view resized from `old_pixels` to `new_pixels`
+    /// that keeps the complex coordinate under `focal_pixel` where it was.
+    ///
+    /// The rect is first widened along one axis to the new aspect ratio,
+    /// as `region_from_centre_zoom` fits a viewport, so all of it stays
+    /// visible without stretching. `focal_pixel` is in continuous window
+    /// coordinates, pixel `(x, y)` covering `[x, x + 1)`, with imaginary
+    /// growing downward as for `YAxis::ScreenDown`; it is the same window
+    /// position before and after the resize. An empty old or new size, or
+    /// a focal pixel that is not finite, leaves the rect as it is.
+    pub fn scale_to_fit_pixels(
+        &self,
+        old_pixels: (u32, u32),
+        new_pixels: (u32, u32),
+        focal_pixel: (f64, f64),
+    ) -> Result<Self, ComplexRectError> {
+        let (old_pixel_width, old_pixel_height) = old_pixels;
+        let (new_pixel_width, new_pixel_height) = new_pixels;
+        let (focal_x, focal_y) = focal_pixel;
+        if old_pixel_width == 0
+            || old_pixel_height == 0
+            || new_pixel_width == 0
+            || new_pixel_height == 0
+            || !(focal_x.is_finite() && focal_y.is_finite())
+        {
+            return Ok(*self);
+        }
+
+        let focal = Complex {
+            real: self.top_left.real + focal_x / f64::from(old_pixel_width) * self.width(),
+            imag: self.top_left.imag + focal_y / f64::from(old_pixel_height) * self.height(),
+        };
+
+        let mut width = self.width();
+        let mut height = self.height();
+        let aspect = f64::from(new_pixel_width) / f64::from(new_pixel_height);
+        if width / height < aspect {
+            width = height * aspect;
+        } else {
+            height = width / aspect;
+        }
+
+        let centre = Complex {
+            real: focal.real + (0.5 - focal_x / f64::from(new_pixel_width)) * width,
+            imag: focal.imag + (0.5 - focal_y / f64::from(new_pixel_height)) * height,
+        };
+
+        Self::from_centre_size(centre, width, height)
+    }
+
     #[allow(dead_code)]
     #[must_use]
     pub fn size(&self) -> u64 {
@@ -84,6 +172,11 @@ impl ComplexRect {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::data::pixel_rect::PixelRect;
+    use crate::core::data::point::Point;
+    use crate::core::util::pixel_to_complex_coords::{
+        PixelSample, YAxis, pixel_to_complex_coords_sampled,
+    };
 
     #[test]
     fn test_complex_rect_new_valid() {
@@ -288,4 +381,192 @@ mod tests {
             imag: 201.0
         }));
     }
+
+    fn boundary_rect() -> ComplexRect {
+        ComplexRect::new(
+            Complex {
+                real: -1.0,
+                imag: -0.5,
+            },
+            Complex {
+                real: 1.0,
+                imag: 0.5,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn contains_point_includes_every_edge_and_corner() {
+        let rect = boundary_rect();
+
+        for (real, imag) in [
+            (-1.0, 0.0),
+            (1.0, 0.0),
+            (0.0, -0.5),
+            (0.0, 0.5),
+            (-1.0, -0.5),
+            (1.0, -0.5),
+            (-1.0, 0.5),
+            (1.0, 0.5),
+        ] {
+            assert!(rect.contains_point(Complex { real, imag }), "({real}, {imag})");
+        }
+    }
+
+    #[test]
+    fn contains_point_half_open_includes_only_top_left_edges() {
+        let rect = boundary_rect();
+
+        for (real, imag) in [(-1.0, 0.0), (0.0, -0.5), (-1.0, -0.5), (0.0, 0.0)] {
+            assert!(
+                rect.contains_point_half_open(Complex { real, imag }),
+                "({real}, {imag})"
+            );
+        }
+
+        for (real, imag) in [
+            (1.0, 0.0),
+            (0.0, 0.5),
+            (1.0, -0.5),
+            (-1.0, 0.5),
+            (1.0, 0.5),
+            (-1.5, 0.0),
+        ] {
+            assert!(
+                !rect.contains_point_half_open(Complex { real, imag }),
+                "({real}, {imag})"
+            );
+        }
+    }
+
+    #[test]
+    fn half_open_adjacent_rects_claim_shared_border_once() {
+        let left = boundary_rect();
+        let right = ComplexRect::new(
+            Complex {
+                real: 1.0,
+                imag: -0.5,
+            },
+            Complex {
+                real: 3.0,
+                imag: 0.5,
+            },
+        )
+        .unwrap();
+        let border = Complex {
+            real: 1.0,
+            imag: 0.0,
+        };
+
+        assert!(left.contains_point(border) && right.contains_point(border));
+        assert!(!left.contains_point_half_open(border));
+        assert!(right.contains_point_half_open(border));
+    }
+
+    #[test]
+    fn from_centre_size_centres_the_rect() {
+        let rect = ComplexRect::from_centre_size(
+            Complex {
+                real: -0.75,
+                imag: 0.5,
+            },
+            3.0,
+            2.0,
+        )
+        .unwrap();
+
+        assert_eq!(
+            rect.top_left(),
+            Complex {
+                real: -2.25,
+                imag: -0.5
+            }
+        );
+        assert_eq!(
+            rect.bottom_right(),
+            Complex {
+                real: 0.75,
+                imag: 1.5
+            }
+        );
+        assert!(ComplexRect::from_centre_size(rect.top_left(), 0.0, 1.0).is_err());
+        assert!(ComplexRect::from_centre_size(rect.top_left(), f64::INFINITY, 1.0).is_err());
+    }
+
+    const EPSILON: f64 = 1e-12;
+
+    fn region() -> ComplexRect {
+        ComplexRect::from_centre_size(
+            Complex {
+                real: -0.75,
+                imag: 0.25,
+            },
+            4.0,
+            3.0,
+        )
+        .unwrap()
+    }
+
+    /// The coordinate at the centre of `pixel` in a `pixels`-sized view
+    /// of `rect`.
+    fn complex_at(rect: ComplexRect, pixels: (u32, u32), pixel: Point) -> Complex {
+        let pixel_rect = PixelRect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: pixels.0 as i32 - 1,
+                y: pixels.1 as i32 - 1,
+            },
+        )
+        .unwrap();
+        pixel_to_complex_coords_sampled(pixel, pixel_rect, rect, YAxis::ScreenDown, PixelSample::Center).unwrap()
+    }
+
+    fn assert_same_point(actual: Complex, expected: Complex) {
+        assert!(
+            (actual.real - expected.real).abs() <= EPSILON
+                && (actual.imag - expected.imag).abs() <= EPSILON,
+            "actual={:?} expected={:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn the_focal_pixel_keeps_its_coordinate_across_a_resize() {
+        let focal = Point { x: 123, y: 456 };
+        let focal_pixel = (f64::from(focal.x) + 0.5, f64::from(focal.y) + 0.5);
+        let before = complex_at(region(), (800, 600), focal);
+
+        for new_pixels in [(800, 600), (1024, 600), (800, 900), (400, 500), (1920, 1080)] {
+            let resized = region().scale_to_fit_pixels((800, 600), new_pixels, focal_pixel).unwrap();
+
+            assert_same_point(complex_at(resized, new_pixels, focal), before);
+        }
+    }
+
+    #[test]
+    fn the_region_widens_to_the_new_aspect() {
+        let wider = region().scale_to_fit_pixels((800, 600), (1600, 600), (0.0, 0.0)).unwrap();
+        assert!((wider.width() - 8.0).abs() <= EPSILON);
+        assert!((wider.height() - 3.0).abs() <= EPSILON);
+
+        let taller = region().scale_to_fit_pixels((800, 600), (800, 1200), (0.0, 0.0)).unwrap();
+        assert!((taller.width() - 4.0).abs() <= EPSILON);
+        assert!((taller.height() - 6.0).abs() <= EPSILON);
+    }
+
+    #[test]
+    fn a_top_left_focus_keeps_the_top_left_corner() {
+        let resized = region().scale_to_fit_pixels((800, 600), (1000, 900), (0.0, 0.0)).unwrap();
+
+        assert_same_point(resized.top_left(), region().top_left());
+    }
+
+    #[test]
+    fn degenerate_inputs_leave_the_region_alone() {
+        assert_eq!(region().scale_to_fit_pixels((800, 600), (0, 600), (10.0, 10.0)), Ok(region()));
+        assert_eq!(region().scale_to_fit_pixels((0, 0), (800, 600), (10.0, 10.0)), Ok(region()));
+        assert_eq!(region().scale_to_fit_pixels((800, 600), (1024, 768), (f64::NAN, 0.0)), Ok(region()));
+    }
 }
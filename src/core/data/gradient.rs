@@ -0,0 +1,247 @@
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{ColourMap, ColourMapError};
+use crate::core::data::colour::Colour;
+use crate::core::util::iteration_colour_lut::IterationColourLut;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientError {
+    /// A gradient needs a stop at each end of `[0, 1]`.
+    TooFewStops { count: usize },
+    NonFinitePosition { index: usize },
+    /// Stop `index` sits before the stop preceding it.
+    OutOfOrder { index: usize },
+    /// The first stop is not at 0 or the last is not at 1.
+    DoesNotCoverUnitRange { first: f64, last: f64 },
+    IterationsExceedMax { iterations: u32, max_iterations: u32 },
+}
+
+impl fmt::Display for GradientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewStops { count } => {
+                write!(f, "a gradient needs at least 2 stops, got {}", count)
+            }
+            Self::NonFinitePosition { index } => {
+                write!(f, "gradient stop {} has a non-finite position", index)
+            }
+            Self::OutOfOrder { index } => {
+                write!(f, "gradient stop {} comes before the stop preceding it", index)
+            }
+            Self::DoesNotCoverUnitRange { first, last } => write!(
+                f,
+                "gradient stops must run from 0 to 1, got {} to {}",
+                first, last
+            ),
+            Self::IterationsExceedMax {
+                iterations,
+                max_iterations,
+            } => write!(
+                f,
+                "iterations {} exceed max_iterations {}",
+                iterations, max_iterations
+            ),
+        }
+    }
+}
+
+impl Error for GradientError {}
+
+/// Colours at positions along `[0, 1]`, interpolated linearly between the
+/// stops either side. Stops may share a position for a hard edge.
+#[derive(Debug, Clone)]
+pub struct Gradient {
+    stops: Vec<(f64, Colour)>,
+}
+
+impl Gradient {
+    /// Checks that `stops` are sorted by position and run from exactly 0 to
+    /// exactly 1.
+    pub fn new(stops: Vec<(f64, Colour)>) -> Result<Self, GradientError> {
+        if stops.len() < 2 {
+            return Err(GradientError::TooFewStops { count: stops.len() });
+        }
+
+        if let Some(index) = stops.iter().position(|(position, _)| !position.is_finite()) {
+            return Err(GradientError::NonFinitePosition { index });
+        }
+
+        if let Some(index) = (1..stops.len()).find(|&index| stops[index].0 < stops[index - 1].0) {
+            return Err(GradientError::OutOfOrder { index });
+        }
+
+        let first = stops[0].0;
+        let last = stops[stops.len() - 1].0;
+        if first != 0.0 || last != 1.0 {
+            return Err(GradientError::DoesNotCoverUnitRange { first, last });
+        }
+
+        Ok(Self { stops })
+    }
+
+    /// The colour at `t`, clamped to `[0, 1]`.
+    #[must_use]
+    pub fn sample(&self, t: f64) -> Colour {
+        let t = t.clamp(0.0, 1.0);
+        // The first stop past `t`, kept within the last segment.
+        let upper = self
+            .stops
+            .partition_point(|&(position, _)| position <= t)
+            .clamp(1, self.stops.len() - 1);
+        let (from_position, from) = self.stops[upper - 1];
+        let (to_position, to) = self.stops[upper];
+
+        let span = to_position - from_position;
+        if span <= 0.0 {
+            return to;
+        }
+
+        from.lerp(to, (t - from_position) / span)
+    }
+
+    #[must_use]
+    pub fn stops(&self) -> &[(f64, Colour)] {
+        &self.stops
+    }
+}
+
+/// Escape counts along a [`Gradient`] of runtime-chosen stops, at
+/// `iterations / max_iterations`. Interior points are black. Fractal colour
+/// maps can wrap it to offer user-defined palettes.
+#[derive(Debug)]
+pub struct GradientColourMap {
+    gradient: Gradient,
+    max_iterations: u32,
+    lut: IterationColourLut,
+}
+
+impl GradientColourMap {
+    pub fn new(stops: Vec<(f64, Colour)>, max_iterations: u32) -> Result<Self, GradientError> {
+        let gradient = Gradient::new(stops)?;
+        let lut = IterationColourLut::new(max_iterations, |t| gradient.sample(t));
+
+        Ok(Self {
+            gradient,
+            max_iterations,
+            lut,
+        })
+    }
+
+    #[must_use]
+    pub fn gradient(&self) -> &Gradient {
+        &self.gradient
+    }
+}
+
+impl ColourMap<u32> for GradientColourMap {
+    fn map(&self, iterations: u32) -> Result<Colour, ColourMapError> {
+        match self.lut.get(iterations) {
+            Some(colour) if iterations <= self.max_iterations => Ok(colour),
+            _ => Err(Box::new(GradientError::IterationsExceedMax {
+                iterations,
+                max_iterations: self.max_iterations,
+            })),
+        }
+    }
+
+    fn display_name(&self) -> &str {
+        "Gradient"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Colour = Colour { r: 0, g: 0, b: 0 };
+    const WHITE: Colour = Colour { r: 255, g: 255, b: 255 };
+    const RED: Colour = Colour { r: 255, g: 0, b: 0 };
+    const BLUE: Colour = Colour { r: 0, g: 0, b: 255 };
+
+    fn channels(colour: Colour) -> (u8, u8, u8) {
+        (colour.r, colour.g, colour.b)
+    }
+
+    #[test]
+    fn two_stops_interpolate_linearly() {
+        let map = GradientColourMap::new(vec![(0.0, BLACK), (1.0, WHITE)], 4).unwrap();
+
+        assert_eq!(channels(map.map(0).unwrap()), (0, 0, 0));
+        assert_eq!(channels(map.map(1).unwrap()), (64, 64, 64));
+        assert_eq!(channels(map.map(2).unwrap()), (128, 128, 128));
+        assert_eq!(channels(map.map(3).unwrap()), (191, 191, 191));
+    }
+
+    #[test]
+    fn three_stops_interpolate_within_each_segment() {
+        let map = GradientColourMap::new(vec![(0.0, RED), (0.25, WHITE), (1.0, BLUE)], 8).unwrap();
+
+        assert_eq!(channels(map.map(0).unwrap()), (255, 0, 0));
+        assert_eq!(channels(map.map(1).unwrap()), (255, 128, 128));
+        assert_eq!(channels(map.map(2).unwrap()), (255, 255, 255));
+        assert_eq!(channels(map.map(5).unwrap()), (128, 128, 255));
+    }
+
+    #[test]
+    fn shared_positions_make_a_hard_edge() {
+        let gradient = Gradient::new(vec![(0.0, BLACK), (0.5, BLACK), (0.5, WHITE), (1.0, WHITE)]).unwrap();
+
+        assert_eq!(channels(gradient.sample(0.49)), (0, 0, 0));
+        assert_eq!(channels(gradient.sample(0.5)), (255, 255, 255));
+        assert_eq!(channels(gradient.sample(1.0)), (255, 255, 255));
+    }
+
+    #[test]
+    fn samples_outside_the_unit_range_are_clamped() {
+        let gradient = Gradient::new(vec![(0.0, RED), (1.0, BLUE)]).unwrap();
+
+        assert_eq!(channels(gradient.sample(-1.0)), (255, 0, 0));
+        assert_eq!(channels(gradient.sample(2.0)), (0, 0, 255));
+    }
+
+    #[test]
+    fn out_of_order_stops_are_an_error() {
+        let result = Gradient::new(vec![(0.0, RED), (0.75, WHITE), (0.5, BLACK), (1.0, BLUE)]);
+
+        assert!(matches!(result, Err(GradientError::OutOfOrder { index: 2 })));
+    }
+
+    #[test]
+    fn stops_must_cover_the_unit_range() {
+        assert!(matches!(
+            Gradient::new(vec![(0.1, RED), (1.0, BLUE)]),
+            Err(GradientError::DoesNotCoverUnitRange { .. })
+        ));
+        assert!(matches!(
+            Gradient::new(vec![(0.0, RED), (0.9, BLUE)]),
+            Err(GradientError::DoesNotCoverUnitRange { .. })
+        ));
+    }
+
+    #[test]
+    fn degenerate_stops_are_an_error() {
+        assert!(matches!(
+            Gradient::new(vec![(0.0, RED)]),
+            Err(GradientError::TooFewStops { count: 1 })
+        ));
+        assert!(matches!(
+            Gradient::new(vec![(0.0, RED), (f64::NAN, WHITE), (1.0, BLUE)]),
+            Err(GradientError::NonFinitePosition { index: 1 })
+        ));
+    }
+
+    #[test]
+    fn interior_is_black_and_above_max_is_an_error() {
+        let map = GradientColourMap::new(vec![(0.0, RED), (1.0, BLUE)], 100).unwrap();
+
+        assert_eq!(channels(map.map(100).unwrap()), (0, 0, 0));
+        let err = map.map(101).expect_err("expected error when iterations exceed max");
+        assert!(matches!(
+            err.downcast_ref::<GradientError>(),
+            Some(GradientError::IterationsExceedMax {
+                iterations: 101,
+                max_iterations: 100
+            })
+        ));
+    }
+}
@@ -3,6 +3,7 @@ pub mod complex;
 pub mod complex_rect;
 pub mod deep_complex;
 pub mod deep_region;
+pub mod gradient;
 pub mod pixel_buffer;
 pub mod pixel_rect;
 pub mod point;
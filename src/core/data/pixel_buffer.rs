@@ -1,4 +1,4 @@
-use crate::core::data::colour::Colour;
+use crate::core::data::colour::{Colour, ColourRgba};
 use crate::core::data::pixel_rect::PixelRect;
 use crate::core::data::point::Point;
 use std::error::Error;
@@ -50,8 +50,10 @@ impl Error for PixelBufferError {}
 
 pub type PixelBufferData = Vec<u8>;
 
-/// Opaque RGBA pixel buffer in row-major order (`r, g, b, a`) with `a = 255`.
-#[derive(Debug)]
+/// RGBA pixel buffer in row-major order (`r, g, b, a`). Opaque (`a = 255`)
+/// unless built with [`Self::from_data_rgba`] or written with
+/// [`Self::set_pixel_rgba`], which keep the given alpha.
+#[derive(Debug, Clone)]
 pub struct PixelBuffer {
     pixel_rect: PixelRect,
     buffer: PixelBufferData,
@@ -94,6 +96,23 @@ impl PixelBuffer {
         Ok(Self { pixel_rect, buffer })
     }
 
+    /// Like [`Self::from_data`], but keeps the alpha bytes as given.
+    pub fn from_data_rgba(
+        pixel_rect: PixelRect,
+        buffer: PixelBufferData,
+    ) -> Result<Self, PixelBufferError> {
+        let buffer_size = pixel_rect_to_buffer_size(pixel_rect);
+
+        if buffer_size != buffer.len() {
+            return Err(PixelBufferError::BoundsMismatch {
+                pixel_rect_size: buffer_size,
+                buffer_size: buffer.len(),
+            });
+        }
+
+        Ok(Self { pixel_rect, buffer })
+    }
+
     pub(crate) fn from_data_opaque(
         pixel_rect: PixelRect,
         buffer: PixelBufferData,
@@ -127,6 +146,48 @@ impl PixelBuffer {
         &self.buffer
     }
 
+    /// The pixels as contiguous RGBA, ready for consumers such as `pixels`
+    /// frames or wgpu textures. The storage is already RGBA, so this borrows.
+    #[must_use]
+    pub fn as_rgba(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Owned copy of [`Self::as_rgba`].
+    #[must_use]
+    pub fn to_rgba(&self) -> Vec<u8> {
+        self.buffer.clone()
+    }
+
+    /// The pixels as contiguous RGB with alpha dropped, e.g. for PPM.
+    #[must_use]
+    pub fn to_rgb(&self) -> Vec<u8> {
+        let mut rgb = Vec::with_capacity(self.buffer.len() / Self::BYTES_PER_PIXEL * 3);
+        for pixel in self.buffer.chunks_exact(Self::BYTES_PER_PIXEL) {
+            rgb.extend_from_slice(&pixel[..3]);
+        }
+        rgb
+    }
+
+    /// True when no pixel has been given any transparency.
+    #[must_use]
+    pub fn is_opaque(&self) -> bool {
+        self.buffer
+            .chunks_exact(Self::BYTES_PER_PIXEL)
+            .all(|pixel| pixel[Self::BYTES_PER_PIXEL - 1] == Self::ALPHA_OPAQUE)
+    }
+
+    /// True when every pixel has the same colour, which usually means the
+    /// view lies entirely inside or entirely outside the set.
+    #[must_use]
+    pub fn is_single_colour(&self) -> bool {
+        let mut pixels = self.buffer.chunks_exact(Self::BYTES_PER_PIXEL);
+        let Some(first) = pixels.next() else {
+            return true;
+        };
+        pixels.all(|pixel| pixel[..3] == first[..3])
+    }
+
     #[must_use]
     pub fn buffer_size(&self) -> usize {
         self.buffer.len()
@@ -148,17 +209,12 @@ impl PixelBuffer {
     }
 
     pub fn set_pixel(&mut self, pixel: Point, colour: Colour) -> Result<(), PixelBufferError> {
-        if !self.pixel_rect.contains_point(pixel) {
+        let Some(index) = Self::byte_index(self.pixel_rect, pixel) else {
             return Err(PixelBufferError::PixelOutsideBounds {
                 pixel,
                 pixel_rect: self.pixel_rect,
             });
-        }
-
-        let relative_x = (pixel.x - self.pixel_rect.top_left().x) as u32;
-        let relative_y = (pixel.y - self.pixel_rect.top_left().y) as u32;
-        let index =
-            ((relative_y * self.pixel_rect.width() + relative_x) as usize) * Self::BYTES_PER_PIXEL;
+        };
 
         self.buffer[index] = colour.r;
         self.buffer[index + 1] = colour.g;
@@ -167,6 +223,73 @@ impl PixelBuffer {
 
         Ok(())
     }
+
+    pub fn set_pixel_rgba(&mut self, pixel: Point, colour: ColourRgba) -> Result<(), PixelBufferError> {
+        let Some(index) = Self::byte_index(self.pixel_rect, pixel) else {
+            return Err(PixelBufferError::PixelOutsideBounds {
+                pixel,
+                pixel_rect: self.pixel_rect,
+            });
+        };
+
+        self.buffer[index..index + Self::BYTES_PER_PIXEL]
+            .copy_from_slice(&[colour.r, colour.g, colour.b, colour.a]);
+
+        Ok(())
+    }
+
+    /// Copies all of `src` into this buffer with its top-left pixel at
+    /// `at`, in this buffer's coordinates. Bytes are copied as they are,
+    /// alpha included. Fails without writing anything if any part of `src`
+    /// would land outside this buffer, naming the corner that does.
+    pub fn blit(&mut self, src: &PixelBuffer, at: Point) -> Result<(), PixelBufferError> {
+        let outside = |pixel| PixelBufferError::PixelOutsideBounds {
+            pixel,
+            pixel_rect: self.pixel_rect,
+        };
+        let corner = |extent: u32, origin: i32| {
+            i32::try_from(i64::from(origin) + i64::from(extent) - 1).unwrap_or(i32::MAX)
+        };
+        let bottom_right = Point {
+            x: corner(src.pixel_rect.width(), at.x),
+            y: corner(src.pixel_rect.height(), at.y),
+        };
+
+        let Some(first) = Self::byte_index(self.pixel_rect, at) else {
+            return Err(outside(at));
+        };
+        if !self.pixel_rect.contains_point(bottom_right) {
+            return Err(outside(bottom_right));
+        }
+
+        let row_bytes = src.pixel_rect.width() as usize * Self::BYTES_PER_PIXEL;
+        let stride = self.pixel_rect.width() as usize * Self::BYTES_PER_PIXEL;
+        for (row, src_row) in src.buffer.chunks_exact(row_bytes).enumerate() {
+            let start = first + row * stride;
+            self.buffer[start..start + row_bytes].copy_from_slice(src_row);
+        }
+
+        Ok(())
+    }
+
+    /// Offset of `pixel`'s first byte, or `None` outside `pixel_rect`. The
+    /// bounds are checked before any arithmetic, which is done in `usize`
+    /// (checked, for 32-bit targets) so large rects cannot wrap.
+    fn byte_index(pixel_rect: PixelRect, pixel: Point) -> Option<usize> {
+        if !pixel_rect.contains_point(pixel) {
+            return None;
+        }
+
+        let top_left = pixel_rect.top_left();
+        let relative_x = usize::try_from(i64::from(pixel.x) - i64::from(top_left.x)).ok()?;
+        let relative_y = usize::try_from(i64::from(pixel.y) - i64::from(top_left.y)).ok()?;
+        let width = usize::try_from(pixel_rect.width()).ok()?;
+
+        relative_y
+            .checked_mul(width)?
+            .checked_add(relative_x)?
+            .checked_mul(Self::BYTES_PER_PIXEL)
+    }
 }
 
 #[cfg(test)]
@@ -599,4 +722,173 @@ mod tests {
         assert_eq!(buffer.buffer(), &expected);
         assert_alpha_is_opaque(buffer.buffer());
     }
+
+    #[test]
+    fn to_rgba_is_opaque_and_keeps_rgb_channels() {
+        let mut buffer = PixelBuffer::new(create_pixel_rect(2, 1));
+        buffer
+            .set_pixel(Point { x: 0, y: 0 }, Colour { r: 10, g: 20, b: 30 })
+            .unwrap();
+        buffer
+            .set_pixel(Point { x: 1, y: 0 }, Colour { r: 200, g: 100, b: 50 })
+            .unwrap();
+
+        let rgba = buffer.to_rgba();
+
+        assert_eq!(rgba, vec![10, 20, 30, 255, 200, 100, 50, 255]);
+        assert_eq!(rgba.as_slice(), buffer.as_rgba());
+        assert_eq!(buffer.to_rgb(), vec![10, 20, 30, 200, 100, 50]);
+    }
+
+    #[test]
+    fn uniform_buffer_is_single_colour() {
+        let mut buffer = PixelBuffer::new(create_pixel_rect(3, 2));
+        assert!(buffer.is_single_colour());
+
+        let colour = Colour { r: 7, g: 8, b: 9 };
+        for y in 0..2 {
+            for x in 0..3 {
+                buffer.set_pixel(Point { x, y }, colour).unwrap();
+            }
+        }
+        assert!(buffer.is_single_colour());
+    }
+
+    #[test]
+    fn one_differing_pixel_is_not_single_colour() {
+        let mut buffer = PixelBuffer::new(create_pixel_rect(3, 2));
+        buffer
+            .set_pixel(Point { x: 2, y: 1 }, Colour { r: 0, g: 0, b: 1 })
+            .unwrap();
+
+        assert!(!buffer.is_single_colour());
+    }
+
+    #[test]
+    fn byte_index_of_a_large_rect_does_not_wrap() {
+        // 100000 rows of 100000 pixels: the row offset alone exceeds u32.
+        let rect = create_offset_pixel_rect(-50_000, -50_000, 100_000, 100_000);
+        let bottom_right = rect.bottom_right();
+
+        let expected = (99_999u64 * 100_000 + 99_999) * PixelBuffer::BYTES_PER_PIXEL as u64;
+
+        assert_eq!(
+            PixelBuffer::byte_index(rect, bottom_right),
+            usize::try_from(expected).ok()
+        );
+        assert_eq!(PixelBuffer::byte_index(rect, rect.top_left()), Some(0));
+    }
+
+    #[test]
+    fn byte_index_rejects_pixels_outside_a_large_rect() {
+        let rect = create_offset_pixel_rect(-50_000, -50_000, 100_000, 100_000);
+
+        for pixel in [
+            Point { x: 50_000, y: 0 },
+            Point { x: 0, y: -50_001 },
+            Point { x: i32::MAX, y: i32::MAX },
+            Point { x: i32::MIN, y: i32::MIN },
+        ] {
+            assert_eq!(PixelBuffer::byte_index(rect, pixel), None, "{pixel:?}");
+        }
+    }
+
+    #[test]
+    fn set_pixel_outside_bounds_leaves_the_buffer_untouched() {
+        let mut buffer = PixelBuffer::new(create_pixel_rect(4, 3));
+        let before = buffer.buffer().clone();
+
+        let result =
+            buffer.set_pixel(Point { x: i32::MAX, y: i32::MAX }, Colour { r: 1, g: 2, b: 3 });
+
+        assert!(matches!(result, Err(PixelBufferError::PixelOutsideBounds { .. })));
+        assert_eq!(buffer.buffer(), &before);
+    }
+
+    #[test]
+    fn from_data_rgba_keeps_alpha() {
+        let pixel_rect = create_pixel_rect(2, 1);
+        let buffer = PixelBuffer::from_data_rgba(pixel_rect, vec![1, 2, 3, 0, 4, 5, 6, 128]).unwrap();
+
+        assert_eq!(buffer.as_rgba(), &[1, 2, 3, 0, 4, 5, 6, 128]);
+        assert!(!buffer.is_opaque());
+        assert!(PixelBuffer::new(pixel_rect).is_opaque());
+    }
+
+    #[test]
+    fn set_pixel_rgba_writes_alpha_and_set_pixel_restores_opacity() {
+        let mut buffer = PixelBuffer::new(create_pixel_rect(2, 2));
+        let pixel = Point { x: 1, y: 1 };
+
+        buffer.set_pixel_rgba(pixel, ColourRgba::TRANSPARENT).unwrap();
+        assert_eq!(&buffer.buffer()[12..16], &[0, 0, 0, 0]);
+
+        buffer.set_pixel(pixel, Colour { r: 9, g: 8, b: 7 }).unwrap();
+        assert_eq!(&buffer.buffer()[12..16], &[9, 8, 7, PixelBuffer::ALPHA_OPAQUE]);
+        assert!(buffer.is_opaque());
+    }
+
+    #[test]
+    fn blit_copies_the_source_at_the_offset() {
+        let mut destination = PixelBuffer::new(create_pixel_rect(5, 4));
+        let mut source = PixelBuffer::new(create_pixel_rect(2, 2));
+        for (i, pixel) in [(0, 0), (1, 0), (0, 1), (1, 1)].into_iter().enumerate() {
+            let value = 10 * (i as u8 + 1);
+            source
+                .set_pixel_rgba(
+                    Point { x: pixel.0, y: pixel.1 },
+                    ColourRgba { r: value, g: value + 1, b: value + 2, a: value + 3 },
+                )
+                .unwrap();
+        }
+
+        destination.blit(&source, Point { x: 2, y: 1 }).unwrap();
+
+        let pixel_at = |x: usize, y: usize| {
+            let index = (y * 5 + x) * PixelBuffer::BYTES_PER_PIXEL;
+            destination.buffer()[index..index + PixelBuffer::BYTES_PER_PIXEL].to_vec()
+        };
+        assert_eq!(pixel_at(2, 1), [10, 11, 12, 13]);
+        assert_eq!(pixel_at(3, 1), [20, 21, 22, 23]);
+        assert_eq!(pixel_at(2, 2), [30, 31, 32, 33]);
+        assert_eq!(pixel_at(3, 2), [40, 41, 42, 43]);
+        for (x, y) in [(1, 1), (4, 1), (2, 0), (2, 3), (0, 0), (4, 3)] {
+            assert_eq!(pixel_at(x, y), [0, 0, 0, PixelBuffer::ALPHA_OPAQUE], "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn blit_uses_the_destination_coordinates() {
+        let mut destination = PixelBuffer::new(create_offset_pixel_rect(10, 20, 3, 3));
+        let source = PixelBuffer::from_data(create_pixel_rect(1, 1), vec![7, 8, 9, 0]).unwrap();
+
+        destination.blit(&source, Point { x: 12, y: 22 }).unwrap();
+
+        assert_eq!(&destination.buffer()[32..36], &[7, 8, 9, PixelBuffer::ALPHA_OPAQUE]);
+    }
+
+    #[test]
+    fn blit_outside_the_destination_errors_and_writes_nothing() {
+        let pixel_rect = create_pixel_rect(4, 4);
+        let mut destination = PixelBuffer::new(pixel_rect);
+        let source = PixelBuffer::from_data(create_pixel_rect(2, 2), vec![255; 16]).unwrap();
+        let before = destination.buffer().clone();
+
+        assert_eq!(
+            destination.blit(&source, Point { x: 3, y: 1 }),
+            Err(PixelBufferError::PixelOutsideBounds {
+                pixel: Point { x: 4, y: 2 },
+                pixel_rect
+            })
+        );
+        assert_eq!(
+            destination.blit(&source, Point { x: -1, y: 0 }),
+            Err(PixelBufferError::PixelOutsideBounds {
+                pixel: Point { x: -1, y: 0 },
+                pixel_rect
+            })
+        );
+        assert!(destination.blit(&source, Point { x: i32::MAX, y: 0 }).is_err());
+        assert_eq!(destination.buffer(), &before);
+    }
 }
@@ -5,6 +5,7 @@ use std::fmt;
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum PixelRectError {
     InvalidSize { width: i32, height: i32 },
+    TooLarge { width: u32, height: u32 },
 }
 
 impl fmt::Display for PixelRectError {
@@ -13,6 +14,9 @@ impl fmt::Display for PixelRectError {
             Self::InvalidSize { width, height } => {
                 write!(f, "pixel rect size must be positive: {}x{}", width, height)
             }
+            Self::TooLarge { width, height } => {
+                write!(f, "pixel rect size must fit in an i32: {}x{}", width, height)
+            }
         }
     }
 }
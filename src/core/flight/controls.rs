@@ -6,6 +6,10 @@ pub struct FlightControlsSnapshot {
     pub d: bool,
     pub accelerate: bool,
     pub decelerate: bool,
+    /// Zoom in at the direct zoom rate while held (`ZoomControlMode::Direct`).
+    pub zoom_in: bool,
+    /// Zoom out at the direct zoom rate while held (`ZoomControlMode::Direct`).
+    pub zoom_out: bool,
     pub pause_toggle_edge: bool,
 }
 
@@ -23,6 +27,8 @@ mod tests {
         assert!(!snapshot.d);
         assert!(!snapshot.accelerate);
         assert!(!snapshot.decelerate);
+        assert!(!snapshot.zoom_in);
+        assert!(!snapshot.zoom_out);
         assert!(!snapshot.pause_toggle_edge);
     }
 }
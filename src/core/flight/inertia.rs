@@ -0,0 +1,138 @@
+use crate::core::flight::limits::FlightLimits;
+use crate::core::flight::motion::{HEADING_ZERO_THRESHOLD, MotionState};
+
+/// Turns the cursor velocity at the end of a drag-pan into flight motion
+/// that carries the view on at the same rate.
+///
+/// `release_velocity_px_per_sec` is in window pixels, `y` growing downward
+/// like pixel rows, over a `window`-sized view. Dragging moves the image
+/// with the cursor, so the heading points the other way. Flight pans
+/// `heading * steer_strength` view extents a second, so the heading is the
+/// velocity in extents a second over `steer_strength`; with no keys held
+/// it then decays as an ordinary heading does, and the glide slows to a
+/// stop. The speed is zero and `gliding` set, so it steers but never
+/// zooms. `None` when the
+/// release is too slow to glide or any input is degenerate.
+#[must_use]
+pub fn glide_motion(
+    release_velocity_px_per_sec: (f64, f64),
+    window: (u32, u32),
+    limits: &FlightLimits,
+) -> Option<MotionState> {
+    let (velocity_x, velocity_y) = release_velocity_px_per_sec;
+    let (width, height) = window;
+
+    if width == 0
+        || height == 0
+        || !limits.steer_strength.is_finite()
+        || limits.steer_strength <= 0.0
+    {
+        return None;
+    }
+
+    let heading = [
+        -velocity_x / f64::from(width) / limits.steer_strength,
+        -velocity_y / f64::from(height) / limits.steer_strength,
+    ];
+    let magnitude = heading[0].hypot(heading[1]);
+    if !magnitude.is_finite() || magnitude < HEADING_ZERO_THRESHOLD {
+        return None;
+    }
+
+    Some(MotionState {
+        paused: false,
+        heading,
+        speed_world_per_sec: 0.0,
+        accel_world_per_sec2: 0.0,
+        gliding: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::{deep_complex::DeepComplex, deep_region::DeepRegion};
+    use crate::core::fractals::mandelbrot::{flight::step_flight, mandelbrot_config::MandelbrotConfig};
+
+    const EPSILON: f64 = 1e-12;
+
+    fn assert_approx_eq(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= EPSILON,
+            "actual={} expected={}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn dragging_right_glides_the_view_left() {
+        let limits = FlightLimits::default();
+
+        let motion = glide_motion((400.0, 0.0), (800, 600), &limits).unwrap();
+
+        // Half the window width a second is a whole extent at steer 0.5.
+        assert_approx_eq(motion.heading[0], -1.0);
+        assert_approx_eq(motion.heading[1], 0.0);
+        assert!(!motion.paused);
+        assert_eq!(motion.speed_world_per_sec, 0.0);
+        assert_eq!(motion.accel_world_per_sec2, 0.0);
+        assert!(motion.gliding);
+    }
+
+    #[test]
+    fn each_axis_is_scaled_by_its_own_window_extent() {
+        let limits = FlightLimits {
+            steer_strength: 1.0,
+            ..FlightLimits::default()
+        };
+
+        let motion = glide_motion((-200.0, 150.0), (800, 300), &limits).unwrap();
+
+        assert_approx_eq(motion.heading[0], 0.25);
+        assert_approx_eq(motion.heading[1], -0.5);
+    }
+
+    #[test]
+    fn slow_releases_do_not_glide() {
+        let limits = FlightLimits::default();
+
+        assert_eq!(glide_motion((0.0, 0.0), (800, 600), &limits), None);
+        assert_eq!(glide_motion((2.0, -1.0), (800, 600), &limits), None);
+    }
+
+    #[test]
+    fn degenerate_inputs_do_not_glide() {
+        let limits = FlightLimits::default();
+        let no_steering = FlightLimits {
+            steer_strength: 0.0,
+            ..FlightLimits::default()
+        };
+
+        assert_eq!(glide_motion((400.0, 0.0), (0, 600), &limits), None);
+        assert_eq!(glide_motion((400.0, 0.0), (800, 600), &no_steering), None);
+        assert_eq!(glide_motion((f64::NAN, 0.0), (800, 600), &limits), None);
+        assert_eq!(glide_motion((f64::INFINITY, 0.0), (800, 600), &limits), None);
+    }
+
+    #[test]
+    fn a_glide_tick_pans_at_the_release_velocity_without_zooming() {
+        let limits = FlightLimits::default();
+        let dt = limits.dt();
+        let centre = DeepComplex::from_f64(-0.5, 0.0).unwrap();
+        let mut config = MandelbrotConfig {
+            region: DeepRegion::new(centre, 4.0, 3.0).unwrap(),
+            ..MandelbrotConfig::default()
+        };
+        let motion = glide_motion((-200.0, 300.0), (800, 600), &limits).unwrap();
+
+        step_flight(&mut config, &motion, dt, &limits);
+
+        // A quarter of the width right and half the height up, a second.
+        let (dre, dim) = config.region.centre().sub_to_f64(&DeepComplex::from_f64(-0.5, 0.0).unwrap());
+        assert_approx_eq(dre, 0.25 * 4.0 * dt);
+        assert_approx_eq(dim, -0.5 * 3.0 * dt);
+        assert_eq!(config.region.width(), 4.0);
+        assert_eq!(config.region.height(), 3.0);
+    }
+}
@@ -1,4 +1,148 @@
+use crate::core::fractals::parse_kind::{ParseKindError, parse_kind};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// How the zoom controls drive `MotionState::speed_world_per_sec`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "lowercase", try_from = "String")
+)]
+pub enum ZoomControlMode {
+    /// `accelerate`/`decelerate` change the zoom speed, which persists
+    /// after they are released.
+    #[default]
+    Accelerate,
+    /// `zoom_in`/`zoom_out` set the zoom speed to
+    /// `direct_zoom_speed_world_per_sec` while held; with neither held the
+    /// zoom stops.
+    Direct,
+}
+
+/// What flight does when panning carries the view centre past
+/// `FlightLimits::max_center_abs` on either axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "lowercase", try_from = "String")
+)]
+pub enum BoundaryBehavior {
+    /// The centre stops at the limit.
+    #[default]
+    Clamp,
+    /// The centre reappears as far inside the opposite limit as it
+    /// overshot this one.
+    Wrap,
+    /// The centre reflects back off the limit and the heading along that
+    /// axis reverses.
+    Bounce,
+}
+
+impl ZoomControlMode {
+    pub const ALL: &'static [Self] = &[Self::Accelerate, Self::Direct];
+
+    /// The name flight profiles and config files use.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Accelerate => "accelerate",
+            Self::Direct => "direct",
+        }
+    }
+}
+
+/// Parses a name case-insensitively, ignoring surrounding whitespace.
+impl FromStr for ZoomControlMode {
+    type Err = ParseKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_kind(Self::ALL, Self::name, "zoom mode", s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<String> for ZoomControlMode {
+    type Error = ParseKindError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
+
+impl BoundaryBehavior {
+    pub const ALL: &'static [Self] = &[Self::Clamp, Self::Wrap, Self::Bounce];
+
+    /// The name flight profiles and config files use.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Clamp => "clamp",
+            Self::Wrap => "wrap",
+            Self::Bounce => "bounce",
+        }
+    }
+
+    /// `centre` brought back within `±max_abs`, and whether the heading
+    /// along its axis should reverse. Centres already inside pass through.
+    #[must_use]
+    pub fn confine(self, centre: f64, max_abs: f64) -> (f64, bool) {
+        if centre.abs() <= max_abs {
+            return (centre, false);
+        }
+
+        match self {
+            Self::Wrap if max_abs > 0.0 => {
+                ((centre + max_abs).rem_euclid(2.0 * max_abs) - max_abs, false)
+            }
+            Self::Bounce => {
+                let limit = max_abs.copysign(centre);
+                ((2.0 * limit - centre).clamp(-max_abs, max_abs), true)
+            }
+            Self::Clamp | Self::Wrap => (centre.clamp(-max_abs, max_abs), false),
+        }
+    }
+}
+
+/// Parses a name case-insensitively, ignoring surrounding whitespace.
+impl FromStr for BoundaryBehavior {
+    type Err = ParseKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_kind(Self::ALL, Self::name, "boundary", s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<String> for BoundaryBehavior {
+    type Error = ParseKindError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlightLimitsError {
+    InvalidLimit { field: &'static str },
+}
+
+impl fmt::Display for FlightLimitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLimit { field } => write!(f, "{} must be positive and finite", field),
+        }
+    }
+}
+
+impl Error for FlightLimitsError {}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default, deny_unknown_fields))]
 pub struct FlightLimits {
     pub tick_hz: u32,
     pub base_accel_world_per_sec2: f64,
@@ -6,13 +150,48 @@ pub struct FlightLimits {
     pub min_region_extent: f64,
     pub max_region_extent: f64,
     pub max_center_abs: f64,
+    pub boundary: BoundaryBehavior,
     pub precision_guard_ulps: f64,
     pub zoom_base: f64,
     pub steer_strength: f64,
     pub max_ticks_per_redraw: u32,
+    pub zoom_mode: ZoomControlMode,
+    pub direct_zoom_speed_world_per_sec: f64,
 }
 
 impl FlightLimits {
+    /// Checks limits read from a file: the tick counts must be nonzero and
+    /// the rates, extents and speeds positive and finite.
+    /// `precision_guard_ulps` may be zero, which turns the guard off.
+    pub fn validate(&self) -> Result<(), FlightLimitsError> {
+        let counts = [
+            ("tick_hz", self.tick_hz),
+            ("max_ticks_per_redraw", self.max_ticks_per_redraw),
+        ];
+        if let Some((field, _)) = counts.into_iter().find(|&(_, value)| value == 0) {
+            return Err(FlightLimitsError::InvalidLimit { field });
+        }
+
+        let values = [
+            ("base_accel_world_per_sec2", self.base_accel_world_per_sec2),
+            ("max_speed_abs_world_per_sec", self.max_speed_abs_world_per_sec),
+            ("min_region_extent", self.min_region_extent),
+            ("max_region_extent", self.max_region_extent),
+            ("max_center_abs", self.max_center_abs),
+            ("zoom_base", self.zoom_base),
+            ("steer_strength", self.steer_strength),
+            ("direct_zoom_speed_world_per_sec", self.direct_zoom_speed_world_per_sec),
+        ];
+        if let Some((field, _)) = values
+            .into_iter()
+            .find(|&(_, value)| !(value.is_finite() && value > 0.0))
+        {
+            return Err(FlightLimitsError::InvalidLimit { field });
+        }
+
+        Ok(())
+    }
+
     #[must_use]
     pub fn dt(&self) -> f64 {
         if self.tick_hz == 0 {
@@ -60,17 +239,20 @@ impl Default for FlightLimits {
             min_region_extent: 1e-280,
             max_region_extent: 20.0,
             max_center_abs: 100.0,
+            boundary: BoundaryBehavior::Clamp,
             precision_guard_ulps: 128.0,
             zoom_base: 2.0,
             steer_strength: 0.5,
             max_ticks_per_redraw: 10,
+            zoom_mode: ZoomControlMode::Accelerate,
+            direct_zoom_speed_world_per_sec: 1.0,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::FlightLimits;
+    use super::*;
 
     #[test]
     fn default_limits_are_finite_and_consistent() {
@@ -86,6 +268,8 @@ mod tests {
         assert!(limits.zoom_base.is_finite());
         assert!(limits.steer_strength.is_finite());
         assert!(limits.max_ticks_per_redraw > 0);
+        assert!(limits.direct_zoom_speed_world_per_sec.is_finite());
+        assert!(limits.direct_zoom_speed_world_per_sec > 0.0);
         assert!(limits.min_region_extent > 0.0);
         assert!(limits.max_region_extent >= limits.min_region_extent);
         assert!(limits.max_speed_abs_world_per_sec >= 0.0);
@@ -96,6 +280,51 @@ mod tests {
         assert!(limits.steer_strength >= 0.0);
     }
 
+    #[test]
+    fn default_limits_validate() {
+        assert_eq!(FlightLimits::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_names_the_first_bad_field() {
+        let zero_ticks = FlightLimits {
+            max_ticks_per_redraw: 0,
+            ..FlightLimits::default()
+        };
+        let negative_zoom = FlightLimits {
+            zoom_base: -2.0,
+            ..FlightLimits::default()
+        };
+        let infinite_speed = FlightLimits {
+            max_speed_abs_world_per_sec: f64::INFINITY,
+            ..FlightLimits::default()
+        };
+
+        assert_eq!(
+            zero_ticks.validate(),
+            Err(FlightLimitsError::InvalidLimit { field: "max_ticks_per_redraw" })
+        );
+        assert_eq!(
+            negative_zoom.validate(),
+            Err(FlightLimitsError::InvalidLimit { field: "zoom_base" })
+        );
+        assert_eq!(
+            infinite_speed.validate(),
+            Err(FlightLimitsError::InvalidLimit { field: "max_speed_abs_world_per_sec" })
+        );
+    }
+
+    #[test]
+    fn modes_parse_ignoring_case_and_whitespace() {
+        assert_eq!(" Direct ".parse::<ZoomControlMode>(), Ok(ZoomControlMode::Direct));
+        assert_eq!("WRAP".parse::<BoundaryBehavior>(), Ok(BoundaryBehavior::Wrap));
+        assert_eq!(
+            "warp".parse::<ZoomControlMode>().unwrap_err().to_string(),
+            "unknown zoom mode 'warp' (expected one of: accelerate, direct)"
+        );
+        assert!("teleport".parse::<BoundaryBehavior>().is_err());
+    }
+
     #[test]
     fn dt_matches_tick_rate() {
         let limits = FlightLimits::default();
@@ -1,9 +1,11 @@
 pub mod controls;
+pub mod inertia;
 pub mod limits;
 pub mod motion;
 pub mod status;
 
 pub use controls::FlightControlsSnapshot;
-pub use limits::FlightLimits;
+pub use inertia::glide_motion;
+pub use limits::{BoundaryBehavior, FlightLimits, FlightLimitsError, ZoomControlMode};
 pub use motion::{MotionState, step_motion};
-pub use status::{FlightStatus, FlightUpdateReport, FlightWarning};
+pub use status::{FlightStatus, FlightUpdateReport, FlightWarning, FlightWarningCounts};
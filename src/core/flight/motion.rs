@@ -1,5 +1,5 @@
 use crate::core::flight::controls::FlightControlsSnapshot;
-use crate::core::flight::limits::FlightLimits;
+use crate::core::flight::limits::{FlightLimits, ZoomControlMode};
 use crate::core::flight::status::FlightWarning;
 
 const DEFAULT_HEADING: [f64; 2] = [0.0, 0.0];
@@ -10,6 +10,21 @@ pub struct MotionState {
     pub heading: [f64; 2],
     pub speed_world_per_sec: f64,
     pub accel_world_per_sec2: f64,
+    /// A glide, coasting on its heading after a drag-pan is released (see
+    /// [`crate::core::flight::glide_motion`]). Flight at zero speed leaves
+    /// the view alone; a glide is the one motion that steers without
+    /// zooming.
+    pub gliding: bool,
+}
+
+impl MotionState {
+    /// True if a flight step would move the view: unpaused, and zooming,
+    /// or gliding on a nonzero heading.
+    #[must_use]
+    pub fn moves_view(&self) -> bool {
+        !self.paused
+            && (self.speed_world_per_sec != 0.0 || (self.gliding && self.heading != DEFAULT_HEADING))
+    }
 }
 
 impl Default for MotionState {
@@ -19,6 +34,7 @@ impl Default for MotionState {
             heading: DEFAULT_HEADING,
             speed_world_per_sec: 0.0,
             accel_world_per_sec2: 0.0,
+            gliding: false,
         }
     }
 }
@@ -53,9 +69,18 @@ pub fn step_motion(
 
     resolve_heading(motion, controls, safe_dt);
 
-    motion.accel_world_per_sec2 =
-        effective_acceleration(controls, limits.base_accel_world_per_sec2);
-    motion.speed_world_per_sec += motion.accel_world_per_sec2 * safe_dt;
+    match limits.zoom_mode {
+        ZoomControlMode::Accelerate => {
+            motion.accel_world_per_sec2 =
+                effective_acceleration(controls, limits.base_accel_world_per_sec2);
+            motion.speed_world_per_sec += motion.accel_world_per_sec2 * safe_dt;
+        }
+        ZoomControlMode::Direct => {
+            motion.accel_world_per_sec2 = 0.0;
+            motion.speed_world_per_sec = axis_from_pair(controls.zoom_in, controls.zoom_out)
+                * limits.direct_zoom_speed_world_per_sec;
+        }
+    }
 
     let max_speed_abs = limits.max_speed_abs_world_per_sec.abs();
     if motion.speed_world_per_sec > max_speed_abs {
@@ -68,7 +93,7 @@ pub fn step_motion(
         report.warning = Some(FlightWarning::SpeedClamped);
     }
 
-    report.view_should_update = motion.speed_world_per_sec != 0.0;
+    report.view_should_update = motion.moves_view();
     report
 }
 
@@ -96,7 +121,7 @@ fn resolve_heading(motion: &mut MotionState, controls: FlightControlsSnapshot, d
 }
 
 const HEADING_RAMP_TIME_CONSTANT: f64 = 0.15;
-const HEADING_ZERO_THRESHOLD: f64 = 0.01;
+pub(crate) const HEADING_ZERO_THRESHOLD: f64 = 0.01;
 
 fn axis_from_pair(positive: bool, negative: bool) -> f64 {
     match (positive, negative) {
@@ -120,7 +145,7 @@ fn effective_acceleration(controls: FlightControlsSnapshot, base_accel: f64) ->
 #[cfg(test)]
 mod tests {
     use super::{MotionState, step_motion};
-    use crate::core::flight::{FlightControlsSnapshot, FlightLimits, FlightWarning};
+    use crate::core::flight::{FlightControlsSnapshot, FlightLimits, FlightWarning, ZoomControlMode};
     use std::f64::consts::FRAC_1_SQRT_2;
 
     const EPSILON: f64 = 1e-12;
@@ -504,6 +529,7 @@ mod tests {
             heading: [1.0, 0.0],
             speed_world_per_sec: 1.0,
             accel_world_per_sec2: 2.0,
+            gliding: false,
         };
         let controls = FlightControlsSnapshot {
             w: true,
@@ -605,4 +631,109 @@ mod tests {
 
         assert!(report.view_should_update);
     }
+
+    #[test]
+    fn report_view_should_update_is_true_only_for_a_glide_at_zero_speed() {
+        let steering = MotionState {
+            heading: [1.0, 0.0],
+            ..MotionState::default()
+        };
+
+        for (gliding, expected) in [(false, false), (true, true)] {
+            let mut motion = MotionState { gliding, ..steering };
+            let report = step_motion(
+                &mut motion,
+                FlightControlsSnapshot::default(),
+                default_limits().dt(),
+                &default_limits(),
+            );
+
+            assert_eq!(motion.speed_world_per_sec, 0.0);
+            assert_eq!(report.view_should_update, expected);
+        }
+    }
+
+    fn direct_limits() -> FlightLimits {
+        FlightLimits {
+            zoom_mode: ZoomControlMode::Direct,
+            ..FlightLimits::default()
+        }
+    }
+
+    #[test]
+    fn direct_zoom_in_reaches_target_rate_in_one_tick() {
+        let mut motion = MotionState::default();
+        let limits = direct_limits();
+        let controls = FlightControlsSnapshot {
+            zoom_in: true,
+            ..FlightControlsSnapshot::default()
+        };
+
+        let report = step_motion(&mut motion, controls, limits.dt(), &limits);
+
+        assert_approx_eq(
+            motion.speed_world_per_sec,
+            limits.direct_zoom_speed_world_per_sec,
+        );
+        assert_approx_eq(motion.accel_world_per_sec2, 0.0);
+        assert!(report.view_should_update);
+    }
+
+    #[test]
+    fn direct_zoom_out_reaches_negative_target_rate_in_one_tick() {
+        let mut motion = MotionState {
+            speed_world_per_sec: 3.0,
+            ..MotionState::default()
+        };
+        let limits = direct_limits();
+        let controls = FlightControlsSnapshot {
+            zoom_out: true,
+            ..FlightControlsSnapshot::default()
+        };
+
+        step_motion(&mut motion, controls, limits.dt(), &limits);
+
+        assert_approx_eq(
+            motion.speed_world_per_sec,
+            -limits.direct_zoom_speed_world_per_sec,
+        );
+    }
+
+    #[test]
+    fn direct_zoom_stops_when_released_and_ignores_acceleration() {
+        let mut motion = MotionState {
+            speed_world_per_sec: 1.0,
+            ..MotionState::default()
+        };
+        let limits = direct_limits();
+        let controls = FlightControlsSnapshot {
+            accelerate: true,
+            ..FlightControlsSnapshot::default()
+        };
+
+        let report = step_motion(&mut motion, controls, limits.dt(), &limits);
+
+        assert_eq!(motion.speed_world_per_sec, 0.0);
+        assert_eq!(motion.accel_world_per_sec2, 0.0);
+        assert!(!report.view_should_update);
+    }
+
+    #[test]
+    fn direct_zoom_rate_is_clamped_to_max_speed() {
+        let mut motion = MotionState::default();
+        let limits = FlightLimits {
+            direct_zoom_speed_world_per_sec: 10.0,
+            max_speed_abs_world_per_sec: 4.0,
+            ..direct_limits()
+        };
+        let controls = FlightControlsSnapshot {
+            zoom_in: true,
+            ..FlightControlsSnapshot::default()
+        };
+
+        let report = step_motion(&mut motion, controls, limits.dt(), &limits);
+
+        assert_approx_eq(motion.speed_world_per_sec, 4.0);
+        assert_eq!(report.warning, Some(FlightWarning::SpeedClamped));
+    }
 }
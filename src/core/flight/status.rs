@@ -6,18 +6,58 @@ pub enum FlightWarning {
     NonFiniteReset,
 }
 
+impl FlightWarning {
+    pub const ALL: [FlightWarning; 4] = [
+        FlightWarning::SpeedClamped,
+        FlightWarning::CenterClamped,
+        FlightWarning::ExtentClamped,
+        FlightWarning::NonFiniteReset,
+    ];
+}
+
+/// How many times each `FlightWarning` has fired since the last reset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FlightWarningCounts {
+    counts: [u32; FlightWarning::ALL.len()],
+}
+
+impl FlightWarningCounts {
+    pub fn record(&mut self, warning: FlightWarning) {
+        let count = &mut self.counts[warning as usize];
+        *count = count.saturating_add(1);
+    }
+
+    #[must_use]
+    pub fn get(&self, warning: FlightWarning) -> u32 {
+        self.counts[warning as usize]
+    }
+
+    /// Every variant with its count, in declaration order.
+    pub fn iter(&self) -> impl Iterator<Item = (FlightWarning, u32)> + '_ {
+        FlightWarning::ALL
+            .into_iter()
+            .map(|warning| (warning, self.get(warning)))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FlightStatus {
     pub paused: bool,
     pub speed: f64,
     pub heading: [f64; 2],
     pub last_warning: Option<FlightWarning>,
+    pub warning_counts: FlightWarningCounts,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct FlightUpdateReport {
     pub clamped: bool,
     pub warning: Option<FlightWarning>,
+    /// The axes, real then imaginary, whose heading should reverse after
+    /// the view bounced off a [`BoundaryBehavior::Bounce`] limit.
+    ///
+    /// [`BoundaryBehavior::Bounce`]: super::BoundaryBehavior::Bounce
+    pub bounced: [bool; 2],
 }
 
 impl Default for FlightStatus {
@@ -27,13 +67,14 @@ impl Default for FlightStatus {
             speed: 0.0,
             heading: [0.0, 0.0],
             last_warning: None,
+            warning_counts: FlightWarningCounts::default(),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{FlightStatus, FlightWarning};
+    use super::{FlightStatus, FlightWarning, FlightWarningCounts};
 
     #[test]
     fn default_status_starts_paused() {
@@ -43,6 +84,7 @@ mod tests {
         assert_eq!(status.speed, 0.0);
         assert_eq!(status.heading, [0.0, 0.0]);
         assert_eq!(status.last_warning, None);
+        assert_eq!(status.warning_counts, FlightWarningCounts::default());
     }
 
     #[test]
@@ -54,4 +96,23 @@ mod tests {
 
         assert_eq!(status.last_warning, Some(FlightWarning::SpeedClamped));
     }
+
+    #[test]
+    fn warning_counts_track_each_variant_separately() {
+        let mut counts = FlightWarningCounts::default();
+
+        counts.record(FlightWarning::ExtentClamped);
+        counts.record(FlightWarning::ExtentClamped);
+        counts.record(FlightWarning::NonFiniteReset);
+
+        assert_eq!(
+            counts.iter().collect::<Vec<_>>(),
+            vec![
+                (FlightWarning::SpeedClamped, 0),
+                (FlightWarning::CenterClamped, 0),
+                (FlightWarning::ExtentClamped, 2),
+                (FlightWarning::NonFiniteReset, 1),
+            ]
+        );
+    }
 }
@@ -0,0 +1,193 @@
+use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::data::complex_rect::ComplexRect;
+use crate::core::data::pixel_rect::PixelRect;
+use crate::core::data::point::Point;
+use crate::core::fractals::burning_ship::errors::burning_ship::BurningShipError;
+use crate::core::fractals::escape_metric::EscapeMetric;
+use crate::core::fractals::smooth_escape::{SmoothEscape, smooth_escape_count};
+use crate::core::util::pixel_to_complex_coords::{
+    PixelToComplexCoordsError, YAxis, check_pixel_steps, pixel_to_complex_coords_with_y_axis,
+};
+
+/// The Burning Ship: `z -> (|re z| + i|im z|)^2 + c` from `z = 0`, with `c`
+/// the pixel's coordinate. Escape counts are `1..=max_iterations` like the
+/// Mandelbrot set's, so it takes the same colour maps.
+///
+/// With the default [`YAxis::ScreenDown`] imaginary grows down the image,
+/// which shows the ship upright as it is usually drawn.
+#[derive(Debug, PartialEq)]
+pub struct BurningShipAlgorithm {
+    pixel_rect: PixelRect,
+    complex_rect: ComplexRect,
+    max_iterations: u32,
+    escape_metric: EscapeMetric,
+    y_axis: YAxis,
+}
+
+impl FractalAlgorithm for BurningShipAlgorithm {
+    type Success = u32;
+    type Failure = PixelToComplexCoordsError;
+
+    fn compute(&self, pixel: Point) -> Result<Self::Success, Self::Failure> {
+        let c = pixel_to_complex_coords_with_y_axis(pixel, self.pixel_rect, self.complex_rect, self.y_axis)?;
+        Ok(self.iterate_point(c.real, c.imag))
+    }
+
+    fn compute_row_segment_into(
+        &self,
+        y: i32,
+        x_start: i32,
+        x_end: i32,
+        output: &mut Vec<Self::Success>,
+    ) -> Result<(), Self::Failure> {
+        check_pixel_steps(self.pixel_rect, self.complex_rect)?;
+        for x in x_start..=x_end {
+            output.push(self.compute(Point { x, y })?);
+        }
+
+        Ok(())
+    }
+
+    fn pixel_rect(&self) -> PixelRect {
+        self.pixel_rect
+    }
+}
+
+impl BurningShipAlgorithm {
+    pub fn new(
+        pixel_rect: PixelRect,
+        complex_rect: ComplexRect,
+        max_iterations: u32,
+    ) -> Result<Self, BurningShipError> {
+        if max_iterations == 0 {
+            return Err(BurningShipError::ZeroMaxIterationsError);
+        }
+
+        Ok(Self {
+            pixel_rect,
+            complex_rect,
+            max_iterations,
+            escape_metric: EscapeMetric::default(),
+            y_axis: YAxis::default(),
+        })
+    }
+
+    /// Selects the escape test; the default `Modulus` is the classic set.
+    #[must_use]
+    pub fn with_escape_metric(mut self, escape_metric: EscapeMetric) -> Self {
+        self.escape_metric = escape_metric;
+        self
+    }
+
+    /// Selects which way imaginary grows down the image; see [`YAxis`].
+    #[must_use]
+    pub fn with_y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    #[must_use]
+    pub fn max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
+
+    #[inline]
+    fn iterate_point(&self, c_real: f64, c_imag: f64) -> u32 {
+        self.escape_orbit(c_real, c_imag)
+            .map_or(self.max_iterations, |(iteration, _)| iteration)
+    }
+
+    /// The iteration the orbit of `c` escaped on and `|z|²` there, or
+    /// `None` if it never did.
+    #[inline]
+    fn escape_orbit(&self, c_real: f64, c_imag: f64) -> Option<(u32, f64)> {
+        let (mut zr, mut zi) = (0.0_f64, 0.0_f64);
+        let (mut zr2, mut zi2) = (0.0_f64, 0.0_f64);
+
+        for iteration in 1..=self.max_iterations {
+            zi = 2.0 * (zr * zi).abs() + c_imag;
+            zr = zr2 - zi2 + c_real;
+            zr2 = zr * zr;
+            zi2 = zi * zi;
+
+            if self.escape_metric.has_escaped(zr, zi, zr2, zi2) {
+                return Some((iteration, zr2 + zi2));
+            }
+        }
+
+        None
+    }
+}
+
+impl SmoothEscape for BurningShipAlgorithm {
+    fn compute_smooth(&self, pixel: Point) -> Result<f64, Self::Failure> {
+        let c = pixel_to_complex_coords_with_y_axis(pixel, self.pixel_rect, self.complex_rect, self.y_axis)?;
+        Ok(smooth_escape_count(self.escape_orbit(c.real, c.imag), self.max_iterations, 2))
+    }
+
+    fn max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::complex::Complex;
+
+    fn algorithm(max_iterations: u32) -> BurningShipAlgorithm {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 10, y: 10 }).unwrap();
+        let complex_rect = ComplexRect::new(
+            Complex { real: -2.0, imag: -2.0 },
+            Complex { real: 2.0, imag: 2.0 },
+        )
+        .unwrap();
+
+        BurningShipAlgorithm::new(pixel_rect, complex_rect, max_iterations).unwrap()
+    }
+
+    #[test]
+    fn max_iterations_must_be_greater_than_zero() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 10, y: 10 }).unwrap();
+        let complex_rect = ComplexRect::new(
+            Complex { real: -2.0, imag: -2.0 },
+            Complex { real: 2.0, imag: 2.0 },
+        )
+        .unwrap();
+
+        assert_eq!(
+            BurningShipAlgorithm::new(pixel_rect, complex_rect, 0),
+            Err(BurningShipError::ZeroMaxIterationsError)
+        );
+    }
+
+    #[test]
+    fn origin_never_escapes_and_far_points_escape_at_once() {
+        let algorithm = algorithm(64);
+
+        assert_eq!(algorithm.iterate_point(0.0, 0.0), 64);
+        assert_eq!(algorithm.iterate_point(3.0, 0.0), 1);
+    }
+
+    #[test]
+    fn folds_before_squaring_unlike_the_mandelbrot_set() {
+        // c = -1 + 1i: the Mandelbrot orbit 0, c, -1 - i, -1 + 3i escapes on
+        // step 3, while taking absolute values gives 0, c, -1 + 3i, which
+        // escapes a step sooner.
+        assert_eq!(algorithm(50).iterate_point(-1.0, 1.0), 2);
+    }
+
+    #[test]
+    fn compute_returns_error_for_pixel_outside_pixel_rect() {
+        let algorithm = algorithm(10);
+        let point = Point { x: 11, y: 0 };
+
+        assert_eq!(
+            algorithm.compute(point),
+            Err(PixelToComplexCoordsError::PointOutsideRect {
+                point,
+                pixel_rect: algorithm.pixel_rect(),
+            })
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    controllers::interactive::data::fractal_config::FractalConfig,
+    core::{
+        actions::generate_pixel_buffer::histogram::ColourSchemeKind,
+        data::{complex::Complex, complex_rect::ComplexRect, pixel_rect::PixelRect},
+        fractals::{
+            burning_ship::algorithm::BurningShipAlgorithm,
+            mandelbrot::colour_mapping::{
+                factory::mandelbrot_colour_map_factory, kinds::MandelbrotColourMapKinds,
+            },
+        },
+    },
+};
+
+const DEFAULT_MAX_ITERATIONS: u32 = 256;
+
+/// The classic view of the small ship on the negative real axis, from
+/// `-1.8 - 0.08i` to `-1.7 + 0i`.
+pub(crate) fn default_region() -> ComplexRect {
+    ComplexRect::new(
+        Complex {
+            real: -1.8,
+            imag: -0.08,
+        },
+        Complex {
+            real: -1.7,
+            imag: 0.0,
+        },
+    )
+    .expect("default fractal region is valid")
+}
+
+/// Burning Ship settings. Its escape counts are coloured with the
+/// Mandelbrot colour maps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BurningShipConfig {
+    pub region: ComplexRect,
+    pub max_iterations: u32,
+    pub colour_map_kind: MandelbrotColourMapKinds,
+    pub contrast_stretch: bool,
+    pub smooth: bool,
+    pub colour_scheme: ColourSchemeKind,
+}
+
+impl Default for BurningShipConfig {
+    fn default() -> Self {
+        Self {
+            region: default_region(),
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            colour_map_kind: MandelbrotColourMapKinds::default(),
+            contrast_stretch: false,
+            smooth: false,
+            colour_scheme: ColourSchemeKind::Linear,
+        }
+    }
+}
+
+impl BurningShipConfig {
+    pub(crate) fn build_render_request(&self, pixel_rect: PixelRect) -> FractalConfig {
+        let colour_map = mandelbrot_colour_map_factory(self.colour_map_kind, self.max_iterations);
+        let algorithm = BurningShipAlgorithm::new(pixel_rect, self.region, self.max_iterations)
+            .expect("burning ship algorithm settings should be valid");
+
+        FractalConfig::BurningShip {
+            colour_map,
+            algorithm,
+            contrast_stretch: self.contrast_stretch,
+            smooth: self.smooth,
+            colour_scheme: self.colour_scheme,
+        }
+    }
+
+    pub fn reset_view(&mut self) {
+        self.region = default_region();
+        self.max_iterations = DEFAULT_MAX_ITERATIONS;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::actions::generate_fractal::generate_fractal_parallel_rayon::generate_fractal_parallel_rayon;
+    use crate::core::data::point::Point;
+    use crate::core::fractals::mandelbrot::colour_mapping::kinds::MandelbrotColourMapKinds;
+
+    #[test]
+    fn default_view_shows_the_ship() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 39, y: 31 }).unwrap();
+        let request = BurningShipConfig::default().build_render_request(pixel_rect);
+
+        let iterations = generate_fractal_parallel_rayon(pixel_rect, request.algorithm()).unwrap();
+
+        // Both the ship itself and the escaping sea around it are in view.
+        assert!(iterations.contains(&DEFAULT_MAX_ITERATIONS));
+        assert!(iterations.iter().any(|&count| count < 10));
+    }
+
+    #[test]
+    fn request_uses_the_chosen_mandelbrot_colour_map() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 7, y: 7 }).unwrap();
+        let config = BurningShipConfig {
+            colour_map_kind: MandelbrotColourMapKinds::FireGradient,
+            ..BurningShipConfig::default()
+        };
+
+        let FractalConfig::BurningShip { colour_map, .. } = config.build_render_request(pixel_rect)
+        else {
+            panic!("expected a Burning Ship request");
+        };
+        assert_eq!(colour_map.kind(), MandelbrotColourMapKinds::FireGradient);
+    }
+}
@@ -0,0 +1,18 @@
+use std::{error::Error, fmt};
+
+#[derive(Debug, PartialEq)]
+pub enum BurningShipError {
+    ZeroMaxIterationsError,
+}
+
+impl fmt::Display for BurningShipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroMaxIterationsError => {
+                write!(f, "Maximum iterations must be greater than zero")
+            }
+        }
+    }
+}
+
+impl Error for BurningShipError {}
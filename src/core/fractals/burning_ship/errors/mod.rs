@@ -0,0 +1 @@
+pub mod burning_ship;
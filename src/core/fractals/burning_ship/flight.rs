@@ -0,0 +1,20 @@
+use crate::core::{
+    data::pixel_rect::PixelRect,
+    flight::{FlightLimits, FlightUpdateReport, MotionState},
+    fractals::{
+        burning_ship::burning_ship_config::{BurningShipConfig, default_region},
+        julia::flight::step_region_in_viewport,
+    },
+};
+
+/// Flies the Burning Ship view the same way as Julia's: both are plain
+/// f64 regions. A view that stops being finite goes back to the ship.
+pub fn step_flight_in_viewport(
+    config: &mut BurningShipConfig,
+    motion: &MotionState,
+    dt: f64,
+    limits: &FlightLimits,
+    viewport: Option<PixelRect>,
+) -> FlightUpdateReport {
+    step_region_in_viewport(&mut config.region, default_region(), motion, dt, limits, viewport)
+}
@@ -0,0 +1,4 @@
+pub mod algorithm;
+pub mod burning_ship_config;
+pub mod errors;
+pub mod flight;
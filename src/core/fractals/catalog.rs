@@ -0,0 +1,63 @@
+use crate::core::fractals::fractal_kinds::FractalKinds;
+use crate::core::fractals::julia::colour_mapping::kinds::JuliaColourMapKinds;
+use crate::core::fractals::mandelbrot::colour_mapping::kinds::MandelbrotColourMapKinds;
+
+/// Every fractal the explorer can render, for building menus or CLI help.
+#[must_use]
+pub fn available_fractals() -> &'static [FractalKinds] {
+    FractalKinds::ALL
+}
+
+/// Display names of the colour maps available for `fractal`. Each name
+/// parses back into that fractal's colour-map kind.
+#[must_use]
+pub fn available_colour_maps(fractal: FractalKinds) -> Vec<&'static str> {
+    match fractal {
+        FractalKinds::Mandelbrot | FractalKinds::BurningShip | FractalKinds::Tricorn => {
+            MandelbrotColourMapKinds::ALL
+                .iter()
+                .map(|kind| kind.display_name())
+                .collect()
+        }
+        FractalKinds::Julia => JuliaColourMapKinds::ALL
+            .iter()
+            .map(|kind| kind.display_name())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fractals_include_mandelbrot() {
+        let names: Vec<_> = available_fractals()
+            .iter()
+            .map(|kind| kind.display_name())
+            .collect();
+
+        assert!(names.contains(&"Mandelbrot"));
+        assert!(names.contains(&"Julia"));
+    }
+
+    #[test]
+    fn every_fractal_has_colour_maps() {
+        for &fractal in available_fractals() {
+            assert!(!available_colour_maps(fractal).is_empty(), "{fractal}");
+        }
+
+        // The blue-white gradient is listed under its display name.
+        assert!(available_colour_maps(FractalKinds::Mandelbrot).contains(&"Ice"));
+    }
+
+    #[test]
+    fn colour_map_names_parse_back() {
+        for name in available_colour_maps(FractalKinds::Mandelbrot) {
+            assert!(name.parse::<MandelbrotColourMapKinds>().is_ok(), "{name}");
+        }
+        for name in available_colour_maps(FractalKinds::Julia) {
+            assert!(name.parse::<JuliaColourMapKinds>().is_ok(), "{name}");
+        }
+    }
+}
@@ -0,0 +1,63 @@
+/// Norm compared against the escape radius of 2 in the iteration loop.
+///
+/// `Modulus` is the usual `|z| > 2` (checked as `|z|² > 4`). The others are
+/// for experimental colouring: `Chebyshev` escapes on `max(|re|, |im|) > 2`,
+/// and `Manhattan` on `|re| + |im| > 2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EscapeMetric {
+    #[default]
+    Modulus,
+    Chebyshev,
+    Manhattan,
+}
+
+impl EscapeMetric {
+    /// `zr2` and `zi2` are the squares of `zr` and `zi`, which the iteration
+    /// loop already has to hand.
+    #[inline]
+    #[must_use]
+    pub fn has_escaped(self, zr: f64, zi: f64, zr2: f64, zi2: f64) -> bool {
+        match self {
+            Self::Modulus => zr2 + zi2 > 4.0,
+            Self::Chebyshev => zr.abs().max(zi.abs()) > 2.0,
+            Self::Manhattan => zr.abs() + zi.abs() > 2.0,
+        }
+    }
+
+    /// True if escaping under this metric implies escaping under
+    /// `Modulus`, so points known never to escape by modulus (such as the
+    /// Mandelbrot cardioid) never escape under it either.
+    #[must_use]
+    pub fn implies_modulus_escape(self) -> bool {
+        matches!(self, Self::Modulus | Self::Chebyshev)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EscapeMetric;
+
+    fn escaped(metric: EscapeMetric, zr: f64, zi: f64) -> bool {
+        metric.has_escaped(zr, zi, zr * zr, zi * zi)
+    }
+
+    #[test]
+    fn metrics_disagree_between_their_boundaries() {
+        // |z| = 2.12, max = 1.5, sum = 3.0
+        assert!(escaped(EscapeMetric::Modulus, 1.5, 1.5));
+        assert!(!escaped(EscapeMetric::Chebyshev, 1.5, 1.5));
+        assert!(escaped(EscapeMetric::Manhattan, 1.5, 1.5));
+
+        // |z| = 1.5, max = 1.2, sum = 2.1
+        assert!(!escaped(EscapeMetric::Modulus, 1.2, -0.9));
+        assert!(!escaped(EscapeMetric::Chebyshev, 1.2, -0.9));
+        assert!(escaped(EscapeMetric::Manhattan, 1.2, -0.9));
+    }
+
+    #[test]
+    fn radius_two_is_not_yet_escaped() {
+        for metric in [EscapeMetric::Modulus, EscapeMetric::Chebyshev, EscapeMetric::Manhattan] {
+            assert!(!escaped(metric, -2.0, 0.0), "{metric:?}");
+        }
+    }
+}
@@ -1,18 +1,66 @@
+use crate::core::fractals::parse_kind::{ParseKindError, parse_kind};
+use std::str::FromStr;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum FractalKinds {
     #[default]
     Mandelbrot,
     Julia,
+    BurningShip,
+    Tricorn,
 }
 
 impl FractalKinds {
-    pub const ALL: &'static [Self] = &[Self::Julia, Self::Mandelbrot];
+    pub const ALL: &'static [Self] = &[Self::Julia, Self::Mandelbrot, Self::BurningShip, Self::Tricorn];
 
     #[must_use]
     pub const fn display_name(self) -> &'static str {
         match self {
             Self::Mandelbrot => "Mandelbrot",
             Self::Julia => "Julia",
+            Self::BurningShip => "Burning Ship",
+            Self::Tricorn => "Tricorn",
         }
     }
 }
+
+impl std::fmt::Display for FractalKinds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str((*self).display_name())
+    }
+}
+
+/// Parses a display name case-insensitively, e.g. from the command line.
+impl FromStr for FractalKinds {
+    type Err = ParseKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_kind(Self::ALL, Self::display_name, "fractal kind", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_variant_round_trips_through_display() {
+        for &kind in FractalKinds::ALL {
+            assert_eq!(kind.to_string().parse::<FractalKinds>(), Ok(kind));
+        }
+    }
+
+    #[test]
+    fn parsing_ignores_case_and_whitespace() {
+        assert_eq!(" JULIA ".parse::<FractalKinds>(), Ok(FractalKinds::Julia));
+        assert_eq!("burning ship".parse::<FractalKinds>(), Ok(FractalKinds::BurningShip));
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        assert!(matches!(
+            "newton".parse::<FractalKinds>(),
+            Err(ParseKindError::Unknown { name, .. }) if name == "newton"
+        ));
+    }
+}
@@ -1,10 +1,13 @@
 use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::data::complex::Complex;
 use crate::core::data::complex_rect::ComplexRect;
 use crate::core::data::pixel_rect::PixelRect;
 use crate::core::data::point::Point;
+use crate::core::fractals::escape_metric::EscapeMetric;
 use crate::core::fractals::julia::errors::julia::JuliaError;
+use crate::core::fractals::smooth_escape::{SmoothEscape, smooth_escape_count};
 use crate::core::util::pixel_to_complex_coords::{
-    PixelToComplexCoordsError, pixel_to_complex_coords,
+    PixelToComplexCoordsError, YAxis, check_pixel_steps, pixel_to_complex_coords_with_y_axis,
 };
 #[cfg(target_arch = "x86")]
 use std::arch::x86::{
@@ -20,14 +23,22 @@ use std::arch::x86_64::{
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 const AVX_LANES: usize = 4;
 
-const JULIA_C_REAL: f64 = -0.7;
-const JULIA_C_IMAG: f64 = 0.27;
+/// The constant added each iteration unless another is chosen: a connected
+/// set with plenty of spiral detail.
+pub const DEFAULT_JULIA_C: Complex = Complex {
+    real: -0.7,
+    imag: 0.27,
+};
 
 #[derive(Debug, PartialEq)]
 pub struct JuliaAlgorithm {
     pub pixel_rect: PixelRect,
     complex_rect: ComplexRect,
     max_iterations: u32,
+    /// The constant of `z -> z^2 + c`, which picks the Julia set drawn.
+    c: Complex,
+    escape_metric: EscapeMetric,
+    y_axis: YAxis,
 }
 
 impl FractalAlgorithm for JuliaAlgorithm {
@@ -35,7 +46,7 @@ impl FractalAlgorithm for JuliaAlgorithm {
     type Failure = PixelToComplexCoordsError;
 
     fn compute(&self, pixel: Point) -> Result<Self::Success, Self::Failure> {
-        let z = pixel_to_complex_coords(pixel, self.pixel_rect, self.complex_rect)?;
+        let z = pixel_to_complex_coords_with_y_axis(pixel, self.pixel_rect, self.complex_rect, self.y_axis)?;
         Ok(self.iterate_point(z.real, z.imag))
     }
 
@@ -64,12 +75,15 @@ impl FractalAlgorithm for JuliaAlgorithm {
             return Ok(());
         }
 
+        check_pixel_steps(self.pixel_rect, self.complex_rect)?;
+
         let real_step = self.complex_rect.width() / (self.pixel_rect.width() - 1) as f64;
         let imag_step = self.complex_rect.height() / (self.pixel_rect.height() - 1) as f64;
         let complex_top_left = self.complex_rect.top_left();
 
         let zr = complex_top_left.real + (x_start - top_left.x) as f64 * real_step;
-        let zi = complex_top_left.imag + (y - top_left.y) as f64 * imag_step;
+        let imag_row = self.y_axis.imag_row(y - top_left.y, self.pixel_rect.height());
+        let zi = complex_top_left.imag + f64::from(imag_row) * imag_step;
 
         let point_count = (x_end - x_start + 1) as usize;
         output.reserve(point_count);
@@ -113,7 +127,8 @@ impl JuliaAlgorithm {
         point_count: usize,
         output: &mut Vec<u32>,
     ) -> bool {
-        if !is_x86_feature_detected!("avx") {
+        // The vector loop only implements the modulus test.
+        if self.escape_metric != EscapeMetric::Modulus || !is_x86_feature_detected!("avx") {
             return false;
         }
 
@@ -154,8 +169,8 @@ impl JuliaAlgorithm {
         let mut results = [self.max_iterations; AVX_LANES];
         let mut active_mask = (1u8 << AVX_LANES) - 1;
 
-        let julia_c_real = _mm256_set1_pd(JULIA_C_REAL);
-        let julia_c_imag = _mm256_set1_pd(JULIA_C_IMAG);
+        let julia_c_real = _mm256_set1_pd(self.c.real);
+        let julia_c_imag = _mm256_set1_pd(self.c.imag);
         let escape_radius_sq = _mm256_set1_pd(4.0);
         let mut zr = unsafe { _mm256_loadu_pd(lane_reals.as_ptr()) };
         let mut zi = _mm256_set1_pd(zi);
@@ -196,50 +211,100 @@ impl JuliaAlgorithm {
     }
 
     #[inline]
-    fn iterate_point(&self, mut zr: f64, mut zi: f64) -> u32 {
+    fn iterate_point(&self, zr: f64, zi: f64) -> u32 {
+        self.escape_orbit(zr, zi)
+            .map_or(self.max_iterations, |(iteration, _)| iteration)
+    }
+
+    /// The iteration the orbit of `z` escaped on and `|z|²` there, or
+    /// `None` if it never did.
+    #[inline]
+    fn escape_orbit(&self, mut zr: f64, mut zi: f64) -> Option<(u32, f64)> {
         let mut zr2 = zr * zr;
         let mut zi2 = zi * zi;
 
         let mut iteration = 1u32;
         while iteration <= self.max_iterations {
-            let zr_next = zr2 - zi2 + JULIA_C_REAL;
-            let zi_next = (zr + zr) * zi + JULIA_C_IMAG;
+            let zr_next = zr2 - zi2 + self.c.real;
+            let zi_next = (zr + zr) * zi + self.c.imag;
             zr = zr_next;
             zi = zi_next;
             zr2 = zr * zr;
             zi2 = zi * zi;
 
-            if zr2 + zi2 > 4.0 {
-                return iteration;
+            if self.escape_metric.has_escaped(zr, zi, zr2, zi2) {
+                return Some((iteration, zr2 + zi2));
             }
 
             iteration += 1;
         }
 
-        self.max_iterations
+        None
     }
 
     pub fn new(
         pixel_rect: PixelRect,
         complex_rect: ComplexRect,
         max_iterations: u32,
+        c: Complex,
     ) -> Result<Self, JuliaError> {
         if max_iterations == 0 {
             return Err(JuliaError::ZeroMaxIterationsError);
         }
 
+        if !(c.real.is_finite() && c.imag.is_finite()) {
+            return Err(JuliaError::NonFiniteConstantError);
+        }
+
         Ok(Self {
             pixel_rect,
             complex_rect,
             max_iterations,
+            c,
+            escape_metric: EscapeMetric::default(),
+            y_axis: YAxis::default(),
         })
     }
+
+    /// Selects the escape test; the default `Modulus` is the classic set.
+    #[must_use]
+    pub fn with_escape_metric(mut self, escape_metric: EscapeMetric) -> Self {
+        self.escape_metric = escape_metric;
+        self
+    }
+
+    /// Selects which way imaginary grows down the image; see [`YAxis`].
+    #[must_use]
+    pub fn with_y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    #[must_use]
+    pub fn max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
+
+    #[must_use]
+    pub fn c(&self) -> Complex {
+        self.c
+    }
+}
+
+impl SmoothEscape for JuliaAlgorithm {
+    fn compute_smooth(&self, pixel: Point) -> Result<f64, Self::Failure> {
+        let z = pixel_to_complex_coords_with_y_axis(pixel, self.pixel_rect, self.complex_rect, self.y_axis)?;
+        Ok(smooth_escape_count(self.escape_orbit(z.real, z.imag), self.max_iterations, 2))
+    }
+
+    fn max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::data::complex::Complex;
     use crate::core::data::complex_rect::ComplexRect;
     use crate::core::data::pixel_rect::PixelRect;
     use crate::core::data::point::Point;
@@ -261,7 +326,7 @@ mod tests {
         )
         .unwrap();
 
-        let algorithm = JuliaAlgorithm::new(pixel_rect, complex_rect, 256);
+        let algorithm = JuliaAlgorithm::new(pixel_rect, complex_rect, 256, DEFAULT_JULIA_C);
 
         assert!(algorithm.is_ok());
     }
@@ -282,7 +347,7 @@ mod tests {
         )
         .unwrap();
 
-        let algorithm = JuliaAlgorithm::new(pixel_rect, complex_rect, 0);
+        let algorithm = JuliaAlgorithm::new(pixel_rect, complex_rect, 0, DEFAULT_JULIA_C);
 
         assert_eq!(
             algorithm,
@@ -306,7 +371,7 @@ mod tests {
         )
         .unwrap();
 
-        let algorithm = JuliaAlgorithm::new(pixel_rect, complex_rect, 10).unwrap();
+        let algorithm = JuliaAlgorithm::new(pixel_rect, complex_rect, 10, DEFAULT_JULIA_C).unwrap();
         let point = Point { x: 11, y: 0 };
         let result = algorithm.compute(point);
 
@@ -330,7 +395,7 @@ mod tests {
             },
         )
         .unwrap();
-        let algorithm = JuliaAlgorithm::new(pixel_rect, complex_rect, 512).unwrap();
+        let algorithm = JuliaAlgorithm::new(pixel_rect, complex_rect, 512, DEFAULT_JULIA_C).unwrap();
 
         let y = 6;
         let x_start = 3;
@@ -361,7 +426,7 @@ mod tests {
             },
         )
         .unwrap();
-        let algorithm = JuliaAlgorithm::new(pixel_rect, complex_rect, 128).unwrap();
+        let algorithm = JuliaAlgorithm::new(pixel_rect, complex_rect, 128, DEFAULT_JULIA_C).unwrap();
 
         let y = 4;
         let x_start = 2;
@@ -396,7 +461,7 @@ mod tests {
             },
         )
         .unwrap();
-        let algorithm = JuliaAlgorithm::new(pixel_rect, complex_rect, 256).unwrap();
+        let algorithm = JuliaAlgorithm::new(pixel_rect, complex_rect, 256, DEFAULT_JULIA_C).unwrap();
         let top_left = pixel_rect.top_left();
         let y = 6;
         let x_start = 1;
@@ -417,4 +482,65 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn chebyshev_escapes_later_than_modulus_for_contrived_point() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 1, y: 1 }).unwrap();
+        let complex_rect = ComplexRect::new(
+            Complex {
+                real: -2.0,
+                imag: -2.0,
+            },
+            Complex {
+                real: 2.0,
+                imag: 2.0,
+            },
+        )
+        .unwrap();
+        let modulus = JuliaAlgorithm::new(pixel_rect, complex_rect, 50, DEFAULT_JULIA_C).unwrap();
+        let chebyshev = JuliaAlgorithm::new(pixel_rect, complex_rect, 50, DEFAULT_JULIA_C)
+            .unwrap()
+            .with_escape_metric(EscapeMetric::Chebyshev);
+        let manhattan = JuliaAlgorithm::new(pixel_rect, complex_rect, 50, DEFAULT_JULIA_C)
+            .unwrap()
+            .with_escape_metric(EscapeMetric::Manhattan);
+
+        assert_eq!(modulus.iterate_point(0.1, 1.0), 2);
+        assert_eq!(chebyshev.iterate_point(0.1, 1.0), 3);
+        assert_eq!(manhattan.iterate_point(0.1, 1.0), 1);
+    }
+
+    #[test]
+    fn rejects_a_non_finite_constant() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 10, y: 10 }).unwrap();
+        let complex_rect = ComplexRect::new(
+            Complex { real: -1.0, imag: -1.0 },
+            Complex { real: 1.0, imag: 1.0 },
+        )
+        .unwrap();
+        let c = Complex { real: f64::NAN, imag: 0.0 };
+
+        assert_eq!(
+            JuliaAlgorithm::new(pixel_rect, complex_rect, 10, c),
+            Err(JuliaError::NonFiniteConstantError)
+        );
+    }
+
+    #[test]
+    fn the_constant_selects_the_set() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 1, y: 1 }).unwrap();
+        let complex_rect = ComplexRect::new(
+            Complex { real: -1.0, imag: -1.0 },
+            Complex { real: 1.0, imag: 1.0 },
+        )
+        .unwrap();
+        let inside = JuliaAlgorithm::new(pixel_rect, complex_rect, 100, Complex { real: 0.0, imag: 0.0 }).unwrap();
+        let outside = JuliaAlgorithm::new(pixel_rect, complex_rect, 100, Complex { real: 1.0, imag: 0.0 }).unwrap();
+
+        // With c = 0 the set is the unit disc, so the origin never escapes;
+        // with c = 1 the origin's orbit 0, 1, 2, 5 escapes on the third step.
+        assert_eq!(inside.iterate_point(0.0, 0.0), 100);
+        assert_eq!(outside.iterate_point(0.0, 0.0), 3);
+        assert_eq!(outside.c(), Complex { real: 1.0, imag: 0.0 });
+    }
 }
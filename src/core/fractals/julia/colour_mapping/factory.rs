@@ -1,4 +1,4 @@
-use crate::core::fractals::julia::colour_mapping::{kinds::JuliaColourMapKinds, map::JuliaColourMap, maps::{ice::JuliaIceColourMap, fire::JuliaFireColourMap}};
+use crate::core::fractals::julia::colour_mapping::{kinds::JuliaColourMapKinds, map::JuliaColourMap, maps::{ice::JuliaIceColourMap, fire::JuliaFireColourMap, viridis::JuliaViridisColourMap}};
 
 #[must_use]
 pub fn julia_colour_map_factory(
@@ -12,6 +12,9 @@ pub fn julia_colour_map_factory(
         JuliaColourMapKinds::BlueWhiteGradient => {
             Box::new(JuliaIceColourMap::new(max_iterations))
         }
+        JuliaColourMapKinds::Viridis => {
+            Box::new(JuliaViridisColourMap::new(max_iterations))
+        }
     }
 }
 
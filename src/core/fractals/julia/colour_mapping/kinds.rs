@@ -1,19 +1,24 @@
+use crate::core::fractals::parse_kind::{ParseKindError, parse_kind};
+use std::str::FromStr;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[derive(Default)]
 pub enum JuliaColourMapKinds {
     BlueWhiteGradient,
     #[default]
     FireGradient,
+    Viridis,
 }
 
 impl JuliaColourMapKinds {
-    pub const ALL: &'static [Self] = &[Self::FireGradient, Self::BlueWhiteGradient];
+    pub const ALL: &'static [Self] = &[Self::FireGradient, Self::BlueWhiteGradient, Self::Viridis];
 
     #[must_use]
     pub const fn display_name(self) -> &'static str {
         match self {
             Self::FireGradient => "Fire",
             Self::BlueWhiteGradient => "Ice",
+            Self::Viridis => "Viridis",
         }
     }
 }
@@ -23,3 +28,37 @@ impl std::fmt::Display for JuliaColourMapKinds {
         f.write_str((*self).display_name())
     }
 }
+
+/// Parses a display name case-insensitively, e.g. from the command line.
+impl FromStr for JuliaColourMapKinds {
+    type Err = ParseKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_kind(Self::ALL, Self::display_name, "Julia colour map", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_variant_round_trips_through_display() {
+        for &kind in JuliaColourMapKinds::ALL {
+            assert_eq!(kind.to_string().parse::<JuliaColourMapKinds>(), Ok(kind));
+        }
+    }
+
+    #[test]
+    fn parsing_ignores_case_and_whitespace() {
+        assert_eq!("FIRE".parse::<JuliaColourMapKinds>(), Ok(JuliaColourMapKinds::FireGradient));
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        assert!(matches!(
+            "rainbow".parse::<JuliaColourMapKinds>(),
+            Err(ParseKindError::Unknown { name, .. }) if name == "rainbow"
+        ));
+    }
+}
@@ -1,2 +1,3 @@
 pub mod ice;
 pub mod fire;
+pub mod viridis;
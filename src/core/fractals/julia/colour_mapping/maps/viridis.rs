@@ -0,0 +1,103 @@
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{ColourMap, ColourMapError};
+use crate::core::data::colour::Colour;
+use crate::core::fractals::julia::colour_mapping::errors::JuliaColourMapErrors;
+use crate::core::fractals::julia::colour_mapping::kinds::JuliaColourMapKinds;
+use crate::core::fractals::julia::colour_mapping::map::JuliaColourMap;
+use crate::core::util::iteration_colour_lut::IterationColourLut;
+use crate::core::util::viridis::viridis;
+
+/// Escape counts along the viridis palette, a colour-blind-safe gradient
+/// whose lightness rises evenly from dark purple to yellow; see
+/// [`viridis`]. Interior points are black.
+#[derive(Debug)]
+pub struct JuliaViridisColourMap {
+    max_iterations: u32,
+    lut: IterationColourLut,
+}
+
+impl ColourMap<u32> for JuliaViridisColourMap {
+    fn map(&self, iterations: u32) -> Result<Colour, ColourMapError> {
+        if iterations > self.max_iterations {
+            return Err(Box::new(JuliaColourMapErrors::IterationsExceedMax {
+                iterations,
+                max_iterations: self.max_iterations,
+            }));
+        }
+
+        if let Some(colour) = self.lut.get(iterations) {
+            return Ok(colour);
+        }
+
+        debug_assert!(
+            false,
+            "LUT invariant broken: iterations <= max_iterations but LUT had no entry"
+        );
+        Err(Box::new(JuliaColourMapErrors::LutInvariantBroken {
+            iterations,
+            max_iterations: self.max_iterations,
+        }))
+    }
+
+    fn display_name(&self) -> &str {
+        self.kind().display_name()
+    }
+}
+
+impl JuliaColourMap for JuliaViridisColourMap {
+    fn kind(&self) -> JuliaColourMapKinds {
+        JuliaColourMapKinds::Viridis
+    }
+}
+
+impl JuliaViridisColourMap {
+    #[must_use]
+    pub fn new(max_iterations: u32) -> Self {
+        let lut = IterationColourLut::new(max_iterations, viridis);
+        Self {
+            max_iterations,
+            lut,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channels(colour: Colour) -> (u8, u8, u8) {
+        (colour.r, colour.g, colour.b)
+    }
+
+    #[test]
+    fn escape_counts_sample_the_palette() {
+        let mapper = JuliaViridisColourMap::new(8);
+
+        for iterations in 0..8 {
+            let expected = viridis(f64::from(iterations) / 8.0);
+            assert_eq!(channels(mapper.map(iterations).unwrap()), channels(expected));
+        }
+        assert_eq!(channels(mapper.map(0).unwrap()), (68, 1, 84));
+        assert_eq!(channels(mapper.map(4).unwrap()), (33, 144, 140));
+    }
+
+    #[test]
+    fn interior_is_black() {
+        let mapper = JuliaViridisColourMap::new(100);
+
+        assert_eq!(channels(mapper.map(100).unwrap()), (0, 0, 0));
+    }
+
+    #[test]
+    fn iterations_above_max_are_an_error() {
+        let mapper = JuliaViridisColourMap::new(100);
+        let err = mapper.map(101).expect_err("expected error when iterations exceed max");
+
+        assert!(matches!(
+            err.downcast_ref::<JuliaColourMapErrors>(),
+            Some(JuliaColourMapErrors::IterationsExceedMax {
+                iterations: 101,
+                max_iterations: 100
+            })
+        ));
+    }
+}
@@ -3,6 +3,7 @@ use std::{error::Error, fmt};
 #[derive(Debug, PartialEq)]
 pub enum JuliaError {
     ZeroMaxIterationsError,
+    NonFiniteConstantError,
 }
 
 impl fmt::Display for JuliaError {
@@ -11,6 +12,9 @@ impl fmt::Display for JuliaError {
             Self::ZeroMaxIterationsError => {
                 write!(f, "Maximum iterations must be greater than zero")
             }
+            Self::NonFiniteConstantError => {
+                write!(f, "The Julia constant must be finite")
+            }
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::core::{
     data::{complex::Complex, complex_rect::ComplexRect, pixel_rect::PixelRect},
-    flight::{FlightLimits, FlightUpdateReport, FlightWarning, MotionState},
+    flight::{BoundaryBehavior, FlightLimits, FlightUpdateReport, FlightWarning, MotionState},
     fractals::julia::julia_config::{JuliaConfig, default_region},
 };
 
@@ -19,39 +19,58 @@ pub fn step_flight_in_viewport(
     dt: f64,
     limits: &FlightLimits,
     viewport: Option<PixelRect>,
+) -> FlightUpdateReport {
+    step_region_in_viewport(&mut config.region, default_region(), motion, dt, limits, viewport)
+}
+
+/// Flies any f64 view: zooms and steers `region`, clamping it to
+/// `limits`, and puts it back to `default_region` if it stops being
+/// finite. Burning Ship and Tricorn fly their views through this too.
+pub(crate) fn step_region_in_viewport(
+    region: &mut ComplexRect,
+    default_region: ComplexRect,
+    motion: &MotionState,
+    dt: f64,
+    limits: &FlightLimits,
+    viewport: Option<PixelRect>,
 ) -> FlightUpdateReport {
     let mut report = FlightUpdateReport::default();
 
-    if motion.paused || motion.speed_world_per_sec == 0.0 {
+    if !motion.moves_view() {
         return report;
     }
 
     let scale = limits.zoom_base.powf(-motion.speed_world_per_sec * dt);
 
-    if let Some(region) =
-        scaled_region_about_focal(&config.region, scale, motion.heading, limits.steer_strength, dt)
+    if let Some(scaled) =
+        scaled_region_about_focal(region, scale, motion.heading, limits.steer_strength, dt)
     {
-        config.region = region;
-    } else {
-        reset_non_finite(config, &mut report);
+        *region = scaled;
+    } else if !zoom_underflowed(region, scale, limits.steer_strength) {
+        reset_non_finite(region, default_region, &mut report);
         return report;
     }
+    // On underflow the region is kept as is; the minimum-extent clamp below
+    // then widens it instead of the whole view being reset.
 
     let max_center_abs = limits.max_center_abs.abs();
-    let width = config.region.width();
-    let height = config.region.height();
-    let (center_real, center_imag) = region_center(&config.region);
-    let clamped_center_real = center_real.clamp(-max_center_abs, max_center_abs);
-    let clamped_center_imag = center_imag.clamp(-max_center_abs, max_center_abs);
-
-    if clamped_center_real != center_real || clamped_center_imag != center_imag {
-        if let Some(region) =
-            rebuild_region(clamped_center_real, clamped_center_imag, width, height)
+    let width = region.width();
+    let height = region.height();
+    let (center_real, center_imag) = region_center(region);
+    let (confined_center_real, bounced_real) = limits.boundary.confine(center_real, max_center_abs);
+    let (confined_center_imag, bounced_imag) = limits.boundary.confine(center_imag, max_center_abs);
+
+    if confined_center_real != center_real || confined_center_imag != center_imag {
+        if let Some(rebuilt) =
+            rebuild_region(confined_center_real, confined_center_imag, width, height)
         {
-            config.region = region;
-            mark_warning(&mut report, FlightWarning::CenterClamped);
+            *region = rebuilt;
+            report.bounced = [bounced_real, bounced_imag];
+            if limits.boundary == BoundaryBehavior::Clamp {
+                mark_warning(&mut report, FlightWarning::CenterClamped);
+            }
         } else {
-            reset_non_finite(config, &mut report);
+            reset_non_finite(region, default_region, &mut report);
             return report;
         }
     }
@@ -61,7 +80,7 @@ pub fn step_flight_in_viewport(
     let (mut min_width, mut min_height) = (min_extent, min_extent);
 
     if let Some(pixel_rect) = viewport {
-        let (real_scale, imag_scale) = axis_coordinate_scales(&config.region);
+        let (real_scale, imag_scale) = axis_coordinate_scales(region);
         min_width = min_width.max(
             limits.precision_min_axis_extent(real_scale, pixel_rect.width()),
         );
@@ -73,8 +92,8 @@ pub fn step_flight_in_viewport(
     min_width = min_width.min(max_extent);
     min_height = min_height.min(max_extent);
 
-    let mut width = config.region.width();
-    let mut height = config.region.height();
+    let width = region.width();
+    let height = region.height();
 
     let scale = if width < min_width || height < min_height {
         let width_scale = if width < min_width {
@@ -105,21 +124,26 @@ pub fn step_flight_in_viewport(
     };
 
     if scale != 1.0 {
-        width *= scale;
-        height *= scale;
-        let (center_real, center_imag) = region_center(&config.region);
+        let (mut width, mut height) = (width * scale, height * scale);
+        if !width.is_finite() || !height.is_finite() {
+            // A near-zero extent makes the ratio overflow; clamp each axis
+            // straight to its minimum instead of scaling by infinity.
+            width = region.width().max(min_width);
+            height = region.height().max(min_height);
+        }
+        let (center_real, center_imag) = region_center(region);
 
-        if let Some(region) = rebuild_region(center_real, center_imag, width, height) {
-            config.region = region;
+        if let Some(rebuilt) = rebuild_region(center_real, center_imag, width, height) {
+            *region = rebuilt;
             mark_warning(&mut report, FlightWarning::ExtentClamped);
         } else {
-            reset_non_finite(config, &mut report);
+            reset_non_finite(region, default_region, &mut report);
             return report;
         }
     }
 
-    if !region_is_finite(&config.region) {
-        reset_non_finite(config, &mut report);
+    if !region_is_finite(region) {
+        reset_non_finite(region, default_region, &mut report);
     }
 
     report
@@ -151,6 +175,16 @@ fn scaled_region_about_focal(
     rebuild_region(new_center_real, new_center_imag, new_width, new_height)
 }
 
+/// True when zooming a finite `region` in by `scale` failed only because
+/// the scaled extent is too small for `f64` to represent.
+fn zoom_underflowed(region: &ComplexRect, scale: f64, steer_strength: f64) -> bool {
+    scale.is_finite()
+        && scale > 0.0
+        && scale < 1.0
+        && steer_strength.is_finite()
+        && region_is_finite(region)
+}
+
 fn rebuild_region(
     center_real: f64,
     center_imag: f64,
@@ -220,8 +254,12 @@ fn mark_warning(report: &mut FlightUpdateReport, warning: FlightWarning) {
     report.warning = Some(warning);
 }
 
-fn reset_non_finite(config: &mut JuliaConfig, report: &mut FlightUpdateReport) {
-    config.region = default_region();
+fn reset_non_finite(
+    region: &mut ComplexRect,
+    default_region: ComplexRect,
+    report: &mut FlightUpdateReport,
+) {
+    *region = default_region;
     mark_warning(report, FlightWarning::NonFiniteReset);
 }
 
@@ -230,7 +268,7 @@ mod tests {
     use super::{axis_coordinate_scales, region_center, step_flight, step_flight_in_viewport};
     use crate::core::{
         data::{complex::Complex, complex_rect::ComplexRect, pixel_rect::PixelRect, point::Point},
-        flight::{FlightLimits, FlightWarning, MotionState},
+        flight::{BoundaryBehavior, FlightLimits, FlightWarning, MotionState},
         fractals::julia::julia_config::{JuliaConfig, default_region},
     };
 
@@ -341,6 +379,26 @@ mod tests {
         assert_approx_eq(config.region.height(), 2.0 * scale);
     }
 
+    #[test]
+    fn gliding_at_zero_speed_pans_without_zooming() {
+        let limits = FlightLimits::default();
+        let mut config = JuliaConfig {
+            region: rect(-2.0, -1.0, 2.0, 1.0),
+            ..JuliaConfig::default()
+        };
+        let motion = MotionState {
+            gliding: true,
+            ..motion([1.0, 0.0], 0.0)
+        };
+        let dt = 1.0;
+
+        step_flight(&mut config, &motion, dt, &limits);
+
+        assert_region_center(&config.region, dt * limits.steer_strength * 4.0, 0.0);
+        assert_approx_eq(config.region.width(), 4.0);
+        assert_approx_eq(config.region.height(), 2.0);
+    }
+
     #[test]
     fn negative_speed_pans_same_direction_as_heading() {
         let limits = FlightLimits::default();
@@ -471,6 +529,47 @@ mod tests {
         assert_region_center(&both_config.region, 0.2, 0.2);
     }
 
+    fn boundary_limits(boundary: BoundaryBehavior) -> FlightLimits {
+        FlightLimits {
+            max_center_abs: 0.8,
+            steer_strength: 0.5,
+            boundary,
+            ..FlightLimits::default()
+        }
+    }
+
+    #[test]
+    fn wrap_moves_the_center_to_the_opposite_side() {
+        let limits = boundary_limits(BoundaryBehavior::Wrap);
+        let mut config = JuliaConfig {
+            region: rect(-1.0, -1.0, 1.0, 1.0),
+            ..JuliaConfig::default()
+        };
+
+        // Pans 1.0 right, 0.2 past the limit.
+        let report = step_flight(&mut config, &motion([1.0, 0.0], 1.0), 1.0, &limits);
+
+        assert_region_center(&config.region, -0.6, 0.0);
+        assert_approx_eq(config.region.width(), 1.0);
+        assert_eq!(report.warning, None);
+        assert_eq!(report.bounced, [false, false]);
+    }
+
+    #[test]
+    fn bounce_reflects_the_center_and_reverses_heading() {
+        let limits = boundary_limits(BoundaryBehavior::Bounce);
+        let mut config = JuliaConfig {
+            region: rect(-1.0, -1.0, 1.0, 1.0),
+            ..JuliaConfig::default()
+        };
+
+        let report = step_flight(&mut config, &motion([1.0, 1.0], 1.0), 1.0, &limits);
+
+        assert_region_center(&config.region, 0.6, 0.6);
+        assert_eq!(report.warning, None);
+        assert_eq!(report.bounced, [true, true]);
+    }
+
     #[test]
     fn extent_clamp_scales_up_and_down_with_aspect_ratio_preserved() {
         let up_limits = FlightLimits {
@@ -557,4 +656,48 @@ mod tests {
         assert!(config.region.width() >= min_width);
         assert!(config.region.height() >= min_height);
     }
+
+    #[test]
+    fn near_zero_extent_clamps_to_min_instead_of_resetting() {
+        let limits = FlightLimits {
+            min_region_extent: 1e-3,
+            steer_strength: 0.0,
+            ..FlightLimits::default()
+        };
+        // min_region_extent / width overflows to infinity.
+        let mut config = JuliaConfig {
+            region: rect(0.0, 0.0, 1e-320, 1e-320),
+            ..JuliaConfig::default()
+        };
+        let motion = motion([0.0, 0.0], 1.0);
+
+        let report = step_flight(&mut config, &motion, limits.dt(), &limits);
+
+        assert_eq!(report.warning, Some(FlightWarning::ExtentClamped));
+        assert_ne!(config.region, default_region());
+        assert_approx_eq(config.region.width(), 1e-3);
+        assert_approx_eq(config.region.height(), 1e-3);
+    }
+
+    #[test]
+    fn zoom_step_that_underflows_the_extent_clamps_to_min_instead_of_resetting() {
+        let limits = FlightLimits {
+            min_region_extent: 1e-3,
+            steer_strength: 0.0,
+            ..FlightLimits::default()
+        };
+        // Two subnormal ULPs wide: halving it leaves no representable
+        // half-extent, so the zoomed region cannot be built.
+        let mut config = JuliaConfig {
+            region: rect(0.0, 0.0, 1e-323, 1e-323),
+            ..JuliaConfig::default()
+        };
+        let motion = motion([0.0, 0.0], 1.0);
+
+        let report = step_flight(&mut config, &motion, 1.0, &limits);
+
+        assert_eq!(report.warning, Some(FlightWarning::ExtentClamped));
+        assert_approx_eq(config.region.width(), 1e-3);
+        assert_approx_eq(config.region.height(), 1e-3);
+    }
 }
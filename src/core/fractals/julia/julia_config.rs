@@ -1,11 +1,14 @@
 use crate::{
     controllers::interactive::data::fractal_config::FractalConfig,
     core::{
+        actions::generate_pixel_buffer::histogram::ColourSchemeKind,
         data::{complex::Complex, complex_rect::ComplexRect, pixel_rect::PixelRect},
         fractals::julia::{
-            algorithm::JuliaAlgorithm,
+            algorithm::{DEFAULT_JULIA_C, JuliaAlgorithm},
             colour_mapping::{factory::julia_colour_map_factory, kinds::JuliaColourMapKinds},
+            split_view::{SplitView, SplitViewAlgorithm},
         },
+        fractals::mandelbrot::algorithm::MandelbrotAlgorithm,
     },
 };
 
@@ -29,7 +32,12 @@ pub(crate) fn default_region() -> ComplexRect {
 pub struct JuliaConfig {
     pub region: ComplexRect,
     pub max_iterations: u32,
+    /// The constant of `z -> z^2 + c`; see [`DEFAULT_JULIA_C`].
+    pub c: Complex,
     pub colour_map_kind: JuliaColourMapKinds,
+    pub contrast_stretch: bool,
+    pub smooth: bool,
+    pub colour_scheme: ColourSchemeKind,
 }
 
 impl Default for JuliaConfig {
@@ -37,7 +45,11 @@ impl Default for JuliaConfig {
         Self {
             region: default_region(),
             max_iterations: DEFAULT_MAX_ITERATIONS,
+            c: DEFAULT_JULIA_C,
             colour_map_kind: JuliaColourMapKinds::default(),
+            contrast_stretch: false,
+            smooth: false,
+            colour_scheme: ColourSchemeKind::Linear,
         }
     }
 }
@@ -45,17 +57,46 @@ impl Default for JuliaConfig {
 impl JuliaConfig {
     pub(crate) fn build_render_request(&self, pixel_rect: PixelRect) -> FractalConfig {
         let colour_map = julia_colour_map_factory(self.colour_map_kind, self.max_iterations);
-        let algorithm = JuliaAlgorithm::new(pixel_rect, self.region, self.max_iterations)
+        let algorithm = JuliaAlgorithm::new(pixel_rect, self.region, self.max_iterations, self.c)
             .expect("julia algorithm settings should be valid");
 
         FractalConfig::Julia {
             colour_map,
             algorithm,
+            contrast_stretch: self.contrast_stretch,
+            smooth: self.smooth,
+            colour_scheme: self.colour_scheme,
+        }
+    }
+
+    /// A [`SplitView`] request: the Mandelbrot set over `mandelbrot_region`
+    /// in the left half of `pixel_rect`, this Julia set in the right half.
+    /// A viewport too narrow to split gets the plain Julia request.
+    pub(crate) fn build_split_view_request(
+        &self,
+        pixel_rect: PixelRect,
+        mandelbrot_region: ComplexRect,
+    ) -> FractalConfig {
+        let Ok(split) = SplitView::new(pixel_rect) else {
+            return self.build_render_request(pixel_rect);
+        };
+        let colour_map = julia_colour_map_factory(self.colour_map_kind, self.max_iterations);
+        let mandelbrot =
+            MandelbrotAlgorithm::new(split.mandelbrot_pane(), mandelbrot_region, self.max_iterations)
+                .expect("mandelbrot algorithm settings should be valid");
+        let julia =
+            JuliaAlgorithm::new(split.julia_pane(), self.region, self.max_iterations, self.c)
+                .expect("julia algorithm settings should be valid");
+
+        FractalConfig::JuliaSplitView {
+            colour_map,
+            algorithm: SplitViewAlgorithm::new(split, mandelbrot, julia),
         }
     }
 
     pub fn reset_view(&mut self) {
         self.region = default_region();
         self.max_iterations = DEFAULT_MAX_ITERATIONS;
+        self.c = DEFAULT_JULIA_C;
     }
 }
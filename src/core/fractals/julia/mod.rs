@@ -4,3 +4,4 @@ pub mod errors;
 pub mod flight;
 pub mod julia_config;
 pub mod params;
+pub mod split_view;
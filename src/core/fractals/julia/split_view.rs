@@ -0,0 +1,276 @@
+use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::data::complex::Complex;
+use crate::core::data::complex_rect::ComplexRect;
+use crate::core::data::pixel_rect::{PixelRect, PixelRectError};
+use crate::core::data::point::Point;
+use crate::core::fractals::julia::algorithm::JuliaAlgorithm;
+use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
+use crate::core::util::pixel_to_complex_coords::{PixelToComplexCoordsError, pixel_to_complex_coords};
+
+/// Side-by-side layout of a viewport: the Mandelbrot set in the left pane,
+/// where each point is a Julia constant `c`, and the Julia set for the
+/// chosen `c` in the right pane.
+///
+/// The left pane gets `width / 2` columns and the right pane the rest, so
+/// an odd column goes to the Julia side.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SplitView {
+    mandelbrot_pane: PixelRect,
+    julia_pane: PixelRect,
+}
+
+impl SplitView {
+    /// Splits `viewport` in two; it must be at least two pixels wide.
+    pub fn new(viewport: PixelRect) -> Result<Self, PixelRectError> {
+        let top_left = viewport.top_left();
+        let bottom_right = viewport.bottom_right();
+        let split_x = top_left.x + (viewport.width() / 2) as i32;
+
+        let mandelbrot_pane = PixelRect::new(
+            top_left,
+            Point {
+                x: split_x - 1,
+                y: bottom_right.y,
+            },
+        )?;
+        let julia_pane = PixelRect::new(
+            Point {
+                x: split_x,
+                y: top_left.y,
+            },
+            bottom_right,
+        )?;
+
+        Ok(Self {
+            mandelbrot_pane,
+            julia_pane,
+        })
+    }
+
+    #[must_use]
+    pub fn mandelbrot_pane(&self) -> PixelRect {
+        self.mandelbrot_pane
+    }
+
+    #[must_use]
+    pub fn julia_pane(&self) -> PixelRect {
+        self.julia_pane
+    }
+
+    /// The Julia constant picked by a click at `point`, where `region` is
+    /// the Mandelbrot pane's view. `None` outside the Mandelbrot pane.
+    #[must_use]
+    pub fn julia_c_at(&self, point: Point, region: ComplexRect) -> Option<Complex> {
+        pixel_to_complex_coords(point, self.mandelbrot_pane, region).ok()
+    }
+
+    /// The Mandelbrot pane pixel nearest to `c`, for drawing the marker.
+    /// `None` when `c` is outside `region` (or not finite).
+    #[must_use]
+    pub fn marker_pixel(&self, c: Complex, region: ComplexRect) -> Option<Point> {
+        if !region.contains_point(c) {
+            return None;
+        }
+
+        let pane = self.mandelbrot_pane;
+        let offset = |fraction: f64, pixels: u32| {
+            (fraction * f64::from(pixels.saturating_sub(1))).round() as i32
+        };
+        let top_left = region.top_left();
+
+        Some(Point {
+            x: pane.top_left().x
+                + offset((c.real - top_left.real) / region.width(), pane.width()),
+            y: pane.top_left().y
+                + offset((c.imag - top_left.imag) / region.height(), pane.height()),
+        })
+    }
+}
+
+/// Renders a [`SplitView`] as one frame: each pixel comes from the
+/// Mandelbrot or the Julia algorithm, whichever pane it falls in. Both
+/// must have been built over their pane's pixel rect.
+#[derive(Debug, PartialEq)]
+pub struct SplitViewAlgorithm {
+    split: SplitView,
+    mandelbrot: MandelbrotAlgorithm,
+    julia: JuliaAlgorithm,
+}
+
+impl SplitViewAlgorithm {
+    #[must_use]
+    pub fn new(split: SplitView, mandelbrot: MandelbrotAlgorithm, julia: JuliaAlgorithm) -> Self {
+        Self {
+            split,
+            mandelbrot,
+            julia,
+        }
+    }
+
+    #[must_use]
+    pub fn split(&self) -> SplitView {
+        self.split
+    }
+
+    #[must_use]
+    pub fn max_iterations(&self) -> u32 {
+        self.julia.max_iterations()
+    }
+}
+
+impl FractalAlgorithm for SplitViewAlgorithm {
+    type Success = u32;
+    type Failure = PixelToComplexCoordsError;
+
+    fn compute(&self, pixel: Point) -> Result<Self::Success, Self::Failure> {
+        if pixel.x < self.split.julia_pane.top_left().x {
+            self.mandelbrot.compute(pixel)
+        } else {
+            self.julia.compute(pixel)
+        }
+    }
+
+    fn compute_row_segment_into(
+        &self,
+        y: i32,
+        x_start: i32,
+        x_end: i32,
+        output: &mut Vec<Self::Success>,
+    ) -> Result<(), Self::Failure> {
+        let split_x = self.split.julia_pane.top_left().x;
+
+        if x_start < split_x {
+            self.mandelbrot
+                .compute_row_segment_into(y, x_start, x_end.min(split_x - 1), output)?;
+        }
+        if x_end >= split_x {
+            self.julia
+                .compute_row_segment_into(y, x_start.max(split_x), x_end, output)?;
+        }
+
+        Ok(())
+    }
+
+    fn pixel_rect(&self) -> PixelRect {
+        PixelRect::new(
+            self.split.mandelbrot_pane.top_left(),
+            self.split.julia_pane.bottom_right(),
+        )
+        .expect("the panes of a split view tile a valid rect")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewport(width: i32, height: i32) -> PixelRect {
+        PixelRect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: width - 1,
+                y: height - 1,
+            },
+        )
+        .unwrap()
+    }
+
+    fn region() -> ComplexRect {
+        ComplexRect::new(
+            Complex {
+                real: -2.0,
+                imag: -1.5,
+            },
+            Complex {
+                real: 1.0,
+                imag: 1.5,
+            },
+        )
+        .unwrap()
+    }
+
+    fn rect(x0: i32, y0: i32, x1: i32, y1: i32) -> PixelRect {
+        PixelRect::new(Point { x: x0, y: y0 }, Point { x: x1, y: y1 }).unwrap()
+    }
+
+    #[test]
+    fn splits_the_viewport_with_the_odd_column_on_the_julia_side() {
+        let split = SplitView::new(viewport(9, 4)).unwrap();
+
+        assert_eq!(split.mandelbrot_pane(), rect(0, 0, 3, 3));
+        assert_eq!(split.julia_pane(), rect(4, 0, 8, 3));
+        assert!(SplitView::new(viewport(1, 4)).is_err());
+    }
+
+    #[test]
+    fn click_in_mandelbrot_pane_maps_to_julia_c() {
+        // 31 columns per pane over a width of 3: 0.1 per column.
+        let split = SplitView::new(viewport(62, 31)).unwrap();
+
+        let c = split.julia_c_at(Point { x: 15, y: 20 }, region()).unwrap();
+
+        assert!((c.real - -0.5).abs() < 1e-12);
+        assert!((c.imag - 0.5).abs() < 1e-12);
+        assert_eq!(
+            split.julia_c_at(Point { x: 0, y: 0 }, region()),
+            Some(region().top_left())
+        );
+    }
+
+    #[test]
+    fn click_in_julia_pane_is_ignored() {
+        let split = SplitView::new(viewport(62, 31)).unwrap();
+
+        assert_eq!(split.julia_c_at(Point { x: 31, y: 10 }, region()), None);
+    }
+
+    #[test]
+    fn marker_pixel_inverts_the_click_mapping() {
+        let split = SplitView::new(viewport(62, 31)).unwrap();
+        let point = Point { x: 7, y: 24 };
+
+        let c = split.julia_c_at(point, region()).unwrap();
+
+        assert_eq!(split.marker_pixel(c, region()), Some(point));
+        assert_eq!(
+            split.marker_pixel(Complex { real: -0.7, imag: 0.1 }, region()),
+            Some(Point { x: 13, y: 16 })
+        );
+    }
+
+    #[test]
+    fn algorithm_takes_each_pane_from_its_own_fractal() {
+        let split = SplitView::new(viewport(62, 31)).unwrap();
+        let c = Complex { real: -0.7, imag: 0.27 };
+        let mandelbrot = MandelbrotAlgorithm::new(split.mandelbrot_pane(), region(), 64).unwrap();
+        let julia = JuliaAlgorithm::new(split.julia_pane(), region(), 64, c).unwrap();
+        let expected: Vec<u32> = (0..62)
+            .map(|x| {
+                let pixel = Point { x, y: 12 };
+                if x < 31 {
+                    mandelbrot.compute(pixel).unwrap()
+                } else {
+                    julia.compute(pixel).unwrap()
+                }
+            })
+            .collect();
+        let algorithm = SplitViewAlgorithm::new(split, mandelbrot, julia);
+
+        let mut row = Vec::new();
+        algorithm.compute_row_segment_into(12, 0, 61, &mut row).unwrap();
+
+        assert_eq!(row, expected);
+        assert_eq!(algorithm.pixel_rect(), viewport(62, 31));
+    }
+
+    #[test]
+    fn marker_outside_the_region_is_hidden() {
+        let split = SplitView::new(viewport(62, 31)).unwrap();
+
+        assert_eq!(split.marker_pixel(Complex { real: 1.5, imag: 0.0 }, region()), None);
+        assert_eq!(
+            split.marker_pixel(Complex { real: f64::NAN, imag: 0.0 }, region()),
+            None
+        );
+    }
+}
@@ -1,30 +1,84 @@
 use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::data::complex::Complex;
 use crate::core::data::complex_rect::ComplexRect;
 use crate::core::data::pixel_rect::PixelRect;
 use crate::core::data::point::Point;
+use crate::core::fractals::escape_metric::EscapeMetric;
 use crate::core::fractals::mandelbrot::errors::mandelbrot::MandelbrotError;
+use crate::core::fractals::mandelbrot::interior::{IterationSample, iterate_detecting_cycles};
+use crate::core::fractals::optimization_flags::OptimizationFlags;
+use crate::core::fractals::precision::Precision;
+use crate::core::fractals::smooth_escape::{SmoothEscape, smooth_escape_count};
 use crate::core::util::pixel_to_complex_coords::{
-    PixelToComplexCoordsError, pixel_to_complex_coords,
+    PixelToComplexCoordsError, YAxis, check_pixel_steps, pixel_to_complex_coords_with_y_axis,
 };
 #[cfg(target_arch = "x86")]
 use std::arch::x86::{
-    _CMP_GT_OQ, _mm256_add_pd, _mm256_cmp_pd, _mm256_loadu_pd, _mm256_movemask_pd,
-    _mm256_mul_pd, _mm256_set1_pd, _mm256_setzero_pd, _mm256_sub_pd,
+    _CMP_EQ_OQ, _CMP_GT_OQ, _mm256_add_pd, _mm256_and_pd, _mm256_cmp_pd, _mm256_loadu_pd,
+    _mm256_movemask_pd, _mm256_mul_pd, _mm256_set1_pd, _mm256_setzero_pd, _mm256_sub_pd,
 };
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::{
-    _CMP_GT_OQ, _mm256_add_pd, _mm256_cmp_pd, _mm256_loadu_pd, _mm256_movemask_pd,
-    _mm256_mul_pd, _mm256_set1_pd, _mm256_setzero_pd, _mm256_sub_pd,
+    _CMP_EQ_OQ, _CMP_GT_OQ, _mm256_add_pd, _mm256_and_pd, _mm256_cmp_pd, _mm256_loadu_pd,
+    _mm256_movemask_pd, _mm256_mul_pd, _mm256_set1_pd, _mm256_setzero_pd, _mm256_sub_pd,
 };
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 const AVX_LANES: usize = 4;
 
-#[derive(Debug, PartialEq)]
+/// The exponent of the classic set, `z² + c`.
+pub const DEFAULT_POWER: u32 = 2;
+
+/// The highest exponent [`MandelbrotAlgorithm::with_power`] accepts.
+pub const MAX_POWER: u32 = 8;
+
+/// Brent-style checkpoint for [`OptimizationFlags::PERIODICITY`]: the
+/// checkpoint moves forward after windows of doubling length, so any cycle
+/// is caught within a few of its periods. Only an exact return counts,
+/// which means the orbit repeats forever without escaping, so stopping
+/// there cannot change the result.
+struct ExactCycleCheck {
+    checkpoint: (f64, f64),
+    checkpoint_at: u32,
+    window: u32,
+}
+
+impl ExactCycleCheck {
+    /// Starts at the orbit's origin, `z = 0` before the first iteration.
+    fn new() -> Self {
+        Self {
+            checkpoint: (0.0, 0.0),
+            checkpoint_at: 0,
+            window: 1,
+        }
+    }
+
+    /// True if `z`, reached on `iteration`, is the checkpoint exactly.
+    #[inline]
+    fn repeats(&mut self, z: (f64, f64), iteration: u32) -> bool {
+        if z == self.checkpoint {
+            return true;
+        }
+
+        if iteration - self.checkpoint_at == self.window {
+            self.checkpoint = z;
+            self.checkpoint_at = iteration;
+            self.window = self.window.saturating_mul(2);
+        }
+        false
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct MandelbrotAlgorithm {
     pub pixel_rect: PixelRect,
     complex_rect: ComplexRect,
     max_iterations: u32,
+    power: u32,
+    optimizations: OptimizationFlags,
+    escape_metric: EscapeMetric,
+    precision: Precision,
+    y_axis: YAxis,
 }
 
 impl FractalAlgorithm for MandelbrotAlgorithm {
@@ -32,7 +86,7 @@ impl FractalAlgorithm for MandelbrotAlgorithm {
     type Failure = PixelToComplexCoordsError;
 
     fn compute(&self, pixel: Point) -> Result<Self::Success, Self::Failure> {
-        let c = pixel_to_complex_coords(pixel, self.pixel_rect, self.complex_rect)?;
+        let c = pixel_to_complex_coords_with_y_axis(pixel, self.pixel_rect, self.complex_rect, self.y_axis)?;
         Ok(self.iterate_point(c.real, c.imag))
     }
 
@@ -61,12 +115,13 @@ impl FractalAlgorithm for MandelbrotAlgorithm {
             return Ok(());
         }
 
+        check_pixel_steps(self.pixel_rect, self.complex_rect)?;
+
         let real_step = self.complex_rect.width() / (self.pixel_rect.width() - 1) as f64;
-        let imag_step = self.complex_rect.height() / (self.pixel_rect.height() - 1) as f64;
         let complex_top_left = self.complex_rect.top_left();
 
         let c_real = complex_top_left.real + (x_start - top_left.x) as f64 * real_step;
-        let c_imag = complex_top_left.imag + (y - top_left.y) as f64 * imag_step;
+        let c_imag = self.row_imag(y);
 
         let point_count = (x_end - x_start + 1) as usize;
         output.reserve(point_count);
@@ -83,9 +138,53 @@ impl FractalAlgorithm for MandelbrotAlgorithm {
     fn pixel_rect(&self) -> PixelRect {
         self.pixel_rect
     }
+
+    /// With [`OptimizationFlags::SYMMETRY`], a row above the real axis is
+    /// the mirror of the row the same distance below it: conjugate `c`
+    /// gives the conjugate orbit, bit for bit, under every power and
+    /// escape metric. Rows are only paired when their imaginary parts are
+    /// exact negations, so the copy is identical to computing the row.
+    fn mirror_row(&self, y: i32) -> Option<i32> {
+        let top = self.pixel_rect.top_left().y;
+        let height = self.pixel_rect.height();
+        if !self.optimizations.contains(OptimizationFlags::SYMMETRY)
+            || y < top
+            || y > self.pixel_rect.bottom_right().y
+            || check_pixel_steps(self.pixel_rect, self.complex_rect).is_err()
+        {
+            return None;
+        }
+
+        let imag = self.row_imag(y);
+        if imag <= 0.0 {
+            return None;
+        }
+
+        // `imag_row` is its own inverse, so it maps the mirror's row of
+        // the complex rect back to a pixel row.
+        let imag_step = self.complex_rect.height() / (height - 1) as f64;
+        let mirror_imag_row =
+            self.y_axis.imag_row(y - top, height) - (2.0 * imag / imag_step).round() as i32;
+        if mirror_imag_row < 0 {
+            return None;
+        }
+        let mirror = top + self.y_axis.imag_row(mirror_imag_row, height);
+
+        (self.row_imag(mirror) == -imag).then_some(mirror)
+    }
 }
 
 impl MandelbrotAlgorithm {
+    /// The imaginary part of `c` along pixel row `y`, as the row loop
+    /// computes it.
+    #[inline]
+    fn row_imag(&self, y: i32) -> f64 {
+        let imag_step = self.complex_rect.height() / (self.pixel_rect.height() - 1) as f64;
+        let imag_row = self.y_axis.imag_row(y - self.pixel_rect.top_left().y, self.pixel_rect.height());
+
+        self.complex_rect.top_left().imag + f64::from(imag_row) * imag_step
+    }
+
     #[inline]
     fn append_row_segment_scalar(
         &self,
@@ -110,7 +209,12 @@ impl MandelbrotAlgorithm {
         point_count: usize,
         output: &mut Vec<u32>,
     ) -> bool {
-        if !is_x86_feature_detected!("avx") {
+        // The vector loop only implements the quadratic modulus test in f64.
+        if self.power != DEFAULT_POWER
+            || self.escape_metric != EscapeMetric::Modulus
+            || self.precision != Precision::F64
+            || !is_x86_feature_detected!("avx")
+        {
             return false;
         }
 
@@ -159,7 +263,7 @@ impl MandelbrotAlgorithm {
         let mut active_mask = 0u8;
 
         for (lane, &c_real) in lane_reals.iter().enumerate() {
-            if !Self::in_main_cardioid(c_real, c_imag) && !Self::in_period2_bulb(c_real, c_imag) {
+            if !self.is_known_interior(c_real, c_imag) {
                 active_mask |= 1 << lane;
             }
         }
@@ -176,6 +280,14 @@ impl MandelbrotAlgorithm {
         let mut zr2 = _mm256_setzero_pd();
         let mut zi2 = _mm256_setzero_pd();
 
+        // `ExactCycleCheck` per lane; the windows line up since every lane
+        // is on the same iteration.
+        let periodicity = self.optimizations.contains(OptimizationFlags::PERIODICITY);
+        let mut checkpoint_r = _mm256_setzero_pd();
+        let mut checkpoint_i = _mm256_setzero_pd();
+        let mut checkpoint_at = 0u32;
+        let mut window = 1u32;
+
         for iteration in 1..=self.max_iterations {
             let zr_next = _mm256_add_pd(_mm256_sub_pd(zr2, zi2), c_real_vec);
             let zi_next = _mm256_add_pd(_mm256_mul_pd(_mm256_add_pd(zr, zr), zi), c_imag_vec);
@@ -190,17 +302,31 @@ impl MandelbrotAlgorithm {
                     as u8;
             let newly_escaped = escaped_mask & active_mask;
 
-            if newly_escaped == 0 {
-                continue;
+            if newly_escaped != 0 {
+                for lane in 0..AVX_LANES {
+                    if (newly_escaped & (1 << lane)) != 0 {
+                        results[lane] = iteration;
+                    }
+                }
+                active_mask &= !escaped_mask;
             }
 
-            for lane in 0..AVX_LANES {
-                if (newly_escaped & (1 << lane)) != 0 {
-                    results[lane] = iteration;
+            if periodicity {
+                let repeated = _mm256_and_pd(
+                    _mm256_cmp_pd(zr, checkpoint_r, _CMP_EQ_OQ),
+                    _mm256_cmp_pd(zi, checkpoint_i, _CMP_EQ_OQ),
+                );
+                // Cycling lanes keep `max_iterations`.
+                active_mask &= !(_mm256_movemask_pd(repeated) as u8);
+
+                if iteration - checkpoint_at == window {
+                    checkpoint_r = zr;
+                    checkpoint_i = zi;
+                    checkpoint_at = iteration;
+                    window = window.saturating_mul(2);
                 }
             }
 
-            active_mask &= !escaped_mask;
             if active_mask == 0 {
                 break;
             }
@@ -211,14 +337,35 @@ impl MandelbrotAlgorithm {
 
     #[inline]
     fn iterate_point(&self, c_real: f64, c_imag: f64) -> u32 {
-        if Self::in_main_cardioid(c_real, c_imag) || Self::in_period2_bulb(c_real, c_imag) {
+        if self.is_known_interior(c_real, c_imag) {
             return self.max_iterations;
         }
 
+        if self.power == DEFAULT_POWER && self.precision == Precision::F32 {
+            return self.iterate_point_f32(c_real as f32, c_imag as f32);
+        }
+
+        self.escape_orbit(c_real, c_imag)
+            .map_or(self.max_iterations, |(iteration, _)| iteration)
+    }
+
+    /// Iterates `c` in f64 without the interior shortcut, returning the
+    /// iteration it escaped on and `|z|²` there, or `None` if it never did.
+    #[inline]
+    fn escape_orbit(&self, c_real: f64, c_imag: f64) -> Option<(u32, f64)> {
+        if self.power != DEFAULT_POWER {
+            return self.escape_orbit_multibrot(Complex {
+                real: c_real,
+                imag: c_imag,
+            });
+        }
+
         let mut zr = 0.0f64;
         let mut zi = 0.0f64;
         let mut zr2 = 0.0f64;
         let mut zi2 = 0.0f64;
+        let periodicity = self.optimizations.contains(OptimizationFlags::PERIODICITY);
+        let mut cycle_check = ExactCycleCheck::new();
 
         let mut iteration = 1u32;
         while iteration <= self.max_iterations {
@@ -229,16 +376,86 @@ impl MandelbrotAlgorithm {
             zr2 = zr * zr;
             zi2 = zi * zi;
 
-            if zr2 + zi2 > 4.0 {
-                return iteration;
+            if self.escape_metric.has_escaped(zr, zi, zr2, zi2) {
+                return Some((iteration, zr2 + zi2));
+            }
+            if periodicity && cycle_check.repeats((zr, zi), iteration) {
+                return None;
             }
 
             iteration += 1;
         }
 
+        None
+    }
+
+    /// `iterate_point` in single precision. The escape test widens the
+    /// values to f64, which is exact, so only the orbit itself is rounded
+    /// to f32.
+    fn iterate_point_f32(&self, c_real: f32, c_imag: f32) -> u32 {
+        let mut zr = 0.0f32;
+        let mut zi = 0.0f32;
+        let mut zr2 = 0.0f32;
+        let mut zi2 = 0.0f32;
+
+        for iteration in 1..=self.max_iterations {
+            let zr_next = zr2 - zi2 + c_real;
+            let zi_next = (zr + zr) * zi + c_imag;
+            zr = zr_next;
+            zi = zi_next;
+            zr2 = zr * zr;
+            zi2 = zi * zi;
+
+            if self.escape_metric.has_escaped(
+                f64::from(zr),
+                f64::from(zi),
+                f64::from(zr2),
+                f64::from(zi2),
+            ) {
+                return iteration;
+            }
+        }
+
         self.max_iterations
     }
 
+    /// `escape_orbit` for `z^power + c` with a power other than 2, raising
+    /// `z` by repeated multiplication.
+    fn escape_orbit_multibrot(&self, c: Complex) -> Option<(u32, f64)> {
+        let mut z = Complex {
+            real: 0.0,
+            imag: 0.0,
+        };
+        let periodicity = self.optimizations.contains(OptimizationFlags::PERIODICITY);
+        let mut cycle_check = ExactCycleCheck::new();
+
+        for iteration in 1..=self.max_iterations {
+            z = z.powi(self.power) + c;
+
+            let (real_sq, imag_sq) = (z.real * z.real, z.imag * z.imag);
+            if self.escape_metric.has_escaped(z.real, z.imag, real_sq, imag_sq) {
+                return Some((iteration, real_sq + imag_sq));
+            }
+            if periodicity && cycle_check.repeats((z.real, z.imag), iteration) {
+                return None;
+            }
+        }
+
+        None
+    }
+
+    /// True if `c` can skip iteration because it lies in the main cardioid or
+    /// period-2 bulb. Always false with [`OptimizationFlags::CARDIOID`]
+    /// cleared, for powers other than 2, whose sets have neither, or with an
+    /// escape metric that can escape where the modulus does not.
+    #[inline]
+    fn is_known_interior(&self, c_real: f64, c_imag: f64) -> bool {
+        self.optimizations.contains(OptimizationFlags::CARDIOID)
+            && self.power == DEFAULT_POWER
+            && self.escape_metric.implies_modulus_escape()
+            && (Self::in_main_cardioid(c_real, c_imag) || Self::in_period2_bulb(c_real, c_imag))
+    }
+
     /// Returns true if c lies inside the main cardioid of the Mandelbrot set.
     fn in_main_cardioid(c_real: f64, c_imag: f64) -> bool {
         let q = (c_real - 0.25) * (c_real - 0.25) + c_imag * c_imag;
@@ -263,8 +480,165 @@ impl MandelbrotAlgorithm {
             pixel_rect,
             complex_rect,
             max_iterations,
+            power: DEFAULT_POWER,
+            optimizations: OptimizationFlags::default(),
+            escape_metric: EscapeMetric::default(),
+            precision: Precision::default(),
+            y_axis: YAxis::default(),
         })
     }
+
+    /// Enables or disables the cardioid/bulb interior shortcut. Disabled,
+    /// every pixel is iterated to escape or `max_iterations`: a brute-force
+    /// reference for checking the shortcut, with identical coordinates.
+    #[must_use]
+    pub fn with_interior_checks(mut self, enabled: bool) -> Self {
+        self.optimizations = self.optimizations.with(OptimizationFlags::CARDIOID, enabled);
+        self
+    }
+
+    /// Selects which shortcuts to take; see [`OptimizationFlags`].
+    /// `CARDIOID` is the same switch as
+    /// [`with_interior_checks`](Self::with_interior_checks); `PERIODICITY`
+    /// applies to every path but the single-precision one, and `SYMMETRY`
+    /// to the row generators (see [`FractalAlgorithm::mirror_row`]).
+    #[must_use]
+    pub fn with_optimizations(mut self, optimizations: OptimizationFlags) -> Self {
+        self.optimizations = optimizations;
+        self
+    }
+
+    #[must_use]
+    pub fn optimizations(&self) -> OptimizationFlags {
+        self.optimizations
+    }
+
+    /// Iterates `z^power + c`: the Mandelbrot set for [`DEFAULT_POWER`], a
+    /// Multibrot set above it. The SIMD path and cardioid shortcut only
+    /// apply to the Mandelbrot set. Powers run from 2 to [`MAX_POWER`].
+    pub fn with_power(mut self, power: u32) -> Result<Self, MandelbrotError> {
+        if power < DEFAULT_POWER {
+            return Err(MandelbrotError::PowerBelowTwoError);
+        }
+        if power > MAX_POWER {
+            return Err(MandelbrotError::PowerAboveEightError);
+        }
+
+        self.power = power;
+        Ok(self)
+    }
+
+    /// Selects the escape test; the default `Modulus` is the classic set.
+    #[must_use]
+    pub fn with_escape_metric(mut self, escape_metric: EscapeMetric) -> Self {
+        self.escape_metric = escape_metric;
+        self
+    }
+
+    /// Selects the floating-point type of the iteration loop; see
+    /// [`Precision`]. `F32` always takes the scalar path, and only applies
+    /// to power 2.
+    #[must_use]
+    pub fn with_precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    #[must_use]
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// Selects which way imaginary grows down the image; see [`YAxis`].
+    #[must_use]
+    pub fn with_y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    #[must_use]
+    pub fn max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
+
+    #[must_use]
+    pub fn power(&self) -> u32 {
+        self.power
+    }
+
+    #[must_use]
+    pub fn complex_rect(&self) -> ComplexRect {
+        self.complex_rect
+    }
+
+    #[must_use]
+    pub fn escape_metric(&self) -> EscapeMetric {
+        self.escape_metric
+    }
+
+    #[must_use]
+    pub fn y_axis(&self) -> YAxis {
+        self.y_axis
+    }
+
+    /// Like `compute`, but with cycle detection; see
+    /// [`iterate_detecting_cycles`]. Detection only knows `z² + c`, so other
+    /// powers report plain counts with no interior signal.
+    pub fn compute_detecting_cycles(
+        &self,
+        pixel: Point,
+    ) -> Result<IterationSample, PixelToComplexCoordsError> {
+        let c = pixel_to_complex_coords_with_y_axis(pixel, self.pixel_rect, self.complex_rect, self.y_axis)?;
+
+        if self.power != DEFAULT_POWER {
+            return Ok(IterationSample {
+                iterations: self.iterate_point(c.real, c.imag),
+                interior: None,
+            });
+        }
+
+        Ok(iterate_detecting_cycles(c.real, c.imag, self.max_iterations, self.escape_metric))
+    }
+}
+
+impl SmoothEscape for MandelbrotAlgorithm {
+    /// Always iterates in f64, whatever the [`Precision`].
+    fn compute_smooth(&self, pixel: Point) -> Result<f64, Self::Failure> {
+        let c = pixel_to_complex_coords_with_y_axis(pixel, self.pixel_rect, self.complex_rect, self.y_axis)?;
+        let escape = if self.is_known_interior(c.real, c.imag) {
+            None
+        } else {
+            self.escape_orbit(c.real, c.imag)
+        };
+
+        Ok(smooth_escape_count(escape, self.max_iterations, self.power))
+    }
+
+    fn max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
+}
+
+#[cfg(test)]
+impl MandelbrotAlgorithm {
+    /// The whole set, `-2.5 - i` to `1 + i`, on a `width`x`height` image:
+    /// the fixture for tests that want both interior and escaping pixels.
+    pub(crate) fn overview(width: i32, height: i32, max_iterations: u32) -> Self {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: width - 1, y: height - 1 }).unwrap();
+        let complex_rect = ComplexRect::new(
+            Complex {
+                real: -2.5,
+                imag: -1.0,
+            },
+            Complex {
+                real: 1.0,
+                imag: 1.0,
+            },
+        )
+        .unwrap();
+
+        Self::new(pixel_rect, complex_rect, max_iterations).unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -321,6 +695,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn power_must_be_at_least_two() {
+        for power in [0, 1] {
+            assert_eq!(
+                unit_algorithm().with_power(power),
+                Err(MandelbrotError::PowerBelowTwoError)
+            );
+        }
+        assert_eq!(unit_algorithm().with_power(8).unwrap().power(), 8);
+    }
+
+    #[test]
+    fn power_must_be_at_most_eight() {
+        for power in [MAX_POWER + 1, u32::MAX] {
+            assert_eq!(
+                unit_algorithm().with_power(power),
+                Err(MandelbrotError::PowerAboveEightError)
+            );
+        }
+    }
+
     #[test]
     fn compute_returns_error_for_pixel_outside_pixel_rect() {
         let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 10, y: 10 }).unwrap();
@@ -523,4 +918,342 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    fn unit_algorithm() -> MandelbrotAlgorithm {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 1, y: 1 }).unwrap();
+        let complex_rect = ComplexRect::new(
+            Complex {
+                real: -2.0,
+                imag: -2.0,
+            },
+            Complex {
+                real: 2.0,
+                imag: 2.0,
+            },
+        )
+        .unwrap();
+
+        MandelbrotAlgorithm::new(pixel_rect, complex_rect, 50).unwrap()
+    }
+
+    #[test]
+    fn chebyshev_escapes_later_than_modulus_for_contrived_point() {
+        let modulus = unit_algorithm();
+        let chebyshev = unit_algorithm().with_escape_metric(EscapeMetric::Chebyshev);
+        let manhattan = unit_algorithm().with_escape_metric(EscapeMetric::Manhattan);
+
+        // z1 = c has |z| ~ 2.12 but max(|re|, |im|) = 1.5.
+        assert_eq!(modulus.iterate_point(1.5, 1.5), 1);
+        assert_eq!(chebyshev.iterate_point(1.5, 1.5), 2);
+
+        // z1 = c has |z| = 1.5 but |re| + |im| = 2.1.
+        assert_eq!(modulus.iterate_point(1.2, 0.9), 2);
+        assert_eq!(manhattan.iterate_point(1.2, 0.9), 1);
+    }
+
+    #[test]
+    fn non_modulus_metrics_skip_the_vector_loop() {
+        let chebyshev = unit_algorithm().with_escape_metric(EscapeMetric::Chebyshev);
+        let mut output = Vec::new();
+
+        assert!(!chebyshev.append_row_segment_avx(-2.0, 0.0, 0.5, 8, &mut output));
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn row_segment_reports_precision_exhausted_for_underflowing_step() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 1000, y: 3 }).unwrap();
+        // 1e-306 over 1000 steps is subnormal.
+        let complex_rect = ComplexRect::new(
+            Complex {
+                real: 0.0,
+                imag: 0.0,
+            },
+            Complex {
+                real: 1e-306,
+                imag: 1e-306,
+            },
+        )
+        .unwrap();
+        let algorithm = MandelbrotAlgorithm::new(pixel_rect, complex_rect, 10).unwrap();
+
+        let mut output = Vec::new();
+        let result = algorithm.compute_row_segment_into(0, 0, 1000, &mut output);
+
+        assert!(matches!(
+            result,
+            Err(PixelToComplexCoordsError::PrecisionExhausted { .. })
+        ));
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn math_up_mirrors_the_image_vertically() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 15, y: 10 }).unwrap();
+        // Off-centre vertically so the mirror is not a symmetry of the set.
+        let complex_rect = ComplexRect::new(
+            Complex {
+                real: -2.0,
+                imag: -0.4,
+            },
+            Complex {
+                real: 0.5,
+                imag: 1.2,
+            },
+        )
+        .unwrap();
+        let down = MandelbrotAlgorithm::new(pixel_rect, complex_rect, 64).unwrap();
+        let up = down.clone().with_y_axis(YAxis::MathUp);
+
+        let rows = |algorithm: &MandelbrotAlgorithm| -> Vec<Vec<u32>> {
+            (0..=10)
+                .map(|y| {
+                    let mut row = Vec::new();
+                    algorithm.compute_row_segment_into(y, 0, 15, &mut row).unwrap();
+                    row
+                })
+                .collect()
+        };
+
+        let mut mirrored = rows(&down);
+        assert_ne!(mirrored, rows(&up));
+        mirrored.reverse();
+        assert_eq!(mirrored, rows(&up));
+
+        for y in 0..=10 {
+            assert_eq!(
+                up.compute(Point { x: 3, y }).unwrap(),
+                down.compute(Point { x: 3, y: 10 - y }).unwrap()
+            );
+        }
+    }
+
+    fn overview_algorithm(max_iterations: u32) -> MandelbrotAlgorithm {
+        MandelbrotAlgorithm::overview(70, 40, max_iterations)
+    }
+
+    fn rows(algorithm: &MandelbrotAlgorithm) -> Vec<u32> {
+        let pixel_rect = algorithm.pixel_rect;
+        let mut output = Vec::new();
+        for y in 0..pixel_rect.height() as i32 {
+            algorithm
+                .compute_row_segment_into(y, 0, pixel_rect.width() as i32 - 1, &mut output)
+                .unwrap();
+        }
+        output
+    }
+
+    #[test]
+    fn cardioid_flag_off_gives_identical_output_for_interior_points() {
+        let max_iterations = 200;
+        let optimized = overview_algorithm(max_iterations);
+        let brute_force = optimized
+            .clone()
+            .with_optimizations(OptimizationFlags::ALL - OptimizationFlags::CARDIOID);
+        assert!(optimized.optimizations().contains(OptimizationFlags::CARDIOID));
+        assert!(!brute_force.optimizations().contains(OptimizationFlags::CARDIOID));
+
+        let optimized_rows = rows(&optimized);
+        assert!(optimized_rows.iter().filter(|&&i| i == max_iterations).count() > 100);
+        assert_eq!(rows(&brute_force), optimized_rows);
+
+        // The shortcut's own regions, through the scalar path too.
+        for (real, imag) in [(0.0, 0.0), (-0.1, 0.3), (0.2, 0.0), (-1.0, 0.0), (-1.1, 0.1)] {
+            assert!(MandelbrotAlgorithm::in_main_cardioid(real, imag)
+                || MandelbrotAlgorithm::in_period2_bulb(real, imag));
+            assert_eq!(brute_force.iterate_point(real, imag), max_iterations);
+            assert_eq!(optimized.iterate_point(real, imag), max_iterations);
+        }
+    }
+
+    #[test]
+    fn periodicity_flag_off_gives_identical_output() {
+        let max_iterations = 500;
+        let checked = overview_algorithm(max_iterations).with_interior_checks(false);
+        let unchecked = checked.clone().with_optimizations(OptimizationFlags::NONE);
+
+        assert_eq!(rows(&unchecked), rows(&checked));
+
+        // The period-3 bulb, outside the cardioid shortcut, through the
+        // scalar path and for a cubic too.
+        assert_eq!(checked.iterate_point(-0.12, 0.75), max_iterations);
+        let cubic = checked.with_power(3).unwrap();
+        let cubic_unchecked = cubic.clone().with_optimizations(OptimizationFlags::NONE);
+        assert_eq!(rows(&cubic_unchecked), rows(&cubic));
+    }
+
+    fn render_both_ways(algorithm: &MandelbrotAlgorithm) -> (Vec<u32>, Vec<u32>) {
+        use crate::core::actions::generate_fractal::generate_fractal_parallel_rayon::generate_fractal_parallel_rayon;
+
+        let unmirrored = algorithm
+            .clone()
+            .with_optimizations(algorithm.optimizations() - OptimizationFlags::SYMMETRY);
+        (
+            generate_fractal_parallel_rayon(algorithm.pixel_rect, algorithm).unwrap(),
+            generate_fractal_parallel_rayon(algorithm.pixel_rect, &unmirrored).unwrap(),
+        )
+    }
+
+    #[test]
+    fn symmetry_pairs_rows_whose_imaginary_parts_negate() {
+        // 33 rows over [-1, 1] puts a row on the axis and steps by 1/16.
+        let algorithm = MandelbrotAlgorithm::overview(70, 33, 100);
+        assert!(algorithm.optimizations().contains(OptimizationFlags::SYMMETRY));
+
+        assert_eq!(algorithm.mirror_row(32), Some(0));
+        assert_eq!(algorithm.mirror_row(20), Some(12));
+        assert_eq!(algorithm.mirror_row(16), None);
+        assert_eq!(algorithm.mirror_row(0), None);
+        assert_eq!(algorithm.mirror_row(33), None);
+
+        let up = algorithm.clone().with_y_axis(YAxis::MathUp);
+        assert_eq!(up.mirror_row(0), Some(32));
+
+        let off = algorithm.with_optimizations(OptimizationFlags::ALL - OptimizationFlags::SYMMETRY);
+        assert_eq!(off.mirror_row(32), None);
+    }
+
+    #[test]
+    fn symmetry_flag_off_gives_identical_output() {
+        let overview = MandelbrotAlgorithm::overview(70, 33, 200);
+        let (mirrored, computed) = render_both_ways(&overview);
+        assert_eq!(mirrored, computed);
+
+        let (mirrored, computed) = render_both_ways(&overview.clone().with_y_axis(YAxis::MathUp));
+        assert_eq!(mirrored, computed);
+
+        let (mirrored, computed) = render_both_ways(
+            &overview
+                .clone()
+                .with_power(3)
+                .unwrap()
+                .with_escape_metric(EscapeMetric::Chebyshev),
+        );
+        assert_eq!(mirrored, computed);
+
+        // Off-centre, so only the rows within 0.5 of the axis have a mirror.
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 59, y: 28 }).unwrap();
+        let complex_rect = ComplexRect::new(
+            Complex { real: -2.0, imag: -0.5 },
+            Complex { real: 0.6, imag: 1.25 },
+        )
+        .unwrap();
+        let skewed = MandelbrotAlgorithm::new(pixel_rect, complex_rect, 200).unwrap();
+        assert_eq!((0..29).filter(|&y| skewed.mirror_row(y).is_some()).count(), 8);
+        assert_eq!(skewed.mirror_row(9), Some(7));
+        assert_eq!(skewed.mirror_row(17), None);
+        let (mirrored, computed) = render_both_ways(&skewed);
+        assert_eq!(mirrored, computed);
+    }
+
+    #[test]
+    fn symmetry_flag_off_gives_identical_pixels() {
+        use crate::core::actions::render_pixel_buffer::render_pixel_buffer_parallel_rayon;
+        use crate::core::fractals::mandelbrot::colour_mapping::maps::fire::MandelbrotFireColourMap;
+
+        let algorithm = MandelbrotAlgorithm::overview(70, 33, 200);
+        let unmirrored =
+            algorithm.clone().with_optimizations(OptimizationFlags::ALL - OptimizationFlags::SYMMETRY);
+        let colour_map = MandelbrotFireColourMap::new(200);
+
+        let mirrored = render_pixel_buffer_parallel_rayon(algorithm.pixel_rect, &algorithm, &colour_map).unwrap();
+        let computed = render_pixel_buffer_parallel_rayon(algorithm.pixel_rect, &unmirrored, &colour_map).unwrap();
+        assert_eq!(mirrored.buffer(), computed.buffer());
+    }
+
+    #[test]
+    fn an_exact_cycle_is_caught_once_the_checkpoint_is_on_it() {
+        // c = -1 cycles 0, -1, 0, -1, ...; the checkpoint moves to -1 after
+        // the first iteration and the orbit is back there two later.
+        let mut cycle_check = ExactCycleCheck::new();
+        let orbit = [(-1.0, 0.0), (0.0, 0.0), (-1.0, 0.0)];
+
+        let repeats: Vec<bool> = (1..)
+            .zip(orbit)
+            .map(|(iteration, z)| cycle_check.repeats(z, iteration))
+            .collect();
+
+        assert_eq!(repeats, [false, false, true]);
+    }
+
+    #[test]
+    fn cubic_power_iterates_z_cubed_plus_c() {
+        let quadratic = overview_algorithm(100);
+        let cubic = overview_algorithm(100).with_power(3).unwrap();
+        let mut output = Vec::new();
+
+        // -1 is in the period-2 bulb, but under z³ + c its orbit runs
+        // -1, -2, -9.
+        assert_eq!(quadratic.iterate_point(-1.0, 0.0), 100);
+        assert_eq!(cubic.iterate_point(-1.0, 0.0), 3);
+        assert!(!cubic.append_row_segment_avx(-2.0, 0.0, 0.5, 8, &mut output));
+
+        // The cubic set is symmetric under c -> -c; the quadratic one isn't.
+        for (real, imag) in [(0.3, 0.2), (-0.5, 0.7), (0.1, -1.1), (1.2, 0.0)] {
+            assert_eq!(cubic.iterate_point(real, imag), cubic.iterate_point(-real, -imag));
+        }
+
+        let per_pixel: Vec<u32> = (0..40)
+            .flat_map(|y| (0..70).map(move |x| Point { x, y }))
+            .map(|point| cubic.compute(point).unwrap())
+            .collect();
+        assert_eq!(rows(&cubic), per_pixel);
+    }
+
+    #[test]
+    fn interior_checks_toggle_only_the_cardioid_flag() {
+        let algorithm = unit_algorithm().with_interior_checks(false);
+        assert_eq!(
+            algorithm.optimizations(),
+            OptimizationFlags::PERIODICITY | OptimizationFlags::SYMMETRY
+        );
+        assert_eq!(algorithm.with_interior_checks(true).optimizations(), OptimizationFlags::ALL);
+    }
+
+    #[test]
+    fn f32_agrees_with_f64_for_exterior_points_at_low_iterations() {
+        let max_iterations = 20;
+        let f64_algorithm = overview_algorithm(max_iterations);
+        let f32_algorithm = f64_algorithm.clone().with_precision(Precision::F32);
+
+        let f64_rows = rows(&f64_algorithm);
+        let f32_rows = rows(&f32_algorithm);
+
+        assert!(f64_rows.iter().any(|&i| i < max_iterations));
+        for (f64_iterations, f32_iterations) in f64_rows.into_iter().zip(f32_rows) {
+            if f64_iterations < max_iterations {
+                assert_eq!(f32_iterations, f64_iterations);
+            }
+        }
+    }
+
+    #[test]
+    fn f32_precision_is_selectable_and_skips_the_vector_loop() {
+        let algorithm = unit_algorithm();
+        assert_eq!(algorithm.precision(), Precision::F64);
+
+        let f32_algorithm = algorithm.with_precision(Precision::F32);
+        assert_eq!(f32_algorithm.precision(), Precision::F32);
+
+        let mut output = Vec::new();
+        assert!(!f32_algorithm.append_row_segment_avx(-2.0, 0.0, 0.5, 8, &mut output));
+
+        // A narrow view in seahorse valley: pixel coordinates collapse in
+        // f32, so the f32 path visibly changes the result.
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 31, y: 15 }).unwrap();
+        let complex_rect = ComplexRect::new(
+            Complex {
+                real: -0.7436,
+                imag: 0.1318,
+            },
+            Complex {
+                real: -0.7436 + 1e-5,
+                imag: 0.1318 + 5e-6,
+            },
+        )
+        .unwrap();
+        let deep = MandelbrotAlgorithm::new(pixel_rect, complex_rect, 500).unwrap();
+
+        assert_ne!(rows(&deep), rows(&deep.clone().with_precision(Precision::F32)));
+    }
 }
@@ -0,0 +1,298 @@
+use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::data::deep_complex::DeepComplex;
+use crate::core::data::deep_region::DeepRegion;
+use crate::core::data::pixel_rect::PixelRect;
+use crate::core::data::point::Point;
+use crate::core::fractals::mandelbrot::algorithm::{DEFAULT_POWER, MAX_POWER};
+use crate::core::fractals::mandelbrot::errors::mandelbrot::MandelbrotError;
+use crate::core::util::pixel_to_complex_coords::PixelToComplexCoordsError;
+use dashu_float::FBig;
+
+const ESCAPE_RADIUS_SQ: f64 = 4.0;
+
+/// Mandelbrot rendering with every pixel iterated at arbitrary precision.
+///
+/// Each pixel's `c` is the deep view centre plus its f64 offset, and
+/// `z' = z^power + c` runs entirely in `FBig` at the precision the zoom
+/// depth requires. This is orders of magnitude slower than the perturbation
+/// path, which deep zoom selects for the Mandelbrot set itself. Multibrot
+/// views, which perturbation cannot render, come here once they pass the
+/// f64 limit; it also serves as an exact reference for checking
+/// perturbation.
+#[derive(Debug, PartialEq)]
+pub struct MandelbrotBigFloatAlgorithm {
+    pixel_rect: PixelRect,
+    region: DeepRegion,
+    max_iterations: u32,
+    power: u32,
+}
+
+impl MandelbrotBigFloatAlgorithm {
+    pub fn new(
+        pixel_rect: PixelRect,
+        region: DeepRegion,
+        max_iterations: u32,
+    ) -> Result<Self, MandelbrotError> {
+        if max_iterations == 0 {
+            return Err(MandelbrotError::ZeroMaxIterationsError);
+        }
+
+        Ok(Self {
+            pixel_rect,
+            region: region.normalised(),
+            max_iterations,
+            power: DEFAULT_POWER,
+        })
+    }
+
+    /// Iterates `z^power + c`, as [`MandelbrotAlgorithm::with_power`] does.
+    ///
+    /// [`MandelbrotAlgorithm::with_power`]: crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm::with_power
+    pub fn with_power(mut self, power: u32) -> Result<Self, MandelbrotError> {
+        if power < DEFAULT_POWER {
+            return Err(MandelbrotError::PowerBelowTwoError);
+        }
+        if power > MAX_POWER {
+            return Err(MandelbrotError::PowerAboveEightError);
+        }
+
+        self.power = power;
+        Ok(self)
+    }
+
+    #[must_use]
+    pub fn region(&self) -> &DeepRegion {
+        &self.region
+    }
+
+    #[must_use]
+    pub fn max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
+
+    #[must_use]
+    pub fn power(&self) -> u32 {
+        self.power
+    }
+
+    /// The pixel's `c`, using the same top-left-to-bottom-right mapping as
+    /// the direct and perturbation algorithms.
+    fn pixel_to_deep_coords(&self, pixel: Point) -> Result<DeepComplex, PixelToComplexCoordsError> {
+        if !self.pixel_rect.contains_point(pixel) {
+            return Err(PixelToComplexCoordsError::PointOutsideRect {
+                point: pixel,
+                pixel_rect: self.pixel_rect,
+            });
+        }
+
+        let top_left = self.pixel_rect.top_left();
+        let offset_re = axis_offset(
+            pixel.x - top_left.x,
+            self.pixel_rect.width(),
+            self.region.width(),
+        );
+        let offset_im = axis_offset(
+            pixel.y - top_left.y,
+            self.pixel_rect.height(),
+            self.region.height(),
+        );
+
+        Ok(self
+            .region
+            .centre()
+            .add_f64(offset_re, offset_im)
+            .expect("pixel offsets within a valid region are finite"))
+    }
+
+    fn iterate_point(&self, c: &DeepComplex) -> u32 {
+        let precision_bits = self.region.required_precision_bits();
+        let c = c.with_precision(precision_bits);
+
+        let mut z_re = FBig::ZERO.with_precision(precision_bits).value();
+        let mut z_im = z_re.clone();
+
+        for iteration in 1..=self.max_iterations {
+            let (mut pow_re, mut pow_im) = (z_re.clone(), z_im.clone());
+            for _ in 1..self.power {
+                let re = &pow_re * &z_re - &pow_im * &z_im;
+                pow_im = &pow_re * &z_im + &pow_im * &z_re;
+                pow_re = re;
+            }
+
+            z_re = pow_re + &c.re;
+            z_im = pow_im + &c.im;
+
+            let re = z_re.to_f64().value();
+            let im = z_im.to_f64().value();
+            if re * re + im * im > ESCAPE_RADIUS_SQ {
+                return iteration;
+            }
+        }
+
+        self.max_iterations
+    }
+}
+
+/// Offset of pixel `relative` from the view centre along one axis.
+fn axis_offset(relative: i32, pixels: u32, extent: f64) -> f64 {
+    if pixels > 1 {
+        f64::from(relative) * (extent / f64::from(pixels - 1)) - extent * 0.5
+    } else {
+        0.0
+    }
+}
+
+impl FractalAlgorithm for MandelbrotBigFloatAlgorithm {
+    type Success = u32;
+    type Failure = PixelToComplexCoordsError;
+
+    fn compute(&self, pixel: Point) -> Result<Self::Success, Self::Failure> {
+        let c = self.pixel_to_deep_coords(pixel)?;
+        Ok(self.iterate_point(&c))
+    }
+
+    fn pixel_rect(&self) -> PixelRect {
+        self.pixel_rect
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
+    use std::collections::HashSet;
+
+    fn pixel_rect(width: i32, height: i32) -> PixelRect {
+        PixelRect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: width - 1,
+                y: height - 1,
+            },
+        )
+        .unwrap()
+    }
+
+    fn deep_region(re: f64, im: f64, extent: f64) -> DeepRegion {
+        DeepRegion::new(DeepComplex::from_f64(re, im).unwrap(), extent, extent).unwrap()
+    }
+
+    fn render_rows<A: FractalAlgorithm<Success = u32>>(algorithm: &A) -> Vec<Vec<u32>> {
+        let rect = algorithm.pixel_rect();
+        (rect.top_left().y..=rect.bottom_right().y)
+            .map(|y| {
+                let mut row = Vec::new();
+                algorithm
+                    .compute_row_segment_into(y, rect.top_left().x, rect.bottom_right().x, &mut row)
+                    .ok()
+                    .unwrap();
+                row
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rejects_zero_max_iterations() {
+        let result = MandelbrotBigFloatAlgorithm::new(pixel_rect(4, 4), deep_region(0.0, 0.0, 1.0), 0);
+
+        assert!(matches!(result, Err(MandelbrotError::ZeroMaxIterationsError)));
+    }
+
+    #[test]
+    fn out_of_bounds_pixel_errors_like_the_direct_algorithm() {
+        let rect = pixel_rect(4, 4);
+        let algorithm =
+            MandelbrotBigFloatAlgorithm::new(rect, deep_region(-0.75, 0.0, 1.0), 10).unwrap();
+        let point = Point { x: 0, y: 4 };
+
+        assert_eq!(
+            algorithm.compute(point),
+            Err(PixelToComplexCoordsError::PointOutsideRect {
+                point,
+                pixel_rect: rect
+            })
+        );
+    }
+
+    #[test]
+    fn matches_direct_f64_algorithm_at_moderate_zoom() {
+        let rect = pixel_rect(16, 12);
+        let max_iterations = 64;
+        let region = deep_region(-0.75, 0.1, 0.5);
+
+        let big = MandelbrotBigFloatAlgorithm::new(rect, region.clone(), max_iterations).unwrap();
+        let direct =
+            MandelbrotAlgorithm::new(rect, region.to_complex_rect().unwrap(), max_iterations)
+                .unwrap();
+
+        let big_rows = render_rows(&big);
+        let direct_rows = render_rows(&direct);
+        let escaped = big_rows
+            .iter()
+            .flatten()
+            .filter(|&&n| n < max_iterations)
+            .count();
+
+        assert!(escaped > 0, "expected escaped pixels in the test view");
+        assert_eq!(big_rows, direct_rows);
+    }
+
+    #[test]
+    fn matches_direct_f64_multibrot_at_moderate_zoom() {
+        let rect = pixel_rect(16, 12);
+        let max_iterations = 64;
+        let region = deep_region(-0.2, 0.6, 0.5);
+
+        let big = MandelbrotBigFloatAlgorithm::new(rect, region.clone(), max_iterations)
+            .and_then(|algorithm| algorithm.with_power(3))
+            .unwrap();
+        let direct =
+            MandelbrotAlgorithm::new(rect, region.to_complex_rect().unwrap(), max_iterations)
+                .and_then(|algorithm| algorithm.with_power(3))
+                .unwrap();
+
+        let big_rows = render_rows(&big);
+        let escaped = big_rows
+            .iter()
+            .flatten()
+            .filter(|&&n| n < max_iterations)
+            .count();
+
+        assert!(escaped > 0, "expected escaped pixels in the test view");
+        assert_eq!(big_rows, render_rows(&direct));
+    }
+
+    #[test]
+    fn rejects_powers_outside_two_to_eight() {
+        let algorithm =
+            || MandelbrotBigFloatAlgorithm::new(pixel_rect(4, 4), deep_region(0.0, 0.0, 1.0), 10).unwrap();
+
+        assert!(matches!(algorithm().with_power(1), Err(MandelbrotError::PowerBelowTwoError)));
+        assert!(matches!(algorithm().with_power(9), Err(MandelbrotError::PowerAboveEightError)));
+    }
+
+    #[test]
+    fn resolves_structure_where_f64_coordinates_collapse() {
+        // A point on the boundary of the main cardioid near -0.744 + 0.1i,
+        // located by bisection to ~1e-36 and written as a sum of f64 parts.
+        let centre = DeepComplex::from_f64(-0.744_389_822_683_040_3, 0.1)
+            .unwrap()
+            .with_precision(256)
+            .add_f64(2.925_903_840_284_399e-17, 0.0)
+            .unwrap()
+            .add_f64(1.076_113_356_426_368_1e-33, 0.0)
+            .unwrap();
+        let region = DeepRegion::new(centre, 1e-30, 1e-30).unwrap();
+        let max_iterations = 400;
+
+        // f64 cannot tell the pixels of this view apart at all.
+        assert!(region.to_complex_rect().is_none());
+
+        let big = MandelbrotBigFloatAlgorithm::new(pixel_rect(16, 1), region, max_iterations).unwrap();
+        let row = render_rows(&big).remove(0);
+
+        assert!(row[0] < max_iterations, "left edge should escape: {row:?}");
+        assert_eq!(row[15], max_iterations, "right edge should be interior: {row:?}");
+        assert!(row.iter().collect::<HashSet<_>>().len() > 1);
+    }
+}
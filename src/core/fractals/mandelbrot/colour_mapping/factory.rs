@@ -1,4 +1,4 @@
-use crate::core::fractals::mandelbrot::colour_mapping::{kinds::MandelbrotColourMapKinds, map::MandelbrotColourMap, maps::{ice::MandelbrotIceColourMap, fire::MandelbrotFireColourMap}};
+use crate::core::fractals::mandelbrot::colour_mapping::{kinds::MandelbrotColourMapKinds, map::MandelbrotColourMap, maps::{ice::MandelbrotIceColourMap, fire::MandelbrotFireColourMap, hsv_rainbow::MandelbrotHsvRainbowColourMap, viridis::MandelbrotViridisColourMap}};
 
 #[must_use]
 pub fn mandelbrot_colour_map_factory(
@@ -12,6 +12,12 @@ pub fn mandelbrot_colour_map_factory(
         MandelbrotColourMapKinds::BlueWhiteGradient => {
             Box::new(MandelbrotIceColourMap::new(max_iterations))
         }
+        MandelbrotColourMapKinds::Viridis => {
+            Box::new(MandelbrotViridisColourMap::new(max_iterations))
+        }
+        MandelbrotColourMapKinds::HsvRainbow => {
+            Box::new(MandelbrotHsvRainbowColourMap::new(max_iterations))
+        }
     }
 }
 
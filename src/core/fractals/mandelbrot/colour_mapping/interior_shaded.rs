@@ -0,0 +1,137 @@
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{ColourMap, ColourMapError};
+use crate::core::data::colour::Colour;
+use crate::core::fractals::mandelbrot::interior::{InteriorSignal, IterationSample};
+
+/// Muted base colours for interior cycles, indexed by `(period - 1)` and
+/// repeating for longer periods.
+const PERIOD_COLOURS: [Colour; 6] = [
+    Colour { r: 40, g: 60, b: 110 },
+    Colour { r: 90, g: 50, b: 110 },
+    Colour { r: 40, g: 100, b: 90 },
+    Colour { r: 110, g: 80, b: 40 },
+    Colour { r: 100, g: 40, b: 60 },
+    Colour { r: 60, g: 90, b: 40 },
+];
+
+/// Darkest shade of a period's colour, for cycles detected only at
+/// `max_iterations`.
+const MIN_BRIGHTNESS: f64 = 0.35;
+
+/// Colours exterior points with an iteration-count map and interior points
+/// on a separate ramp from their [`InteriorSignal`].
+///
+/// The interior hue comes from the cycle's period, so each bulb of the set
+/// gets its own colour; brightness falls off with how many iterations
+/// detection took, which darkens the body towards its boundary. Points
+/// without a signal (escaped, or interior but undetected) go to the
+/// exterior map unchanged.
+#[derive(Debug)]
+pub struct InteriorShadedColourMap<'a, CMap: ?Sized> {
+    exterior: &'a CMap,
+    max_iterations: u32,
+}
+
+impl<'a, CMap: ColourMap<u32> + ?Sized> InteriorShadedColourMap<'a, CMap> {
+    #[must_use]
+    pub fn new(exterior: &'a CMap, max_iterations: u32) -> Self {
+        Self {
+            exterior,
+            max_iterations,
+        }
+    }
+
+    fn interior_colour(&self, signal: InteriorSignal) -> Colour {
+        let base = PERIOD_COLOURS[(signal.period.saturating_sub(1) as usize) % PERIOD_COLOURS.len()];
+        let speed = 1.0 - f64::from(signal.detected_at) / f64::from(self.max_iterations.max(1));
+        let brightness = MIN_BRIGHTNESS + (1.0 - MIN_BRIGHTNESS) * speed.clamp(0.0, 1.0);
+        let shade = |channel: u8| (f64::from(channel) * brightness).round() as u8;
+
+        Colour {
+            r: shade(base.r),
+            g: shade(base.g),
+            b: shade(base.b),
+        }
+    }
+}
+
+impl<CMap: ColourMap<u32> + ?Sized> ColourMap<IterationSample> for InteriorShadedColourMap<'_, CMap> {
+    fn map(&self, sample: IterationSample) -> Result<Colour, ColourMapError> {
+        match sample.interior {
+            Some(signal) => Ok(self.interior_colour(signal)),
+            None => self.exterior.map(sample.iterations),
+        }
+    }
+
+    fn display_name(&self) -> &str {
+        self.exterior.display_name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::actions::generate_fractal::generate_fractal_serial::generate_fractal_serial;
+    use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+    use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
+    use crate::core::fractals::mandelbrot::colour_mapping::maps::fire::MandelbrotFireColourMap;
+    use crate::core::fractals::mandelbrot::interior::MandelbrotInteriorAlgorithm;
+
+    const MAX_ITERATIONS: u32 = 256;
+
+    fn rgb(colour: Colour) -> (u8, u8, u8) {
+        (colour.r, colour.g, colour.b)
+    }
+
+    fn samples() -> Vec<IterationSample> {
+        let plain = MandelbrotAlgorithm::overview(35, 20, MAX_ITERATIONS);
+        let pixel_rect = plain.pixel_rect();
+
+        generate_fractal_serial(pixel_rect, &MandelbrotInteriorAlgorithm::new(plain)).unwrap()
+    }
+
+    #[test]
+    fn interior_points_are_shaded_instead_of_black() {
+        let exterior = MandelbrotFireColourMap::new(MAX_ITERATIONS);
+        let map = InteriorShadedColourMap::new(&exterior, MAX_ITERATIONS);
+
+        let interior: Vec<_> = samples().into_iter().filter(|s| s.interior.is_some()).collect();
+        assert!(!interior.is_empty());
+
+        for sample in interior {
+            assert_ne!(rgb(map.map(sample).unwrap()), (0, 0, 0), "{sample:?}");
+        }
+    }
+
+    #[test]
+    fn exterior_points_keep_the_exterior_gradient() {
+        let exterior = MandelbrotFireColourMap::new(MAX_ITERATIONS);
+        let map = InteriorShadedColourMap::new(&exterior, MAX_ITERATIONS);
+
+        for sample in samples().into_iter().filter(|s| s.interior.is_none()) {
+            assert_eq!(
+                rgb(map.map(sample).unwrap()),
+                rgb(exterior.map(sample.iterations).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn hue_follows_period_and_brightness_detection_speed() {
+        let exterior = MandelbrotFireColourMap::new(MAX_ITERATIONS);
+        let map = InteriorShadedColourMap::new(&exterior, MAX_ITERATIONS);
+        let colour = |period, detected_at| {
+            rgb(map
+                .map(IterationSample {
+                    iterations: MAX_ITERATIONS,
+                    interior: Some(InteriorSignal { period, detected_at }),
+                })
+                .unwrap())
+        };
+
+        assert_ne!(colour(1, 10), colour(2, 10));
+        assert_eq!(colour(1, 10), colour(7, 10));
+
+        let (fast, slow) = (colour(1, 1), colour(1, MAX_ITERATIONS));
+        assert!(fast.0 > slow.0 && fast.1 > slow.1 && fast.2 > slow.2);
+    }
+}
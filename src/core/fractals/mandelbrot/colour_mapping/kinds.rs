@@ -1,19 +1,26 @@
+use crate::core::fractals::parse_kind::{ParseKindError, parse_kind};
+use std::str::FromStr;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[derive(Default)]
 pub enum MandelbrotColourMapKinds {
     #[default]
     BlueWhiteGradient,
     FireGradient,
+    Viridis,
+    HsvRainbow,
 }
 
 impl MandelbrotColourMapKinds {
-    pub const ALL: &'static [Self] = &[Self::BlueWhiteGradient, Self::FireGradient];
+    pub const ALL: &'static [Self] = &[Self::BlueWhiteGradient, Self::FireGradient, Self::Viridis, Self::HsvRainbow];
 
     #[must_use]
     pub const fn display_name(self) -> &'static str {
         match self {
             Self::FireGradient => "Fire",
             Self::BlueWhiteGradient => "Ice",
+            Self::Viridis => "Viridis",
+            Self::HsvRainbow => "HSV Rainbow",
         }
     }
 }
@@ -23,3 +30,37 @@ impl std::fmt::Display for MandelbrotColourMapKinds {
         f.write_str((*self).display_name())
     }
 }
+
+/// Parses a display name case-insensitively, e.g. from the command line.
+impl FromStr for MandelbrotColourMapKinds {
+    type Err = ParseKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_kind(Self::ALL, Self::display_name, "Mandelbrot colour map", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_variant_round_trips_through_display() {
+        for &kind in MandelbrotColourMapKinds::ALL {
+            assert_eq!(kind.to_string().parse::<MandelbrotColourMapKinds>(), Ok(kind));
+        }
+    }
+
+    #[test]
+    fn parsing_ignores_case_and_whitespace() {
+        assert_eq!("ice".parse::<MandelbrotColourMapKinds>(), Ok(MandelbrotColourMapKinds::BlueWhiteGradient));
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        assert!(matches!(
+            "rainbow".parse::<MandelbrotColourMapKinds>(),
+            Err(ParseKindError::Unknown { name, .. }) if name == "rainbow"
+        ));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{ColourMap, ColourMapError};
+use crate::core::data::colour::Colour;
+use crate::core::fractals::mandelbrot::colour_mapping::errors::MandelbrotColourMapErrors;
+use crate::core::fractals::mandelbrot::colour_mapping::kinds::MandelbrotColourMapKinds;
+use crate::core::fractals::mandelbrot::colour_mapping::map::MandelbrotColourMap;
+use crate::core::util::iteration_colour_lut::IterationColourLut;
+
+/// Escape counts around the hue wheel once, red through green and blue back
+/// towards red, at a fixed saturation and full value. Interior points are
+/// black.
+#[derive(Debug)]
+pub struct MandelbrotHsvRainbowColourMap {
+    max_iterations: u32,
+    saturation: f64,
+    lut: IterationColourLut,
+}
+
+impl ColourMap<u32> for MandelbrotHsvRainbowColourMap {
+    fn map(&self, iterations: u32) -> Result<Colour, ColourMapError> {
+        if iterations > self.max_iterations {
+            return Err(Box::new(MandelbrotColourMapErrors::IterationsExceedMax {
+                iterations,
+                max_iterations: self.max_iterations,
+            }));
+        }
+
+        if let Some(colour) = self.lut.get(iterations) {
+            return Ok(colour);
+        }
+
+        debug_assert!(
+            false,
+            "LUT invariant broken: iterations <= max_iterations but LUT had no entry"
+        );
+        Err(Box::new(MandelbrotColourMapErrors::LutInvariantBroken {
+            iterations,
+            max_iterations: self.max_iterations,
+        }))
+    }
+
+    fn display_name(&self) -> &str {
+        self.kind().display_name()
+    }
+}
+
+impl MandelbrotColourMap for MandelbrotHsvRainbowColourMap {
+    fn kind(&self) -> MandelbrotColourMapKinds {
+        MandelbrotColourMapKinds::HsvRainbow
+    }
+}
+
+impl MandelbrotHsvRainbowColourMap {
+    pub const DEFAULT_SATURATION: f64 = 0.85;
+    const VALUE: f64 = 1.0;
+
+    #[must_use]
+    pub fn new(max_iterations: u32) -> Self {
+        Self {
+            max_iterations,
+            saturation: Self::DEFAULT_SATURATION,
+            lut: Self::build_lut(max_iterations, Self::DEFAULT_SATURATION),
+        }
+    }
+
+    /// Sets the saturation of every escaped colour, clamped to `[0, 1]`:
+    /// 0 is greyscale white, 1 the pure hues.
+    #[must_use]
+    pub fn with_saturation(self, saturation: f64) -> Self {
+        let saturation = saturation.clamp(0.0, 1.0);
+        Self {
+            saturation,
+            lut: Self::build_lut(self.max_iterations, saturation),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn saturation(&self) -> f64 {
+        self.saturation
+    }
+
+    fn build_lut(max_iterations: u32, saturation: f64) -> IterationColourLut {
+        IterationColourLut::new(max_iterations, |t| {
+            Colour::from_hsv(t * 360.0, saturation, Self::VALUE)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channels(colour: Colour) -> (u8, u8, u8) {
+        (colour.r, colour.g, colour.b)
+    }
+
+    #[test]
+    fn escape_counts_cycle_the_hue() {
+        let mapper = MandelbrotHsvRainbowColourMap::new(3).with_saturation(1.0);
+
+        assert_eq!(channels(mapper.map(0).unwrap()), (255, 0, 0));
+        assert_eq!(channels(mapper.map(1).unwrap()), (0, 255, 0));
+        assert_eq!(channels(mapper.map(2).unwrap()), (0, 0, 255));
+    }
+
+    #[test]
+    fn saturation_is_applied_to_every_escaped_count() {
+        let mapper = MandelbrotHsvRainbowColourMap::new(3).with_saturation(0.5);
+
+        assert_eq!(channels(mapper.map(0).unwrap()), (255, 128, 128));
+        assert_eq!(channels(mapper.map(1).unwrap()), (128, 255, 128));
+        assert_eq!(channels(mapper.map(2).unwrap()), (128, 128, 255));
+    }
+
+    #[test]
+    fn saturation_defaults_and_is_clamped() {
+        assert_eq!(
+            MandelbrotHsvRainbowColourMap::new(100).saturation(),
+            MandelbrotHsvRainbowColourMap::DEFAULT_SATURATION
+        );
+        assert_eq!(MandelbrotHsvRainbowColourMap::new(100).with_saturation(2.0).saturation(), 1.0);
+        assert_eq!(MandelbrotHsvRainbowColourMap::new(100).with_saturation(-1.0).saturation(), 0.0);
+    }
+
+    #[test]
+    fn interior_is_black() {
+        let mapper = MandelbrotHsvRainbowColourMap::new(100);
+
+        assert_eq!(channels(mapper.map(100).unwrap()), (0, 0, 0));
+    }
+
+    #[test]
+    fn iterations_above_max_are_an_error() {
+        let mapper = MandelbrotHsvRainbowColourMap::new(100);
+        let err = mapper.map(101).expect_err("expected error when iterations exceed max");
+
+        assert!(matches!(
+            err.downcast_ref::<MandelbrotColourMapErrors>(),
+            Some(MandelbrotColourMapErrors::IterationsExceedMax {
+                iterations: 101,
+                max_iterations: 100
+            })
+        ));
+    }
+}
@@ -1,2 +1,4 @@
 pub mod ice;
 pub mod fire;
+pub mod hsv_rainbow;
+pub mod viridis;
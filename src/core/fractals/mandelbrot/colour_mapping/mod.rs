@@ -1,5 +1,7 @@
 pub mod errors;
 pub mod factory;
+pub mod interior_shaded;
 pub mod kinds;
 pub mod map;
 pub mod maps;
+pub mod transparent_interior;
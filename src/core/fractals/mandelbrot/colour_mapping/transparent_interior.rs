@@ -0,0 +1,85 @@
+use crate::core::actions::generate_pixel_buffer::ports::colour_map::{
+    ColourMap, ColourMapError, ColourMapRgba,
+};
+use crate::core::data::colour::ColourRgba;
+
+/// Colours escaped points with an iteration-count map and leaves points
+/// that reached `max_iterations` fully transparent, so the set's interior
+/// shows whatever the image is composited over.
+#[derive(Debug)]
+pub struct TransparentInteriorColourMap<'a, CMap: ?Sized> {
+    exterior: &'a CMap,
+    max_iterations: u32,
+}
+
+impl<'a, CMap: ColourMap<u32> + ?Sized> TransparentInteriorColourMap<'a, CMap> {
+    #[must_use]
+    pub fn new(exterior: &'a CMap, max_iterations: u32) -> Self {
+        Self {
+            exterior,
+            max_iterations,
+        }
+    }
+}
+
+impl<CMap: ColourMap<u32> + ?Sized> ColourMapRgba<u32> for TransparentInteriorColourMap<'_, CMap> {
+    fn map_rgba(&self, iterations: u32) -> Result<ColourRgba, ColourMapError> {
+        if iterations == self.max_iterations {
+            return Ok(ColourRgba::TRANSPARENT);
+        }
+
+        self.exterior.map(iterations).map(ColourRgba::from)
+    }
+
+    fn display_name(&self) -> &str {
+        self.exterior.display_name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::actions::generate_fractal::generate_fractal_serial::generate_fractal_serial;
+    use crate::core::actions::generate_pixel_buffer::generate_pixel_buffer::generate_pixel_buffer_rgba;
+    use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+    use crate::core::data::pixel_buffer::PixelBuffer;
+    use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
+    use crate::core::fractals::mandelbrot::colour_mapping::maps::fire::MandelbrotFireColourMap;
+
+    const MAX_ITERATIONS: u32 = 256;
+
+    #[test]
+    fn interior_pixels_are_transparent_and_exterior_opaque() {
+        let algorithm = MandelbrotAlgorithm::overview(35, 20, MAX_ITERATIONS);
+        let pixel_rect = algorithm.pixel_rect();
+        let iterations = generate_fractal_serial(pixel_rect, &algorithm).unwrap();
+        let exterior = MandelbrotFireColourMap::new(MAX_ITERATIONS);
+        let map = TransparentInteriorColourMap::new(&exterior, MAX_ITERATIONS);
+
+        let buffer = generate_pixel_buffer_rgba(iterations.clone(), &map, pixel_rect).unwrap();
+
+        assert!(iterations.contains(&MAX_ITERATIONS));
+        assert!(iterations.iter().any(|&i| i < MAX_ITERATIONS));
+        for (pixel, &i) in buffer.as_rgba().chunks_exact(PixelBuffer::BYTES_PER_PIXEL).zip(&iterations) {
+            let expected_alpha = if i == MAX_ITERATIONS { 0 } else { PixelBuffer::ALPHA_OPAQUE };
+            assert_eq!(pixel[3], expected_alpha, "iterations {i}");
+        }
+    }
+
+    #[test]
+    fn exterior_keeps_the_exterior_gradient() {
+        let exterior = MandelbrotFireColourMap::new(MAX_ITERATIONS);
+        let map = TransparentInteriorColourMap::new(&exterior, MAX_ITERATIONS);
+        let colour = exterior.map(40).unwrap();
+
+        assert_eq!(
+            map.map_rgba(40).unwrap(),
+            ColourRgba {
+                r: colour.r,
+                g: colour.g,
+                b: colour.b,
+                a: 255,
+            }
+        );
+    }
+}
@@ -3,6 +3,8 @@ use std::{error::Error, fmt};
 #[derive(Debug, PartialEq)]
 pub enum MandelbrotError {
     ZeroMaxIterationsError,
+    PowerBelowTwoError,
+    PowerAboveEightError,
 }
 
 impl fmt::Display for MandelbrotError {
@@ -11,6 +13,8 @@ impl fmt::Display for MandelbrotError {
             Self::ZeroMaxIterationsError => {
                 write!(f, "Maximum iterations must be greater than zero")
             }
+            Self::PowerBelowTwoError => write!(f, "Power must be at least 2"),
+            Self::PowerAboveEightError => write!(f, "Power must be at most 8"),
         }
     }
 }
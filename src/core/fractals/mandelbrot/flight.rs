@@ -1,6 +1,6 @@
 use crate::core::{
     data::pixel_rect::PixelRect,
-    flight::{FlightLimits, FlightUpdateReport, FlightWarning, MotionState},
+    flight::{BoundaryBehavior, FlightLimits, FlightUpdateReport, FlightWarning, MotionState},
     fractals::mandelbrot::mandelbrot_config::{MandelbrotConfig, default_region},
 };
 
@@ -30,7 +30,7 @@ pub fn step_flight_in_viewport(
 ) -> FlightUpdateReport {
     let mut report = FlightUpdateReport::default();
 
-    if motion.paused || motion.speed_world_per_sec == 0.0 {
+    if !motion.moves_view() {
         return report;
     }
 
@@ -122,26 +122,27 @@ pub fn step_flight_in_viewport(
 
     let max_center_abs = limits.max_center_abs.abs();
     let (centre_re, centre_im) = region.centre().to_f64();
-    let clamped_re = centre_re.clamp(-max_center_abs, max_center_abs);
-    let clamped_im = centre_im.clamp(-max_center_abs, max_center_abs);
+    let (confined_re, bounced_re) = limits.boundary.confine(centre_re, max_center_abs);
+    let (confined_im, bounced_im) = limits.boundary.confine(centre_im, max_center_abs);
 
-    if clamped_re != centre_re {
-        let Some(centre) = region.centre().with_re_f64(clamped_re) else {
+    if confined_re != centre_re {
+        let Some(centre) = region.centre().with_re_f64(confined_re) else {
             reset_non_finite(config, &mut report);
             return report;
         };
         region = region.with_centre(centre);
-        mark_warning(&mut report, FlightWarning::CenterClamped);
+        mark_centre_confined(&mut report, limits.boundary);
     }
 
-    if clamped_im != centre_im {
-        let Some(centre) = region.centre().with_im_f64(clamped_im) else {
+    if confined_im != centre_im {
+        let Some(centre) = region.centre().with_im_f64(confined_im) else {
             reset_non_finite(config, &mut report);
             return report;
         };
         region = region.with_centre(centre);
-        mark_warning(&mut report, FlightWarning::CenterClamped);
+        mark_centre_confined(&mut report, limits.boundary);
     }
+    report.bounced = [bounced_re, bounced_im];
 
     if extent_clamped {
         mark_warning(&mut report, FlightWarning::ExtentClamped);
@@ -156,6 +157,14 @@ fn mark_warning(report: &mut FlightUpdateReport, warning: FlightWarning) {
     report.warning = Some(warning);
 }
 
+/// Only clamping is a warning; wrapping and bouncing are the flight the
+/// user asked for.
+fn mark_centre_confined(report: &mut FlightUpdateReport, boundary: BoundaryBehavior) {
+    if boundary == BoundaryBehavior::Clamp {
+        mark_warning(report, FlightWarning::CenterClamped);
+    }
+}
+
 fn reset_non_finite(config: &mut MandelbrotConfig, report: &mut FlightUpdateReport) {
     config.region = default_region();
     mark_warning(report, FlightWarning::NonFiniteReset);
@@ -166,7 +175,7 @@ mod tests {
     use super::{step_flight, step_flight_in_viewport};
     use crate::core::{
         data::{deep_complex::DeepComplex, deep_region::DeepRegion},
-        flight::{FlightLimits, FlightWarning, MotionState},
+        flight::{BoundaryBehavior, FlightLimits, FlightWarning, MotionState},
         fractals::mandelbrot::mandelbrot_config::{MandelbrotConfig, default_region},
     };
 
@@ -266,6 +275,23 @@ mod tests {
         assert_approx_eq(config.region.height(), 2.0 * scale);
     }
 
+    #[test]
+    fn gliding_at_zero_speed_pans_without_zooming() {
+        let limits = FlightLimits::default();
+        let mut config = config_with(region(0.0, 0.0, 4.0, 2.0));
+        let motion = MotionState {
+            gliding: true,
+            ..motion([1.0, 0.0], 0.0)
+        };
+        let dt = 1.0;
+
+        step_flight(&mut config, &motion, dt, &limits);
+
+        assert_centre(&config, dt * limits.steer_strength * 4.0, 0.0);
+        assert_eq!(config.region.width(), 4.0);
+        assert_eq!(config.region.height(), 2.0);
+    }
+
     #[test]
     fn negative_speed_pans_same_direction_as_heading() {
         let limits = FlightLimits::default();
@@ -357,6 +383,41 @@ mod tests {
         assert_centre(&both_config, 0.2, 0.2);
     }
 
+    fn boundary_limits(boundary: BoundaryBehavior) -> FlightLimits {
+        FlightLimits {
+            max_center_abs: 0.8,
+            steer_strength: 0.5,
+            boundary,
+            ..FlightLimits::default()
+        }
+    }
+
+    #[test]
+    fn wrap_moves_the_centre_to_the_opposite_side() {
+        let limits = boundary_limits(BoundaryBehavior::Wrap);
+        let mut config = config_with(region(0.0, 0.0, 2.0, 2.0));
+
+        // Pans 1.0 right, 0.2 past the limit.
+        let report = step_flight(&mut config, &motion([1.0, 0.0], 1.0), 1.0, &limits);
+
+        assert_centre(&config, -0.6, 0.0);
+        assert_approx_eq(config.region.width(), 1.0);
+        assert_eq!(report.warning, None);
+        assert_eq!(report.bounced, [false, false]);
+    }
+
+    #[test]
+    fn bounce_reflects_the_centre_and_reverses_heading() {
+        let limits = boundary_limits(BoundaryBehavior::Bounce);
+        let mut config = config_with(region(0.0, 0.0, 2.0, 2.0));
+
+        let report = step_flight(&mut config, &motion([0.0, -1.0], 1.0), 1.0, &limits);
+
+        assert_centre(&config, 0.0, -0.6);
+        assert_eq!(report.warning, None);
+        assert_eq!(report.bounced, [false, true]);
+    }
+
     #[test]
     fn extent_clamp_scales_up_and_down_with_aspect_ratio_preserved() {
         let up_limits = FlightLimits {
@@ -0,0 +1,191 @@
+use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::data::pixel_rect::PixelRect;
+use crate::core::data::point::Point;
+use crate::core::fractals::escape_metric::EscapeMetric;
+use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
+use crate::core::util::pixel_to_complex_coords::PixelToComplexCoordsError;
+
+/// Squared distance under which the orbit counts as back at its checkpoint.
+const CYCLE_EPSILON_SQ: f64 = 1e-20;
+
+/// How an interior point's orbit was found to be periodic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InteriorSignal {
+    /// Length of the attracting cycle the orbit settled into.
+    pub period: u32,
+    /// Iteration at which the cycle was detected.
+    pub detected_at: u32,
+}
+
+/// One pixel from [`MandelbrotInteriorAlgorithm`]: the usual iteration
+/// count, plus the cycle for interior points where one was detected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IterationSample {
+    pub iterations: u32,
+    pub interior: Option<InteriorSignal>,
+}
+
+/// Iterates `z² + c` like [`MandelbrotAlgorithm`], but also watches for the
+/// orbit returning to a checkpoint (Brent's method: the checkpoint moves
+/// forward after windows of doubling length). A return means `c` is
+/// interior, reported with `max_iterations` and the cycle found. Interior
+/// points whose orbit is still converging at `max_iterations` have no
+/// signal.
+#[must_use]
+pub fn iterate_detecting_cycles(
+    c_real: f64,
+    c_imag: f64,
+    max_iterations: u32,
+    escape_metric: EscapeMetric,
+) -> IterationSample {
+    let mut zr = 0.0f64;
+    let mut zi = 0.0f64;
+    let mut zr2 = 0.0f64;
+    let mut zi2 = 0.0f64;
+
+    let mut checkpoint = (0.0f64, 0.0f64);
+    let mut checkpoint_at = 0u32;
+    let mut window = 1u32;
+
+    for iteration in 1..=max_iterations {
+        let zr_next = zr2 - zi2 + c_real;
+        let zi_next = (zr + zr) * zi + c_imag;
+        zr = zr_next;
+        zi = zi_next;
+        zr2 = zr * zr;
+        zi2 = zi * zi;
+
+        if escape_metric.has_escaped(zr, zi, zr2, zi2) {
+            return IterationSample {
+                iterations: iteration,
+                interior: None,
+            };
+        }
+
+        if distance_sq((zr, zi), checkpoint) < CYCLE_EPSILON_SQ {
+            return IterationSample {
+                iterations: max_iterations,
+                interior: Some(InteriorSignal {
+                    period: minimal_period((zr, zi), (c_real, c_imag), iteration - checkpoint_at),
+                    detected_at: iteration,
+                }),
+            };
+        }
+
+        if iteration - checkpoint_at == window {
+            checkpoint = (zr, zi);
+            checkpoint_at = iteration;
+            window = window.saturating_mul(2);
+        }
+    }
+
+    IterationSample {
+        iterations: max_iterations,
+        interior: None,
+    }
+}
+
+/// A return after `candidate` steps can be a multiple of the true period
+/// when a converging orbit comes within the tolerance of an older point
+/// before the next one. Checks the divisors of `candidate` from `z`, which
+/// by now lies on the cycle to within the tolerance.
+fn minimal_period(z: (f64, f64), c: (f64, f64), candidate: u32) -> u32 {
+    // Iterating on from `z` only contracts towards the cycle, but allow a
+    // little slack for rounding.
+    let tolerance_sq = CYCLE_EPSILON_SQ * 100.0;
+    let mut w = z;
+
+    for period in 1..candidate {
+        w = (w.0 * w.0 - w.1 * w.1 + c.0, 2.0 * w.0 * w.1 + c.1);
+
+        if candidate.is_multiple_of(period) && distance_sq(w, z) < tolerance_sq {
+            return period;
+        }
+    }
+
+    candidate
+}
+
+fn distance_sq(a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dr, di) = (a.0 - b.0, a.1 - b.1);
+    dr * dr + di * di
+}
+
+/// [`MandelbrotAlgorithm`] with cycle detection, producing
+/// [`IterationSample`]s for an interior-shading colour map. Slower than the
+/// plain algorithm: no SIMD path and no cardioid shortcut, since the
+/// shortcut would skip the orbit whose cycle is wanted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MandelbrotInteriorAlgorithm {
+    inner: MandelbrotAlgorithm,
+}
+
+impl MandelbrotInteriorAlgorithm {
+    #[must_use]
+    pub fn new(inner: MandelbrotAlgorithm) -> Self {
+        Self { inner }
+    }
+}
+
+impl FractalAlgorithm for MandelbrotInteriorAlgorithm {
+    type Success = IterationSample;
+    type Failure = PixelToComplexCoordsError;
+
+    fn compute(&self, pixel: Point) -> Result<Self::Success, Self::Failure> {
+        self.inner.compute_detecting_cycles(pixel)
+    }
+
+    fn pixel_rect(&self) -> PixelRect {
+        self.inner.pixel_rect
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::actions::generate_fractal::generate_fractal_serial::generate_fractal_serial;
+
+    fn sample(c_real: f64, c_imag: f64) -> IterationSample {
+        iterate_detecting_cycles(c_real, c_imag, 1000, EscapeMetric::Modulus)
+    }
+
+    #[test]
+    fn detects_the_period_of_each_bulb() {
+        // Main cardioid, period-2 bulb and the period-3 bulb above it.
+        let cases = [(0.0, 0.0, 1), (-0.1, 0.1, 1), (-1.0, 0.0, 2), (-0.12, 0.75, 3)];
+
+        for (c_real, c_imag, period) in cases {
+            let sample = sample(c_real, c_imag);
+
+            assert_eq!(sample.iterations, 1000);
+            assert_eq!(
+                sample.interior.map(|signal| signal.period),
+                Some(period),
+                "c = {c_real}{c_imag:+}i"
+            );
+        }
+    }
+
+    #[test]
+    fn escaping_points_match_the_plain_iteration_count() {
+        let plain = MandelbrotAlgorithm::overview(35, 20, 256);
+        let pixel_rect = plain.pixel_rect();
+        let detecting = MandelbrotInteriorAlgorithm::new(plain.clone());
+
+        let plain = generate_fractal_serial(pixel_rect, &plain).unwrap();
+        let detecting = generate_fractal_serial(pixel_rect, &detecting).unwrap();
+
+        assert!(detecting.iter().any(|sample| sample.interior.is_some()));
+        for (iterations, sample) in plain.into_iter().zip(detecting) {
+            assert_eq!(sample.iterations, iterations);
+        }
+    }
+
+    #[test]
+    fn slow_convergence_near_the_boundary_has_no_signal() {
+        // c = 0.25 is the cusp, where the orbit creeps toward 0.5.
+        let sample = iterate_detecting_cycles(0.25, 0.0, 100, EscapeMetric::Modulus);
+
+        assert_eq!(sample, IterationSample { iterations: 100, interior: None });
+    }
+}
@@ -1,12 +1,15 @@
 use crate::{
     controllers::interactive::data::fractal_config::FractalConfig,
     core::{
+        actions::{
+            diff_iterations::IterationDiffColourMap, generate_pixel_buffer::histogram::ColourSchemeKind,
+        },
         data::{
             complex::Complex, complex_rect::ComplexRect, deep_region::DeepRegion,
             pixel_rect::PixelRect,
         },
         fractals::mandelbrot::{
-            algorithm::MandelbrotAlgorithm,
+            algorithm::{DEFAULT_POWER, MandelbrotAlgorithm},
             colour_mapping::{
                 factory::mandelbrot_colour_map_factory, kinds::MandelbrotColourMapKinds,
             },
@@ -15,6 +18,8 @@ use crate::{
         },
     },
 };
+#[cfg(feature = "bigfloat")]
+use crate::core::fractals::mandelbrot::bigfloat::MandelbrotBigFloatAlgorithm;
 use std::sync::Arc;
 
 const DEFAULT_MAX_ITERATIONS: u32 = 800;
@@ -43,7 +48,15 @@ pub(crate) fn default_region() -> DeepRegion {
 pub struct MandelbrotConfig {
     pub region: DeepRegion,
     pub max_iterations: u32,
+    /// The exponent `d` in `z^d + c`; 2 is the Mandelbrot set, higher
+    /// powers the Multibrot sets.
+    pub power: u32,
     pub colour_map_kind: MandelbrotColourMapKinds,
+    pub contrast_stretch: bool,
+    /// Shade by continuous escape counts rather than whole ones. Not
+    /// available at perturbation depths, which render banded.
+    pub smooth: bool,
+    pub colour_scheme: ColourSchemeKind,
     pub orbit_cache: Arc<OrbitCache>,
 }
 
@@ -52,7 +65,11 @@ impl Default for MandelbrotConfig {
         Self {
             region: default_region(),
             max_iterations: DEFAULT_MAX_ITERATIONS,
+            power: DEFAULT_POWER,
             colour_map_kind: MandelbrotColourMapKinds::default(),
+            contrast_stretch: false,
+            smooth: false,
+            colour_scheme: ColourSchemeKind::Linear,
             orbit_cache: Arc::new(OrbitCache::new()),
         }
     }
@@ -63,7 +80,11 @@ impl PartialEq for MandelbrotConfig {
         // The orbit cache is shared infrastructure, not view state.
         self.region == other.region
             && self.max_iterations == other.max_iterations
+            && self.power == other.power
             && self.colour_map_kind == other.colour_map_kind
+            && self.contrast_stretch == other.contrast_stretch
+            && self.smooth == other.smooth
+            && self.colour_scheme == other.colour_scheme
     }
 }
 
@@ -81,14 +102,12 @@ impl MandelbrotConfig {
                 )
                 .expect("mandelbrot perturbation settings should be valid"),
             )
+        } else if let Some(algorithm) = self.bigfloat_path(pixel_rect) {
+            algorithm
         } else {
-            let region = self
-                .region
-                .to_complex_rect()
-                .expect("region above the perturbation threshold cannot collapse in f64");
-
             MandelbrotRenderPath::Direct(
-                MandelbrotAlgorithm::new(pixel_rect, region, self.max_iterations)
+                MandelbrotAlgorithm::new(pixel_rect, self.direct_region(), self.max_iterations)
+                    .and_then(|algorithm| algorithm.with_power(self.power))
                     .expect("mandelbrot algorithm settings should be valid"),
             )
         };
@@ -96,12 +115,85 @@ impl MandelbrotConfig {
         FractalConfig::Mandelbrot {
             colour_map,
             algorithm,
+            contrast_stretch: self.contrast_stretch,
+            smooth: self.smooth,
+            colour_scheme: self.colour_scheme,
         }
     }
 
+    /// The current view as a reference-diff request: the direct algorithm
+    /// against itself with interior checks disabled. `None` at perturbation
+    /// and big-float depths, where there is no direct render to check.
+    pub(crate) fn build_reference_diff_request(&self, pixel_rect: PixelRect) -> Option<FractalConfig> {
+        if self.uses_perturbation() || self.uses_bigfloat() {
+            return None;
+        }
+
+        let algorithm = MandelbrotAlgorithm::new(pixel_rect, self.direct_region(), self.max_iterations)
+            .and_then(|algorithm| algorithm.with_power(self.power))
+            .expect("mandelbrot algorithm settings should be valid");
+
+        Some(FractalConfig::MandelbrotReferenceDiff {
+            colour_map: IterationDiffColourMap,
+            reference: algorithm.clone().with_interior_checks(false),
+            algorithm,
+        })
+    }
+
+    /// Perturbation only knows `z² + c`, so other powers always render
+    /// directly.
     #[must_use]
     pub fn uses_perturbation(&self) -> bool {
-        self.region.min_extent() <= PERTURBATION_EXTENT_THRESHOLD
+        self.power == DEFAULT_POWER && self.region.min_extent() <= PERTURBATION_EXTENT_THRESHOLD
+    }
+
+    /// Views of other powers zoomed past the perturbation threshold, where
+    /// f64 can no longer resolve their pixels, iterate in arbitrary
+    /// precision. Always false without the `bigfloat` feature.
+    #[must_use]
+    pub fn uses_bigfloat(&self) -> bool {
+        cfg!(feature = "bigfloat")
+            && self.power != DEFAULT_POWER
+            && self.region.min_extent() < PERTURBATION_EXTENT_THRESHOLD
+    }
+
+    #[cfg(feature = "bigfloat")]
+    fn bigfloat_path(&self, pixel_rect: PixelRect) -> Option<MandelbrotRenderPath> {
+        if !self.uses_bigfloat() {
+            return None;
+        }
+
+        let algorithm =
+            MandelbrotBigFloatAlgorithm::new(pixel_rect, self.region.clone(), self.max_iterations)
+                .and_then(|algorithm| algorithm.with_power(self.power))
+                .expect("mandelbrot big-float settings should be valid");
+        Some(MandelbrotRenderPath::BigFloat(algorithm))
+    }
+
+    #[cfg(not(feature = "bigfloat"))]
+    fn bigfloat_path(&self, _pixel_rect: PixelRect) -> Option<MandelbrotRenderPath> {
+        None
+    }
+
+    /// The view as an f64 rect for the direct algorithm. Without the
+    /// `bigfloat` feature, views of other powers zoomed past the
+    /// perturbation threshold render at the threshold's extent, where f64
+    /// still resolves every pixel.
+    fn direct_region(&self) -> ComplexRect {
+        let region = if self.region.min_extent() < PERTURBATION_EXTENT_THRESHOLD {
+            self.region
+                .with_extent(
+                    self.region.width().max(PERTURBATION_EXTENT_THRESHOLD),
+                    self.region.height().max(PERTURBATION_EXTENT_THRESHOLD),
+                )
+                .expect("widening a valid region keeps it valid")
+        } else {
+            self.region.clone()
+        };
+
+        region
+            .to_complex_rect()
+            .expect("region at or above the perturbation threshold cannot collapse in f64")
     }
 
     pub(crate) fn reset_view(&mut self) {
@@ -156,6 +248,66 @@ mod tests {
         ));
     }
 
+    #[test]
+    #[cfg(not(feature = "bigfloat"))]
+    fn other_powers_render_directly_at_any_depth() {
+        let mut config = MandelbrotConfig {
+            power: 3,
+            ..MandelbrotConfig::default()
+        };
+        config.region = config.region.with_extent(1e-30, 1e-30).unwrap();
+        let pixel_rect = PixelRect::new(
+            crate::core::data::point::Point { x: 0, y: 0 },
+            crate::core::data::point::Point { x: 7, y: 7 },
+        )
+        .unwrap();
+
+        assert!(!config.uses_perturbation());
+        match config.build_render_request(pixel_rect) {
+            FractalConfig::Mandelbrot {
+                algorithm: MandelbrotRenderPath::Direct(algorithm),
+                ..
+            } => {
+                assert_eq!(algorithm.power(), 3);
+                let width = algorithm.complex_rect().width();
+                assert!((width / PERTURBATION_EXTENT_THRESHOLD - 1.0).abs() < 1e-6, "width {width}");
+            }
+            _ => panic!("expected a direct render"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "bigfloat")]
+    fn other_powers_switch_to_big_floats_past_the_f64_limit() {
+        let mut config = MandelbrotConfig {
+            power: 3,
+            ..MandelbrotConfig::default()
+        };
+        let pixel_rect = PixelRect::new(
+            crate::core::data::point::Point { x: 0, y: 0 },
+            crate::core::data::point::Point { x: 7, y: 7 },
+        )
+        .unwrap();
+
+        assert!(!config.uses_bigfloat());
+
+        config.region = config.region.with_extent(1e-30, 1e-30).unwrap();
+
+        assert!(config.uses_bigfloat());
+        assert!(!config.uses_perturbation());
+        assert!(config.build_reference_diff_request(pixel_rect).is_none());
+        match config.build_render_request(pixel_rect) {
+            FractalConfig::Mandelbrot {
+                algorithm: MandelbrotRenderPath::BigFloat(algorithm),
+                ..
+            } => {
+                assert_eq!(algorithm.power(), 3);
+                assert_eq!(algorithm.region(), &config.region.normalised());
+            }
+            _ => panic!("expected a big-float render"),
+        }
+    }
+
     #[test]
     fn equality_ignores_the_orbit_cache() {
         let a = MandelbrotConfig::default();
@@ -166,5 +318,27 @@ mod tests {
 
         b.max_iterations += 1;
         assert_ne!(a, b);
+
+        b.max_iterations -= 1;
+        b.power = 4;
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn reference_diff_request_only_at_direct_depths() {
+        let pixel_rect = PixelRect::new(
+            crate::core::data::point::Point { x: 0, y: 0 },
+            crate::core::data::point::Point { x: 7, y: 7 },
+        )
+        .unwrap();
+        let mut config = MandelbrotConfig::default();
+
+        assert!(matches!(
+            config.build_reference_diff_request(pixel_rect),
+            Some(FractalConfig::MandelbrotReferenceDiff { .. })
+        ));
+
+        config.region = config.region.with_extent(1e-12, 1e-12).unwrap();
+        assert!(config.build_reference_diff_request(pixel_rect).is_none());
     }
 }
@@ -1,7 +1,10 @@
 pub mod algorithm;
+#[cfg(feature = "bigfloat")]
+pub mod bigfloat;
 pub mod colour_mapping;
 pub mod errors;
 pub mod flight;
+pub mod interior;
 pub mod mandelbrot_config;
 pub mod params;
 pub mod perturbation;
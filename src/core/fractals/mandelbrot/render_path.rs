@@ -3,15 +3,21 @@ use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlg
 use crate::core::data::pixel_rect::PixelRect;
 use crate::core::data::point::Point;
 use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
+#[cfg(feature = "bigfloat")]
+use crate::core::fractals::mandelbrot::bigfloat::MandelbrotBigFloatAlgorithm;
 use crate::core::fractals::mandelbrot::perturbation::algorithm::MandelbrotPerturbationAlgorithm;
 use crate::core::util::pixel_to_complex_coords::PixelToComplexCoordsError;
 
 /// How a Mandelbrot frame gets computed: directly in f64 (fast and exact at
-/// shallow zoom) or via a perturbation reference orbit (deep zoom).
+/// shallow zoom), via a perturbation reference orbit (deep zoom), or, with
+/// the `bigfloat` feature, entirely in arbitrary precision (deep Multibrot
+/// views, which perturbation cannot render).
 #[derive(Debug, PartialEq)]
 pub enum MandelbrotRenderPath {
     Direct(MandelbrotAlgorithm),
     Perturbation(MandelbrotPerturbationAlgorithm),
+    #[cfg(feature = "bigfloat")]
+    BigFloat(MandelbrotBigFloatAlgorithm),
 }
 
 impl MandelbrotRenderPath {
@@ -21,6 +27,18 @@ impl MandelbrotRenderPath {
         match self {
             Self::Direct(_) => Ok(()),
             Self::Perturbation(algorithm) => algorithm.prepare(cancel),
+            #[cfg(feature = "bigfloat")]
+            Self::BigFloat(_) => Ok(()),
+        }
+    }
+
+    #[must_use]
+    pub fn max_iterations(&self) -> u32 {
+        match self {
+            Self::Direct(algorithm) => algorithm.max_iterations(),
+            Self::Perturbation(algorithm) => algorithm.max_iterations(),
+            #[cfg(feature = "bigfloat")]
+            Self::BigFloat(algorithm) => algorithm.max_iterations(),
         }
     }
 
@@ -29,6 +47,8 @@ impl MandelbrotRenderPath {
         match self {
             Self::Direct(_) => "CPU f64",
             Self::Perturbation(_) => "CPU perturbation",
+            #[cfg(feature = "bigfloat")]
+            Self::BigFloat(_) => "CPU arbitrary precision",
         }
     }
 }
@@ -41,6 +61,8 @@ impl FractalAlgorithm for MandelbrotRenderPath {
         match self {
             Self::Direct(algorithm) => algorithm.compute(pixel),
             Self::Perturbation(algorithm) => algorithm.compute(pixel),
+            #[cfg(feature = "bigfloat")]
+            Self::BigFloat(algorithm) => algorithm.compute(pixel),
         }
     }
 
@@ -56,6 +78,8 @@ impl FractalAlgorithm for MandelbrotRenderPath {
             Self::Perturbation(algorithm) => {
                 algorithm.compute_row_segment_into(y, x_start, x_end, output)
             }
+            #[cfg(feature = "bigfloat")]
+            Self::BigFloat(algorithm) => algorithm.compute_row_segment_into(y, x_start, x_end, output),
         }
     }
 
@@ -63,6 +87,15 @@ impl FractalAlgorithm for MandelbrotRenderPath {
         match self {
             Self::Direct(algorithm) => algorithm.pixel_rect(),
             Self::Perturbation(algorithm) => algorithm.pixel_rect(),
+            #[cfg(feature = "bigfloat")]
+            Self::BigFloat(algorithm) => algorithm.pixel_rect(),
+        }
+    }
+
+    fn mirror_row(&self, y: i32) -> Option<i32> {
+        match self {
+            Self::Direct(algorithm) => algorithm.mirror_row(y),
+            _ => None,
         }
     }
 }
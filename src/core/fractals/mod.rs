@@ -1,3 +1,11 @@
 pub mod mandelbrot;
 pub mod julia;
+pub mod burning_ship;
+pub mod tricorn;
+pub mod catalog;
+pub mod escape_metric;
+pub mod smooth_escape;
 pub mod fractal_kinds;
+pub mod parse_kind;
+pub mod precision;
+pub mod optimization_flags;
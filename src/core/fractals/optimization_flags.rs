@@ -0,0 +1,86 @@
+use std::ops::{BitOr, Sub};
+
+/// Set of shortcuts an escape-time algorithm may take, each of which must
+/// leave the output unchanged. Switching one off gives a slower reference
+/// to A/B its speed against or to check that it really is exact.
+///
+/// All are on by default. Flags combine with `|` and are removed with `-`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OptimizationFlags(u8);
+
+impl OptimizationFlags {
+    pub const NONE: Self = Self(0);
+    /// Skip iterating points inside the main cardioid or period-2 bulb.
+    pub const CARDIOID: Self = Self(1 << 0);
+    /// Stop iterating once the orbit returns exactly to an earlier point,
+    /// after which it can never escape.
+    pub const PERIODICITY: Self = Self(1 << 1);
+    /// Copy rows from their mirror image across the real axis instead of
+    /// computing both halves, where the mirror row's imaginary part is the
+    /// exact negation.
+    pub const SYMMETRY: Self = Self(1 << 2);
+    pub const ALL: Self = Self(Self::CARDIOID.0 | Self::PERIODICITY.0 | Self::SYMMETRY.0);
+
+    /// True if every flag in `other` is set.
+    #[must_use]
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// These flags with `other` set or cleared.
+    #[must_use]
+    pub fn with(self, other: Self, enabled: bool) -> Self {
+        if enabled { self | other } else { self - other }
+    }
+}
+
+impl Default for OptimizationFlags {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl BitOr for OptimizationFlags {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl Sub for OptimizationFlags {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_enables_everything() {
+        let flags = OptimizationFlags::default();
+
+        assert_eq!(flags, OptimizationFlags::ALL);
+        assert!(flags.contains(OptimizationFlags::CARDIOID));
+        assert!(flags.contains(OptimizationFlags::PERIODICITY));
+        assert!(flags.contains(OptimizationFlags::SYMMETRY));
+    }
+
+    #[test]
+    fn flags_toggle_independently() {
+        let flags = OptimizationFlags::ALL.with(OptimizationFlags::CARDIOID, false);
+
+        assert!(!flags.contains(OptimizationFlags::CARDIOID));
+        assert!(flags.contains(OptimizationFlags::PERIODICITY | OptimizationFlags::SYMMETRY));
+        assert_eq!(flags.with(OptimizationFlags::CARDIOID, true), OptimizationFlags::ALL);
+        assert_eq!(
+            OptimizationFlags::ALL - OptimizationFlags::ALL,
+            OptimizationFlags::NONE
+        );
+        assert!(OptimizationFlags::NONE.contains(OptimizationFlags::NONE));
+    }
+}
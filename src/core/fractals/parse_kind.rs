@@ -0,0 +1,73 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseKindError {
+    Unknown {
+        kind: &'static str,
+        name: String,
+        expected: Vec<&'static str>,
+    },
+}
+
+impl fmt::Display for ParseKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown {
+                kind,
+                name,
+                expected,
+            } => write!(
+                f,
+                "unknown {} '{}' (expected one of: {})",
+                kind,
+                name,
+                expected.join(", ")
+            ),
+        }
+    }
+}
+
+impl Error for ParseKindError {}
+
+/// Finds the variant of `all` whose display name matches `name`, ignoring
+/// case and surrounding whitespace. `kind` names the enum in the error.
+pub(crate) fn parse_kind<T: Copy>(
+    all: &[T],
+    display_name: fn(T) -> &'static str,
+    kind: &'static str,
+    name: &str,
+) -> Result<T, ParseKindError> {
+    let trimmed = name.trim();
+
+    all.iter()
+        .copied()
+        .find(|&variant| display_name(variant).eq_ignore_ascii_case(trimmed))
+        .ok_or_else(|| ParseKindError::Unknown {
+            kind,
+            name: name.to_owned(),
+            expected: all
+                .iter()
+                .map(|&variant| display_name(variant))
+                .collect(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_lists_expected_names() {
+        let err = ParseKindError::Unknown {
+            kind: "fractal kind",
+            name: "newton".to_owned(),
+            expected: vec!["Julia", "Mandelbrot"],
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "unknown fractal kind 'newton' (expected one of: Julia, Mandelbrot)"
+        );
+    }
+}
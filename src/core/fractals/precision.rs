@@ -0,0 +1,13 @@
+/// Floating-point type the escape-time loop iterates in.
+///
+/// `F64` is the default and the only choice for deep zooms. `F32` trades
+/// precision for speed on hardware where single precision is faster: orbits
+/// agree with `F64` for a few dozen iterations, but pixel coordinates are
+/// rounded to about seven significant digits, so views narrower than about
+/// `1e-5` collapse into blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Precision {
+    F32,
+    #[default]
+    F64,
+}
@@ -0,0 +1,78 @@
+use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::data::pixel_rect::PixelRect;
+use crate::core::data::point::Point;
+
+/// The continuous escape count `n + 1 - ln(ln|z|) / ln(power)` for an orbit
+/// that escaped on iteration `n` with `|z|² = magnitude_sq`, which removes
+/// the banding of whole counts. `None` (never escaped) is the interior,
+/// `max_iterations` exactly; escaped points stay below it.
+#[must_use]
+pub fn smooth_escape_count(escape: Option<(u32, f64)>, max_iterations: u32, power: u32) -> f64 {
+    let Some((iteration, magnitude_sq)) = escape else {
+        return f64::from(max_iterations);
+    };
+
+    let log_modulus = 0.5 * magnitude_sq.ln();
+    let count = f64::from(iteration) + 1.0 - log_modulus.ln() / f64::from(power).ln();
+    if !count.is_finite() {
+        return f64::from(iteration).min(f64::from(max_iterations).next_down());
+    }
+
+    count.clamp(0.0, f64::from(max_iterations).next_down())
+}
+
+/// An escape-time algorithm that can also report continuous escape counts
+/// for smooth shading; see [`smooth_escape_count`].
+pub trait SmoothEscape: FractalAlgorithm<Success = u32> {
+    /// Like `compute`, but fractional: escaped points fall in
+    /// `[0, max_iterations)` and interior points are `max_iterations`.
+    fn compute_smooth(&self, pixel: Point) -> Result<f64, Self::Failure>;
+
+    fn max_iterations(&self) -> u32;
+}
+
+/// Presents a [`SmoothEscape`] algorithm as one whose results are its
+/// continuous counts, for the generate-fractal actions.
+pub struct SmoothAlgorithm<'a, A: ?Sized>(pub &'a A);
+
+impl<A: SmoothEscape + ?Sized> FractalAlgorithm for SmoothAlgorithm<'_, A> {
+    type Success = f64;
+    type Failure = A::Failure;
+
+    fn compute(&self, pixel: Point) -> Result<Self::Success, Self::Failure> {
+        self.0.compute_smooth(pixel)
+    }
+
+    fn pixel_rect(&self) -> PixelRect {
+        self.0.pixel_rect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interior_is_exactly_max_iterations() {
+        assert_eq!(smooth_escape_count(None, 100, 2), 100.0);
+    }
+
+    #[test]
+    fn counts_fall_as_the_escape_overshoots_further() {
+        let just_out = smooth_escape_count(Some((5, 4.01)), 100, 2);
+        let far_out = smooth_escape_count(Some((5, 1e6)), 100, 2);
+
+        // ln(ln 2) / ln 2 is about -0.53, so a bare escape lands near n + 1.53.
+        assert!((just_out - 6.53).abs() < 0.01, "got {just_out}");
+        assert!(far_out < just_out);
+        assert!(far_out > 0.0);
+    }
+
+    #[test]
+    fn escaped_points_stay_below_the_interior() {
+        let count = smooth_escape_count(Some((100, 4.01)), 100, 2);
+
+        assert!(count < 100.0);
+        assert_eq!(smooth_escape_count(Some((1, f64::MAX)), 100, 2), 0.0);
+    }
+}
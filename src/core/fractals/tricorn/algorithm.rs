@@ -0,0 +1,204 @@
+use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::data::complex::Complex;
+use crate::core::data::complex_rect::ComplexRect;
+use crate::core::data::pixel_rect::PixelRect;
+use crate::core::data::point::Point;
+use crate::core::fractals::escape_metric::EscapeMetric;
+use crate::core::fractals::smooth_escape::{SmoothEscape, smooth_escape_count};
+use crate::core::fractals::tricorn::errors::tricorn::TricornError;
+use crate::core::util::pixel_to_complex_coords::{
+    PixelToComplexCoordsError, YAxis, check_pixel_steps, pixel_to_complex_coords_with_y_axis,
+};
+
+/// The Tricorn (Mandelbar): `z -> conj(z)^2 + c` from `z = 0`, with `c` the
+/// pixel's coordinate. Escape counts are `1..=max_iterations` like the
+/// Mandelbrot set's, so it takes the same colour maps.
+#[derive(Debug, PartialEq)]
+pub struct TricornAlgorithm {
+    pixel_rect: PixelRect,
+    complex_rect: ComplexRect,
+    max_iterations: u32,
+    escape_metric: EscapeMetric,
+    y_axis: YAxis,
+}
+
+impl FractalAlgorithm for TricornAlgorithm {
+    type Success = u32;
+    type Failure = PixelToComplexCoordsError;
+
+    fn compute(&self, pixel: Point) -> Result<Self::Success, Self::Failure> {
+        let c = pixel_to_complex_coords_with_y_axis(pixel, self.pixel_rect, self.complex_rect, self.y_axis)?;
+        Ok(self.iterate_point(c))
+    }
+
+    fn compute_row_segment_into(
+        &self,
+        y: i32,
+        x_start: i32,
+        x_end: i32,
+        output: &mut Vec<Self::Success>,
+    ) -> Result<(), Self::Failure> {
+        check_pixel_steps(self.pixel_rect, self.complex_rect)?;
+        for x in x_start..=x_end {
+            output.push(self.compute(Point { x, y })?);
+        }
+
+        Ok(())
+    }
+
+    fn pixel_rect(&self) -> PixelRect {
+        self.pixel_rect
+    }
+}
+
+impl TricornAlgorithm {
+    pub fn new(
+        pixel_rect: PixelRect,
+        complex_rect: ComplexRect,
+        max_iterations: u32,
+    ) -> Result<Self, TricornError> {
+        if max_iterations == 0 {
+            return Err(TricornError::ZeroMaxIterationsError);
+        }
+
+        Ok(Self {
+            pixel_rect,
+            complex_rect,
+            max_iterations,
+            escape_metric: EscapeMetric::default(),
+            y_axis: YAxis::default(),
+        })
+    }
+
+    /// Selects the escape test; the default `Modulus` is the classic set.
+    #[must_use]
+    pub fn with_escape_metric(mut self, escape_metric: EscapeMetric) -> Self {
+        self.escape_metric = escape_metric;
+        self
+    }
+
+    /// Selects which way imaginary grows down the image; see [`YAxis`].
+    #[must_use]
+    pub fn with_y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    #[must_use]
+    pub fn max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
+
+    #[inline]
+    fn iterate_point(&self, c: Complex) -> u32 {
+        self.escape_orbit(c)
+            .map_or(self.max_iterations, |(iteration, _)| iteration)
+    }
+
+    /// The iteration the orbit of `c` escaped on and `|z|²` there, or
+    /// `None` if it never did.
+    #[inline]
+    fn escape_orbit(&self, c: Complex) -> Option<(u32, f64)> {
+        let mut z = Complex {
+            real: 0.0,
+            imag: 0.0,
+        };
+
+        for iteration in 1..=self.max_iterations {
+            let conjugate = z.conjugate();
+            z = conjugate * conjugate + c;
+
+            let (real_sq, imag_sq) = (z.real * z.real, z.imag * z.imag);
+            if self.escape_metric.has_escaped(z.real, z.imag, real_sq, imag_sq) {
+                return Some((iteration, real_sq + imag_sq));
+            }
+        }
+
+        None
+    }
+}
+
+impl SmoothEscape for TricornAlgorithm {
+    fn compute_smooth(&self, pixel: Point) -> Result<f64, Self::Failure> {
+        let c = pixel_to_complex_coords_with_y_axis(pixel, self.pixel_rect, self.complex_rect, self.y_axis)?;
+        Ok(smooth_escape_count(self.escape_orbit(c), self.max_iterations, 2))
+    }
+
+    fn max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(real: f64, imag: f64) -> Complex {
+        Complex { real, imag }
+    }
+
+    /// `-2 - 2i` to `2 + 2i` on a 9x9 grid, so every pixel lands exactly on
+    /// a multiple of 0.5 and rows `y` and `8 - y` are exact conjugates.
+    fn whole_set(max_iterations: u32) -> Result<TricornAlgorithm, TricornError> {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 8, y: 8 }).unwrap();
+        let complex_rect = ComplexRect::new(c(-2.0, -2.0), c(2.0, 2.0)).unwrap();
+
+        TricornAlgorithm::new(pixel_rect, complex_rect, max_iterations)
+    }
+
+    #[test]
+    fn max_iterations_must_be_greater_than_zero() {
+        assert_eq!(whole_set(0), Err(TricornError::ZeroMaxIterationsError));
+    }
+
+    #[test]
+    fn i_escapes_although_the_mandelbrot_orbit_stays_bounded() {
+        // Squaring alone cycles i through -1 + i and -i forever; conjugating
+        // first sends it to -1 + i and then 3i, out on the third step.
+        assert_eq!(whole_set(50).unwrap().iterate_point(c(0.0, 1.0)), 3);
+    }
+
+    #[test]
+    fn is_symmetric_about_the_real_axis() {
+        let algorithm = whole_set(100).unwrap();
+
+        for step in 0..40 {
+            let point = c(-2.0 + 0.1 * f64::from(step), 0.05 + 0.037 * f64::from(step));
+            assert_eq!(
+                algorithm.iterate_point(point),
+                algorithm.iterate_point(point.conjugate()),
+                "{point}"
+            );
+        }
+    }
+
+    #[test]
+    fn rendered_rows_mirror_about_the_real_axis() {
+        let algorithm = whole_set(100).unwrap();
+        let mut rows = Vec::new();
+        for y in 0..=8 {
+            let mut row = Vec::new();
+            algorithm.compute_row_segment_into(y, 0, 8, &mut row).unwrap();
+            rows.push(row);
+        }
+
+        let mirrored: Vec<_> = rows.iter().rev().cloned().collect();
+        assert_eq!(rows, mirrored);
+        // Not trivially: the set's three lobes make the rows differ.
+        assert_ne!(rows[2], rows[4]);
+    }
+
+    #[test]
+    fn compute_returns_error_for_pixel_outside_pixel_rect() {
+        let algorithm = whole_set(10).unwrap();
+        let point = Point { x: 9, y: 0 };
+
+        assert_eq!(
+            algorithm.compute(point),
+            Err(PixelToComplexCoordsError::PointOutsideRect {
+                point,
+                pixel_rect: algorithm.pixel_rect(),
+            })
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use crate::core::{
+    data::pixel_rect::PixelRect,
+    flight::{FlightLimits, FlightUpdateReport, MotionState},
+    fractals::{
+        julia::flight::step_region_in_viewport,
+        tricorn::tricorn_config::{TricornConfig, default_region},
+    },
+};
+
+/// Flies the Tricorn view the same way as Julia's: both are plain f64
+/// regions. A view that stops being finite goes back to the whole set.
+pub fn step_flight_in_viewport(
+    config: &mut TricornConfig,
+    motion: &MotionState,
+    dt: f64,
+    limits: &FlightLimits,
+    viewport: Option<PixelRect>,
+) -> FlightUpdateReport {
+    step_region_in_viewport(&mut config.region, default_region(), motion, dt, limits, viewport)
+}
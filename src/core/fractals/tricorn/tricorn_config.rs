@@ -0,0 +1,111 @@
+use crate::{
+    controllers::interactive::data::fractal_config::FractalConfig,
+    core::{
+        actions::generate_pixel_buffer::histogram::ColourSchemeKind,
+        data::{complex::Complex, complex_rect::ComplexRect, pixel_rect::PixelRect},
+        fractals::{
+            tricorn::algorithm::TricornAlgorithm,
+            mandelbrot::colour_mapping::{
+                factory::mandelbrot_colour_map_factory, kinds::MandelbrotColourMapKinds,
+            },
+        },
+    },
+};
+
+const DEFAULT_MAX_ITERATIONS: u32 = 256;
+
+/// The whole Tricorn, from `-2.5 - 2i` to `2 + 2i`.
+pub(crate) fn default_region() -> ComplexRect {
+    ComplexRect::new(
+        Complex {
+            real: -2.5,
+            imag: -2.0,
+        },
+        Complex {
+            real: 2.0,
+            imag: 2.0,
+        },
+    )
+    .expect("default fractal region is valid")
+}
+
+/// Tricorn settings. Its escape counts are coloured with the Mandelbrot
+/// colour maps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TricornConfig {
+    pub region: ComplexRect,
+    pub max_iterations: u32,
+    pub colour_map_kind: MandelbrotColourMapKinds,
+    pub contrast_stretch: bool,
+    pub smooth: bool,
+    pub colour_scheme: ColourSchemeKind,
+}
+
+impl Default for TricornConfig {
+    fn default() -> Self {
+        Self {
+            region: default_region(),
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            colour_map_kind: MandelbrotColourMapKinds::default(),
+            contrast_stretch: false,
+            smooth: false,
+            colour_scheme: ColourSchemeKind::Linear,
+        }
+    }
+}
+
+impl TricornConfig {
+    pub(crate) fn build_render_request(&self, pixel_rect: PixelRect) -> FractalConfig {
+        let colour_map = mandelbrot_colour_map_factory(self.colour_map_kind, self.max_iterations);
+        let algorithm = TricornAlgorithm::new(pixel_rect, self.region, self.max_iterations)
+            .expect("tricorn algorithm settings should be valid");
+
+        FractalConfig::Tricorn {
+            colour_map,
+            algorithm,
+            contrast_stretch: self.contrast_stretch,
+            smooth: self.smooth,
+            colour_scheme: self.colour_scheme,
+        }
+    }
+
+    pub fn reset_view(&mut self) {
+        self.region = default_region();
+        self.max_iterations = DEFAULT_MAX_ITERATIONS;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::actions::generate_fractal::generate_fractal_parallel_rayon::generate_fractal_parallel_rayon;
+    use crate::core::data::point::Point;
+    use crate::core::fractals::mandelbrot::colour_mapping::kinds::MandelbrotColourMapKinds;
+
+    #[test]
+    fn default_view_shows_the_whole_set() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 39, y: 31 }).unwrap();
+        let request = TricornConfig::default().build_render_request(pixel_rect);
+
+        let iterations = generate_fractal_parallel_rayon(pixel_rect, request.algorithm()).unwrap();
+
+        // The set and the escaping plane around it are both in view.
+        assert!(iterations.contains(&DEFAULT_MAX_ITERATIONS));
+        assert!(iterations.iter().any(|&count| count < 10));
+    }
+
+    #[test]
+    fn request_uses_the_chosen_mandelbrot_colour_map() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 7, y: 7 }).unwrap();
+        let config = TricornConfig {
+            colour_map_kind: MandelbrotColourMapKinds::FireGradient,
+            ..TricornConfig::default()
+        };
+
+        let FractalConfig::Tricorn { colour_map, .. } = config.build_render_request(pixel_rect)
+        else {
+            panic!("expected a Tricorn request");
+        };
+        assert_eq!(colour_map.kind(), MandelbrotColourMapKinds::FireGradient);
+    }
+}
@@ -2,3 +2,7 @@ pub mod calculate_bands_in_pixel_rect;
 pub mod calculate_threads_for_pixel_rect_banding;
 pub mod iteration_colour_lut;
 pub mod pixel_to_complex_coords;
+#[cfg(test)]
+pub mod temp_file_path;
+pub mod upsample_nearest;
+pub mod viridis;
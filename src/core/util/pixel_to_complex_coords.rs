@@ -5,9 +5,64 @@ use crate::core::data::point::Point;
 use std::error::Error;
 use std::fmt;
 
+/// Which way imaginary grows along pixel rows.
+///
+/// `ScreenDown` maps the top pixel row to `top_left.imag` (the minimum), so
+/// imaginary grows downward like pixel y. `MathUp` flips this so the top row
+/// is the maximum imaginary, matching textbook plots.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum YAxis {
+    #[default]
+    ScreenDown,
+    MathUp,
+}
+
+impl YAxis {
+    /// Row offset from the top of a `height`-pixel rect to use for the
+    /// imaginary coordinate of row `relative_y`.
+    #[inline]
+    #[must_use]
+    pub fn imag_row(self, relative_y: i32, height: u32) -> i32 {
+        match self {
+            Self::ScreenDown => relative_y,
+            Self::MathUp => height as i32 - 1 - relative_y,
+        }
+    }
+}
+
+/// Where within a pixel its complex coordinate is taken.
+///
+/// `Corner` spaces pixels so the first and last columns (and rows) land
+/// exactly on the complex rect's edges. `Center` treats the pixel rect as
+/// cells tiling the complex rect and samples the middle of each, half a
+/// cell in from the edges; it is what supersampling and the inverse map
+/// expect.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum PixelSample {
+    #[default]
+    Corner,
+    Center,
+}
+
+impl PixelSample {
+    /// Fraction of the way across an axis of `pixels` pixels at which
+    /// pixel `relative` samples.
+    #[inline]
+    #[must_use]
+    pub fn fraction(self, relative: i32, pixels: u32) -> f64 {
+        match self {
+            Self::Corner => f64::from(relative) / f64::from(pixels - 1),
+            Self::Center => (f64::from(relative) + 0.5) / f64::from(pixels),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum PixelToComplexCoordsError {
     PointOutsideRect { point: Point, pixel_rect: PixelRect },
+    /// The per-pixel step along an axis is zero or subnormal, so
+    /// neighbouring pixels collapse onto the same coordinate.
+    PrecisionExhausted { real_step: f64, imag_step: f64 },
 }
 
 impl fmt::Display for PixelToComplexCoordsError {
@@ -25,6 +80,14 @@ impl fmt::Display for PixelToComplexCoordsError {
                     pixel_rect.bottom_right().y
                 )
             }
+            Self::PrecisionExhausted {
+                real_step,
+                imag_step,
+            } => write!(
+                f,
+                "precision exhausted: per-pixel step (real: {:e}, imag: {:e}) underflows f64",
+                real_step, imag_step
+            ),
         }
     }
 }
@@ -35,6 +98,27 @@ pub fn pixel_to_complex_coords(
     pixel_position: Point,
     pixel_rect: PixelRect,
     complex_rect: ComplexRect,
+) -> Result<Complex, PixelToComplexCoordsError> {
+    pixel_to_complex_coords_with_y_axis(pixel_position, pixel_rect, complex_rect, YAxis::ScreenDown)
+}
+
+pub fn pixel_to_complex_coords_with_y_axis(
+    pixel_position: Point,
+    pixel_rect: PixelRect,
+    complex_rect: ComplexRect,
+    y_axis: YAxis,
+) -> Result<Complex, PixelToComplexCoordsError> {
+    pixel_to_complex_coords_sampled(pixel_position, pixel_rect, complex_rect, y_axis, PixelSample::Corner)
+}
+
+/// The complex coordinate of `pixel_position`, taken at the point of the
+/// pixel `sample_at` selects.
+pub fn pixel_to_complex_coords_sampled(
+    pixel_position: Point,
+    pixel_rect: PixelRect,
+    complex_rect: ComplexRect,
+    y_axis: YAxis,
+    sample_at: PixelSample,
 ) -> Result<Complex, PixelToComplexCoordsError> {
     if !pixel_rect.contains_point(pixel_position) {
         return Err(PixelToComplexCoordsError::PointOutsideRect {
@@ -43,16 +127,47 @@ pub fn pixel_to_complex_coords(
         });
     }
 
-    let relative_pixel_x = (pixel_position.x - pixel_rect.top_left().x) as f64;
-    let relative_pixel_y = (pixel_position.y - pixel_rect.top_left().y) as f64;
+    let relative_pixel_x = pixel_position.x - pixel_rect.top_left().x;
+    let relative_pixel_y =
+        y_axis.imag_row(pixel_position.y - pixel_rect.top_left().y, pixel_rect.height());
     let real = complex_rect.top_left().real
-        + (relative_pixel_x / (pixel_rect.width() - 1) as f64) * complex_rect.width();
+        + sample_at.fraction(relative_pixel_x, pixel_rect.width()) * complex_rect.width();
     let imag = complex_rect.top_left().imag
-        + (relative_pixel_y / (pixel_rect.height() - 1) as f64) * complex_rect.height();
+        + sample_at.fraction(relative_pixel_y, pixel_rect.height()) * complex_rect.height();
 
     Ok(Complex { real, imag })
 }
 
+/// Rejects regions whose per-pixel step is zero or subnormal on an axis
+/// with more than one pixel. Past that point f64 cannot tell neighbouring
+/// pixels apart and the render would come out as flat blocks.
+///
+/// The per-pixel mapping does not check this itself; algorithms run it
+/// once per row rather than for every pixel.
+pub fn check_pixel_steps(
+    pixel_rect: PixelRect,
+    complex_rect: ComplexRect,
+) -> Result<(), PixelToComplexCoordsError> {
+    let step = |extent: f64, pixels: u32| {
+        if pixels > 1 {
+            extent / f64::from(pixels - 1)
+        } else {
+            extent
+        }
+    };
+    let real_step = step(complex_rect.width(), pixel_rect.width());
+    let imag_step = step(complex_rect.height(), pixel_rect.height());
+
+    if !real_step.is_normal() || !imag_step.is_normal() {
+        return Err(PixelToComplexCoordsError::PrecisionExhausted {
+            real_step,
+            imag_step,
+        });
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -159,4 +274,178 @@ mod tests {
             })
         );
     }
+
+    fn underflowing_rect() -> ComplexRect {
+        // 1e-306 over 1000 steps is ~1e-309, below f64::MIN_POSITIVE.
+        ComplexRect::new(
+            Complex {
+                real: 0.0,
+                imag: 0.0,
+            },
+            Complex {
+                real: 1e-306,
+                imag: 1e-306,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn subnormal_step_reports_precision_exhausted() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 1000, y: 1000 }).unwrap();
+
+        let result = check_pixel_steps(pixel_rect, underflowing_rect());
+
+        assert!(matches!(
+            result,
+            Err(PixelToComplexCoordsError::PrecisionExhausted { real_step, imag_step })
+                if real_step < f64::MIN_POSITIVE && imag_step < f64::MIN_POSITIVE
+        ));
+        assert!(result.unwrap_err().to_string().starts_with("precision exhausted"));
+    }
+
+    #[test]
+    fn small_but_normal_step_is_accepted() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 10, y: 10 }).unwrap();
+
+        assert!(check_pixel_steps(pixel_rect, underflowing_rect()).is_ok());
+    }
+
+    #[test]
+    fn math_up_mirrors_rows_vertically() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 4, y: 6 }).unwrap();
+        let complex_rect = ComplexRect::new(
+            Complex {
+                real: -1.0,
+                imag: -0.5,
+            },
+            Complex {
+                real: 1.0,
+                imag: 1.0,
+            },
+        )
+        .unwrap();
+
+        for y in 0..=6 {
+            for x in 0..=4 {
+                let down = pixel_to_complex_coords_with_y_axis(
+                    Point { x, y },
+                    pixel_rect,
+                    complex_rect,
+                    YAxis::ScreenDown,
+                )
+                .unwrap();
+                let up = pixel_to_complex_coords_with_y_axis(
+                    Point { x, y: 6 - y },
+                    pixel_rect,
+                    complex_rect,
+                    YAxis::MathUp,
+                )
+                .unwrap();
+
+                assert_eq!(down, up);
+            }
+        }
+
+        let top_left_up = pixel_to_complex_coords_with_y_axis(
+            Point { x: 0, y: 0 },
+            pixel_rect,
+            complex_rect,
+            YAxis::MathUp,
+        )
+        .unwrap();
+        assert_eq!(top_left_up, Complex { real: -1.0, imag: 1.0 });
+    }
+
+    fn sampled(point: Point, sample_at: PixelSample) -> Complex {
+        // 4x2 pixels over a 4x2 region: one unit per pixel.
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 1 }).unwrap();
+        let complex_rect = ComplexRect::new(
+            Complex {
+                real: 0.0,
+                imag: 0.0,
+            },
+            Complex {
+                real: 4.0,
+                imag: 2.0,
+            },
+        )
+        .unwrap();
+
+        pixel_to_complex_coords_sampled(point, pixel_rect, complex_rect, YAxis::ScreenDown, sample_at)
+            .unwrap()
+    }
+
+    #[test]
+    fn corner_sampling_reaches_both_edges() {
+        assert_eq!(sampled(Point { x: 0, y: 0 }, PixelSample::Corner), Complex { real: 0.0, imag: 0.0 });
+        assert_eq!(sampled(Point { x: 3, y: 1 }, PixelSample::Corner), Complex { real: 4.0, imag: 2.0 });
+        assert_eq!(
+            sampled(Point { x: 1, y: 0 }, PixelSample::Corner),
+            Complex {
+                real: 4.0 / 3.0,
+                imag: 0.0
+            }
+        );
+    }
+
+    #[test]
+    fn center_sampling_takes_the_middle_of_each_cell() {
+        assert_eq!(sampled(Point { x: 0, y: 0 }, PixelSample::Center), Complex { real: 0.5, imag: 0.5 });
+        assert_eq!(sampled(Point { x: 1, y: 0 }, PixelSample::Center), Complex { real: 1.5, imag: 0.5 });
+        assert_eq!(sampled(Point { x: 3, y: 1 }, PixelSample::Center), Complex { real: 3.5, imag: 1.5 });
+    }
+
+    #[test]
+    fn default_sampling_matches_the_existing_mapping() {
+        let pixel_rect = PixelRect::new(Point { x: 2, y: 3 }, Point { x: 12, y: 9 }).unwrap();
+        let complex_rect = ComplexRect::new(
+            Complex {
+                real: -2.0,
+                imag: -1.0,
+            },
+            Complex {
+                real: 1.0,
+                imag: 1.0,
+            },
+        )
+        .unwrap();
+
+        for (x, y) in [(2, 3), (7, 5), (12, 9)] {
+            let point = Point { x, y };
+            for y_axis in [YAxis::ScreenDown, YAxis::MathUp] {
+                assert_eq!(
+                    pixel_to_complex_coords_sampled(point, pixel_rect, complex_rect, y_axis, PixelSample::default()),
+                    pixel_to_complex_coords_with_y_axis(point, pixel_rect, complex_rect, y_axis)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn center_sampling_mirrors_under_math_up() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 0, y: 3 }).unwrap();
+        let complex_rect = ComplexRect::new(
+            Complex {
+                real: 0.0,
+                imag: 0.0,
+            },
+            Complex {
+                real: 1.0,
+                imag: 4.0,
+            },
+        )
+        .unwrap();
+
+        let top = pixel_to_complex_coords_sampled(
+            Point { x: 0, y: 0 },
+            pixel_rect,
+            complex_rect,
+            YAxis::MathUp,
+            PixelSample::Center,
+        )
+        .unwrap();
+
+        assert_eq!(top, Complex { real: 0.5, imag: 3.5 });
+    }
 }
@@ -0,0 +1,19 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A fresh path in the system temp directory for a test to write
+/// `test_name`'s output to, unique to this process and moment so parallel
+/// tests never collide.
+pub fn temp_file_path(test_name: &str, extension: &str) -> PathBuf {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!(
+        "fractal_explorer_{}_{}_{}.{}",
+        test_name,
+        std::process::id(),
+        timestamp,
+        extension
+    ))
+}
@@ -0,0 +1,87 @@
+/// Nearest-neighbour resample of a row-major `src_width`x`src_height` field
+/// to `dst_width`x`dst_height`.
+///
+/// Each destination cell takes the source cell its top-left corner falls
+/// in, so an integer upscale replicates every value into a block. Works
+/// for iteration fields and, with `[u8; 4]` cells, for RGBA frames. Returns
+/// `None` if `src` does not hold `src_width * src_height` cells or the
+/// source is empty while the destination is not.
+pub fn upsample_nearest<T: Copy>(
+    src: &[T],
+    src_width: u32,
+    src_height: u32,
+    dst_width: u32,
+    dst_height: u32,
+) -> Option<Vec<T>> {
+    let (src_width, src_height) = (src_width as usize, src_height as usize);
+    let (dst_width, dst_height) = (dst_width as usize, dst_height as usize);
+
+    if src.len() != src_width * src_height {
+        return None;
+    }
+
+    if dst_width == 0 || dst_height == 0 {
+        return Some(Vec::new());
+    }
+
+    if src.is_empty() {
+        return None;
+    }
+
+    let columns: Vec<usize> = (0..dst_width)
+        .map(|x| x * src_width / dst_width)
+        .collect();
+
+    let mut dst = Vec::with_capacity(dst_width * dst_height);
+    for y in 0..dst_height {
+        let row = &src[(y * src_height / dst_height) * src_width..][..src_width];
+        dst.extend(columns.iter().map(|&x| row[x]));
+    }
+
+    Some(dst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn doubling_replicates_each_value_into_a_block() {
+        let src = [1u32, 2, 3, 4];
+
+        let dst = upsample_nearest(&src, 2, 2, 4, 4).unwrap();
+
+        assert_eq!(
+            dst,
+            vec![
+                1, 1, 2, 2, //
+                1, 1, 2, 2, //
+                3, 3, 4, 4, //
+                3, 3, 4, 4,
+            ]
+        );
+    }
+
+    #[test]
+    fn non_integer_scale_picks_the_covering_source_cell() {
+        let src = [10u32, 20, 30];
+
+        let dst = upsample_nearest(&src, 3, 1, 5, 2).unwrap();
+
+        assert_eq!(dst, vec![10, 10, 20, 20, 30, 10, 10, 20, 20, 30]);
+    }
+
+    #[test]
+    fn same_size_is_a_copy() {
+        let src = [[1u8, 2, 3, 255], [4, 5, 6, 255]];
+
+        assert_eq!(upsample_nearest(&src, 2, 1, 2, 1).unwrap(), src.to_vec());
+    }
+
+    #[test]
+    fn rejects_mismatched_source_length() {
+        assert_eq!(upsample_nearest(&[1u32, 2, 3], 2, 2, 4, 4), None);
+        assert_eq!(upsample_nearest::<u32>(&[], 0, 0, 4, 4), None);
+        assert_eq!(upsample_nearest(&[1u32], 1, 1, 0, 4), Some(Vec::new()));
+    }
+}
@@ -0,0 +1,79 @@
+use crate::core::data::colour::Colour;
+
+/// Viridis sampled at nine evenly spaced points, dark purple to yellow.
+/// Lightness rises steadily along it and it avoids red-green contrast, so
+/// bands stay distinguishable with the common forms of colour blindness
+/// and in greyscale.
+const CONTROL_POINTS: [Colour; 9] = [
+    Colour { r: 68, g: 1, b: 84 },
+    Colour { r: 71, g: 45, b: 123 },
+    Colour { r: 59, g: 82, b: 139 },
+    Colour { r: 44, g: 114, b: 142 },
+    Colour { r: 33, g: 144, b: 140 },
+    Colour { r: 39, g: 173, b: 129 },
+    Colour { r: 93, g: 200, b: 99 },
+    Colour { r: 170, g: 220, b: 50 },
+    Colour { r: 253, g: 231, b: 37 },
+];
+
+/// The viridis colour at `t` in `[0, 1]` (clamped), interpolated linearly
+/// between the control points either side.
+#[must_use]
+pub fn viridis(t: f64) -> Colour {
+    let segments = (CONTROL_POINTS.len() - 1) as f64;
+    let position = t.clamp(0.0, 1.0) * segments;
+    let index = (position.floor() as usize).min(CONTROL_POINTS.len() - 2);
+
+    CONTROL_POINTS[index].lerp(CONTROL_POINTS[index + 1], position - index as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Colour, expected: (u8, u8, u8), tolerance: u8) {
+        let close = actual.r.abs_diff(expected.0) <= tolerance
+            && actual.g.abs_diff(expected.1) <= tolerance
+            && actual.b.abs_diff(expected.2) <= tolerance;
+
+        assert!(close, "{actual:?} is not within {tolerance} of {expected:?}");
+    }
+
+    /// Rec. 709 relative luminance of the raw channel values, enough to
+    /// compare lightness along the palette.
+    fn luminance(colour: Colour) -> f64 {
+        0.2126 * f64::from(colour.r) + 0.7152 * f64::from(colour.g) + 0.0722 * f64::from(colour.b)
+    }
+
+    #[test]
+    fn samples_match_the_reference_palette() {
+        // Reference values from matplotlib's 256-entry viridis table.
+        assert_close(viridis(0.0), (68, 1, 84), 0);
+        assert_close(viridis(0.25), (59, 82, 139), 1);
+        assert_close(viridis(0.5), (33, 145, 140), 2);
+        assert_close(viridis(0.75), (94, 201, 98), 2);
+        assert_close(viridis(1.0), (253, 231, 37), 0);
+    }
+
+    #[test]
+    fn points_between_samples_are_interpolated() {
+        let colour = viridis(1.0 / 16.0);
+
+        assert_close(colour, (70, 23, 104), 0);
+    }
+
+    #[test]
+    fn lightness_rises_monotonically() {
+        let lightness: Vec<f64> = (0..=64).map(|i| luminance(viridis(f64::from(i) / 64.0))).collect();
+
+        for pair in lightness.windows(2) {
+            assert!(pair[1] >= pair[0], "lightness falls from {} to {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn t_outside_the_range_is_clamped() {
+        assert_close(viridis(-0.5), (68, 1, 84), 0);
+        assert_close(viridis(3.0), (253, 231, 37), 0);
+    }
+}
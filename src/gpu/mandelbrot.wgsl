@@ -0,0 +1,62 @@
+// Direct Mandelbrot iteration, z -> z^2 + c from z = 0.
+//
+// Mirrors MandelbrotAlgorithm's modulus escape test (f64 on the CPU) in
+// f32: one invocation per pixel iterates its own c and writes the escape
+// iteration count, or max_iterations for points that never escape.
+
+struct Params {
+    width: u32,
+    height: u32,
+    max_iterations: u32,
+    _padding: u32,
+    origin_re: f32,
+    origin_im: f32,
+    step_re: f32,
+    step_im: f32,
+}
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read_write> iterations: array<u32>;
+
+const ESCAPE_RADIUS_SQ: f32 = 4.0;
+
+// Main cardioid or period-2 bulb: never escapes.
+fn is_known_interior(c: vec2<f32>) -> bool {
+    let x = c.x - 0.25;
+    let q = x * x + c.y * c.y;
+    if (q * (q + x) <= 0.25 * c.y * c.y) {
+        return true;
+    }
+
+    let bulb_x = c.x + 1.0;
+    return bulb_x * bulb_x + c.y * c.y <= 0.0625;
+}
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
+    if (gid.x >= params.width || gid.y >= params.height) {
+        return;
+    }
+
+    let c = vec2<f32>(
+        params.origin_re + f32(gid.x) * params.step_re,
+        params.origin_im + f32(gid.y) * params.step_im,
+    );
+
+    var result = params.max_iterations;
+
+    if (!is_known_interior(c)) {
+        var z = vec2<f32>(0.0, 0.0);
+
+        for (var n = 1u; n <= params.max_iterations; n = n + 1u) {
+            z = vec2<f32>(z.x * z.x - z.y * z.y + c.x, 2.0 * z.x * z.y + c.y);
+
+            if (dot(z, z) > ESCAPE_RADIUS_SQ) {
+                result = n;
+                break;
+            }
+        }
+    }
+
+    iterations[gid.y * params.width + gid.x] = result;
+}
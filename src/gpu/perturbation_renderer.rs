@@ -1,12 +1,20 @@
 use crate::controllers::interactive::ports::gpu_renderer::GpuFractalRendererPort;
 use crate::core::actions::generate_fractal::ports::fractal_algorithm::FractalAlgorithm;
+use crate::core::fractals::escape_metric::EscapeMetric;
+use crate::core::fractals::mandelbrot::algorithm::{DEFAULT_POWER, MandelbrotAlgorithm};
 use crate::core::fractals::mandelbrot::perturbation::algorithm::MandelbrotPerturbationAlgorithm;
+use crate::core::util::pixel_to_complex_coords::YAxis;
 use wgpu::util::DeviceExt;
 
 /// Below this view extent, f32 deltas underflow/lose too much precision and
 /// the render falls back to the CPU's f64 delta iteration.
 const MIN_SUPPORTED_EXTENT: f64 = 1e-30;
 
+/// Below this per-pixel step, f32 coordinates can no longer tell nearby
+/// pixels apart cleanly and direct renders stay on the CPU's f64 path
+/// (deeper views use perturbation anyway).
+const MIN_DIRECT_PIXEL_STEP: f64 = 1e-5;
+
 const WORKGROUP_SIZE: u32 = 16;
 
 #[repr(C)]
@@ -22,14 +30,30 @@ struct GpuParams {
     step_im: f32,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct GpuDirectParams {
+    width: u32,
+    height: u32,
+    max_iterations: u32,
+    padding: u32,
+    origin_re: f32,
+    origin_im: f32,
+    step_re: f32,
+    step_im: f32,
+}
+
 struct GpuState {
     device: wgpu::Device,
     queue: wgpu::Queue,
     pipeline: wgpu::ComputePipeline,
     bind_group_layout: wgpu::BindGroupLayout,
+    direct_pipeline: wgpu::ComputePipeline,
+    direct_bind_group_layout: wgpu::BindGroupLayout,
 }
 
-/// wgpu compute implementation of the perturbation delta iteration.
+/// wgpu compute implementation of the perturbation delta iteration, and of
+/// the direct iteration for shallow views (`mandelbrot.wgsl`).
 ///
 /// The device is created lazily on first use (on the render worker thread);
 /// if initialisation or any render step fails, the renderer reports
@@ -88,66 +112,82 @@ impl WgpuPerturbationRenderer {
         ))
         .ok()?;
 
+        let (pipeline, bind_group_layout) = Self::create_pipeline(
+            &device,
+            "perturbation",
+            include_str!("perturbation.wgsl"),
+            &[
+                wgpu::BufferBindingType::Uniform,
+                wgpu::BufferBindingType::Storage { read_only: true },
+                wgpu::BufferBindingType::Storage { read_only: false },
+            ],
+        );
+        let (direct_pipeline, direct_bind_group_layout) = Self::create_pipeline(
+            &device,
+            "direct mandelbrot",
+            include_str!("mandelbrot.wgsl"),
+            &[
+                wgpu::BufferBindingType::Uniform,
+                wgpu::BufferBindingType::Storage { read_only: false },
+            ],
+        );
+
+        Some(GpuState {
+            device,
+            queue,
+            pipeline,
+            bind_group_layout,
+            direct_pipeline,
+            direct_bind_group_layout,
+        })
+    }
+
+    /// A compute pipeline running `source`'s `main`, with one buffer binding
+    /// per entry of `bindings`, numbered from zero.
+    fn create_pipeline(
+        device: &wgpu::Device,
+        label: &str,
+        source: &str,
+        bindings: &[wgpu::BufferBindingType],
+    ) -> (wgpu::ComputePipeline, wgpu::BindGroupLayout) {
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("perturbation compute shader"),
-            source: wgpu::ShaderSource::Wgsl(include_str!("perturbation.wgsl").into()),
+            label: Some(label),
+            source: wgpu::ShaderSource::Wgsl(source.into()),
         });
 
-        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
-            label: Some("perturbation bind group layout"),
-            entries: &[
-                wgpu::BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: wgpu::ShaderStages::COMPUTE,
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: None,
-                    },
-                    count: None,
-                },
-                wgpu::BindGroupLayoutEntry {
-                    binding: 1,
-                    visibility: wgpu::ShaderStages::COMPUTE,
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Storage { read_only: true },
-                        has_dynamic_offset: false,
-                        min_binding_size: None,
-                    },
-                    count: None,
-                },
-                wgpu::BindGroupLayoutEntry {
-                    binding: 2,
-                    visibility: wgpu::ShaderStages::COMPUTE,
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Storage { read_only: false },
-                        has_dynamic_offset: false,
-                        min_binding_size: None,
-                    },
-                    count: None,
+        let entries: Vec<wgpu::BindGroupLayoutEntry> = bindings
+            .iter()
+            .zip(0..)
+            .map(|(&ty, binding)| wgpu::BindGroupLayoutEntry {
+                binding,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
                 },
-            ],
+                count: None,
+            })
+            .collect();
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some(label),
+            entries: &entries,
         });
 
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-            label: Some("perturbation pipeline layout"),
+            label: Some(label),
             bind_group_layouts: &[&bind_group_layout],
             push_constant_ranges: &[],
         });
 
         let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
-            label: Some("perturbation pipeline"),
+            label: Some(label),
             layout: Some(&pipeline_layout),
             module: &shader,
             entry_point: "main",
         });
 
-        Some(GpuState {
-            device,
-            queue,
-            pipeline,
-            bind_group_layout,
-        })
+        (pipeline, bind_group_layout)
     }
 
     fn render(
@@ -187,12 +227,6 @@ impl WgpuPerturbationRenderer {
             .map(|z| [z[0] as f32, z[1] as f32])
             .collect();
 
-        let output_size = (width as u64) * (height as u64) * std::mem::size_of::<u32>() as u64;
-        let max_binding = state.device.limits().max_storage_buffer_binding_size as u64;
-        if output_size > max_binding {
-            return None;
-        }
-
         let params_buffer = state
             .device
             .create_buffer_init(&wgpu::util::BufferInitDescriptor {
@@ -209,51 +243,130 @@ impl WgpuPerturbationRenderer {
                 usage: wgpu::BufferUsages::STORAGE,
             });
 
+        Self::run(
+            state,
+            &state.pipeline,
+            &state.bind_group_layout,
+            &[&params_buffer, &orbit_buffer],
+            width,
+            height,
+        )
+    }
+
+    fn render_direct(state: &GpuState, algorithm: &MandelbrotAlgorithm) -> Option<Vec<u32>> {
+        let pixel_rect = algorithm.pixel_rect();
+        let width = pixel_rect.width();
+        let height = pixel_rect.height();
+
+        // Only the quadratic modulus test is implemented in the shader.
+        if width < 2
+            || height < 2
+            || algorithm.power() != DEFAULT_POWER
+            || algorithm.escape_metric() != EscapeMetric::Modulus
+        {
+            return None;
+        }
+
+        let region = algorithm.complex_rect();
+        let step_re = region.width() / f64::from(width - 1);
+        let step_im = region.height() / f64::from(height - 1);
+        if step_re.min(step_im) < MIN_DIRECT_PIXEL_STEP {
+            return None;
+        }
+
+        let top_left = region.top_left();
+        let (origin_im, step_im) = match algorithm.y_axis() {
+            YAxis::ScreenDown => (top_left.imag, step_im),
+            YAxis::MathUp => (top_left.imag + region.height(), -step_im),
+        };
+        let params = GpuDirectParams {
+            width,
+            height,
+            max_iterations: algorithm.max_iterations(),
+            padding: 0,
+            origin_re: top_left.real as f32,
+            origin_im: origin_im as f32,
+            step_re: step_re as f32,
+            step_im: step_im as f32,
+        };
+
+        let params_buffer = state
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("direct mandelbrot params"),
+                contents: bytemuck::bytes_of(&params),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+
+        Self::run(
+            state,
+            &state.direct_pipeline,
+            &state.direct_bind_group_layout,
+            &[&params_buffer],
+            width,
+            height,
+        )
+    }
+
+    /// Dispatches `pipeline` over a `width`x`height` grid with `inputs`
+    /// bound in order and a `u32` per pixel output bound after them, then
+    /// reads the output back. `None` if the output is too large to bind or
+    /// the readback fails.
+    fn run(
+        state: &GpuState,
+        pipeline: &wgpu::ComputePipeline,
+        bind_group_layout: &wgpu::BindGroupLayout,
+        inputs: &[&wgpu::Buffer],
+        width: u32,
+        height: u32,
+    ) -> Option<Vec<u32>> {
+        let output_size = (width as u64) * (height as u64) * std::mem::size_of::<u32>() as u64;
+        let max_binding = state.device.limits().max_storage_buffer_binding_size as u64;
+        if output_size > max_binding {
+            return None;
+        }
+
         let output_buffer = state.device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("perturbation output"),
+            label: Some("iteration output"),
             size: output_size,
             usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
             mapped_at_creation: false,
         });
 
         let staging_buffer = state.device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("perturbation staging"),
+            label: Some("iteration staging"),
             size: output_size,
             usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
 
+        let entries: Vec<wgpu::BindGroupEntry> = inputs
+            .iter()
+            .chain(std::iter::once(&&output_buffer))
+            .zip(0..)
+            .map(|(buffer, binding)| wgpu::BindGroupEntry {
+                binding,
+                resource: buffer.as_entire_binding(),
+            })
+            .collect();
         let bind_group = state.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("perturbation bind group"),
-            layout: &state.bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0,
-                    resource: params_buffer.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 1,
-                    resource: orbit_buffer.as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 2,
-                    resource: output_buffer.as_entire_binding(),
-                },
-            ],
+            label: Some("iteration bind group"),
+            layout: bind_group_layout,
+            entries: &entries,
         });
 
         let mut encoder = state
             .device
             .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                label: Some("perturbation encoder"),
+                label: Some("iteration encoder"),
             });
 
         {
             let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
-                label: Some("perturbation pass"),
+                label: Some("iteration pass"),
                 timestamp_writes: None,
             });
-            pass.set_pipeline(&state.pipeline);
+            pass.set_pipeline(pipeline);
             pass.set_bind_group(0, &bind_group, &[]);
             pass.dispatch_workgroups(
                 width.div_ceil(WORKGROUP_SIZE),
@@ -302,12 +415,20 @@ impl GpuFractalRendererPort for WgpuPerturbationRenderer {
         let state = self.state()?;
         Self::render(state, algorithm)
     }
+
+    fn render_direct_iterations(&mut self, algorithm: &MandelbrotAlgorithm) -> Option<Vec<u32>> {
+        let state = self.state()?;
+        Self::render_direct(state, algorithm)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::actions::cancellation::NeverCancel;
+    use crate::core::actions::generate_fractal::generate_fractal_parallel_rayon::generate_fractal_parallel_rayon;
+    use crate::core::data::complex::Complex;
+    use crate::core::data::complex_rect::ComplexRect;
     use crate::core::data::deep_complex::DeepComplex;
     use crate::core::data::deep_region::DeepRegion;
     use crate::core::data::pixel_rect::PixelRect;
@@ -403,6 +524,88 @@ mod tests {
         );
     }
 
+    fn direct_algorithm(width: i32, height: i32, max_iterations: u32) -> MandelbrotAlgorithm {
+        let pixel_rect = PixelRect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: width - 1,
+                y: height - 1,
+            },
+        )
+        .unwrap();
+        let region = ComplexRect::new(
+            Complex {
+                real: -2.5,
+                imag: -1.0,
+            },
+            Complex {
+                real: 1.0,
+                imag: 1.0,
+            },
+        )
+        .unwrap();
+
+        MandelbrotAlgorithm::new(pixel_rect, region, max_iterations).unwrap()
+    }
+
+    #[test]
+    fn gpu_matches_cpu_iterations_for_direct_renders() {
+        let Some(mut renderer) = gpu_or_skip() else {
+            return;
+        };
+
+        let (width, height) = (120, 80);
+        let algorithm = direct_algorithm(width, height, 256);
+
+        let gpu = renderer
+            .render_direct_iterations(&algorithm)
+            .expect("GPU render should succeed");
+        let cpu = generate_fractal_parallel_rayon(algorithm.pixel_rect(), &algorithm).unwrap();
+        assert_eq!(gpu.len(), cpu.len());
+
+        // f32 coordinates and iterates round differently from the CPU's
+        // f64; pixels near the boundary may escape a little earlier or
+        // later, but the images must agree almost everywhere.
+        let total = gpu.len();
+        let mismatches = gpu.iter().zip(cpu.iter()).filter(|(g, c)| g != c).count();
+        assert!(
+            (mismatches as f64 / total as f64) < 0.02,
+            "GPU diverged from CPU: {mismatches}/{total} pixels differ"
+        );
+    }
+
+    #[test]
+    fn declines_direct_renders_too_deep_for_f32() {
+        let Some(mut renderer) = gpu_or_skip() else {
+            return;
+        };
+
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 7, y: 7 }).unwrap();
+        let region = ComplexRect::from_centre_size(
+            Complex {
+                real: -0.75,
+                imag: 0.1,
+            },
+            1e-6,
+            1e-6,
+        )
+        .unwrap();
+        let algorithm = MandelbrotAlgorithm::new(pixel_rect, region, 100).unwrap();
+
+        assert!(renderer.render_direct_iterations(&algorithm).is_none());
+    }
+
+    #[test]
+    fn declines_direct_renders_of_other_powers() {
+        let Some(mut renderer) = gpu_or_skip() else {
+            return;
+        };
+
+        let cubic = direct_algorithm(16, 12, 100).with_power(3).unwrap();
+
+        assert!(renderer.render_direct_iterations(&cubic).is_none());
+    }
+
     #[test]
     fn declines_below_the_f32_extent_floor() {
         let Some(mut renderer) = gpu_or_skip() else {
@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+/// Iteration budget used while flying: `low` while the view is moving,
+/// ramping linearly back up to the user's setting over `ramp` once flight
+/// stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdaptiveIterations {
+    pub low: u32,
+    pub ramp: Duration,
+}
+
+impl Default for AdaptiveIterations {
+    fn default() -> Self {
+        Self {
+            low: 128,
+            ramp: Duration::from_millis(500),
+        }
+    }
+}
+
+impl AdaptiveIterations {
+    /// Picks `max_iterations` for the next request. `high` is the user's
+    /// setting; `since_idle` is the time since flight was last active, or
+    /// `None` if it has not been active since the last reset.
+    #[must_use]
+    pub fn select(&self, high: u32, flight_active: bool, since_idle: Option<Duration>) -> u32 {
+        let low = self.low.clamp(1, high.max(1));
+
+        if flight_active {
+            return low;
+        }
+
+        let Some(since_idle) = since_idle else {
+            return high;
+        };
+
+        if since_idle >= self.ramp {
+            return high;
+        }
+
+        let fraction = since_idle.as_secs_f64() / self.ramp.as_secs_f64();
+        let ramped = f64::from(low) + f64::from(high - low) * fraction;
+
+        (ramped as u32).clamp(low, high)
+    }
+
+    /// True while `select` is still climbing toward the user's setting.
+    #[must_use]
+    pub fn is_ramping(&self, flight_active: bool, since_idle: Option<Duration>) -> bool {
+        !flight_active && since_idle.is_some_and(|since_idle| since_idle < self.ramp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AdaptiveIterations;
+    use std::time::Duration;
+
+    fn adaptive() -> AdaptiveIterations {
+        AdaptiveIterations {
+            low: 100,
+            ramp: Duration::from_secs(1),
+        }
+    }
+
+    #[test]
+    fn active_flight_uses_low_iterations() {
+        assert_eq!(adaptive().select(1000, true, None), 100);
+        assert_eq!(adaptive().select(1000, true, Some(Duration::ZERO)), 100);
+    }
+
+    #[test]
+    fn low_never_exceeds_user_setting() {
+        assert_eq!(adaptive().select(50, true, None), 50);
+        assert_eq!(adaptive().select(50, false, Some(Duration::ZERO)), 50);
+    }
+
+    #[test]
+    fn never_active_uses_user_setting() {
+        assert_eq!(adaptive().select(1000, false, None), 1000);
+    }
+
+    #[test]
+    fn idle_ramps_linearly_to_user_setting() {
+        let adaptive = adaptive();
+
+        assert_eq!(adaptive.select(1000, false, Some(Duration::ZERO)), 100);
+        assert_eq!(adaptive.select(1000, false, Some(Duration::from_millis(500))), 550);
+        assert_eq!(adaptive.select(1000, false, Some(Duration::from_secs(1))), 1000);
+        assert_eq!(adaptive.select(1000, false, Some(Duration::from_secs(5))), 1000);
+    }
+
+    #[test]
+    fn zero_ramp_jumps_straight_to_user_setting() {
+        let adaptive = AdaptiveIterations {
+            low: 100,
+            ramp: Duration::ZERO,
+        };
+
+        assert_eq!(adaptive.select(1000, false, Some(Duration::ZERO)), 1000);
+        assert!(!adaptive.is_ramping(false, Some(Duration::ZERO)));
+    }
+
+    #[test]
+    fn is_ramping_only_while_idle_within_ramp() {
+        let adaptive = adaptive();
+
+        assert!(!adaptive.is_ramping(true, Some(Duration::ZERO)));
+        assert!(!adaptive.is_ramping(false, None));
+        assert!(adaptive.is_ramping(false, Some(Duration::from_millis(999))));
+        assert!(!adaptive.is_ramping(false, Some(Duration::from_secs(1))));
+    }
+}
@@ -0,0 +1,114 @@
+use crate::core::data::complex::Complex;
+use crate::core::data::complex_rect::{ComplexRect, ComplexRectError};
+
+/// The panel's centre and zoom fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CentreZoomInput {
+    pub centre: Complex,
+    pub zoom: f64,
+}
+
+impl Default for CentreZoomInput {
+    fn default() -> Self {
+        Self {
+            centre: Complex {
+                real: -0.75,
+                imag: 0.0,
+            },
+            zoom: 1.0,
+        }
+    }
+}
+
+/// The region shown by zooming `zoom` times into `centre` from a view
+/// `default_extent` wide and high.
+///
+/// The zoomed extent is then widened along one axis to match the
+/// `viewport` aspect ratio, so the whole zoomed extent stays visible
+/// without stretching. An empty viewport skips the fit. A zoom that is not
+/// positive and finite gives an invalid size.
+pub fn region_from_centre_zoom(
+    input: CentreZoomInput,
+    default_extent: (f64, f64),
+    viewport: (u32, u32),
+) -> Result<ComplexRect, ComplexRectError> {
+    let (default_width, default_height) = default_extent;
+    let mut width = default_width / input.zoom;
+    let mut height = default_height / input.zoom;
+
+    if input.zoom <= 0.0 {
+        return Err(ComplexRectError::InvalidSize { width, height });
+    }
+
+    let (viewport_width, viewport_height) = viewport;
+    if viewport_width > 0 && viewport_height > 0 {
+        let aspect = f64::from(viewport_width) / f64::from(viewport_height);
+        if width / height < aspect {
+            width = height * aspect;
+        } else {
+            height = width / aspect;
+        }
+    }
+
+    ComplexRect::from_centre_size(input.centre, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEFAULT_EXTENT: (f64, f64) = (3.5, 2.0);
+
+    fn input(real: f64, imag: f64, zoom: f64) -> CentreZoomInput {
+        CentreZoomInput {
+            centre: Complex { real, imag },
+            zoom,
+        }
+    }
+
+    fn assert_extent(rect: ComplexRect, width: f64, height: f64) {
+        assert!((rect.width() - width).abs() <= width * 1e-9, "width {}", rect.width());
+        assert!((rect.height() - height).abs() <= height * 1e-9, "height {}", rect.height());
+    }
+
+    #[test]
+    fn zoom_divides_the_default_extent() {
+        // 7:4 matches the default extent, so no fitting applies.
+        for zoom in [1.0, 2.0, 10.0, 1e6] {
+            let rect = region_from_centre_zoom(input(-0.75, 0.1, zoom), DEFAULT_EXTENT, (700, 400))
+                .unwrap();
+
+            assert_extent(rect, 3.5 / zoom, 2.0 / zoom);
+            assert!((rect.top_left().real + rect.width() / 2.0 - -0.75).abs() < 1e-12);
+            assert!((rect.top_left().imag + rect.height() / 2.0 - 0.1).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn fits_the_viewport_aspect_without_cropping() {
+        let wide = region_from_centre_zoom(input(0.0, 0.0, 2.0), DEFAULT_EXTENT, (800, 200))
+            .unwrap();
+        assert_extent(wide, 4.0, 1.0);
+
+        let tall = region_from_centre_zoom(input(0.0, 0.0, 2.0), DEFAULT_EXTENT, (400, 400))
+            .unwrap();
+        assert_extent(tall, 1.75, 1.75);
+    }
+
+    #[test]
+    fn zoom_below_one_zooms_out() {
+        let rect = region_from_centre_zoom(input(0.0, 0.0, 0.5), DEFAULT_EXTENT, (0, 0)).unwrap();
+
+        assert_extent(rect, 7.0, 4.0);
+    }
+
+    #[test]
+    fn rejects_non_positive_or_infinite_zoom() {
+        for zoom in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(
+                region_from_centre_zoom(input(0.0, 0.0, zoom), DEFAULT_EXTENT, (700, 400)).is_err(),
+                "zoom {zoom}"
+            );
+        }
+    }
+}
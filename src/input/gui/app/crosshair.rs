@@ -0,0 +1,72 @@
+/// The optional aiming crosshair drawn over the view centre.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Crosshair {
+    pub enabled: bool,
+    /// Length of each arm from the centre, in egui points.
+    pub arm_length: f32,
+}
+
+impl Default for Crosshair {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            arm_length: 12.0,
+        }
+    }
+}
+
+/// The window pixel a centre zoom focuses on, or `None` for an empty
+/// window.
+///
+/// An odd side has a middle pixel. An even side's midpoint falls on the
+/// edge between two pixels, and the one after the midpoint is used, so
+/// lines drawn through the pixel's centre stay crisp at half a pixel off.
+#[must_use]
+pub fn centre_pixel(width: u32, height: u32) -> Option<(u32, u32)> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    Some((width / 2, height / 2))
+}
+
+/// Where to draw the crosshair in egui points: the middle of
+/// [`centre_pixel`] on a window `width`x`height` physical pixels.
+#[must_use]
+pub fn centre_point(width: u32, height: u32, pixels_per_point: f32) -> Option<(f32, f32)> {
+    let (x, y) = centre_pixel(width, height)?;
+
+    Some((
+        (x as f32 + 0.5) / pixels_per_point,
+        (y as f32 + 0.5) / pixels_per_point,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn odd_sides_use_the_middle_pixel() {
+        assert_eq!(centre_pixel(5, 3), Some((2, 1)));
+        assert_eq!(centre_pixel(1, 1), Some((0, 0)));
+    }
+
+    #[test]
+    fn even_sides_use_the_pixel_after_the_midpoint() {
+        assert_eq!(centre_pixel(4, 6), Some((2, 3)));
+        assert_eq!(centre_pixel(800, 601), Some((400, 300)));
+    }
+
+    #[test]
+    fn empty_window_has_no_centre() {
+        assert_eq!(centre_pixel(0, 10), None);
+        assert_eq!(centre_point(10, 0, 1.0), None);
+    }
+
+    #[test]
+    fn centre_point_scales_to_egui_points() {
+        assert_eq!(centre_point(5, 3, 1.0), Some((2.5, 1.5)));
+        assert_eq!(centre_point(800, 600, 2.0), Some((200.25, 150.25)));
+    }
+}
@@ -0,0 +1,178 @@
+use crate::controllers::interactive::data::fractal_config::FractalConfig;
+use crate::core::actions::generate_fractal::generate_fractal_parallel_rayon::generate_fractal_parallel_rayon_cancelable;
+use crate::core::actions::seek_detail::suggest_detail_centre;
+use crate::core::data::deep_region::DeepRegion;
+use crate::core::data::pixel_rect::PixelRect;
+use crate::core::data::point::Point;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread::{self, JoinHandle};
+
+/// Runs [`suggest_detail_centre`] on a background thread, so computing the
+/// coarse iteration field never stalls the UI.
+///
+/// Only one seek runs at a time: starting another cancels the first and
+/// joins its thread, which checks the cancel flag on every row, so at most
+/// one seek thread is ever alive. A result is dropped if the view has
+/// moved since the seek started, as the suggested pixel would no longer be
+/// where it was found.
+#[derive(Debug, Default)]
+pub struct DetailSeek {
+    pending: Option<PendingSeek>,
+}
+
+#[derive(Debug)]
+struct PendingSeek {
+    region: DeepRegion,
+    pixel_rect: PixelRect,
+    cancelled: Arc<AtomicBool>,
+    result: Receiver<Option<Point>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Drop for PendingSeek {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl DetailSeek {
+    /// Starts seeking on `request`, a render of `region` at `pixel_rect`.
+    pub fn start(&mut self, request: FractalConfig, pixel_rect: PixelRect, region: DeepRegion) {
+        self.cancel();
+
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let (sender, result) = mpsc::channel();
+
+        let worker_cancelled = Arc::clone(&cancelled);
+        let worker = thread::spawn(move || {
+            let cancel = || worker_cancelled.load(Ordering::Relaxed);
+            let centre = request
+                .prepare(&cancel)
+                .ok()
+                .and_then(|()| {
+                    generate_fractal_parallel_rayon_cancelable(pixel_rect, request.algorithm(), &cancel).ok()
+                })
+                .and_then(|field| suggest_detail_centre(&field, pixel_rect).ok());
+            // The receiver is gone if the seek was cancelled.
+            let _ = sender.send(centre);
+        });
+
+        self.pending = Some(PendingSeek {
+            region,
+            pixel_rect,
+            cancelled,
+            result,
+            worker: Some(worker),
+        });
+    }
+
+    /// True while a seek is running.
+    #[must_use]
+    pub fn is_waiting(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Abandons the running seek, if any, and waits for its thread to stop.
+    pub fn cancel(&mut self) {
+        self.pending = None;
+    }
+
+    /// The suggested centre and the pixel rect it lies in, once the seek
+    /// has finished and only if the view is still `current`.
+    pub fn poll(&mut self, current: &DeepRegion) -> Option<(Point, PixelRect)> {
+        let pending = self.pending.as_ref()?;
+        let centre = match pending.result.try_recv() {
+            Ok(centre) => centre,
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => None,
+        };
+
+        let pending = self.pending.take()?;
+        centre
+            .filter(|_| pending.region == *current)
+            .map(|centre| (centre, pending.pixel_rect))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::complex::Complex;
+    use crate::core::data::complex_rect::ComplexRect;
+    use crate::core::fractals::mandelbrot::colour_mapping::kinds::MandelbrotColourMapKinds;
+    use std::time::{Duration, Instant};
+
+    fn start_seek(seek: &mut DetailSeek) -> (PixelRect, DeepRegion) {
+        let request =
+            FractalConfig::mandelbrot(48, 32, [-2.5, -1.0, 1.0, 1.0], 64, MandelbrotColourMapKinds::BlueWhiteGradient)
+                .unwrap();
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 47, y: 31 }).unwrap();
+        let region = DeepRegion::from_complex_rect(
+            &ComplexRect::new(Complex { real: -2.5, imag: -1.0 }, Complex { real: 1.0, imag: 1.0 }).unwrap(),
+        );
+        seek.start(request, pixel_rect, region.clone());
+        (pixel_rect, region)
+    }
+
+    fn wait_for(seek: &mut DetailSeek, current: &DeepRegion) -> Option<(Point, PixelRect)> {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while seek.is_waiting() && Instant::now() < deadline {
+            if let Some(found) = seek.poll(current) {
+                return Some(found);
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        None
+    }
+
+    #[test]
+    fn suggests_a_point_in_the_seek_grid() {
+        let mut seek = DetailSeek::default();
+        let (pixel_rect, region) = start_seek(&mut seek);
+        assert!(seek.is_waiting());
+
+        let (centre, found_in) = wait_for(&mut seek, &region).expect("seek finishes");
+
+        assert_eq!(found_in, pixel_rect);
+        assert!(pixel_rect.contains_point(centre), "{centre:?} outside {pixel_rect:?}");
+        assert!(!seek.is_waiting());
+    }
+
+    #[test]
+    fn result_is_dropped_once_the_view_moves() {
+        let mut seek = DetailSeek::default();
+        let (_, region) = start_seek(&mut seek);
+        let moved = region.panned_by(0.5, 0.0).unwrap();
+
+        assert_eq!(wait_for(&mut seek, &moved), None);
+        assert!(!seek.is_waiting());
+    }
+
+    #[test]
+    fn cancel_stops_waiting() {
+        let mut seek = DetailSeek::default();
+        let (_, region) = start_seek(&mut seek);
+
+        seek.cancel();
+
+        assert!(!seek.is_waiting());
+        assert_eq!(seek.poll(&region), None);
+    }
+
+    #[test]
+    fn restarting_replaces_the_running_seek() {
+        let mut seek = DetailSeek::default();
+        start_seek(&mut seek);
+        let (pixel_rect, region) = start_seek(&mut seek);
+
+        let (centre, found_in) = wait_for(&mut seek, &region).expect("second seek finishes");
+
+        assert_eq!(found_in, pixel_rect);
+        assert!(pixel_rect.contains_point(centre));
+    }
+}
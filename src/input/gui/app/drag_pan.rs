@@ -0,0 +1,224 @@
+use std::time::{Duration, Instant};
+
+/// A left-button drag panning the view, tracking the cursor's velocity so a
+/// release can hand it on to flight as a glide. Positions are window
+/// pixels, as winit reports the cursor.
+///
+/// Velocity is smoothed exponentially over `time_constant`, so one jittery
+/// move does not set the glide. A release after the cursor has rested for
+/// `rest_threshold` does not glide at all.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DragPan {
+    pub time_constant: Duration,
+    pub rest_threshold: Duration,
+    last: Option<((f64, f64), Instant)>,
+    velocity_px_per_sec: (f64, f64),
+    released: bool,
+}
+
+impl Default for DragPan {
+    fn default() -> Self {
+        Self {
+            time_constant: Duration::from_millis(50),
+            rest_threshold: Duration::from_millis(100),
+            last: None,
+            velocity_px_per_sec: (0.0, 0.0),
+            released: false,
+        }
+    }
+}
+
+impl DragPan {
+    pub fn begin(&mut self, position: (f64, f64), now: Instant) {
+        self.last = Some((position, now));
+        self.velocity_px_per_sec = (0.0, 0.0);
+    }
+
+    #[must_use]
+    pub fn is_dragging(&self) -> bool {
+        self.last.is_some()
+    }
+
+    /// Moves the cursor, returning how far it went in pixels since the last
+    /// move; `None` when no drag is in progress.
+    pub fn drag_to(&mut self, position: (f64, f64), now: Instant) -> Option<(f64, f64)> {
+        let (last_position, last_instant) = self.last?;
+        let delta = (position.0 - last_position.0, position.1 - last_position.1);
+        let dt = now.saturating_duration_since(last_instant).as_secs_f64();
+
+        if dt > 0.0 {
+            let alpha = if self.time_constant.is_zero() {
+                1.0
+            } else {
+                1.0 - (-dt / self.time_constant.as_secs_f64()).exp()
+            };
+            let (vx, vy) = self.velocity_px_per_sec;
+            self.velocity_px_per_sec = (
+                vx + (delta.0 / dt - vx) * alpha,
+                vy + (delta.1 / dt - vy) * alpha,
+            );
+        }
+
+        self.last = Some((position, now));
+        Some(delta)
+    }
+
+    /// Ends the drag, returning the cursor's velocity in pixels a second, or
+    /// `None` when no drag was in progress or the cursor had come to rest.
+    pub fn finish(&mut self, now: Instant) -> Option<(f64, f64)> {
+        let (_, last_instant) = self.last.take()?;
+        self.released = true;
+
+        if now.saturating_duration_since(last_instant) >= self.rest_threshold {
+            return None;
+        }
+
+        Some(self.velocity_px_per_sec)
+    }
+
+    /// True once after a drag is finished, so the region it left behind
+    /// can be rendered straight away rather than debounced.
+    pub fn take_release(&mut self) -> bool {
+        std::mem::take(&mut self.released)
+    }
+
+    pub fn cancel(&mut self) {
+        self.last = None;
+        self.released = false;
+    }
+}
+
+/// How far to move the view's centre in the complex plane for a drag of
+/// `delta_px` window pixels, so the image follows the cursor: each axis is
+/// the drag over the `window` extent in pixels, times the view's `extent`
+/// in the plane, reversed. `None` for an empty window.
+#[must_use]
+pub fn pan_offset(delta_px: (f64, f64), window: (u32, u32), extent: (f64, f64)) -> Option<(f64, f64)> {
+    let (width, height) = window;
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    Some((
+        -delta_px.0 / f64::from(width) * extent.0,
+        -delta_px.1 / f64::from(height) * extent.1,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(16);
+
+    fn assert_approx_eq(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "actual={} expected={}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn drag_to_reports_the_distance_moved() {
+        let start = Instant::now();
+        let mut drag = DragPan::default();
+
+        assert_eq!(drag.drag_to((10.0, 10.0), start), None);
+
+        drag.begin((10.0, 10.0), start);
+        assert_eq!(drag.drag_to((14.0, 7.0), start + FRAME), Some((4.0, -3.0)));
+        assert_eq!(drag.drag_to((15.0, 7.0), start + 2 * FRAME), Some((1.0, 0.0)));
+    }
+
+    #[test]
+    fn a_steady_drag_settles_on_its_velocity() {
+        let start = Instant::now();
+        let mut drag = DragPan::default();
+        drag.begin((0.0, 0.0), start);
+
+        // 8 px right and 4 px down per 16 ms frame: 500 and 250 px/s.
+        let mut now = start;
+        for frame in 1..=30 {
+            now += FRAME;
+            drag.drag_to((8.0 * f64::from(frame), 4.0 * f64::from(frame)), now);
+        }
+        let (vx, vy) = drag.finish(now).unwrap();
+
+        assert_approx_eq(vx, 500.0, 0.1);
+        assert_approx_eq(vy, 250.0, 0.1);
+        assert!(!drag.is_dragging());
+    }
+
+    #[test]
+    fn one_jittery_move_is_smoothed() {
+        let start = Instant::now();
+        let mut drag = DragPan::default();
+        drag.begin((0.0, 0.0), start);
+
+        drag.drag_to((8.0, 0.0), start + FRAME);
+        let (vx, _) = drag.finish(start + FRAME).unwrap();
+
+        assert!(vx > 0.0 && vx < 500.0, "vx={}", vx);
+    }
+
+    #[test]
+    fn releasing_after_resting_does_not_glide() {
+        let start = Instant::now();
+        let mut drag = DragPan::default();
+        drag.begin((0.0, 0.0), start);
+        drag.drag_to((8.0, 0.0), start + FRAME);
+
+        assert_eq!(drag.finish(start + FRAME + drag.rest_threshold), None);
+        assert!(!drag.is_dragging());
+    }
+
+    #[test]
+    fn finish_without_a_drag_is_none() {
+        let mut drag = DragPan::default();
+
+        assert_eq!(drag.finish(Instant::now()), None);
+    }
+
+    #[test]
+    fn cancel_ends_the_drag() {
+        let start = Instant::now();
+        let mut drag = DragPan::default();
+        drag.begin((0.0, 0.0), start);
+
+        drag.cancel();
+
+        assert!(!drag.is_dragging());
+        assert!(!drag.take_release());
+        assert_eq!(drag.drag_to((8.0, 0.0), start + FRAME), None);
+    }
+
+    #[test]
+    fn finishing_a_drag_is_reported_once() {
+        let start = Instant::now();
+        let mut drag = DragPan::default();
+        assert!(!drag.take_release());
+
+        // Released at rest: no glide, but the region still needs a render.
+        drag.begin((0.0, 0.0), start);
+        drag.finish(start + drag.rest_threshold);
+
+        assert!(drag.take_release());
+        assert!(!drag.take_release());
+    }
+
+    #[test]
+    fn pan_offset_scales_pixels_to_the_view_extent() {
+        // A quarter of the width right and half the height down moves the
+        // centre a quarter of 4.0 left and half of 3.0 up.
+        assert_eq!(pan_offset((200.0, 300.0), (800, 600), (4.0, 3.0)), Some((-1.0, -1.5)));
+        assert_eq!(pan_offset((-80.0, 0.0), (800, 600), (1e-20, 7.5e-21)), Some((1e-21, 0.0)));
+    }
+
+    #[test]
+    fn pan_offset_needs_a_window() {
+        assert_eq!(pan_offset((10.0, 10.0), (0, 600), (4.0, 3.0)), None);
+        assert_eq!(pan_offset((10.0, 10.0), (800, 0), (4.0, 3.0)), None);
+    }
+}
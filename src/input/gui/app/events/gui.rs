@@ -1,4 +1,9 @@
+use winit::window::WindowId;
+
 #[derive(Debug, Clone, Copy)]
 pub enum GuiEvent {
+    /// Wake every window, e.g. from a presenter that does not know its own.
     Wake,
+    /// A finished render for the app in window `WindowId`.
+    WakeWindow(WindowId),
 }
@@ -10,8 +10,14 @@ pub struct FlightInputState {
     d_held: bool,
     arrow_down_held: bool,
     arrow_up_held: bool,
+    e_held: bool,
+    q_held: bool,
     p_edge_pending: bool,
     h_edge_pending: bool,
+    step_edge_pending: bool,
+    tab_edge_pending: bool,
+    f11_edge_pending: bool,
+    f12_edge_pending: bool,
 }
 
 impl FlightInputState {
@@ -25,12 +31,26 @@ impl FlightInputState {
             KeyCode::KeyD => self.d_held = pressed,
             KeyCode::ArrowDown => self.arrow_down_held = pressed,
             KeyCode::ArrowUp => self.arrow_up_held = pressed,
+            KeyCode::KeyE => self.e_held = pressed,
+            KeyCode::KeyQ => self.q_held = pressed,
             KeyCode::KeyP if pressed => {
                 self.p_edge_pending = true;
             }
             KeyCode::KeyH if pressed => {
                 self.h_edge_pending = true;
             }
+            KeyCode::Period if pressed => {
+                self.step_edge_pending = true;
+            }
+            KeyCode::Tab if pressed => {
+                self.tab_edge_pending = true;
+            }
+            KeyCode::F11 if pressed => {
+                self.f11_edge_pending = true;
+            }
+            KeyCode::F12 if pressed => {
+                self.f12_edge_pending = true;
+            }
             _ => {}
         }
     }
@@ -39,6 +59,7 @@ impl FlightInputState {
         if text_editing {
             self.p_edge_pending = false;
             self.h_edge_pending = false;
+            self.step_edge_pending = false;
             return FlightControlsSnapshot::default();
         }
 
@@ -49,6 +70,8 @@ impl FlightInputState {
             d: self.d_held,
             accelerate: self.arrow_up_held,
             decelerate: self.arrow_down_held,
+            zoom_in: self.e_held,
+            zoom_out: self.q_held,
             pause_toggle_edge: self.p_edge_pending,
         };
 
@@ -62,6 +85,37 @@ impl FlightInputState {
         toggle_requested
     }
 
+    /// Debug single-step request (`.`), consumed once per press.
+    pub fn take_single_step(&mut self) -> bool {
+        let step_requested = self.step_edge_pending;
+        self.step_edge_pending = false;
+        step_requested
+    }
+
+    /// Settings panel show/hide request (`Tab`), consumed once per press.
+    /// Dropped while a text field has focus, where Tab belongs to egui.
+    pub fn take_panel_toggle(&mut self, text_editing: bool) -> bool {
+        let toggle_requested = self.tab_edge_pending && !text_editing;
+        self.tab_edge_pending = false;
+        toggle_requested
+    }
+
+    /// Fullscreen toggle request (`F11`), consumed once per press and
+    /// dropped while a text field has focus.
+    pub fn take_fullscreen_toggle(&mut self, text_editing: bool) -> bool {
+        let toggle_requested = self.f11_edge_pending && !text_editing;
+        self.f11_edge_pending = false;
+        toggle_requested
+    }
+
+    /// Screenshot request (`F12`), consumed once per press and dropped
+    /// while a text field has focus.
+    pub fn take_screenshot_request(&mut self, text_editing: bool) -> bool {
+        let screenshot_requested = self.f12_edge_pending && !text_editing;
+        self.f12_edge_pending = false;
+        screenshot_requested
+    }
+
     pub fn reset(&mut self) {
         *self = Self::default();
     }
@@ -107,6 +161,27 @@ mod tests {
         assert!(!released_snapshot.accelerate);
     }
 
+    #[test]
+    fn e_and_q_hold_direct_zoom_in_and_out() {
+        let mut input = FlightInputState::default();
+
+        input.handle_key_event(KeyCode::KeyE, ElementState::Pressed);
+        let zooming_in = input.snapshot(false);
+        assert!(zooming_in.zoom_in);
+        assert!(!zooming_in.zoom_out);
+
+        input.handle_key_event(KeyCode::KeyE, ElementState::Released);
+        input.handle_key_event(KeyCode::KeyQ, ElementState::Pressed);
+        let zooming_out = input.snapshot(false);
+        assert!(!zooming_out.zoom_in);
+        assert!(zooming_out.zoom_out);
+
+        input.handle_key_event(KeyCode::KeyQ, ElementState::Released);
+        let released = input.snapshot(false);
+        assert!(!released.zoom_in);
+        assert!(!released.zoom_out);
+    }
+
     #[test]
     fn p_press_sets_single_pending_edge_even_with_repeats() {
         let mut input = FlightInputState::default();
@@ -147,6 +222,70 @@ mod tests {
         assert!(!input.take_pause_overlay_toggle());
     }
 
+    #[test]
+    fn period_press_sets_single_step_edge_consumed_once() {
+        let mut input = FlightInputState::default();
+
+        input.handle_key_event(KeyCode::Period, ElementState::Pressed);
+        input.handle_key_event(KeyCode::Period, ElementState::Released);
+
+        assert!(input.take_single_step());
+        assert!(!input.take_single_step());
+    }
+
+    #[test]
+    fn tab_press_sets_single_panel_edge_consumed_once() {
+        let mut input = FlightInputState::default();
+
+        input.handle_key_event(KeyCode::Tab, ElementState::Pressed);
+        input.handle_key_event(KeyCode::Tab, ElementState::Pressed);
+        input.handle_key_event(KeyCode::Tab, ElementState::Released);
+
+        assert!(input.take_panel_toggle(false));
+        assert!(!input.take_panel_toggle(false));
+
+        input.handle_key_event(KeyCode::Tab, ElementState::Pressed);
+        assert!(input.take_panel_toggle(false));
+    }
+
+    #[test]
+    fn tab_while_text_editing_is_dropped() {
+        let mut input = FlightInputState::default();
+
+        input.handle_key_event(KeyCode::Tab, ElementState::Pressed);
+
+        assert!(!input.take_panel_toggle(true));
+        assert!(!input.take_panel_toggle(false));
+    }
+
+    #[test]
+    fn f11_press_sets_single_fullscreen_edge_dropped_while_text_editing() {
+        let mut input = FlightInputState::default();
+
+        input.handle_key_event(KeyCode::F11, ElementState::Pressed);
+        input.handle_key_event(KeyCode::F11, ElementState::Released);
+        assert!(input.take_fullscreen_toggle(false));
+        assert!(!input.take_fullscreen_toggle(false));
+
+        input.handle_key_event(KeyCode::F11, ElementState::Pressed);
+        assert!(!input.take_fullscreen_toggle(true));
+        assert!(!input.take_fullscreen_toggle(false));
+    }
+
+    #[test]
+    fn f12_press_sets_single_screenshot_edge_dropped_while_text_editing() {
+        let mut input = FlightInputState::default();
+
+        input.handle_key_event(KeyCode::F12, ElementState::Pressed);
+        input.handle_key_event(KeyCode::F12, ElementState::Released);
+        assert!(input.take_screenshot_request(false));
+        assert!(!input.take_screenshot_request(false));
+
+        input.handle_key_event(KeyCode::F12, ElementState::Pressed);
+        assert!(!input.take_screenshot_request(true));
+        assert!(!input.take_screenshot_request(false));
+    }
+
     #[test]
     fn focus_suppression_returns_neutral_snapshot_and_clears_edge() {
         let mut input = FlightInputState::default();
@@ -154,6 +293,7 @@ mod tests {
         input.handle_key_event(KeyCode::KeyW, ElementState::Pressed);
         input.handle_key_event(KeyCode::KeyP, ElementState::Pressed);
         input.handle_key_event(KeyCode::KeyH, ElementState::Pressed);
+        input.handle_key_event(KeyCode::Period, ElementState::Pressed);
 
         let suppressed = input.snapshot(true);
         assert_eq!(
@@ -165,6 +305,7 @@ mod tests {
         assert!(after_focus.w);
         assert!(!after_focus.pause_toggle_edge);
         assert!(!input.take_pause_overlay_toggle());
+        assert!(!input.take_single_step());
     }
 
     #[test]
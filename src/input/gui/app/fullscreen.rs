@@ -0,0 +1,26 @@
+use winit::window::Fullscreen;
+
+/// The mode F11 switches to from `current`, the window's own
+/// `Window::fullscreen()`: borderless on the current monitor from a normal
+/// window, or `None` to leave any fullscreen. Asking the window rather than
+/// remembering the last toggle keeps F11 right after the window manager or
+/// OS leaves fullscreen on its own.
+#[must_use]
+pub fn toggled_fullscreen(current: Option<&Fullscreen>) -> Option<Fullscreen> {
+    current.is_none().then_some(Fullscreen::Borderless(None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_window_enters_borderless_fullscreen() {
+        assert_eq!(toggled_fullscreen(None), Some(Fullscreen::Borderless(None)));
+    }
+
+    #[test]
+    fn any_fullscreen_returns_to_a_window() {
+        assert_eq!(toggled_fullscreen(Some(&Fullscreen::Borderless(None))), None);
+    }
+}
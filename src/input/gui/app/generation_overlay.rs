@@ -0,0 +1,75 @@
+use crate::controllers::interactive::generation::is_newer;
+
+/// The render generations shown by the optional on-screen debug overlay,
+/// for watching requests coalesce and frames go stale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenerationOverlay {
+    /// The newest generation submitted to the controller.
+    pub latest_submitted: u64,
+    /// The newest generation the controller finished and emitted.
+    pub last_completed: u64,
+    /// The generation of the frame on screen.
+    pub last_presented: u64,
+}
+
+impl GenerationOverlay {
+    /// True while a newer request than the frame on screen has been
+    /// submitted, so what is shown no longer matches the view.
+    #[must_use]
+    pub fn is_stale(&self) -> bool {
+        is_newer(self.latest_submitted, self.last_presented)
+    }
+
+    #[must_use]
+    pub fn lines(&self) -> [String; 4] {
+        [
+            format!("Submitted: {}", self.latest_submitted),
+            format!("Completed: {}", self.last_completed),
+            format!("Presented: {}", self.last_presented),
+            format!("Frame: {}", if self.is_stale() { "stale" } else { "current" }),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_frame_is_current_once_the_latest_request_is_presented() {
+        let overlay = GenerationOverlay {
+            latest_submitted: 7,
+            last_completed: 7,
+            last_presented: 7,
+        };
+
+        assert!(!overlay.is_stale());
+        assert_eq!(
+            overlay.lines(),
+            ["Submitted: 7", "Completed: 7", "Presented: 7", "Frame: current"]
+        );
+    }
+
+    #[test]
+    fn the_frame_is_stale_while_a_newer_request_is_outstanding() {
+        let overlay = GenerationOverlay {
+            latest_submitted: 9,
+            last_completed: 8,
+            last_presented: 7,
+        };
+
+        assert!(overlay.is_stale());
+        assert_eq!(overlay.lines()[3], "Frame: stale");
+    }
+
+    #[test]
+    fn staleness_survives_the_counter_wrapping() {
+        let overlay = GenerationOverlay {
+            latest_submitted: 1,
+            last_completed: u64::MAX,
+            last_presented: u64::MAX,
+        };
+
+        assert!(overlay.is_stale());
+    }
+}
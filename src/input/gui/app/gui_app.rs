@@ -1,34 +1,64 @@
+use crate::controllers::config::explore_config::ExploreConfig;
 use crate::controllers::interactive::InteractiveController;
 use crate::controllers::interactive::data::fractal_config::FractalConfig;
-use crate::controllers::interactive::flight::{FlightSimulator, RenderScheduler, SchedulerAction};
+use crate::controllers::interactive::flight::{FlightSimulator, RenderScheduler, RequestPriority, SchedulerAction};
+use crate::controllers::interactive::load_tracker::LoadTracker;
+use crate::core::actions::generate_pixel_buffer::histogram::ColourSchemeKind;
+use crate::core::data::complex::Complex;
+use crate::core::data::complex_rect::ComplexRect;
+use crate::core::data::deep_region::DeepRegion;
 use crate::core::data::pixel_rect::PixelRect;
 use crate::core::data::point::Point;
-use crate::core::flight::{FlightLimits, FlightWarning};
+use crate::core::flight::{FlightLimits, FlightUpdateReport, FlightWarning, MotionState, glide_motion};
+use crate::core::fractals::burning_ship::flight as burning_ship_flight;
 use crate::core::fractals::fractal_kinds::FractalKinds;
 use crate::core::fractals::julia::colour_mapping::kinds::JuliaColourMapKinds;
 use crate::core::fractals::julia::flight as julia_flight;
+use crate::core::fractals::mandelbrot::algorithm::{DEFAULT_POWER, MAX_POWER};
 use crate::core::fractals::mandelbrot::colour_mapping::kinds::MandelbrotColourMapKinds;
 use crate::core::fractals::mandelbrot::flight as mandelbrot_flight;
+use crate::core::fractals::tricorn::flight as tricorn_flight;
+use crate::input::gui::app::adaptive_iterations::AdaptiveIterations;
+use crate::input::gui::app::centre_zoom::{CentreZoomInput, region_from_centre_zoom};
+use crate::input::gui::app::crosshair::{Crosshair, centre_point};
+use crate::input::gui::app::detail_seek::DetailSeek;
+use crate::input::gui::app::drag_pan::{DragPan, pan_offset};
 use crate::input::gui::app::events::gui::GuiEvent;
 use crate::input::gui::app::frame_overlay::FrameOverlay;
+use crate::input::gui::app::iteration_ceiling::{DEFAULT_MAX_ITERATIONS_CEILING, clamp_to_ceiling};
 use crate::input::gui::app::flight_input::FlightInputState;
+use crate::input::gui::app::fullscreen::toggled_fullscreen;
+use crate::input::gui::app::generation_overlay::GenerationOverlay;
+use crate::input::gui::app::input_idle::InputIdle;
 use crate::input::gui::app::ports::presenter::GuiPresenterPort;
+use crate::input::gui::app::render_size::ViewportSize;
+use crate::input::gui::app::request_debounce::{DebounceDecision, RequestDebounce};
+use crate::input::gui::app::rubber_band::{RubberBand, region_from_selection};
+use crate::input::gui::app::screenshot::{SCREENSHOT_DIR, ScreenshotNotice, save_screenshot};
 use crate::input::gui::app::state::GuiAppState;
+use crate::input::gui::app::zoom_animator::{WHEEL_ZOOM_FACTOR, ZoomAnimator};
 use egui::{Color32, Context, Rounding, Stroke};
 use egui_winit::State as EguiWinitState;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime};
 use winit::{
-    event::{Event, WindowEvent},
+    event::{ElementState, Event, MouseButton, MouseScrollDelta, WindowEvent},
     event_loop::EventLoop,
     keyboard::PhysicalKey,
     window::Window,
 };
 
+/// Whether the event loop driving a [`GuiApp`] should keep running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppControl {
+    Continue,
+    Exit,
+}
+
 pub struct GuiApp<T: GuiPresenterPort> {
     window: &'static Window,
-    width: u32,
-    height: u32,
+    size: ViewportSize,
     pub scale_factor: f64,
     presenter: T,
     pub controller: InteractiveController,
@@ -36,11 +66,31 @@ pub struct GuiApp<T: GuiPresenterPort> {
     flight_input: FlightInputState,
     flight_sim: FlightSimulator,
     scheduler: RenderScheduler,
+    request_debounce: RequestDebounce<Arc<FractalConfig>>,
+    adaptive_iterations: AdaptiveIterations,
+    max_iterations_ceiling: u32,
+    last_flight_active_instant: Option<Instant>,
+    input_idle: InputIdle,
+    zoom_animator: ZoomAnimator,
+    detail_seek: DetailSeek,
+    cursor_position: Option<(f64, f64)>,
     last_redraw_instant: Instant,
+    continuous: bool,
+    centre_zoom: CentreZoomInput,
+    crosshair: Crosshair,
+    /// Draws the render generations and frame staleness over the view.
+    show_generation_overlay: bool,
+    rubber_band: RubberBand,
+    drag_pan: DragPan,
     last_selected_fractal: FractalKinds,
     last_render_duration: Option<Duration>,
     last_error_message: Option<String>,
     show_pause_overlay: bool,
+    /// Whether the settings panel is drawn; toggled with Tab or its close
+    /// button.
+    show_panel: bool,
+    /// Confirms an F12 screenshot in the panel.
+    screenshot_notice: ScreenshotNotice,
     pub egui_ctx: Context,
     pub egui_state: EguiWinitState,
 }
@@ -69,8 +119,7 @@ impl<T: GuiPresenterPort> GuiApp<T> {
 
         Self {
             window,
-            width: size.width,
-            height: size.height,
+            size: ViewportSize::new(size.width, size.height),
             scale_factor,
             presenter,
             controller,
@@ -78,16 +127,109 @@ impl<T: GuiPresenterPort> GuiApp<T> {
             flight_input: FlightInputState::default(),
             flight_sim: FlightSimulator::new(FlightLimits::default()),
             scheduler: RenderScheduler::new(),
+            request_debounce: RequestDebounce::default(),
+            adaptive_iterations: AdaptiveIterations::default(),
+            max_iterations_ceiling: DEFAULT_MAX_ITERATIONS_CEILING,
+            last_flight_active_instant: None,
+            input_idle: InputIdle::default(),
+            zoom_animator: ZoomAnimator::default(),
+            detail_seek: DetailSeek::default(),
+            cursor_position: None,
             last_redraw_instant: Instant::now(),
+            continuous: true,
+            centre_zoom: CentreZoomInput::default(),
+            crosshair: Crosshair::default(),
+            show_generation_overlay: false,
+            rubber_band: RubberBand::default(),
+            drag_pan: DragPan::default(),
             last_selected_fractal,
             last_render_duration: None,
             last_error_message: None,
             show_pause_overlay: true,
+            show_panel: true,
+            screenshot_notice: ScreenshotNotice::default(),
             egui_ctx,
             egui_state,
         }
     }
 
+    /// Continuous mode (the default) lets flight, zoom easing and the
+    /// iteration ramp keep redrawing on their own. With it off, frames are
+    /// drawn only in response to input events and finished renders, so an
+    /// idle window does no GPU work; easing and the ramp are disabled since
+    /// nothing would advance them.
+    #[must_use]
+    pub fn with_continuous(mut self, continuous: bool) -> Self {
+        self.continuous = continuous;
+        if !continuous {
+            self.zoom_animator.time_constant = Duration::ZERO;
+            self.adaptive_iterations.ramp = Duration::ZERO;
+        }
+        self
+    }
+
+    /// Holds back requests from settings edits until they have stopped
+    /// changing for `interval` (100 ms by default); see [`RequestDebounce`].
+    /// Flight, zoom easing, the iteration ramp and resizes still submit
+    /// every frame. `Duration::ZERO` turns it off.
+    #[must_use]
+    pub fn with_request_debounce(mut self, interval: Duration) -> Self {
+        self.request_debounce.interval = interval;
+        self
+    }
+
+    /// Once flight and zoom easing have stopped for `delay` (150 ms by
+    /// default), submits one render at the full iteration budget, skipping
+    /// the rest of the iteration ramp and any load scaling; see
+    /// [`InputIdle`]. `Duration::ZERO` turns it off.
+    #[must_use]
+    pub fn with_idle_render_delay(mut self, delay: Duration) -> Self {
+        self.input_idle.threshold = delay;
+        self
+    }
+
+    /// Opens on the view and settings from a config file, and flies with its
+    /// limits.
+    #[must_use]
+    pub fn with_explore_config(mut self, config: &ExploreConfig) -> Self {
+        self.ui_state = GuiAppState::from_explore_config(config);
+        self.last_selected_fractal = self.ui_state.selected_fractal;
+        self.flight_sim = FlightSimulator::new(config.flight_limits);
+        self
+    }
+
+    /// Caps the `max_iterations` sent to the renderer at
+    /// `max_iterations_ceiling` (see [`clamp_to_ceiling`]), whatever the
+    /// panel, config file or frame budget asks for. The panel warns while
+    /// the setting is over it.
+    #[must_use]
+    pub fn with_max_iterations_ceiling(mut self, max_iterations_ceiling: u32) -> Self {
+        self.max_iterations_ceiling = max_iterations_ceiling;
+        self
+    }
+
+    /// Caps the render buffer's longer side at `max_buffer_dimension`
+    /// pixels (see [`clamp_render_size`]); larger windows are rendered at
+    /// the capped size and upscaled.
+    #[must_use]
+    pub fn with_max_buffer_dimension(mut self, max_buffer_dimension: u32) -> Self {
+        self.size.set_max_buffer_dimension(max_buffer_dimension);
+        let (width, height) = self.size.window_size();
+        self.resize(width, height);
+        self
+    }
+
+    /// Rounds the render buffer's sides down to even numbers, for backends
+    /// that shear odd-width surfaces; the presenter letterboxes the odd
+    /// pixel left over. See [`ViewportSize::render_size`].
+    #[must_use]
+    pub fn with_even_buffer(mut self, even_buffer: bool) -> Self {
+        self.size.set_even_buffer(even_buffer);
+        let (width, height) = self.size.window_size();
+        self.resize(width, height);
+        self
+    }
+
     pub fn render(&mut self, egui_output: egui::FullOutput) -> Result<(), pixels::Error> {
         let frame_overlay = self.build_frame_overlay();
         self.presenter.render(
@@ -99,19 +241,108 @@ impl<T: GuiPresenterPort> GuiApp<T> {
     }
 
     pub fn resize(&mut self, width: u32, height: u32) {
-        self.width = width;
-        self.height = height;
+        self.size.resize(width, height);
 
-        if width == 0 || height == 0 {
+        if !self.size.is_presentable() {
             return;
         }
 
-        self.presenter.resize(width, height);
+        self.presenter.resize((width, height), self.size.render_size());
+    }
+
+    #[must_use]
+    pub fn window(&self) -> &'static Window {
+        self.window
+    }
+
+    /// The window's size in physical pixels, as of the last resize.
+    #[must_use]
+    pub fn window_size(&self) -> (u32, u32) {
+        self.size.window_size()
+    }
+
+    /// The window size clamped to the render buffer cap.
+    #[must_use]
+    pub fn render_size(&self) -> (u32, u32) {
+        self.size.render_size()
+    }
+
+    /// Whether the app wants another frame; an external event loop should
+    /// then request a redraw (the built-in loop does so on `AboutToWait`).
+    #[must_use]
+    pub fn needs_redraw(&self) -> bool {
+        self.ui_state.redraw_pending
+    }
+
+    /// Handles a wake-up from the presenter, sent when a render finishes.
+    pub fn on_wake(&mut self) {
+        self.ui_state.redraw_pending = true;
+    }
+
+    /// Handles the window becoming `width`x`height` physical pixels. The
+    /// view is refitted to the new aspect ratio about the cursor, or the
+    /// window centre when the cursor is outside, so the point there stays
+    /// put.
+    pub fn on_resize(&mut self, width: u32, height: u32) {
+        let old_size = self.size.window_size();
+        self.resize(width, height);
+
+        if old_size != (width, height) {
+            let focal = self
+                .cursor_position
+                .unwrap_or((f64::from(old_size.0) / 2.0, f64::from(old_size.1) / 2.0));
+            if let Some(region) =
+                region_resized(&self.ui_state.active_region(), old_size, (width, height), focal)
+            {
+                self.zoom_animator.cancel();
+                self.ui_state.set_active_region(region);
+            }
+        }
+
+        self.ui_state.redraw_pending = true;
+    }
+
+    pub fn on_scale_factor_changed(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+        self.egui_ctx.set_pixels_per_point(scale_factor as f32);
+
+        let size = self.window.inner_size();
+        self.on_resize(size.width, size.height);
+    }
+
+    fn build_desired_request(&self, now: Instant) -> Option<Arc<FractalConfig>> {
+        // A detail budget already tracks render time, so load scaling on
+        // top of it would only fight it.
+        let (high, quality) = match self.controller.detail_iterations() {
+            Some(budget) => (budget, 1.0),
+            None => (self.ui_state.active_max_iterations(), self.controller.load_quality()),
+        };
+        let high = clamp_to_ceiling(high, self.max_iterations_ceiling).max_iterations;
+        let max_iterations = self.input_idle.max_iterations(
+            high,
+            LoadTracker::scale_iterations(
+                self.adaptive_iterations.select(high, self.flight_sim.is_active(), self.since_flight_idle(now)),
+                quality,
+            ),
+        );
+
+        self.viewport_pixel_rect().map(|pixel_rect| {
+            // Only clone the config when the adaptive budget differs from
+            // the user's setting.
+            let request = if max_iterations == self.ui_state.active_max_iterations() {
+                self.ui_state.build_render_request(pixel_rect)
+            } else {
+                self.ui_state
+                    .build_render_request_with_max_iterations(pixel_rect, max_iterations)
+            };
+
+            Arc::new(request)
+        })
     }
 
-    fn build_desired_request(&self) -> Option<Arc<FractalConfig>> {
-        self.viewport_pixel_rect()
-            .map(|pixel_rect| Arc::new(self.ui_state.build_render_request(pixel_rect)))
+    fn since_flight_idle(&self, now: Instant) -> Option<Duration> {
+        self.last_flight_active_instant
+            .map(|instant| now.saturating_duration_since(instant))
     }
 
     fn warning_label(warning: FlightWarning) -> &'static str {
@@ -141,47 +372,244 @@ impl<T: GuiPresenterPort> GuiApp<T> {
         let _ = self.flight_sim.advance(
             elapsed,
             || flight_input.snapshot(text_editing),
-            |motion, dt, limits| match selected_fractal {
-                FractalKinds::Mandelbrot => {
-                    mandelbrot_flight::step_flight_in_viewport(
-                        &mut ui_state.mandelbrot,
-                        motion,
-                        dt,
-                        limits,
-                        viewport,
-                    )
+            |motion, dt, limits| {
+                step_selected_fractal(ui_state, selected_fractal, motion, dt, limits, viewport)
+            },
+        );
+
+        if flight_input.take_single_step() && self.flight_sim.status().paused {
+            let _ = self.flight_sim.step_once(
+                || flight_input.snapshot(text_editing),
+                |motion, dt, limits| {
+                    step_selected_fractal(ui_state, selected_fractal, motion, dt, limits, viewport)
+                },
+            );
+        }
+    }
+
+    /// Starts (or extends) an eased zoom about the cursor, or the view
+    /// centre when the cursor is outside the window.
+    fn handle_mouse_wheel(&mut self, delta: MouseScrollDelta) {
+        let notches = match delta {
+            MouseScrollDelta::LineDelta(_, y) => f64::from(y),
+            MouseScrollDelta::PixelDelta(position) => position.y / PIXELS_PER_WHEEL_NOTCH,
+        };
+
+        if notches == 0.0 || self.flight_sim.is_active() {
+            return;
+        }
+
+        let anchor = self
+            .cursor_position
+            .map(|(x, y)| {
+                (
+                    cursor_anchor_fraction(x, self.size.window_size().0),
+                    cursor_anchor_fraction(y, self.size.window_size().1),
+                )
+            })
+            .unwrap_or((0.0, 0.0));
+
+        self.zoom_animator.zoom_toward(
+            &self.ui_state.active_region(),
+            anchor,
+            WHEEL_ZOOM_FACTOR.powf(notches),
+        );
+        self.ui_state.redraw_pending = true;
+    }
+
+    /// Right-drag selects a rectangle; releasing zooms the view to it.
+    /// Ignored while flight owns the view or egui has the press.
+    fn handle_right_button(&mut self, state: ElementState, egui_consumed: bool) {
+        match state {
+            ElementState::Pressed => {
+                if let Some(position) = self.cursor_position
+                    && !egui_consumed
+                    && !self.flight_sim.is_active()
+                {
+                    self.rubber_band.begin(position);
                 }
-                FractalKinds::Julia => {
-                    julia_flight::step_flight_in_viewport(
-                        &mut ui_state.julia,
-                        motion,
-                        dt,
-                        limits,
-                        viewport,
-                    )
+            }
+            ElementState::Released => {
+                if !self.rubber_band.is_dragging() {
+                    return;
                 }
-            },
+                let window_size = self.size.window_size();
+                let selected = self
+                    .rubber_band
+                    .finish(window_size)
+                    .and_then(|selection| {
+                        region_from_selection(&self.ui_state.active_region(), window_size, selection)
+                    });
+
+                if let Some(region) = selected
+                    && !self.flight_sim.is_active()
+                {
+                    self.zoom_animator.cancel();
+                    self.ui_state.set_active_region(region);
+                }
+                self.ui_state.redraw_pending = true;
+            }
+        }
+    }
+
+    /// Left-drag pans the view with the cursor; releasing while it still
+    /// moves hands that motion to flight as a glide, and pressing again
+    /// catches a glide in progress. Ignored while flight proper owns the
+    /// view or egui has the press. In the split view, a click in the
+    /// Mandelbrot pane picks the Julia constant instead.
+    fn handle_left_button(&mut self, state: ElementState, egui_consumed: bool) {
+        match state {
+            ElementState::Pressed => {
+                let Some(position) = self.cursor_position else {
+                    return;
+                };
+                if egui_consumed {
+                    return;
+                }
+                if let Some(viewport) = self.viewport_pixel_rect()
+                    && let Some(point) =
+                        window_to_render_pixel(position, self.size.window_size(), self.render_size())
+                    && self.ui_state.pick_julia_c(point, viewport)
+                {
+                    self.ui_state.redraw_pending = true;
+                    return;
+                }
+                self.flight_sim.stop_glide();
+                if !self.flight_sim.is_active() {
+                    self.zoom_animator.cancel();
+                    self.drag_pan.begin(position, Instant::now());
+                }
+            }
+            ElementState::Released => {
+                if !self.drag_pan.is_dragging() {
+                    return;
+                }
+                // The redraw submits the region the drag left, glide or not.
+                self.ui_state.redraw_pending = true;
+                let Some(velocity) = self.drag_pan.finish(Instant::now()) else {
+                    return;
+                };
+                if self.flight_sim.is_active() {
+                    return;
+                }
+                let window_size = self.size.window_size();
+                if let Some(motion) = glide_motion(velocity, window_size, self.flight_sim.limits()) {
+                    self.flight_sim.glide(motion);
+                }
+            }
+        }
+    }
+
+    /// Moves the view with a drag-pan in progress, so the point under the
+    /// cursor stays under it.
+    fn drag_pan_to(&mut self, position: (f64, f64)) {
+        let Some((dx, dy)) = self.drag_pan.drag_to(position, Instant::now()) else {
+            return;
+        };
+        if self.flight_sim.is_active() {
+            self.drag_pan.cancel();
+            return;
+        }
+
+        let region = self.ui_state.active_region();
+        let Some((dre, dim)) =
+            pan_offset((dx, dy), self.size.window_size(), (region.width(), region.height()))
+        else {
+            return;
+        };
+        if let Some(panned) = region.panned_by(dre, dim) {
+            self.ui_state.set_active_region(panned.normalised());
+        }
+        self.ui_state.redraw_pending = true;
+    }
+
+    /// Saves the last rendered frame as a PNG in [`SCREENSHOT_DIR`]. Before
+    /// the first frame arrives there is nothing to save.
+    fn save_screenshot(&mut self) {
+        let Some(frame) = self.presenter.last_frame() else {
+            return;
+        };
+
+        match save_screenshot(frame, Path::new(SCREENSHOT_DIR), SystemTime::now()) {
+            Ok(path) => self.screenshot_notice.record(path, Instant::now()),
+            Err(e) => self.last_error_message = Some(format!("Screenshot failed: {e}")),
+        }
+        self.ui_state.redraw_pending = true;
+    }
+
+    /// Eases the view toward any pending wheel-zoom target. Flight owns the
+    /// view while active, so a pending zoom is dropped rather than fought.
+    fn update_zoom_animation(&mut self, elapsed: Duration) {
+        if self.flight_sim.is_active() {
+            self.zoom_animator.cancel();
+            return;
+        }
+
+        if let Some(region) = self.zoom_animator.step(&self.ui_state.active_region(), elapsed)
+            && !self.ui_state.set_active_region(region)
+        {
+            self.zoom_animator.cancel();
+        }
+    }
+
+    /// Starts a [`DetailSeek`] on a coarse field of the current view, at
+    /// the same iteration ceiling as the renders.
+    fn seek_detail(&mut self) {
+        if self.flight_sim.is_active() {
+            return;
+        }
+
+        let (width, height) = self.render_size();
+        let Some(pixel_rect) = seek_pixel_rect(width, height) else {
+            return;
+        };
+
+        let max_iterations =
+            clamp_to_ceiling(self.ui_state.active_max_iterations(), self.max_iterations_ceiling).max_iterations;
+        let request = self
+            .ui_state
+            .build_render_request_with_max_iterations(pixel_rect, max_iterations);
+        self.detail_seek
+            .start(request, pixel_rect, self.ui_state.active_region());
+        self.ui_state.redraw_pending = true;
+    }
+
+    /// Eases the view centre toward the spot a finished [`DetailSeek`]
+    /// found, unless the view has moved since.
+    fn update_detail_seek(&mut self) {
+        let current = self.ui_state.active_region();
+        let Some((centre, pixel_rect)) = self.detail_seek.poll(&current) else {
+            return;
+        };
+
+        let anchor = (
+            cursor_anchor_fraction(f64::from(centre.x), pixel_rect.width()),
+            cursor_anchor_fraction(f64::from(centre.y), pixel_rect.height()),
         );
+        self.zoom_animator.pan_toward(&current, anchor);
     }
 
     fn viewport_pixel_rect(&self) -> Option<PixelRect> {
-        if self.width < 1 || self.height < 1 {
+        let (width, height) = self.render_size();
+        if width < 1 || height < 1 {
             return None;
         }
 
         PixelRect::new(
             Point { x: 0, y: 0 },
             Point {
-                x: (self.width as i32) - 1,
-                y: (self.height as i32) - 1,
+                x: (width as i32) - 1,
+                y: (height as i32) - 1,
             },
         )
         .ok()
     }
 
     fn schedule_desired_request(&mut self, desired_request: Arc<FractalConfig>) {
+        let priority = self.ui_state.submission_priority(&desired_request);
         let action = self.scheduler.update(
             Arc::clone(&desired_request),
+            priority,
             self.flight_sim.is_active(),
             self.controller.last_completed_generation(),
             |request| self.controller.submit_request(request),
@@ -195,9 +623,22 @@ impl<T: GuiPresenterPort> GuiApp<T> {
 
     pub fn update_ui(&mut self, window: &Window) -> egui::FullOutput {
         let raw_input = self.egui_state.take_egui_input(window);
-
-        self.egui_ctx.run(raw_input, |ctx| {
+        let (render_width, render_height) = self.render_size();
+        let (window_width, window_height) = self.window_size();
+
+        let julia_c_marker = self
+            .viewport_pixel_rect()
+            .and_then(|viewport| self.ui_state.julia_c_marker(viewport))
+            .map(|point| {
+                render_to_window_position(point, (window_width, window_height), (render_width, render_height))
+            });
+
+        let mut show_panel = self.show_panel;
+        let mut seek_detail = false;
+        let mut gpu_direct_rendering = self.controller.gpu_direct_rendering();
+        let output = self.egui_ctx.run(raw_input, |ctx| {
             egui::Window::new("Settings")
+                .open(&mut show_panel)
                 .default_pos([10.0, 10.0])
                 .default_size([300.0, 320.0])
                 .show(ctx, |ui| {
@@ -235,9 +676,43 @@ impl<T: GuiPresenterPort> GuiApp<T> {
                                     1..=10000,
                                 ));
                             }
+                            FractalKinds::BurningShip => {
+                                ui.add(egui::Slider::new(
+                                    &mut self.ui_state.burning_ship.max_iterations,
+                                    1..=10000,
+                                ));
+                            }
+                            FractalKinds::Tricorn => {
+                                ui.add(egui::Slider::new(
+                                    &mut self.ui_state.tricorn.max_iterations,
+                                    1..=10000,
+                                ));
+                            }
                         }
                     });
 
+                    if self.ui_state.selected_fractal == FractalKinds::Mandelbrot {
+                        ui.horizontal(|ui| {
+                            ui.label("Power:");
+                            ui.add(egui::Slider::new(&mut self.ui_state.mandelbrot.power, DEFAULT_POWER..=MAX_POWER));
+                        });
+                    }
+
+                    if self.ui_state.selected_fractal == FractalKinds::Julia {
+                        ui.horizontal(|ui| {
+                            ui.label("c real:");
+                            ui.add(egui::Slider::new(&mut self.ui_state.julia.c.real, -2.0..=2.0));
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("c imag:");
+                            ui.add(egui::Slider::new(&mut self.ui_state.julia.c.imag, -2.0..=2.0));
+                        });
+                        ui.checkbox(
+                            &mut self.ui_state.julia_split_view,
+                            "Split view (click the Mandelbrot set to pick c)",
+                        );
+                    }
+
                     ui.horizontal(|ui| {
                         ui.label("Colour map:");
 
@@ -272,9 +747,72 @@ impl<T: GuiPresenterPort> GuiApp<T> {
                                         }
                                     });
                             }
+                            FractalKinds::BurningShip => {
+                                egui::ComboBox::from_id_source("fractal_colour_map")
+                                    .selected_text(
+                                        self.ui_state.burning_ship.colour_map_kind.display_name(),
+                                    )
+                                    .show_ui(ui, |ui| {
+                                        for &kind in MandelbrotColourMapKinds::ALL {
+                                            ui.selectable_value(
+                                                &mut self.ui_state.burning_ship.colour_map_kind,
+                                                kind,
+                                                kind.display_name(),
+                                            );
+                                        }
+                                    });
+                            }
+                            FractalKinds::Tricorn => {
+                                egui::ComboBox::from_id_source("fractal_colour_map")
+                                    .selected_text(
+                                        self.ui_state.tricorn.colour_map_kind.display_name(),
+                                    )
+                                    .show_ui(ui, |ui| {
+                                        for &kind in MandelbrotColourMapKinds::ALL {
+                                            ui.selectable_value(
+                                                &mut self.ui_state.tricorn.colour_map_kind,
+                                                kind,
+                                                kind.display_name(),
+                                            );
+                                        }
+                                    });
+                            }
                         }
                     });
 
+                    let contrast_stretch = match self.ui_state.selected_fractal {
+                        FractalKinds::Mandelbrot => &mut self.ui_state.mandelbrot.contrast_stretch,
+                        FractalKinds::Julia => &mut self.ui_state.julia.contrast_stretch,
+                        FractalKinds::BurningShip => &mut self.ui_state.burning_ship.contrast_stretch,
+                        FractalKinds::Tricorn => &mut self.ui_state.tricorn.contrast_stretch,
+                    };
+                    let colour_scheme = match self.ui_state.selected_fractal {
+                        FractalKinds::Mandelbrot => &mut self.ui_state.mandelbrot.colour_scheme,
+                        FractalKinds::Julia => &mut self.ui_state.julia.colour_scheme,
+                        FractalKinds::BurningShip => &mut self.ui_state.burning_ship.colour_scheme,
+                        FractalKinds::Tricorn => &mut self.ui_state.tricorn.colour_scheme,
+                    };
+                    ui.horizontal(|ui| {
+                        ui.label("Colour scheme:");
+                        egui::ComboBox::from_id_source("fractal_colour_scheme")
+                            .selected_text(colour_scheme.display_name())
+                            .show_ui(ui, |ui| {
+                                for &kind in ColourSchemeKind::ALL {
+                                    ui.selectable_value(colour_scheme, kind, kind.display_name());
+                                }
+                            });
+                    });
+
+                    ui.checkbox(contrast_stretch, "Stretch colours to the visible range");
+
+                    let smooth = match self.ui_state.selected_fractal {
+                        FractalKinds::Mandelbrot => &mut self.ui_state.mandelbrot.smooth,
+                        FractalKinds::Julia => &mut self.ui_state.julia.smooth,
+                        FractalKinds::BurningShip => &mut self.ui_state.burning_ship.smooth,
+                        FractalKinds::Tricorn => &mut self.ui_state.tricorn.smooth,
+                    };
+                    ui.checkbox(smooth, "Smooth shading");
+
                     ui.separator();
                     ui.label("View region:");
 
@@ -290,13 +828,28 @@ impl<T: GuiPresenterPort> GuiApp<T> {
                                 "Renderer: {}",
                                 if self.ui_state.mandelbrot.uses_perturbation() {
                                     "perturbation (deep zoom)"
+                                } else if self.ui_state.mandelbrot.uses_bigfloat() {
+                                    "arbitrary precision (deep zoom)"
                                 } else {
                                     "direct f64"
                                 }
                             ));
+
+                            ui.checkbox(&mut gpu_direct_rendering, "Render shallow views on the GPU");
+
+                            if cfg!(debug_assertions) {
+                                ui.checkbox(
+                                    &mut self.ui_state.compare_against_reference,
+                                    "Diff against brute force (black = identical)",
+                                );
+                            }
                         }
-                        FractalKinds::Julia => {
-                            let region = self.ui_state.julia.region;
+                        FractalKinds::Julia | FractalKinds::BurningShip | FractalKinds::Tricorn => {
+                            let region = match self.ui_state.selected_fractal {
+                                FractalKinds::BurningShip => self.ui_state.burning_ship.region,
+                                FractalKinds::Tricorn => self.ui_state.tricorn.region,
+                                _ => self.ui_state.julia.region,
+                            };
                             let top_left = region.top_left();
                             let bottom_right = region.bottom_right();
 
@@ -312,12 +865,52 @@ impl<T: GuiPresenterPort> GuiApp<T> {
                         }
                     }
 
-                    if ui.button("Reset view").clicked() {
-                        self.ui_state.reset_view();
-                    }
+                    ui.horizontal(|ui| {
+                        if ui.button("Reset view").clicked() {
+                            self.ui_state.reset_view();
+                        }
+                        seek_detail = ui.button("Seek detail").clicked();
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label("Centre:");
+                        ui.add(egui::DragValue::new(&mut self.centre_zoom.centre.real).speed(0.01));
+                        ui.add(egui::DragValue::new(&mut self.centre_zoom.centre.imag).speed(0.01));
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Zoom:");
+                        ui.add(
+                            egui::DragValue::new(&mut self.centre_zoom.zoom)
+                                .speed(0.1)
+                                .clamp_range(f64::MIN_POSITIVE..=f64::MAX),
+                        );
+                        if ui.button("Go").clicked()
+                            && let Ok(rect) = region_from_centre_zoom(
+                                self.centre_zoom,
+                                self.ui_state.active_default_extent(),
+                                (render_width, render_height),
+                            )
+                        {
+                            self.zoom_animator.cancel();
+                            self.ui_state
+                                .set_active_region(DeepRegion::from_complex_rect(&rect));
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut self.crosshair.enabled, "Crosshair");
+                        ui.add_enabled(
+                            self.crosshair.enabled,
+                            egui::Slider::new(&mut self.crosshair.arm_length, 4.0..=64.0)
+                                .text("size"),
+                        );
+                    });
+                    ui.checkbox(&mut self.show_generation_overlay, "Generation overlay");
 
                     ui.separator();
-                    ui.label(format!("Window size: {}x{}", self.width, self.height));
+                    ui.label(format!("Window size: {}x{}", window_width, window_height));
+                    if (render_width, render_height) != (window_width, window_height) {
+                        ui.label(format!("Render size: {}x{}", render_width, render_height));
+                    }
 
                     ui.label(format!(
                         "Latest generation: {}",
@@ -351,6 +944,13 @@ impl<T: GuiPresenterPort> GuiApp<T> {
                     if let Some(warning) = flight_status.last_warning {
                         ui.label(format!("Warning: {}", Self::warning_label(warning)));
                     }
+                    for (warning, count) in flight_status
+                        .warning_counts
+                        .iter()
+                        .filter(|&(_, count)| count > 0)
+                    {
+                        ui.label(format!("{}: {}x", Self::warning_label(warning), count));
+                    }
 
                     if let Some(in_flight_generation) = self.scheduler.in_flight_generation() {
                         ui.label(format!("In-flight gen: {}", in_flight_generation));
@@ -359,17 +959,108 @@ impl<T: GuiPresenterPort> GuiApp<T> {
                         "Scheduler pending: {}",
                         self.scheduler.has_pending()
                     ));
+                    ui.label(format!(
+                        "Scheduler: {} submitted, {} coalesced",
+                        self.scheduler.submitted_count(),
+                        self.scheduler.coalesced_count()
+                    ));
+
+                    let load_quality = self.controller.load_quality();
+                    if let Some(iterations) = self.controller.detail_iterations() {
+                        ui.label(format!("Frame budget: {iterations} iterations"));
+                    } else if load_quality < 1.0 {
+                        ui.label(format!(
+                            "Reduced quality under load: {:.0}% iterations",
+                            load_quality * 100.0
+                        ));
+                    }
+
+                    if let Some(warning) =
+                        clamp_to_ceiling(self.ui_state.active_max_iterations(), self.max_iterations_ceiling)
+                            .warning()
+                    {
+                        ui.colored_label(egui::Color32::YELLOW, warning);
+                    }
 
                     if let Some(render_duration) = self.last_render_duration {
                         ui.label(format!("Last render: {} ms", render_duration.as_millis()));
                     }
+                    if let Some(latency) = self.presenter.last_present_latency() {
+                        ui.label(format!("Latency: {} ms", latency.as_millis()));
+                    }
+
+                    if let Some(path) = self.screenshot_notice.visible(Instant::now()) {
+                        ui.label(format!("Saved {}", path.display()));
+                    }
 
                     if let Some(message) = &self.last_error_message {
                         ui.separator();
                         ui.colored_label(egui::Color32::LIGHT_RED, message);
                     }
+
+                    if self.presenter.last_frame_single_colour() {
+                        ui.separator();
+                        ui.colored_label(egui::Color32::YELLOW, NO_DETAIL_HINT);
+                    }
                 });
-        })
+
+            if let Some((start, end)) = self.rubber_band.corners() {
+                let pixels_per_point = self.scale_factor as f32;
+                let to_point =
+                    |(x, y): (f64, f64)| egui::pos2(x as f32 / pixels_per_point, y as f32 / pixels_per_point);
+                let painter = ctx.layer_painter(egui::LayerId::new(
+                    egui::Order::Background,
+                    egui::Id::new("rubber_band"),
+                ));
+                paint_rubber_band(&painter, egui::Rect::from_two_pos(to_point(start), to_point(end)));
+            }
+
+            if self.crosshair.enabled
+                && let Some((x, y)) =
+                    centre_point(window_width, window_height, self.scale_factor as f32)
+            {
+                let painter = ctx.layer_painter(egui::LayerId::new(
+                    egui::Order::Background,
+                    egui::Id::new("crosshair"),
+                ));
+                paint_crosshair(&painter, egui::pos2(x, y), self.crosshair.arm_length);
+            }
+
+            if let Some((x, y)) = julia_c_marker {
+                let pixels_per_point = self.scale_factor as f32;
+                let painter = ctx.layer_painter(egui::LayerId::new(
+                    egui::Order::Background,
+                    egui::Id::new("julia_c_marker"),
+                ));
+                paint_julia_c_marker(
+                    &painter,
+                    egui::pos2(x as f32 / pixels_per_point, y as f32 / pixels_per_point),
+                );
+            }
+
+            if self.show_generation_overlay {
+                let overlay = GenerationOverlay {
+                    latest_submitted: self.ui_state.latest_submitted_generation,
+                    last_completed: self.controller.last_completed_generation(),
+                    last_presented: self.presenter.last_presented_generation(),
+                };
+                let painter = ctx.layer_painter(egui::LayerId::new(
+                    egui::Order::Background,
+                    egui::Id::new("generation_overlay"),
+                ));
+                paint_generation_overlay(&painter, ctx.screen_rect().right_top(), &overlay);
+            }
+        });
+        self.show_panel = show_panel;
+        if gpu_direct_rendering != self.controller.gpu_direct_rendering() {
+            self.controller.set_gpu_direct_rendering(gpu_direct_rendering);
+            self.ui_state.forget_last_submission();
+        }
+        if seek_detail {
+            self.seek_detail();
+        }
+
+        output
     }
 
     pub fn handle_window_event(&mut self, window: &Window, event: &WindowEvent) -> (bool, bool) {
@@ -378,139 +1069,389 @@ impl<T: GuiPresenterPort> GuiApp<T> {
         (response.consumed, response.repaint)
     }
 
-    pub fn run(mut self, event_loop: EventLoop<GuiEvent>) {
-        event_loop
-            .run(move |event, elwt| {
-                match event {
-                    Event::UserEvent(GuiEvent::Wake) => {
-                        self.ui_state.redraw_pending = true;
-                    }
-                    Event::WindowEvent {
-                        ref event,
-                        window_id,
-                    } if window_id == self.window.id() => {
-                        let (egui_consumed, egui_repaint) =
-                            self.handle_window_event(self.window, event);
-
-                        if egui_repaint {
-                            self.ui_state.redraw_pending = true;
-                        }
+    /// Handles one event for this app's window and says whether the event
+    /// loop should keep running. `RedrawRequested`, `Resized` and
+    /// `ScaleFactorChanged` go through [`Self::on_redraw`],
+    /// [`Self::on_resize`] and [`Self::on_scale_factor_changed`]; a render
+    /// error is reported on stderr and ends the loop.
+    pub fn on_window_event(&mut self, event: &WindowEvent) -> AppControl {
+        let (egui_consumed, egui_repaint) = self.handle_window_event(self.window, event);
 
-                        if let WindowEvent::KeyboardInput { event, .. } = event {
-                            if let PhysicalKey::Code(key_code) = event.physical_key {
-                                self.flight_input.handle_key_event(key_code, event.state);
-                                self.ui_state.redraw_pending = true;
-                            }
-                        }
+        if egui_repaint {
+            self.ui_state.redraw_pending = true;
+        }
 
-                        match event {
-                            WindowEvent::CloseRequested => {
-                                self.controller.shutdown();
-                                elwt.exit();
-                            }
-                            WindowEvent::RedrawRequested => {
-                                self.ui_state.redraw_pending = false;
-                                self.scheduler.observe_completion(
-                                    self.controller.last_completed_generation(),
-                                );
+        if let WindowEvent::KeyboardInput { event, .. } = event
+            && let PhysicalKey::Code(key_code) = event.physical_key
+        {
+            self.flight_input.handle_key_event(key_code, event.state);
+            self.ui_state.redraw_pending = true;
+        }
 
-                                let egui_output = self.update_ui(self.window);
+        match event {
+            WindowEvent::CloseRequested => {
+                self.controller.shutdown();
+                return AppControl::Exit;
+            }
+            WindowEvent::RedrawRequested => {
+                if let Err(e) = self.on_redraw() {
+                    eprintln!("Render error: {e}");
+                    return AppControl::Exit;
+                }
+            }
+            WindowEvent::CursorMoved { position, .. } => {
+                self.cursor_position = Some((position.x, position.y));
+                if self.rubber_band.is_dragging() {
+                    self.rubber_band.drag_to((position.x, position.y));
+                    self.ui_state.redraw_pending = true;
+                }
+                if self.drag_pan.is_dragging() {
+                    self.drag_pan_to((position.x, position.y));
+                }
+            }
+            WindowEvent::MouseInput {
+                state,
+                button: MouseButton::Right,
+                ..
+            } => self.handle_right_button(*state, egui_consumed),
+            WindowEvent::MouseInput {
+                state,
+                button: MouseButton::Left,
+                ..
+            } => self.handle_left_button(*state, egui_consumed),
+            WindowEvent::CursorLeft { .. } => {
+                self.cursor_position = None;
+            }
+            WindowEvent::MouseWheel { delta, .. } if !egui_consumed => {
+                self.handle_mouse_wheel(*delta);
+            }
+            WindowEvent::Resized(size) => self.on_resize(size.width, size.height),
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                self.on_scale_factor_changed(*scale_factor);
+            }
+            _ => {}
+        }
 
-                                if self.ui_state.selected_fractal != self.last_selected_fractal {
-                                    self.flight_sim.reset_motion();
-                                    self.flight_input.reset();
-                                    self.scheduler.reset();
-                                    self.last_selected_fractal = self.ui_state.selected_fractal;
-                                }
+        AppControl::Continue
+    }
 
-                                let now = Instant::now();
-                                let elapsed =
-                                    now.saturating_duration_since(self.last_redraw_instant);
-                                self.last_redraw_instant = now;
+    /// Draws one frame: runs the settings UI, advances flight and zoom
+    /// easing by the time since the last frame, presents, and schedules the
+    /// render the view now calls for.
+    pub fn on_redraw(&mut self) -> Result<(), pixels::Error> {
+        self.ui_state.redraw_pending = false;
+        self.scheduler
+            .observe_completion(self.controller.last_completed_generation());
 
-                                let text_editing = self.egui_ctx.wants_keyboard_input();
-                                self.update_flight_simulation(elapsed, text_editing);
-                                let pause_overlay_toggle_requested =
-                                    self.flight_input.take_pause_overlay_toggle();
+        if self.flight_input.take_panel_toggle(self.egui_ctx.wants_keyboard_input()) {
+            self.show_panel = !self.show_panel;
+        }
 
-                                if pause_overlay_toggle_requested && self.flight_sim.status().paused
-                                {
-                                    self.show_pause_overlay = !self.show_pause_overlay;
-                                }
+        if self.flight_input.take_fullscreen_toggle(self.egui_ctx.wants_keyboard_input()) {
+            // The resize this causes arrives as an ordinary `Resized` event.
+            self.window.set_fullscreen(toggled_fullscreen(self.window.fullscreen().as_ref()));
+        }
 
-                                let mut request_to_schedule: Option<Arc<FractalConfig>> = None;
-                                if let Some(desired_request) = self.build_desired_request() {
-                                    let request_changed =
-                                        self.ui_state.should_submit(desired_request.as_ref());
-                                    let should_schedule =
-                                        request_changed || self.scheduler.has_pending();
+        if self.flight_input.take_screenshot_request(self.egui_ctx.wants_keyboard_input()) {
+            self.save_screenshot();
+        }
 
-                                    if should_schedule {
-                                        request_to_schedule = Some(desired_request);
-                                    }
-                                }
+        let egui_output = self.update_ui(self.window);
+
+        if self.ui_state.selected_fractal != self.last_selected_fractal {
+            self.flight_sim.reset_motion();
+            self.flight_input.reset();
+            self.scheduler.reset();
+            self.request_debounce.cancel();
+            self.last_flight_active_instant = None;
+            self.input_idle.reset();
+            self.zoom_animator.cancel();
+            self.detail_seek.cancel();
+            self.rubber_band.cancel();
+            self.drag_pan.cancel();
+            self.last_selected_fractal = self.ui_state.selected_fractal;
+        }
 
-                                self.ui_state.redraw_pending =
-                                    self.flight_sim.is_active() || self.scheduler.has_pending();
+        let now = Instant::now();
+        let elapsed = now.saturating_duration_since(self.last_redraw_instant);
+        self.last_redraw_instant = now;
 
-                                self.egui_state.handle_platform_output(
-                                    self.window,
-                                    egui_output.platform_output.clone(),
-                                );
+        let text_editing = self.egui_ctx.wants_keyboard_input();
+        self.update_flight_simulation(elapsed, text_editing);
+        self.update_detail_seek();
+        self.update_zoom_animation(elapsed);
+        let pause_overlay_toggle_requested = self.flight_input.take_pause_overlay_toggle();
 
-                                if egui_output
-                                    .viewport_output
-                                    .values()
-                                    .any(|v| v.repaint_delay.is_zero())
-                                {
-                                    self.ui_state.redraw_pending = true;
-                                }
+        if pause_overlay_toggle_requested && self.flight_sim.status().paused {
+            self.show_pause_overlay = !self.show_pause_overlay;
+        }
 
-                                if let Err(e) = self.render(egui_output) {
-                                    eprintln!("Render error: {e}");
-                                    elwt.exit();
-                                }
+        if self.flight_sim.is_active() {
+            self.last_flight_active_instant = Some(now);
+        }
+        if self.flight_sim.is_active() || self.zoom_animator.is_animating() {
+            self.input_idle.note_input(now);
+        }
+        let idle_render = self.input_idle.take_idle(now);
+        let drag_released = self.drag_pan.take_release();
+        let iterations_ramping = !self.input_idle.is_settled()
+            && self.adaptive_iterations.is_ramping(
+                self.flight_sim.is_active(),
+                self.since_flight_idle(now),
+            );
+
+        let mut request_to_schedule: Option<Arc<FractalConfig>> = None;
+        if let Some(desired_request) = self.build_desired_request(now) {
+            let request_changed = self.ui_state.should_submit(desired_request.as_ref());
+            let should_schedule = request_changed || self.scheduler.has_pending();
+
+            if should_schedule {
+                // Only settings edits wait for the view to settle: the first
+                // request, a resize, or a view in motion goes out at once.
+                let immediate = self.scheduler.submitted_count() == 0
+                    || self.scheduler.has_pending()
+                    || self.ui_state.submission_priority(&desired_request) == RequestPriority::Preempt
+                    || self.flight_sim.is_active()
+                    || self.zoom_animator.is_animating()
+                    || self.drag_pan.is_dragging()
+                    || drag_released
+                    || iterations_ramping
+                    || idle_render;
+
+                if let DebounceDecision::Submit(request) =
+                    self.request_debounce.offer(now, desired_request, immediate)
+                {
+                    request_to_schedule = Some(request);
+                }
+            } else {
+                self.request_debounce.cancel();
+            }
+        }
 
-                                if let Some(desired_request) = request_to_schedule {
-                                    self.schedule_desired_request(desired_request);
-                                    self.ui_state.redraw_pending = true;
-                                }
+        self.ui_state.redraw_pending = should_redraw_after_frame(
+            self.continuous,
+            self.flight_sim.is_active() || iterations_ramping || self.zoom_animator.is_animating(),
+            self.scheduler.has_pending()
+                || self.request_debounce.is_waiting()
+                || self.input_idle.is_waiting()
+                || self.detail_seek.is_waiting(),
+        );
 
-                                self.ui_state.redraw_pending |=
-                                    self.flight_sim.is_active() || self.scheduler.has_pending();
-                            }
-                            WindowEvent::Resized(size) => {
-                                self.resize(size.width, size.height);
-                                self.ui_state.redraw_pending = true;
-                            }
-                            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
-                                self.scale_factor = *scale_factor;
-                                self.egui_ctx.set_pixels_per_point(*scale_factor as f32);
+        self.egui_state
+            .handle_platform_output(self.window, egui_output.platform_output.clone());
 
-                                let size = self.window.inner_size();
+        if egui_output
+            .viewport_output
+            .values()
+            .any(|v| v.repaint_delay.is_zero())
+        {
+            self.ui_state.redraw_pending = true;
+        }
 
-                                self.resize(size.width, size.height);
-                                self.ui_state.redraw_pending = true;
-                            }
-                            _ => {}
-                        }
+        let rendered = self.render(egui_output);
 
-                        // Suppress unused variable warning - consumed will be used
-                        // when we add pan/zoom to avoid passing clicks through UI
-                        let _ = egui_consumed;
-                    }
-                    Event::AboutToWait => {
-                        if self.ui_state.redraw_pending {
-                            self.window.request_redraw();
-                        }
-                    }
-                    _ => {}
+        // On demand, the presenter's wake-up for the finished render brings
+        // the next frame.
+        if let Some(desired_request) = request_to_schedule {
+            self.schedule_desired_request(desired_request);
+            self.ui_state.redraw_pending |= self.continuous;
+        }
+
+        self.ui_state.redraw_pending |= should_redraw_after_frame(
+            self.continuous,
+            self.flight_sim.is_active(),
+            self.scheduler.has_pending()
+                || self.request_debounce.is_waiting()
+                || self.input_idle.is_waiting()
+                || self.detail_seek.is_waiting(),
+        );
+
+        rendered
+    }
+
+    pub fn run(mut self, event_loop: EventLoop<GuiEvent>) {
+        event_loop
+            .run(move |event, elwt| match event {
+                Event::UserEvent(GuiEvent::Wake) => self.on_wake(),
+                Event::UserEvent(GuiEvent::WakeWindow(window_id)) if window_id == self.window.id() => {
+                    self.on_wake();
+                }
+                Event::WindowEvent {
+                    ref event,
+                    window_id,
+                } if window_id == self.window.id() && self.on_window_event(event) == AppControl::Exit => {
+                    elwt.exit();
                 }
+                Event::AboutToWait if self.needs_redraw() => {
+                    self.window.request_redraw();
+                }
+                _ => {}
             })
             .expect("Event loop error");
     }
 }
 
+/// Exit order: the controller shuts down (closing output and joining its
+/// worker) before any field is dropped, so the presenter and its event loop
+/// proxy always outlive the last `present` call.
+impl<T: GuiPresenterPort> Drop for GuiApp<T> {
+    fn drop(&mut self) {
+        self.controller.shutdown();
+    }
+}
+
+fn step_selected_fractal(
+    ui_state: &mut GuiAppState,
+    selected_fractal: FractalKinds,
+    motion: &MotionState,
+    dt: f64,
+    limits: &FlightLimits,
+    viewport: Option<PixelRect>,
+) -> FlightUpdateReport {
+    match selected_fractal {
+        FractalKinds::Mandelbrot => mandelbrot_flight::step_flight_in_viewport(
+            &mut ui_state.mandelbrot,
+            motion,
+            dt,
+            limits,
+            viewport,
+        ),
+        FractalKinds::Julia => {
+            julia_flight::step_flight_in_viewport(&mut ui_state.julia, motion, dt, limits, viewport)
+        }
+        FractalKinds::BurningShip => burning_ship_flight::step_flight_in_viewport(
+            &mut ui_state.burning_ship,
+            motion,
+            dt,
+            limits,
+            viewport,
+        ),
+        FractalKinds::Tricorn => tricorn_flight::step_flight_in_viewport(
+            &mut ui_state.tricorn,
+            motion,
+            dt,
+            limits,
+            viewport,
+        ),
+    }
+}
+
+/// Shown when the last frame came back a single colour.
+const NO_DETAIL_HINT: &str = "No detail in view \u{2014} try zooming out";
+
+/// Scroll distance reported by touchpads that counts as one wheel notch.
+const PIXELS_PER_WHEEL_NOTCH: f64 = 50.0;
+
+/// Longer side, in pixels, of the field "Seek detail" searches.
+const SEEK_DETAIL_RESOLUTION: u32 = 160;
+
+/// Whether a frame should queue another one by itself rather than wait for
+/// input. A request still waiting on the scheduler or the debounce, or a
+/// detail seek still running, always does, so it is never stranded;
+/// animation (flight, zoom easing, the iteration ramp) only does in
+/// continuous mode.
+fn should_redraw_after_frame(continuous: bool, animating: bool, render_pending: bool) -> bool {
+    render_pending || (continuous && animating)
+}
+
+/// Cursor position along one axis as a fraction of the view from its
+/// centre, matching the pixel-to-complex mapping of the render algorithms.
+fn cursor_anchor_fraction(position: f64, pixels: u32) -> f64 {
+    if pixels > 1 {
+        (position / f64::from(pixels - 1) - 0.5).clamp(-0.5, 0.5)
+    } else {
+        0.0
+    }
+}
+
+/// `region` refitted for a window resized from `old_size` to `new_size`
+/// by [`ComplexRect::scale_to_fit_pixels`], keeping the point under window
+/// position `focal` fixed. The fit is done relative to the centre, so a
+/// deep region keeps its precision. `None` if the result is degenerate.
+fn region_resized(
+    region: &DeepRegion,
+    old_size: (u32, u32),
+    new_size: (u32, u32),
+    focal: (f64, f64),
+) -> Option<DeepRegion> {
+    let (half_width, half_height) = (region.width() / 2.0, region.height() / 2.0);
+    let relative = ComplexRect::new(
+        Complex { real: -half_width, imag: -half_height },
+        Complex { real: half_width, imag: half_height },
+    )
+    .ok()?
+    .scale_to_fit_pixels(old_size, new_size, focal)
+    .ok()?;
+
+    let (top_left, bottom_right) = (relative.top_left(), relative.bottom_right());
+    region
+        .panned_by(
+            (top_left.real + bottom_right.real) / 2.0,
+            (top_left.imag + bottom_right.imag) / 2.0,
+        )?
+        .with_extent(relative.width(), relative.height())
+        .ok()
+}
+
+/// The render buffer pixel under a window position, both in physical
+/// pixels; `None` outside the window.
+fn window_to_render_pixel(
+    (x, y): (f64, f64),
+    (window_width, window_height): (u32, u32),
+    (render_width, render_height): (u32, u32),
+) -> Option<Point> {
+    let axis = |position: f64, window: u32, render: u32| {
+        if window == 0 || !(0.0..f64::from(window)).contains(&position) {
+            return None;
+        }
+        let pixel = (position * f64::from(render) / f64::from(window)).floor() as i32;
+        Some(pixel.min(render as i32 - 1))
+    };
+
+    Some(Point {
+        x: axis(x, window_width, render_width)?,
+        y: axis(y, window_height, render_height)?,
+    })
+}
+
+/// The window position, in physical pixels, of the centre of render buffer
+/// pixel `point`; the inverse of [`window_to_render_pixel`].
+fn render_to_window_position(
+    point: Point,
+    (window_width, window_height): (u32, u32),
+    (render_width, render_height): (u32, u32),
+) -> (f64, f64) {
+    let axis = |pixel: i32, window: u32, render: u32| {
+        (f64::from(pixel) + 0.5) * f64::from(window) / f64::from(render.max(1))
+    };
+
+    (
+        axis(point.x, window_width, render_width),
+        axis(point.y, window_height, render_height),
+    )
+}
+
+/// A grid with the viewport's aspect ratio and at most
+/// `SEEK_DETAIL_RESOLUTION` pixels along its longer side, or `None` for an
+/// empty viewport.
+fn seek_pixel_rect(width: u32, height: u32) -> Option<PixelRect> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let scale = (f64::from(SEEK_DETAIL_RESOLUTION) / f64::from(width.max(height))).min(1.0);
+    let scaled = |extent: u32| ((f64::from(extent) * scale).round() as i32).max(1);
+
+    PixelRect::new(
+        Point { x: 0, y: 0 },
+        Point {
+            x: scaled(width) - 1,
+            y: scaled(height) - 1,
+        },
+    )
+    .ok()
+}
+
 /// Enough decimal digits to distinguish positions within the current view,
 /// plus a small margin.
 fn centre_display_digits(extent: f64) -> usize {
@@ -538,6 +1479,60 @@ fn build_frame_overlay_from_state(
     }
 }
 
+/// Draws the crosshair's arms around `centre`, outlined so they show
+/// against both light and dark parts of the fractal.
+fn paint_crosshair(painter: &egui::Painter, centre: egui::Pos2, arm_length: f32) {
+    let outline = Stroke::new(3.0, Color32::from_black_alpha(160));
+    let line = Stroke::new(1.0, Color32::from_rgb(255, 232, 180));
+    let horizontal = [centre - egui::vec2(arm_length, 0.0), centre + egui::vec2(arm_length, 0.0)];
+    let vertical = [centre - egui::vec2(0.0, arm_length), centre + egui::vec2(0.0, arm_length)];
+
+    for stroke in [outline, line] {
+        painter.line_segment(horizontal, stroke);
+        painter.line_segment(vertical, stroke);
+    }
+}
+
+/// Lists the overlay's generations in the top-right corner on a dark
+/// backplate, the staleness line in yellow while stale.
+fn paint_generation_overlay(painter: &egui::Painter, top_right: egui::Pos2, overlay: &GenerationOverlay) {
+    const MARGIN: f32 = 8.0;
+    const PADDING: f32 = 4.0;
+    let text_colour = Color32::from_rgb(255, 232, 180);
+
+    let [submitted, completed, presented, frame] = overlay.lines();
+    let font = egui::FontId::monospace(12.0);
+    let counts = painter.layout_no_wrap(format!("{submitted}\n{completed}\n{presented}"), font.clone(), text_colour);
+    let frame_colour = if overlay.is_stale() { Color32::YELLOW } else { text_colour };
+    let frame = painter.layout_no_wrap(frame, font, frame_colour);
+
+    let size = egui::vec2(counts.size().x.max(frame.size().x), counts.size().y + frame.size().y);
+    let origin = top_right + egui::vec2(-MARGIN - PADDING - size.x, MARGIN + PADDING);
+    let backplate = egui::Rect::from_min_size(origin, size).expand(PADDING);
+
+    painter.rect_filled(backplate, Rounding::same(3.0), Color32::from_black_alpha(160));
+    let frame_origin = origin + egui::vec2(0.0, counts.size().y);
+    painter.galley(origin, counts, text_colour);
+    painter.galley(frame_origin, frame, frame_colour);
+}
+
+/// Rings the split view's Julia constant, dark under light like the
+/// crosshair.
+fn paint_julia_c_marker(painter: &egui::Painter, centre: egui::Pos2) {
+    const RADIUS: f32 = 5.0;
+
+    painter.circle_stroke(centre, RADIUS, Stroke::new(3.0, Color32::from_black_alpha(160)));
+    painter.circle_stroke(centre, RADIUS, Stroke::new(1.0, Color32::from_rgb(255, 232, 180)));
+}
+
+/// Outlines the zoom selection, dark under light like the crosshair, over
+/// a faint tint.
+fn paint_rubber_band(painter: &egui::Painter, rect: egui::Rect) {
+    painter.rect_filled(rect, Rounding::ZERO, Color32::from_white_alpha(24));
+    painter.rect_stroke(rect, Rounding::ZERO, Stroke::new(3.0, Color32::from_black_alpha(160)));
+    painter.rect_stroke(rect, Rounding::ZERO, Stroke::new(1.0, Color32::from_rgb(255, 232, 180)));
+}
+
 fn configure_egui_style(ctx: &Context) {
     // Colours drawn from the PAUSED overlay palette:
     //   [88,6,0]  [168,30,0]  [230,88,8]  [255,166,48]  [255,232,180]
@@ -624,8 +1619,17 @@ fn configure_egui_style(ctx: &Context) {
 
 #[cfg(test)]
 mod tests {
-    use super::build_frame_overlay_from_state;
-    use crate::{core::flight::FlightWarning, input::gui::app::frame_overlay::FrameOverlay};
+    use super::{
+        build_frame_overlay_from_state, cursor_anchor_fraction, region_resized, render_to_window_position,
+        seek_pixel_rect, should_redraw_after_frame, window_to_render_pixel,
+    };
+    use crate::{
+        core::{
+            data::{complex::Complex, complex_rect::ComplexRect, deep_region::DeepRegion, point::Point},
+            flight::FlightWarning,
+        },
+        input::gui::app::frame_overlay::FrameOverlay,
+    };
 
     #[test]
     fn build_frame_overlay_reflects_pause_and_limit_visibility() {
@@ -662,4 +1666,73 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn cursor_anchor_fraction_spans_the_view_from_its_centre() {
+        assert_eq!(cursor_anchor_fraction(0.0, 101), -0.5);
+        assert_eq!(cursor_anchor_fraction(50.0, 101), 0.0);
+        assert_eq!(cursor_anchor_fraction(100.0, 101), 0.5);
+        assert_eq!(cursor_anchor_fraction(250.0, 101), 0.5);
+        assert_eq!(cursor_anchor_fraction(3.0, 1), 0.0);
+    }
+
+    #[test]
+    fn seek_pixel_rect_shrinks_to_the_resolution_keeping_aspect() {
+        let rect = seek_pixel_rect(1600, 900).unwrap();
+        assert_eq!((rect.width(), rect.height()), (160, 90));
+
+        let small = seek_pixel_rect(40, 30).unwrap();
+        assert_eq!((small.width(), small.height()), (40, 30));
+
+        assert!(seek_pixel_rect(0, 30).is_none());
+    }
+
+    #[test]
+    fn window_positions_map_to_render_pixels_and_back() {
+        // A 200x100 window rendered at half size.
+        let window = (200, 100);
+        let render = (100, 50);
+
+        assert_eq!(window_to_render_pixel((0.0, 0.0), window, render), Some(Point { x: 0, y: 0 }));
+        assert_eq!(window_to_render_pixel((51.0, 99.9), window, render), Some(Point { x: 25, y: 49 }));
+        assert_eq!(window_to_render_pixel((200.0, 10.0), window, render), None);
+        assert_eq!(window_to_render_pixel((-1.0, 10.0), window, render), None);
+
+        let point = Point { x: 25, y: 49 };
+        let (x, y) = render_to_window_position(point, window, render);
+        assert_eq!((x, y), (51.0, 99.0));
+        assert_eq!(window_to_render_pixel((x, y), window, render), Some(point));
+    }
+
+    #[test]
+    fn resizing_keeps_the_focal_point_and_fits_the_new_aspect() {
+        let region = DeepRegion::from_complex_rect(
+            &ComplexRect::new(Complex { real: -2.0, imag: -1.0 }, Complex { real: 2.0, imag: 1.0 }).unwrap(),
+        );
+
+        // Widening a 400x200 window to 800x200 about its left edge keeps
+        // the left edge's point and doubles the visible width.
+        let wider = region_resized(&region, (400, 200), (800, 200), (0.0, 100.0)).unwrap();
+        let rect = wider.to_complex_rect().unwrap();
+        assert!((rect.top_left().real + 2.0).abs() < 1e-12, "{rect:?}");
+        assert!((rect.width() - 8.0).abs() < 1e-12, "{rect:?}");
+        assert!((rect.height() - 2.0).abs() < 1e-12, "{rect:?}");
+
+        // About the old window centre, its point stays at that window
+        // position, now a quarter of the way down.
+        let taller = region_resized(&region, (400, 200), (400, 400), (200.0, 100.0)).unwrap();
+        let rect = taller.to_complex_rect().unwrap();
+        assert!((rect.top_left().real + rect.width() / 2.0).abs() < 1e-12, "{rect:?}");
+        assert!((rect.top_left().imag + rect.height() / 4.0).abs() < 1e-12, "{rect:?}");
+        assert!((rect.width() / rect.height() - 1.0).abs() < 1e-12, "{rect:?}");
+    }
+
+    #[test]
+    fn on_demand_mode_ignores_animation_but_not_pending_renders() {
+        assert!(should_redraw_after_frame(true, true, false));
+        assert!(!should_redraw_after_frame(true, false, false));
+        assert!(!should_redraw_after_frame(false, true, false));
+        assert!(should_redraw_after_frame(false, false, true));
+        assert!(should_redraw_after_frame(false, true, true));
+    }
 }
@@ -0,0 +1,148 @@
+use std::time::{Duration, Instant};
+
+/// Notices when the view stops moving for a moment, so one full-quality
+/// render can follow the reduced frames drawn while it moved.
+///
+/// Motion is reported with [`Self::note_input`]. Once none has arrived for
+/// `threshold`, [`Self::take_idle`] fires once and the detector stays
+/// settled, asking for the full budget through [`Self::max_iterations`],
+/// until motion resumes. A zero threshold turns it off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputIdle {
+    pub threshold: Duration,
+    last_input: Option<Instant>,
+    settled: bool,
+}
+
+impl Default for InputIdle {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(150))
+    }
+}
+
+impl InputIdle {
+    #[must_use]
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            last_input: None,
+            settled: false,
+        }
+    }
+
+    /// Records motion at `now`, ending any settled period.
+    pub fn note_input(&mut self, now: Instant) {
+        if self.threshold.is_zero() {
+            return;
+        }
+
+        self.last_input = Some(now);
+        self.settled = false;
+    }
+
+    /// True exactly once per pause: the first call at least `threshold`
+    /// after the last input. Never fires before any input has been seen.
+    pub fn take_idle(&mut self, now: Instant) -> bool {
+        let Some(last_input) = self.last_input else {
+            return false;
+        };
+
+        if self.settled || now.saturating_duration_since(last_input) < self.threshold {
+            return false;
+        }
+
+        self.settled = true;
+        true
+    }
+
+    /// True from the idle firing until the next input.
+    #[must_use]
+    pub fn is_settled(&self) -> bool {
+        self.settled
+    }
+
+    /// True while input has stopped but the threshold has not yet passed,
+    /// so a frame is still owed.
+    #[must_use]
+    pub fn is_waiting(&self) -> bool {
+        self.last_input.is_some() && !self.settled
+    }
+
+    /// `high` once settled, otherwise the `live` budget used while moving
+    /// or ramping.
+    #[must_use]
+    pub fn max_iterations(&self, high: u32, live: u32) -> u32 {
+        if self.settled { high } else { live }
+    }
+
+    pub fn reset(&mut self) {
+        self.last_input = None;
+        self.settled = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::pixel_rect::PixelRect;
+    use crate::core::data::point::Point;
+    use crate::input::gui::app::state::GuiAppState;
+
+    #[test]
+    fn fires_once_after_the_threshold_and_rearms_on_input() {
+        let start = Instant::now();
+        let mut idle = InputIdle::default();
+
+        assert!(!idle.take_idle(start + Duration::from_secs(1)));
+        assert!(!idle.is_waiting());
+
+        idle.note_input(start);
+        assert!(idle.is_waiting());
+        assert!(!idle.take_idle(start + Duration::from_millis(149)));
+        assert!(idle.take_idle(start + Duration::from_millis(150)));
+        assert!(idle.is_settled());
+        assert!(!idle.is_waiting());
+        assert!(!idle.take_idle(start + Duration::from_millis(400)));
+
+        idle.note_input(start + Duration::from_millis(500));
+        assert!(!idle.is_settled());
+        assert!(!idle.take_idle(start + Duration::from_millis(600)));
+        assert!(idle.take_idle(start + Duration::from_millis(650)));
+    }
+
+    #[test]
+    fn zero_threshold_never_fires() {
+        let start = Instant::now();
+        let mut idle = InputIdle::new(Duration::ZERO);
+
+        idle.note_input(start);
+
+        assert!(!idle.is_waiting());
+        assert!(!idle.take_idle(start + Duration::from_secs(1)));
+        assert_eq!(idle.max_iterations(1000, 128), 128);
+    }
+
+    #[test]
+    fn settled_request_uses_the_full_iteration_budget() {
+        let start = Instant::now();
+        let mut idle = InputIdle::default();
+        let mut ui_state = GuiAppState::default();
+        ui_state.mandelbrot.max_iterations = 1000;
+        let high = ui_state.active_max_iterations();
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 79, y: 59 }).unwrap();
+
+        idle.note_input(start);
+        let live = ui_state.build_render_request_with_max_iterations(
+            pixel_rect,
+            idle.max_iterations(high, 128),
+        );
+        assert!(live != ui_state.build_render_request(pixel_rect));
+
+        assert!(idle.take_idle(start + idle.threshold));
+        let settled = ui_state.build_render_request_with_max_iterations(
+            pixel_rect,
+            idle.max_iterations(high, 128),
+        );
+        assert!(settled == ui_state.build_render_request(pixel_rect));
+    }
+}
@@ -0,0 +1,89 @@
+/// Highest `max_iterations` the GUI will request unless configured
+/// otherwise.
+///
+/// The panel slider stops at 1000, but a config file or an embedding app can
+/// set far more, and a full-screen frame at millions of iterations freezes
+/// the view for seconds.
+pub const DEFAULT_MAX_ITERATIONS_CEILING: u32 = 100_000;
+
+/// A `max_iterations` value after applying the ceiling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CeilingClamp {
+    pub max_iterations: u32,
+    /// The value asked for, when it was over the ceiling.
+    pub clamped_from: Option<u32>,
+}
+
+impl CeilingClamp {
+    /// Panel text explaining the clamp, or `None` if nothing was clamped.
+    #[must_use]
+    pub fn warning(&self) -> Option<String> {
+        self.clamped_from.map(|requested| {
+            format!(
+                "Max iterations {requested} clamped to {}",
+                self.max_iterations
+            )
+        })
+    }
+}
+
+/// Limits `max_iterations` to `ceiling`, noting when it had to. A
+/// `ceiling` of zero is treated as one.
+#[must_use]
+pub fn clamp_to_ceiling(max_iterations: u32, ceiling: u32) -> CeilingClamp {
+    let ceiling = ceiling.max(1);
+
+    if max_iterations > ceiling {
+        CeilingClamp {
+            max_iterations: ceiling,
+            clamped_from: Some(max_iterations),
+        }
+    } else {
+        CeilingClamp {
+            max_iterations,
+            clamped_from: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_within_the_ceiling_pass_through() {
+        let clamp = clamp_to_ceiling(1000, DEFAULT_MAX_ITERATIONS_CEILING);
+
+        assert_eq!(
+            clamp,
+            CeilingClamp {
+                max_iterations: 1000,
+                clamped_from: None,
+            }
+        );
+        assert_eq!(clamp.warning(), None);
+        assert_eq!(clamp_to_ceiling(5000, 5000).clamped_from, None);
+    }
+
+    #[test]
+    fn over_ceiling_value_is_clamped_and_flagged() {
+        let clamp = clamp_to_ceiling(5_000_000, 20_000);
+
+        assert_eq!(
+            clamp,
+            CeilingClamp {
+                max_iterations: 20_000,
+                clamped_from: Some(5_000_000),
+            }
+        );
+        assert_eq!(
+            clamp.warning().as_deref(),
+            Some("Max iterations 5000000 clamped to 20000")
+        );
+    }
+
+    #[test]
+    fn zero_ceiling_is_treated_as_one() {
+        assert_eq!(clamp_to_ceiling(10, 0).max_iterations, 1);
+    }
+}
@@ -1,6 +1,21 @@
+pub mod adaptive_iterations;
+pub mod centre_zoom;
+pub mod crosshair;
+pub mod detail_seek;
+pub mod drag_pan;
 pub mod events;
 pub mod frame_overlay;
 pub mod flight_input;
+pub mod fullscreen;
+pub mod generation_overlay;
 pub mod gui_app;
+pub mod input_idle;
+pub mod iteration_ceiling;
 pub mod ports;
+pub mod render_size;
+pub mod request_debounce;
+pub mod rubber_band;
+pub mod screenshot;
 pub mod state;
+pub mod window_router;
+pub mod zoom_animator;
@@ -1,10 +1,12 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use egui::Context as EguiContext;
 use winit::{event_loop::EventLoopProxy, window::Window};
 
 use crate::{
     controllers::interactive::ports::presenter::InteractiveControllerPresenterPort,
+    core::data::pixel_buffer::PixelBuffer,
     input::gui::app::{events::gui::GuiEvent, frame_overlay::FrameOverlay},
 };
 
@@ -18,5 +20,17 @@ pub trait GuiPresenterPort {
         frame_overlay: &FrameOverlay,
     ) -> Result<(), pixels::Error>;
     fn share_adapter(&self) -> Arc<dyn InteractiveControllerPresenterPort>;
-    fn resize(&mut self, width: u32, height: u32);
+    /// Resizes the window surface to `surface_size` and the render buffer
+    /// to `buffer_size`; a smaller buffer is upscaled to fill the surface.
+    fn resize(&mut self, surface_size: (u32, u32), buffer_size: (u32, u32));
+    /// Whether the frame on screen is a single colour (no detail in view).
+    fn last_frame_single_colour(&self) -> bool;
+    /// The last frame presented as the controller rendered it, without the
+    /// overlays or egui drawn over it; `None` before the first frame.
+    fn last_frame(&self) -> Option<&PixelBuffer>;
+    /// How long the last frame presented took from its request's
+    /// submission to being drawn; `None` before the first frame.
+    fn last_present_latency(&self) -> Option<Duration>;
+    /// The generation of the frame on screen; 0 before the first frame.
+    fn last_presented_generation(&self) -> u64;
 }
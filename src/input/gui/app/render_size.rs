@@ -0,0 +1,188 @@
+/// Largest render buffer side, in pixels, unless configured otherwise.
+///
+/// A full-screen render allocates an iteration field and an RGBA buffer per
+/// pixel, so an unbounded window would let a huge resize exhaust memory.
+pub const DEFAULT_MAX_BUFFER_DIMENSION: u32 = 4096;
+
+/// Render buffer size for a `width`x`height` window.
+///
+/// Sizes within `max_dimension` on both axes are returned unchanged. Larger
+/// ones are divided by the smallest whole factor that brings the longer
+/// side within `max_dimension`, so the presenter's integer upscale fills the
+/// window (up to a border narrower than that factor). A `max_dimension` of
+/// zero is treated as one.
+#[must_use]
+pub fn clamp_render_size(width: u32, height: u32, max_dimension: u32) -> (u32, u32) {
+    let max_dimension = max_dimension.max(1);
+    let longest = width.max(height);
+
+    if longest <= max_dimension {
+        return (width, height);
+    }
+
+    let factor = longest.div_ceil(max_dimension);
+
+    ((width / factor).max(1), (height / factor).max(1))
+}
+
+/// `width`x`height` with each side rounded down to an even number, for
+/// surfaces that misalign odd-width rows. The presenter centres the smaller
+/// buffer, leaving the odd pixel as a letterbox border. A side of one is
+/// kept, since rounding it down would leave nothing to draw.
+#[must_use]
+pub fn even_render_size(width: u32, height: u32) -> (u32, u32) {
+    let even = |side: u32| if side > 1 { side & !1 } else { side };
+
+    (even(width), even(height))
+}
+
+/// A window's pixel size together with its render buffer cap, so the
+/// render size always follows the latest resize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewportSize {
+    width: u32,
+    height: u32,
+    max_buffer_dimension: u32,
+    even_buffer: bool,
+}
+
+impl ViewportSize {
+    #[must_use]
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            max_buffer_dimension: DEFAULT_MAX_BUFFER_DIMENSION,
+            even_buffer: false,
+        }
+    }
+
+    pub fn resize(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+
+    pub fn set_max_buffer_dimension(&mut self, max_buffer_dimension: u32) {
+        self.max_buffer_dimension = max_buffer_dimension;
+    }
+
+    pub fn set_even_buffer(&mut self, even_buffer: bool) {
+        self.even_buffer = even_buffer;
+    }
+
+    #[must_use]
+    pub fn window_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// The window size clamped to the render buffer cap, then rounded down
+    /// to even sides if the even buffer is on.
+    #[must_use]
+    pub fn render_size(&self) -> (u32, u32) {
+        let (width, height) = clamp_render_size(self.width, self.height, self.max_buffer_dimension);
+
+        if self.even_buffer {
+            even_render_size(width, height)
+        } else {
+            (width, height)
+        }
+    }
+
+    /// `false` while either side is zero (e.g. minimised), when there is
+    /// nothing to present into.
+    #[must_use]
+    pub fn is_presentable(&self) -> bool {
+        self.width > 0 && self.height > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes_within_the_cap_are_unchanged() {
+        assert_eq!(clamp_render_size(800, 600, 4096), (800, 600));
+        assert_eq!(clamp_render_size(4096, 4096, 4096), (4096, 4096));
+        assert_eq!(clamp_render_size(0, 0, 4096), (0, 0));
+    }
+
+    #[test]
+    fn oversized_window_is_divided_by_a_whole_factor() {
+        assert_eq!(clamp_render_size(8192, 4096, 4096), (4096, 2048));
+        assert_eq!(clamp_render_size(3000, 12000, 4096), (1000, 4000));
+        assert_eq!(clamp_render_size(5000, 3001, 4096), (2500, 1500));
+    }
+
+    #[test]
+    fn extreme_aspect_keeps_at_least_one_pixel() {
+        assert_eq!(clamp_render_size(100_000, 1, 4096), (4000, 1));
+    }
+
+    #[test]
+    fn zero_cap_is_treated_as_one() {
+        assert_eq!(clamp_render_size(10, 5, 0), (1, 1));
+    }
+
+    #[test]
+    fn even_render_size_rounds_odd_sides_down() {
+        assert_eq!(even_render_size(801, 601), (800, 600));
+        assert_eq!(even_render_size(800, 601), (800, 600));
+        assert_eq!(even_render_size(3, 2), (2, 2));
+    }
+
+    #[test]
+    fn even_render_size_keeps_even_and_unit_sides() {
+        assert_eq!(even_render_size(800, 600), (800, 600));
+        assert_eq!(even_render_size(1, 1), (1, 1));
+        assert_eq!(even_render_size(0, 0), (0, 0));
+    }
+
+    #[test]
+    fn even_buffer_applies_after_the_cap() {
+        let mut size = ViewportSize::new(801, 601);
+        assert_eq!(size.render_size(), (801, 601));
+
+        size.set_even_buffer(true);
+        assert_eq!(size.window_size(), (801, 601));
+        assert_eq!(size.render_size(), (800, 600));
+
+        size.set_max_buffer_dimension(1000);
+        size.resize(3000, 1503);
+        assert_eq!(size.render_size(), (1000, 500));
+    }
+
+    #[test]
+    fn resize_updates_window_and_render_size() {
+        let mut size = ViewportSize::new(800, 600);
+
+        size.resize(1024, 768);
+
+        assert_eq!(size.window_size(), (1024, 768));
+        assert_eq!(size.render_size(), (1024, 768));
+        assert!(size.is_presentable());
+    }
+
+    #[test]
+    fn render_size_follows_the_cap_across_resizes() {
+        let mut size = ViewportSize::new(800, 600);
+        size.set_max_buffer_dimension(1000);
+
+        size.resize(3000, 1500);
+        assert_eq!(size.window_size(), (3000, 1500));
+        assert_eq!(size.render_size(), (1000, 500));
+
+        size.resize(640, 480);
+        assert_eq!(size.render_size(), (640, 480));
+    }
+
+    #[test]
+    fn minimised_window_is_recorded_but_not_presentable() {
+        let mut size = ViewportSize::new(800, 600);
+
+        size.resize(0, 600);
+
+        assert_eq!(size.window_size(), (0, 600));
+        assert!(!size.is_presentable());
+    }
+}
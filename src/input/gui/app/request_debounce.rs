@@ -0,0 +1,161 @@
+use std::time::{Duration, Instant};
+
+/// What to do with the request the view currently wants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebounceDecision<T> {
+    Submit(T),
+    /// Hold off: the request has not been stable for long enough. Offer it
+    /// again after at most this long.
+    Wait(Duration),
+}
+
+/// Holds back requests until they stop changing, so dragging a slider does
+/// not queue a render per frame.
+///
+/// A request is submitted once the same one (by content) has been offered
+/// for `interval`; a different one restarts the wait. Offers marked
+/// immediate, such as flight frames, skip the wait, as does a zero
+/// interval. Generic over the request so the timing can be tested with
+/// plain values; [`GuiApp`] uses it with render requests.
+///
+/// [`GuiApp`]: crate::input::gui::app::gui_app::GuiApp
+#[derive(Debug, Clone)]
+pub struct RequestDebounce<T> {
+    pub interval: Duration,
+    candidate: Option<(T, Instant)>,
+}
+
+impl<T> Default for RequestDebounce<T> {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(100))
+    }
+}
+
+impl<T> RequestDebounce<T> {
+    #[must_use]
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            candidate: None,
+        }
+    }
+
+    /// True while a request is being held back.
+    #[must_use]
+    pub fn is_waiting(&self) -> bool {
+        self.candidate.is_some()
+    }
+
+    /// Forgets the held request, e.g. once the view no longer wants it.
+    pub fn cancel(&mut self) {
+        self.candidate = None;
+    }
+}
+
+impl<T: PartialEq> RequestDebounce<T> {
+    /// Offers the request the view wants at `now`.
+    pub fn offer(&mut self, now: Instant, request: T, immediate: bool) -> DebounceDecision<T> {
+        if immediate || self.interval.is_zero() {
+            self.candidate = None;
+            return DebounceDecision::Submit(request);
+        }
+
+        let since = match &self.candidate {
+            Some((candidate, since)) if *candidate == request => *since,
+            _ => now,
+        };
+
+        let stable_for = now.saturating_duration_since(since);
+        if stable_for >= self.interval {
+            self.candidate = None;
+            return DebounceDecision::Submit(request);
+        }
+
+        self.candidate = Some((request, since));
+        DebounceDecision::Wait(self.interval - stable_for)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTERVAL: Duration = Duration::from_millis(100);
+
+    /// Offers `(milliseconds, request)` pairs in order, none immediate,
+    /// returning the requests submitted and when. Like `GuiApp`, skips
+    /// offers equal to the last submitted request and cancels the wait
+    /// for them.
+    fn run(stream: &[(u64, u32)]) -> Vec<(u64, u32)> {
+        let start = Instant::now();
+        let mut debounce = RequestDebounce::new(INTERVAL);
+        let mut submitted: Vec<(u64, u32)> = Vec::new();
+
+        for &(ms, request) in stream {
+            if submitted.last().is_some_and(|&(_, last)| last == request) {
+                debounce.cancel();
+                continue;
+            }
+
+            if let DebounceDecision::Submit(request) =
+                debounce.offer(start + Duration::from_millis(ms), request, false)
+            {
+                submitted.push((ms, request));
+            }
+        }
+
+        submitted
+    }
+
+    #[test]
+    fn a_slider_drag_submits_once_it_settles() {
+        // A value per 16 ms frame while dragging, then held at 5.
+        let mut stream: Vec<(u64, u32)> = (0..5).map(|i| (i * 16, i as u32 + 1)).collect();
+        stream.extend((5..20).map(|i| (i * 16, 5)));
+
+        // 5 is first offered at 64 ms, so it is stable from 164 ms: the
+        // first frame at or after that is 176 ms.
+        assert_eq!(run(&stream), [(176, 5)]);
+    }
+
+    #[test]
+    fn each_change_restarts_the_wait() {
+        let stream = [(0, 1), (90, 2), (180, 2), (189, 2), (190, 2), (250, 2)];
+
+        assert_eq!(run(&stream), [(190, 2)]);
+    }
+
+    #[test]
+    fn returning_to_the_submitted_value_drops_the_held_request() {
+        // 3 is held back, then the edit goes back to the submitted 2.
+        let stream = [(0, 2), (100, 2), (150, 3), (200, 2), (300, 2)];
+
+        assert_eq!(run(&stream), [(100, 2)]);
+    }
+
+    #[test]
+    fn wait_reports_the_time_left() {
+        let start = Instant::now();
+        let mut debounce = RequestDebounce::new(INTERVAL);
+
+        assert_eq!(debounce.offer(start, 'a', false), DebounceDecision::Wait(INTERVAL));
+        assert_eq!(
+            debounce.offer(start + Duration::from_millis(30), 'a', false),
+            DebounceDecision::Wait(Duration::from_millis(70))
+        );
+        assert!(debounce.is_waiting());
+    }
+
+    #[test]
+    fn immediate_offers_and_a_zero_interval_skip_the_wait() {
+        let start = Instant::now();
+        let mut debounce = RequestDebounce::new(INTERVAL);
+
+        assert_eq!(debounce.offer(start, 1, false), DebounceDecision::Wait(INTERVAL));
+        assert_eq!(debounce.offer(start, 2, true), DebounceDecision::Submit(2));
+        assert!(!debounce.is_waiting());
+
+        let mut off = RequestDebounce::new(Duration::ZERO);
+        assert_eq!(off.offer(start, 3, false), DebounceDecision::Submit(3));
+    }
+}
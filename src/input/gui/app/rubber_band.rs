@@ -0,0 +1,212 @@
+use crate::core::data::deep_region::DeepRegion;
+use crate::core::data::pixel_rect::PixelRect;
+use crate::core::data::point::Point;
+
+/// Selections narrower or shorter than this many pixels are treated as a
+/// stray click rather than a zoom.
+pub const MIN_SELECTION_PIXELS: i32 = 4;
+
+/// A right-button drag selecting a rectangle to zoom into. Positions are
+/// window pixels, as winit reports the cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RubberBand {
+    start: Option<(f64, f64)>,
+    end: (f64, f64),
+}
+
+impl RubberBand {
+    pub fn begin(&mut self, position: (f64, f64)) {
+        self.start = Some(position);
+        self.end = position;
+    }
+
+    /// Moves the dragged corner; ignored when no drag is in progress.
+    pub fn drag_to(&mut self, position: (f64, f64)) {
+        if self.start.is_some() {
+            self.end = position;
+        }
+    }
+
+    #[must_use]
+    pub fn is_dragging(&self) -> bool {
+        self.start.is_some()
+    }
+
+    /// The dragged corners while a drag is in progress, for drawing.
+    #[must_use]
+    pub fn corners(&self) -> Option<((f64, f64), (f64, f64))> {
+        self.start.map(|start| (start, self.end))
+    }
+
+    /// Ends the drag, returning the selected pixels of a `window`-sized
+    /// window; see [`selection_pixel_rect`].
+    pub fn finish(&mut self, window: (u32, u32)) -> Option<PixelRect> {
+        let (start, end) = self.corners()?;
+        self.start = None;
+
+        selection_pixel_rect(start, end, window)
+    }
+
+    pub fn cancel(&mut self) {
+        self.start = None;
+    }
+}
+
+/// The pixels between two drag corners, in either order, clipped to the
+/// window. `None` when the clipped selection is under
+/// [`MIN_SELECTION_PIXELS`] on either side.
+#[must_use]
+pub fn selection_pixel_rect(start: (f64, f64), end: (f64, f64), window: (u32, u32)) -> Option<PixelRect> {
+    let (width, height) = window;
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let to_pixel = |position: f64, pixels: u32| position.floor().clamp(0.0, f64::from(pixels - 1)) as i32;
+    let (x0, x1) = (to_pixel(start.0, width), to_pixel(end.0, width));
+    let (y0, y1) = (to_pixel(start.1, height), to_pixel(end.1, height));
+
+    if (x1 - x0).abs() + 1 < MIN_SELECTION_PIXELS || (y1 - y0).abs() + 1 < MIN_SELECTION_PIXELS {
+        return None;
+    }
+
+    PixelRect::new(
+        Point {
+            x: x0.min(x1),
+            y: y0.min(y1),
+        },
+        Point {
+            x: x0.max(x1),
+            y: y0.max(y1),
+        },
+    )
+    .ok()
+}
+
+/// The view that puts `selection`, a pixel rect of a `window`-sized view
+/// of `current`, edge to edge in the window.
+///
+/// As in rendering, the first and last pixel of each row and column sit on
+/// the region's edges, so the selection's outer pixels become the new
+/// edges. A selection whose shape does not match the view is widened along
+/// its short axis, about its centre, so all of it stays visible without
+/// stretching. `None` for a window too small to select in, or a region
+/// the result cannot represent.
+#[must_use]
+pub fn region_from_selection(current: &DeepRegion, window: (u32, u32), selection: PixelRect) -> Option<DeepRegion> {
+    let (width, height) = window;
+    if width < 2 || height < 2 {
+        return None;
+    }
+
+    let last_column = f64::from(width - 1);
+    let last_row = f64::from(height - 1);
+    let top_left = selection.top_left();
+    let bottom_right = selection.bottom_right();
+
+    // Selection centre, as fractions of the view from its centre; rows grow
+    // downward, as in `ZoomAnimator::zoom_toward`.
+    let centre_x = (f64::from(top_left.x) + f64::from(bottom_right.x)) / 2.0 / last_column - 0.5;
+    let centre_y = (f64::from(top_left.y) + f64::from(bottom_right.y)) / 2.0 / last_row - 0.5;
+
+    let mut new_width = f64::from(bottom_right.x - top_left.x) / last_column * current.width();
+    let mut new_height = f64::from(bottom_right.y - top_left.y) / last_row * current.height();
+
+    let aspect = current.width() / current.height();
+    if new_width / new_height < aspect {
+        new_width = new_height * aspect;
+    } else {
+        new_height = new_width / aspect;
+    }
+
+    current
+        .panned_by(centre_x * current.width(), centre_y * current.height())?
+        .with_extent(new_width, new_height)
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::deep_complex::DeepComplex;
+
+    const WINDOW: (u32, u32) = (801, 401);
+
+    fn view() -> DeepRegion {
+        DeepRegion::new(DeepComplex::from_f64(-0.5, 0.0).unwrap(), 4.0, 2.0).unwrap()
+    }
+
+    fn rect(x0: i32, y0: i32, x1: i32, y1: i32) -> PixelRect {
+        PixelRect::new(Point { x: x0, y: y0 }, Point { x: x1, y: y1 }).unwrap()
+    }
+
+    fn assert_region(region: &DeepRegion, centre: (f64, f64), extent: (f64, f64)) {
+        let (dre, dim) = region.centre().sub_to_f64(&DeepComplex::from_f64(centre.0, centre.1).unwrap());
+        assert!(dre.abs() < 1e-12 && dim.abs() < 1e-12, "centre off by {dre}, {dim}");
+        assert!((region.width() - extent.0).abs() < 1e-12, "width {}", region.width());
+        assert!((region.height() - extent.1).abs() < 1e-12, "height {}", region.height());
+    }
+
+    #[test]
+    fn matching_selection_maps_its_edge_pixels_to_the_new_edges() {
+        // Columns are 4/800 = 0.005 apart and rows 2/400 = 0.005 apart. The
+        // selection spans 200x100 pixel steps, the view's 2:1 shape.
+        let region = region_from_selection(&view(), WINDOW, rect(400, 200, 600, 300)).unwrap();
+
+        assert_region(&region, (-0.5 + 0.5, 0.25), (1.0, 0.5));
+    }
+
+    #[test]
+    fn whole_window_selection_keeps_the_view() {
+        let region = region_from_selection(&view(), WINDOW, rect(0, 0, 800, 400)).unwrap();
+
+        assert_region(&region, (-0.5, 0.0), (4.0, 2.0));
+    }
+
+    #[test]
+    fn mismatched_selection_widens_its_short_axis_about_its_centre() {
+        // Tall: 40 steps wide, 200 high -> 0.2 x 1.0, widened to 2.0 x 1.0.
+        let tall = region_from_selection(&view(), WINDOW, rect(0, 0, 40, 200)).unwrap();
+        assert_region(&tall, (-2.5 + 0.1, -1.0 + 0.5), (2.0, 1.0));
+
+        // Wide: 400 steps wide, 20 high -> 2.0 x 0.1, heightened to 2.0 x 1.0.
+        let wide = region_from_selection(&view(), WINDOW, rect(400, 380, 800, 400)).unwrap();
+        assert_region(&wide, (-0.5 + 1.0, 1.0 - 0.05), (2.0, 1.0));
+    }
+
+    #[test]
+    fn drag_corners_in_any_order_select_the_same_clipped_pixels() {
+        let expected = Some(rect(10, 20, 110, 70));
+
+        assert_eq!(selection_pixel_rect((10.2, 20.9), (110.5, 70.0), WINDOW), expected);
+        assert_eq!(selection_pixel_rect((110.5, 20.9), (10.2, 70.0), WINDOW), expected);
+        assert_eq!(selection_pixel_rect((110.5, 70.0), (10.2, 20.9), WINDOW), expected);
+
+        assert_eq!(
+            selection_pixel_rect((-50.0, 300.0), (900.0, 500.0), WINDOW),
+            Some(rect(0, 300, 800, 400))
+        );
+    }
+
+    #[test]
+    fn tiny_drags_are_not_selections() {
+        assert_eq!(selection_pixel_rect((10.0, 10.0), (12.0, 80.0), WINDOW), None);
+        assert_eq!(selection_pixel_rect((10.0, 10.0), (80.0, 10.5), WINDOW), None);
+        assert_eq!(selection_pixel_rect((10.0, 10.0), (80.0, 80.0), (0, 0)), None);
+    }
+
+    #[test]
+    fn finish_ends_the_drag() {
+        let mut band = RubberBand::default();
+        band.drag_to((50.0, 50.0));
+        assert!(!band.is_dragging());
+
+        band.begin((10.0, 10.0));
+        band.drag_to((60.0, 40.0));
+        assert_eq!(band.corners(), Some(((10.0, 10.0), (60.0, 40.0))));
+
+        assert_eq!(band.finish(WINDOW), Some(rect(10, 10, 60, 40)));
+        assert!(!band.is_dragging());
+        assert_eq!(band.finish(WINDOW), None);
+    }
+}
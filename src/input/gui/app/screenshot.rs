@@ -0,0 +1,98 @@
+use crate::controllers::ports::file_presenter::FilePresenterPort;
+use crate::core::data::pixel_buffer::PixelBuffer;
+use crate::presenters::file::png::PngFilePresenter;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Where F12 saves screenshots, alongside the CLI's output.
+pub const SCREENSHOT_DIR: &str = "output";
+
+/// How long the panel confirms a saved screenshot.
+const NOTICE_DURATION: Duration = Duration::from_secs(3);
+
+/// `dir/screenshot_<unix milliseconds>.png`, so successive captures sort
+/// in the order they were taken.
+#[must_use]
+pub fn screenshot_path(dir: &Path, now: SystemTime) -> PathBuf {
+    let millis = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+    dir.join(format!("screenshot_{millis}.png"))
+}
+
+/// Writes `buffer` as a PNG to a timestamped file in `dir`, creating the
+/// directory if needed, and returns the file's path.
+pub fn save_screenshot(buffer: &PixelBuffer, dir: &Path, now: SystemTime) -> std::io::Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let path = screenshot_path(dir, now);
+    PngFilePresenter::new().present(buffer, &path)?;
+    Ok(path)
+}
+
+/// The panel's confirmation of the last screenshot saved, shown for a few
+/// seconds.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ScreenshotNotice {
+    saved: Option<(PathBuf, Instant)>,
+}
+
+impl ScreenshotNotice {
+    pub fn record(&mut self, path: PathBuf, now: Instant) {
+        self.saved = Some((path, now));
+    }
+
+    /// The saved file's path while the notice is still showing.
+    #[must_use]
+    pub fn visible(&self, now: Instant) -> Option<&Path> {
+        self.saved
+            .as_ref()
+            .filter(|(_, saved_at)| now.saturating_duration_since(*saved_at) < NOTICE_DURATION)
+            .map(|(path, _)| path.as_path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::pixel_rect::PixelRect;
+    use crate::core::data::point::Point;
+    use std::fs;
+
+    #[test]
+    fn paths_are_named_by_unix_milliseconds() {
+        let now = UNIX_EPOCH + Duration::from_millis(1_760_000_000_123);
+
+        assert_eq!(
+            screenshot_path(Path::new("output"), now),
+            Path::new("output/screenshot_1760000000123.png")
+        );
+    }
+
+    #[test]
+    fn save_creates_the_directory_and_writes_a_png() {
+        let dir = std::env::temp_dir().join(format!(
+            "fractal_explorer_screenshot_{}_{}",
+            std::process::id(),
+            SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos()
+        ));
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 2, y: 1 }).unwrap();
+        let buffer = PixelBuffer::new(pixel_rect);
+
+        let path = save_screenshot(&buffer, &dir, SystemTime::now()).unwrap();
+        let output = fs::read(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(path.starts_with(&dir));
+        assert_eq!(output[..8], [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']);
+    }
+
+    #[test]
+    fn the_notice_shows_only_for_a_few_seconds() {
+        let saved_at = Instant::now();
+        let mut notice = ScreenshotNotice::default();
+        assert_eq!(notice.visible(saved_at), None);
+
+        notice.record(PathBuf::from("output/screenshot_1.png"), saved_at);
+
+        assert_eq!(notice.visible(saved_at), Some(Path::new("output/screenshot_1.png")));
+        assert_eq!(notice.visible(saved_at + NOTICE_DURATION), None);
+    }
+}
@@ -1,14 +1,38 @@
+use crate::controllers::config::explore_config::ExploreConfig;
 use crate::controllers::interactive::data::fractal_config::FractalConfig;
+use crate::controllers::interactive::flight::RequestPriority;
+use crate::core::data::complex_rect::ComplexRect;
+use crate::core::data::deep_region::DeepRegion;
 use crate::core::data::pixel_rect::PixelRect;
+use crate::core::data::point::Point;
+use crate::core::fractals::burning_ship::burning_ship_config::{
+    BurningShipConfig, default_region as burning_ship_default_region,
+};
 use crate::core::fractals::fractal_kinds::FractalKinds;
-use crate::core::fractals::julia::julia_config::JuliaConfig;
-use crate::core::fractals::mandelbrot::mandelbrot_config::MandelbrotConfig;
+use crate::core::fractals::julia::julia_config::{
+    JuliaConfig, default_region as julia_default_region,
+};
+use crate::core::fractals::julia::split_view::SplitView;
+use crate::core::fractals::mandelbrot::mandelbrot_config::{
+    MandelbrotConfig, default_region as mandelbrot_default_region,
+};
+use crate::core::fractals::tricorn::tricorn_config::{
+    TricornConfig, default_region as tricorn_default_region,
+};
 use std::sync::Arc;
 
 pub struct GuiAppState {
     pub selected_fractal: FractalKinds,
     pub mandelbrot: MandelbrotConfig,
     pub julia: JuliaConfig,
+    pub burning_ship: BurningShipConfig,
+    pub tricorn: TricornConfig,
+    /// Debug builds only: render Mandelbrot frames as a diff against the
+    /// brute-force reference instead of colour mapping them.
+    pub compare_against_reference: bool,
+    /// Draws Julia frames beside the Mandelbrot set, where clicking picks
+    /// the Julia constant; see [`SplitView`].
+    pub julia_split_view: bool,
     last_submitted_request: Option<Arc<FractalConfig>>,
     pub latest_submitted_generation: u64,
     pub redraw_pending: bool,
@@ -20,6 +44,10 @@ impl Default for GuiAppState {
             selected_fractal: FractalKinds::default(),
             mandelbrot: MandelbrotConfig::default(),
             julia: JuliaConfig::default(),
+            burning_ship: BurningShipConfig::default(),
+            tricorn: TricornConfig::default(),
+            compare_against_reference: false,
+            julia_split_view: false,
             last_submitted_request: None,
             latest_submitted_generation: 0,
             redraw_pending: true,
@@ -28,11 +56,132 @@ impl Default for GuiAppState {
 }
 
 impl GuiAppState {
+    /// Starts from the fractal, view and settings read from a config file.
+    #[must_use]
+    pub fn from_explore_config(config: &ExploreConfig) -> Self {
+        Self {
+            selected_fractal: config.fractal,
+            mandelbrot: config.mandelbrot.clone(),
+            julia: config.julia,
+            burning_ship: config.burning_ship,
+            tricorn: config.tricorn,
+            ..Self::default()
+        }
+    }
+
     #[must_use]
     pub fn build_render_request(&self, pixel_rect: PixelRect) -> FractalConfig {
         match self.selected_fractal {
-            FractalKinds::Mandelbrot => self.mandelbrot.build_render_request(pixel_rect),
-            FractalKinds::Julia => self.julia.build_render_request(pixel_rect),
+            FractalKinds::Mandelbrot => Self::build_mandelbrot_request(
+                &self.mandelbrot,
+                pixel_rect,
+                self.compare_against_reference,
+            ),
+            FractalKinds::Julia => self.build_julia_request(&self.julia, pixel_rect),
+            FractalKinds::BurningShip => self.burning_ship.build_render_request(pixel_rect),
+            FractalKinds::Tricorn => self.tricorn.build_render_request(pixel_rect),
+        }
+    }
+
+    /// Like `build_render_request`, but with the active fractal's
+    /// `max_iterations` replaced (e.g. by the adaptive flight budget).
+    #[must_use]
+    pub fn build_render_request_with_max_iterations(
+        &self,
+        pixel_rect: PixelRect,
+        max_iterations: u32,
+    ) -> FractalConfig {
+        match self.selected_fractal {
+            FractalKinds::Mandelbrot => Self::build_mandelbrot_request(
+                &MandelbrotConfig {
+                    max_iterations,
+                    ..self.mandelbrot.clone()
+                },
+                pixel_rect,
+                self.compare_against_reference,
+            ),
+            FractalKinds::Julia => self.build_julia_request(
+                &JuliaConfig {
+                    max_iterations,
+                    ..self.julia
+                },
+                pixel_rect,
+            ),
+            FractalKinds::BurningShip => BurningShipConfig {
+                max_iterations,
+                ..self.burning_ship
+            }
+            .build_render_request(pixel_rect),
+            FractalKinds::Tricorn => TricornConfig {
+                max_iterations,
+                ..self.tricorn
+            }
+            .build_render_request(pixel_rect),
+        }
+    }
+
+    /// Reference comparison only applies where the direct algorithm runs;
+    /// perturbation depths render normally.
+    fn build_mandelbrot_request(
+        config: &MandelbrotConfig,
+        pixel_rect: PixelRect,
+        compare_against_reference: bool,
+    ) -> FractalConfig {
+        compare_against_reference
+            .then(|| config.build_reference_diff_request(pixel_rect))
+            .flatten()
+            .unwrap_or_else(|| config.build_render_request(pixel_rect))
+    }
+
+    fn build_julia_request(&self, config: &JuliaConfig, pixel_rect: PixelRect) -> FractalConfig {
+        if self.julia_split_view {
+            config.build_split_view_request(pixel_rect, split_view_mandelbrot_region())
+        } else {
+            config.build_render_request(pixel_rect)
+        }
+    }
+
+    /// The split `viewport` while the split view is showing, else `None`.
+    #[must_use]
+    pub fn split_view(&self, viewport: PixelRect) -> Option<SplitView> {
+        if self.selected_fractal == FractalKinds::Julia && self.julia_split_view {
+            SplitView::new(viewport).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Sets the Julia constant to the Mandelbrot point under `point`, a
+    /// pixel of `viewport`. Returns `false`, changing nothing, unless the
+    /// split view is showing and `point` is in its Mandelbrot pane.
+    pub fn pick_julia_c(&mut self, point: Point, viewport: PixelRect) -> bool {
+        let Some(c) = self
+            .split_view(viewport)
+            .and_then(|split| split.julia_c_at(point, split_view_mandelbrot_region()))
+        else {
+            return false;
+        };
+
+        self.julia.c = c;
+        true
+    }
+
+    /// Where to mark the current Julia constant in the split view's
+    /// Mandelbrot pane, as a pixel of `viewport`.
+    #[must_use]
+    pub fn julia_c_marker(&self, viewport: PixelRect) -> Option<Point> {
+        self.split_view(viewport)
+            .and_then(|split| split.marker_pixel(self.julia.c, split_view_mandelbrot_region()))
+    }
+
+    /// The active fractal's user-selected iteration limit.
+    #[must_use]
+    pub fn active_max_iterations(&self) -> u32 {
+        match self.selected_fractal {
+            FractalKinds::Mandelbrot => self.mandelbrot.max_iterations,
+            FractalKinds::Julia => self.julia.max_iterations,
+            FractalKinds::BurningShip => self.burning_ship.max_iterations,
+            FractalKinds::Tricorn => self.tricorn.max_iterations,
         }
     }
 
@@ -44,6 +193,79 @@ impl GuiAppState {
                 (self.mandelbrot.region.width(), self.mandelbrot.region.height())
             }
             FractalKinds::Julia => (self.julia.region.width(), self.julia.region.height()),
+            FractalKinds::BurningShip => {
+                (self.burning_ship.region.width(), self.burning_ship.region.height())
+            }
+            FractalKinds::Tricorn => (self.tricorn.region.width(), self.tricorn.region.height()),
+        }
+    }
+
+    /// Width and height of the active fractal's reset view, which a zoom
+    /// factor of one reproduces.
+    #[must_use]
+    pub fn active_default_extent(&self) -> (f64, f64) {
+        match self.selected_fractal {
+            FractalKinds::Mandelbrot => {
+                let region = mandelbrot_default_region();
+                (region.width(), region.height())
+            }
+            FractalKinds::Julia => {
+                let region = julia_default_region();
+                (region.width(), region.height())
+            }
+            FractalKinds::BurningShip => {
+                let region = burning_ship_default_region();
+                (region.width(), region.height())
+            }
+            FractalKinds::Tricorn => {
+                let region = tricorn_default_region();
+                (region.width(), region.height())
+            }
+        }
+    }
+
+    /// The active fractal's view as a deep region, so GUI-side view
+    /// animation can treat both fractals alike.
+    #[must_use]
+    pub fn active_region(&self) -> DeepRegion {
+        match self.selected_fractal {
+            FractalKinds::Mandelbrot => self.mandelbrot.region.clone(),
+            FractalKinds::Julia => DeepRegion::from_complex_rect(&self.julia.region),
+            FractalKinds::BurningShip => DeepRegion::from_complex_rect(&self.burning_ship.region),
+            FractalKinds::Tricorn => DeepRegion::from_complex_rect(&self.tricorn.region),
+        }
+    }
+
+    /// Replaces the active fractal's view. Julia, Burning Ship and Tricorn
+    /// views are f64 rects, so a region too small to represent there is
+    /// ignored and `false` returned.
+    pub fn set_active_region(&mut self, region: DeepRegion) -> bool {
+        match self.selected_fractal {
+            FractalKinds::Mandelbrot => {
+                self.mandelbrot.region = region;
+                true
+            }
+            FractalKinds::Julia => match region.to_complex_rect() {
+                Some(rect) => {
+                    self.julia.region = rect;
+                    true
+                }
+                None => false,
+            },
+            FractalKinds::BurningShip => match region.to_complex_rect() {
+                Some(rect) => {
+                    self.burning_ship.region = rect;
+                    true
+                }
+                None => false,
+            },
+            FractalKinds::Tricorn => match region.to_complex_rect() {
+                Some(rect) => {
+                    self.tricorn.region = rect;
+                    true
+                }
+                None => false,
+            },
         }
     }
 
@@ -54,6 +276,27 @@ impl GuiAppState {
             .is_none_or(|last| last.as_ref() != request)
     }
 
+    /// A request for a different viewport size preempts the in-flight
+    /// render; anything else waits its turn.
+    #[must_use]
+    pub fn submission_priority(&self, request: &FractalConfig) -> RequestPriority {
+        let resized = self.last_submitted_request.as_ref().is_some_and(|last| {
+            last.algorithm().pixel_rect() != request.algorithm().pixel_rect()
+        });
+
+        if resized {
+            RequestPriority::Preempt
+        } else {
+            RequestPriority::Normal
+        }
+    }
+
+    /// Makes the next request count as changed even if it equals the last
+    /// one, e.g. after switching renderer.
+    pub fn forget_last_submission(&mut self) {
+        self.last_submitted_request = None;
+    }
+
     pub fn record_submission(&mut self, request: Arc<FractalConfig>, generation: u64) {
         self.last_submitted_request = Some(request);
         self.latest_submitted_generation = generation;
@@ -63,15 +306,26 @@ impl GuiAppState {
         match self.selected_fractal {
             FractalKinds::Mandelbrot => self.mandelbrot.reset_view(),
             FractalKinds::Julia => self.julia.reset_view(),
+            FractalKinds::BurningShip => self.burning_ship.reset_view(),
+            FractalKinds::Tricorn => self.tricorn.reset_view(),
         }
     }
 }
 
+/// The split view's Mandelbrot pane always shows the whole set, so every
+/// interesting `c` is there to click.
+fn split_view_mandelbrot_region() -> ComplexRect {
+    mandelbrot_default_region()
+        .to_complex_rect()
+        .expect("default fractal region is representable in f64")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::{
-        data::point::Point,
+        actions::generate_pixel_buffer::histogram::ColourSchemeKind,
+        data::deep_complex::DeepComplex,
         fractals::mandelbrot::colour_mapping::kinds::MandelbrotColourMapKinds,
     };
 
@@ -119,6 +373,51 @@ mod tests {
         assert!(ui_state.should_submit(&changed_request));
     }
 
+    #[test]
+    fn changing_power_triggers_should_submit() {
+        let mut ui_state = GuiAppState::default();
+        let pixel_rect = create_pixel_rect(100, 100);
+
+        let request1 = ui_state.build_render_request(pixel_rect);
+        ui_state.record_submission(Arc::new(request1), 1);
+
+        ui_state.mandelbrot.power = 3;
+        let changed_request = ui_state.build_render_request(pixel_rect);
+
+        assert!(ui_state.should_submit(&changed_request));
+    }
+
+    #[test]
+    fn toggling_smooth_shading_triggers_should_submit() {
+        let mut ui_state = GuiAppState {
+            selected_fractal: FractalKinds::Julia,
+            ..Default::default()
+        };
+        let pixel_rect = create_pixel_rect(100, 100);
+
+        let request1 = ui_state.build_render_request(pixel_rect);
+        ui_state.record_submission(Arc::new(request1), 1);
+
+        ui_state.julia.smooth = true;
+        let changed_request = ui_state.build_render_request(pixel_rect);
+
+        assert!(ui_state.should_submit(&changed_request));
+    }
+
+    #[test]
+    fn changing_colour_scheme_triggers_should_submit() {
+        let mut ui_state = GuiAppState::default();
+        let pixel_rect = create_pixel_rect(100, 100);
+
+        let request1 = ui_state.build_render_request(pixel_rect);
+        ui_state.record_submission(Arc::new(request1), 1);
+
+        ui_state.mandelbrot.colour_scheme = ColourSchemeKind::Histogram;
+        let changed_request = ui_state.build_render_request(pixel_rect);
+
+        assert!(ui_state.should_submit(&changed_request));
+    }
+
     #[test]
     fn switching_selected_fractal_triggers_should_submit() {
         let mut ui_state = GuiAppState::default();
@@ -133,6 +432,26 @@ mod tests {
         assert!(ui_state.should_submit(&changed_request));
     }
 
+    #[test]
+    fn changing_julia_constant_triggers_should_submit_and_reset_restores_it() {
+        let mut ui_state = GuiAppState {
+            selected_fractal: FractalKinds::Julia,
+            ..GuiAppState::default()
+        };
+        let pixel_rect = create_pixel_rect(100, 100);
+        let default_c = ui_state.julia.c;
+
+        let request1 = ui_state.build_render_request(pixel_rect);
+        ui_state.record_submission(Arc::new(request1), 1);
+
+        ui_state.julia.c.imag += 0.1;
+        let changed_request = ui_state.build_render_request(pixel_rect);
+        assert!(ui_state.should_submit(&changed_request));
+
+        ui_state.reset_view();
+        assert_eq!(ui_state.julia.c, default_c);
+    }
+
     #[test]
     fn build_render_request_uses_selected_fractal_variant() {
         let mut ui_state = GuiAppState::default();
@@ -149,6 +468,37 @@ mod tests {
             ui_state.build_render_request(pixel_rect),
             FractalConfig::Julia { .. }
         ));
+
+        ui_state.selected_fractal = FractalKinds::BurningShip;
+
+        assert!(matches!(
+            ui_state.build_render_request(pixel_rect),
+            FractalConfig::BurningShip { .. }
+        ));
+
+        ui_state.selected_fractal = FractalKinds::Tricorn;
+
+        assert!(matches!(
+            ui_state.build_render_request(pixel_rect),
+            FractalConfig::Tricorn { .. }
+        ));
+    }
+
+    #[cfg(feature = "config")]
+    #[test]
+    fn explore_config_sets_the_initial_state() {
+        let config = ExploreConfig::from_json(
+            r#"{ "fractal": "Julia", "max_iterations": 77, "extent": [1.0, 1.0] }"#,
+        )
+        .unwrap();
+
+        let ui_state = GuiAppState::from_explore_config(&config);
+
+        assert_eq!(ui_state.selected_fractal, FractalKinds::Julia);
+        assert_eq!(ui_state.active_max_iterations(), 77);
+        assert_eq!(ui_state.active_extent(), (1.0, 1.0));
+        assert_eq!(ui_state.mandelbrot, MandelbrotConfig::default());
+        assert!(ui_state.redraw_pending);
     }
 
     #[test]
@@ -164,4 +514,158 @@ mod tests {
         ui_state.selected_fractal = FractalKinds::Julia;
         assert_eq!(ui_state.julia.max_iterations, 111);
     }
+
+    #[test]
+    fn build_render_request_with_max_iterations_overrides_only_iterations() {
+        let mut ui_state = GuiAppState::default();
+        let pixel_rect = create_pixel_rect(100, 100);
+        let overridden_iterations = ui_state.active_max_iterations() / 2;
+
+        ui_state.mandelbrot.max_iterations = overridden_iterations;
+        let expected = ui_state.build_render_request(pixel_rect);
+        ui_state.mandelbrot.max_iterations = overridden_iterations * 2;
+
+        assert!(
+            ui_state.build_render_request_with_max_iterations(pixel_rect, overridden_iterations)
+                == expected
+        );
+        assert_eq!(ui_state.active_max_iterations(), overridden_iterations * 2);
+    }
+
+    #[test]
+    fn set_active_region_round_trips_for_each_fractal() {
+        let mut ui_state = GuiAppState::default();
+        let region = ui_state.active_region().with_extent(0.5, 0.25).unwrap();
+
+        for kind in [FractalKinds::Mandelbrot, FractalKinds::Julia] {
+            ui_state.selected_fractal = kind;
+
+            assert!(ui_state.set_active_region(region.clone()));
+            assert_eq!(ui_state.active_extent(), (0.5, 0.25));
+        }
+    }
+
+    #[test]
+    fn set_active_region_ignores_regions_julia_cannot_represent() {
+        let mut ui_state = GuiAppState {
+            selected_fractal: FractalKinds::Julia,
+            ..Default::default()
+        };
+        let before = ui_state.julia.region;
+        let centre = DeepComplex::from_f64(1.0, 1.0).unwrap();
+        let region = DeepRegion::new(centre, 1e-300, 1e-300).unwrap();
+
+        assert!(!ui_state.set_active_region(region));
+        assert_eq!(ui_state.julia.region, before);
+    }
+
+    #[test]
+    fn compare_against_reference_builds_diff_requests_for_mandelbrot_only() {
+        let mut ui_state = GuiAppState::default();
+        let pixel_rect = create_pixel_rect(100, 100);
+        ui_state.compare_against_reference = true;
+
+        assert!(matches!(
+            ui_state.build_render_request(pixel_rect),
+            FractalConfig::MandelbrotReferenceDiff { .. }
+        ));
+        assert!(matches!(
+            ui_state.build_render_request_with_max_iterations(pixel_rect, 50),
+            FractalConfig::MandelbrotReferenceDiff { .. }
+        ));
+
+        ui_state.selected_fractal = FractalKinds::Julia;
+        assert!(matches!(
+            ui_state.build_render_request(pixel_rect),
+            FractalConfig::Julia { .. }
+        ));
+    }
+
+    #[test]
+    fn split_view_applies_to_julia_frames_only() {
+        let mut ui_state = GuiAppState {
+            julia_split_view: true,
+            ..Default::default()
+        };
+        let pixel_rect = create_pixel_rect(100, 100);
+
+        assert!(matches!(
+            ui_state.build_render_request(pixel_rect),
+            FractalConfig::Mandelbrot { .. }
+        ));
+        assert!(ui_state.split_view(pixel_rect).is_none());
+
+        ui_state.selected_fractal = FractalKinds::Julia;
+        assert!(matches!(
+            ui_state.build_render_request(pixel_rect),
+            FractalConfig::JuliaSplitView { .. }
+        ));
+        assert!(matches!(
+            ui_state.build_render_request_with_max_iterations(pixel_rect, 50),
+            FractalConfig::JuliaSplitView { .. }
+        ));
+        assert_eq!(
+            ui_state.build_render_request(pixel_rect).algorithm().pixel_rect(),
+            pixel_rect
+        );
+    }
+
+    #[test]
+    fn clicking_the_mandelbrot_pane_picks_c_and_resubmits() {
+        let mut ui_state = GuiAppState {
+            selected_fractal: FractalKinds::Julia,
+            julia_split_view: true,
+            ..Default::default()
+        };
+        let pixel_rect = create_pixel_rect(100, 100);
+        let request = ui_state.build_render_request(pixel_rect);
+        ui_state.record_submission(Arc::new(request), 1);
+
+        // The pane's top-left corner is the default region's.
+        assert!(ui_state.pick_julia_c(Point { x: 0, y: 0 }, pixel_rect));
+        assert_eq!((ui_state.julia.c.real, ui_state.julia.c.imag), (-2.5, -1.0));
+        assert!(ui_state.should_submit(&ui_state.build_render_request(pixel_rect)));
+
+        let before = ui_state.julia.c;
+        assert!(!ui_state.pick_julia_c(Point { x: 70, y: 10 }, pixel_rect));
+        assert_eq!(ui_state.julia.c, before);
+
+        ui_state.julia_split_view = false;
+        assert!(!ui_state.pick_julia_c(Point { x: 10, y: 10 }, pixel_rect));
+        assert_eq!(ui_state.julia.c, before);
+    }
+
+    #[test]
+    fn marker_follows_the_picked_c() {
+        let mut ui_state = GuiAppState {
+            selected_fractal: FractalKinds::Julia,
+            julia_split_view: true,
+            ..Default::default()
+        };
+        let pixel_rect = create_pixel_rect(101, 60);
+        let point = Point { x: 21, y: 37 };
+
+        assert!(ui_state.pick_julia_c(point, pixel_rect));
+
+        assert_eq!(ui_state.julia_c_marker(pixel_rect), Some(point));
+        ui_state.julia_split_view = false;
+        assert_eq!(ui_state.julia_c_marker(pixel_rect), None);
+    }
+
+    #[test]
+    fn resized_requests_preempt_and_others_do_not() {
+        let mut ui_state = GuiAppState::default();
+        let pixel_rect = create_pixel_rect(100, 100);
+
+        let first = ui_state.build_render_request(pixel_rect);
+        assert_eq!(ui_state.submission_priority(&first), RequestPriority::Normal);
+        ui_state.record_submission(Arc::new(first), 1);
+
+        ui_state.mandelbrot.max_iterations += 1;
+        let same_size = ui_state.build_render_request(pixel_rect);
+        assert_eq!(ui_state.submission_priority(&same_size), RequestPriority::Normal);
+
+        let resized = ui_state.build_render_request(create_pixel_rect(120, 90));
+        assert_eq!(ui_state.submission_priority(&resized), RequestPriority::Preempt);
+    }
 }
@@ -0,0 +1,146 @@
+/// Apps sharing one event loop, looked up by the id of the window each one
+/// draws into. Generic over the id and app so the routing can be tested
+/// without a display; [`RunGuiCommand`] uses it with winit `WindowId`s and
+/// `GuiApp`s.
+///
+/// Kept in registration order, which is also the order `apps_mut` visits
+/// them. A handful of windows is expected, so lookups scan a `Vec`.
+///
+/// [`RunGuiCommand`]: crate::input::gui::commands::run_gui::RunGuiCommand
+#[derive(Debug)]
+pub struct WindowRouter<Id, App> {
+    apps: Vec<(Id, App)>,
+}
+
+impl<Id, App> Default for WindowRouter<Id, App> {
+    fn default() -> Self {
+        Self { apps: Vec::new() }
+    }
+}
+
+impl<Id: Copy + PartialEq, App> WindowRouter<Id, App> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Routes `id`'s events to `app` from now on, returning the app it
+    /// replaces, if any.
+    pub fn register(&mut self, id: Id, app: App) -> Option<App> {
+        match self.apps.iter_mut().find(|(existing, _)| *existing == id) {
+            Some((_, existing)) => Some(std::mem::replace(existing, app)),
+            None => {
+                self.apps.push((id, app));
+                None
+            }
+        }
+    }
+
+    /// Stops routing to `id` and hands its app back, e.g. to drop it when
+    /// its window closes.
+    pub fn remove(&mut self, id: Id) -> Option<App> {
+        let index = self.apps.iter().position(|(existing, _)| *existing == id)?;
+
+        Some(self.apps.remove(index).1)
+    }
+
+    /// The app for `id`, or `None` for a window no app is registered for.
+    pub fn get_mut(&mut self, id: Id) -> Option<&mut App> {
+        self.apps
+            .iter_mut()
+            .find(|(existing, _)| *existing == id)
+            .map(|(_, app)| app)
+    }
+
+    /// Every registered app, for events that concern all windows.
+    pub fn apps_mut(&mut self) -> impl Iterator<Item = &mut App> {
+        self.apps.iter_mut().map(|(_, app)| app)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.apps.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.apps.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WindowRouter;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct App {
+        name: &'static str,
+        events: u32,
+    }
+
+    fn app(name: &'static str) -> App {
+        App { name, events: 0 }
+    }
+
+    fn two_apps() -> WindowRouter<u64, App> {
+        let mut router = WindowRouter::new();
+        assert!(router.register(1, app("left")).is_none());
+        assert!(router.register(2, app("right")).is_none());
+        router
+    }
+
+    #[test]
+    fn events_reach_only_their_window_app() {
+        let mut router = two_apps();
+
+        router.get_mut(2).unwrap().events += 1;
+        router.get_mut(2).unwrap().events += 1;
+        router.get_mut(1).unwrap().events += 1;
+
+        assert_eq!(router.get_mut(1).map(|app| (app.name, app.events)), Some(("left", 1)));
+        assert_eq!(router.get_mut(2).map(|app| (app.name, app.events)), Some(("right", 2)));
+        assert!(router.get_mut(3).is_none());
+    }
+
+    #[test]
+    fn broadcast_visits_every_app_in_registration_order() {
+        let mut router = two_apps();
+
+        let names: Vec<_> = router
+            .apps_mut()
+            .map(|app| {
+                app.events += 1;
+                app.name
+            })
+            .collect();
+
+        assert_eq!(names, ["left", "right"]);
+        assert_eq!(router.get_mut(1).unwrap().events, 1);
+        assert_eq!(router.get_mut(2).unwrap().events, 1);
+    }
+
+    #[test]
+    fn closing_one_window_leaves_the_other_routed() {
+        let mut router = two_apps();
+
+        assert_eq!(router.remove(1).map(|app| app.name), Some("left"));
+        assert!(router.remove(1).is_none());
+        assert!(router.get_mut(1).is_none());
+        assert_eq!(router.get_mut(2).map(|app| app.name), Some("right"));
+        assert_eq!(router.len(), 1);
+
+        router.remove(2);
+        assert!(router.is_empty());
+    }
+
+    #[test]
+    fn registering_an_existing_id_replaces_its_app() {
+        let mut router = two_apps();
+
+        let replaced = router.register(1, app("replacement"));
+
+        assert_eq!(replaced.map(|app| app.name), Some("left"));
+        assert_eq!(router.len(), 2);
+        assert_eq!(router.get_mut(1).map(|app| app.name), Some("replacement"));
+    }
+}
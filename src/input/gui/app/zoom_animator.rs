@@ -0,0 +1,268 @@
+use crate::core::data::deep_region::DeepRegion;
+use std::time::Duration;
+
+/// View scale applied per mouse-wheel notch.
+pub const WHEEL_ZOOM_FACTOR: f64 = 0.8;
+
+/// Eases the view toward a mouse-zoom target instead of snapping to it.
+///
+/// Each frame the centre offset and the log of each extent close by
+/// `1 - exp(-dt / time_constant)` of the remaining gap, so motion starts fast
+/// and settles smoothly. Once the gap is within `tolerance` (relative to the
+/// target extent) the view snaps to the target and the animation ends.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoomAnimator {
+    pub time_constant: Duration,
+    pub tolerance: f64,
+    target: Option<DeepRegion>,
+}
+
+impl Default for ZoomAnimator {
+    fn default() -> Self {
+        Self {
+            time_constant: Duration::from_millis(80),
+            tolerance: 1e-3,
+            target: None,
+        }
+    }
+}
+
+impl ZoomAnimator {
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        self.target.is_some()
+    }
+
+    /// Drops any pending target, leaving the view where it is.
+    pub fn cancel(&mut self) {
+        self.target = None;
+    }
+
+    /// Scales the target by `factor` about the point under the cursor.
+    ///
+    /// `anchor` is the cursor position in `current` as fractions of its
+    /// extents from the centre (`-0.5..=0.5`, `y` growing downward like
+    /// pixel rows). Repeated calls compound onto any pending target, so fast
+    /// wheel spins keep zooming about the same point.
+    pub fn zoom_toward(&mut self, current: &DeepRegion, anchor: (f64, f64), factor: f64) {
+        if !factor.is_finite() || factor <= 0.0 {
+            return;
+        }
+
+        let base = self.target.as_ref().unwrap_or(current);
+
+        // Cursor point relative to the base centre.
+        let (centre_dre, centre_dim) = current.centre().sub_to_f64(base.centre());
+        let anchor_dre = centre_dre + anchor.0 * current.width();
+        let anchor_dim = centre_dim + anchor.1 * current.height();
+
+        let Some(panned) =
+            base.panned_by(anchor_dre * (1.0 - factor), anchor_dim * (1.0 - factor))
+        else {
+            return;
+        };
+
+        if let Ok(target) = panned.with_extent(base.width() * factor, base.height() * factor) {
+            self.target = Some(target.normalised());
+        }
+    }
+
+    /// Moves the target's centre to the point at `anchor` in `current`,
+    /// keeping the pending extent.
+    ///
+    /// `anchor` uses the same fractions as [`Self::zoom_toward`].
+    pub fn pan_toward(&mut self, current: &DeepRegion, anchor: (f64, f64)) {
+        let base = self.target.as_ref().unwrap_or(current);
+
+        let (centre_dre, centre_dim) = current.centre().sub_to_f64(base.centre());
+        let dre = centre_dre + anchor.0 * current.width();
+        let dim = centre_dim + anchor.1 * current.height();
+
+        if let Some(target) = base.panned_by(dre, dim) {
+            self.target = Some(target.normalised());
+        }
+    }
+
+    /// Advances `current` toward the target by one frame of `dt`.
+    ///
+    /// Returns the new view, or `None` when there is nothing to animate.
+    /// The returned view equals the target exactly on the converging frame.
+    pub fn step(&mut self, current: &DeepRegion, dt: Duration) -> Option<DeepRegion> {
+        let target = self.target.as_ref()?;
+
+        let alpha = if self.time_constant.is_zero() {
+            1.0
+        } else {
+            1.0 - (-dt.as_secs_f64() / self.time_constant.as_secs_f64()).exp()
+        };
+
+        let (dre, dim) = target.centre().sub_to_f64(current.centre());
+        let log_width = (target.width() / current.width()).ln();
+        let log_height = (target.height() / current.height()).ln();
+
+        let remaining = 1.0 - alpha;
+        let converged = remaining * (dre / target.width()).abs() <= self.tolerance
+            && remaining * (dim / target.height()).abs() <= self.tolerance
+            && remaining * log_width.abs() <= self.tolerance
+            && remaining * log_height.abs() <= self.tolerance;
+
+        if converged {
+            return self.target.take();
+        }
+
+        let next = current
+            .panned_by(dre * alpha, dim * alpha)
+            .and_then(|panned| {
+                panned
+                    .with_extent(
+                        current.width() * (log_width * alpha).exp(),
+                        current.height() * (log_height * alpha).exp(),
+                    )
+                    .ok()
+            })
+            .map(|next| next.normalised());
+
+        if next.is_none() {
+            // Non-finite step; jump rather than stall.
+            return self.target.take();
+        }
+
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::deep_complex::DeepComplex;
+
+    fn region(re: f64, im: f64, width: f64, height: f64) -> DeepRegion {
+        DeepRegion::new(DeepComplex::from_f64(re, im).unwrap(), width, height).unwrap()
+    }
+
+    /// Largest remaining gap, in the same units as `tolerance`.
+    fn gap(current: &DeepRegion, target: &DeepRegion) -> f64 {
+        let (dre, dim) = target.centre().sub_to_f64(current.centre());
+        [
+            (dre / target.width()).abs(),
+            (dim / target.height()).abs(),
+            (target.width() / current.width()).ln().abs(),
+            (target.height() / current.height()).ln().abs(),
+        ]
+        .into_iter()
+        .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn idle_animator_does_nothing() {
+        let mut animator = ZoomAnimator::default();
+
+        assert!(!animator.is_animating());
+        assert_eq!(animator.step(&region(0.0, 0.0, 1.0, 1.0), Duration::from_millis(16)), None);
+    }
+
+    #[test]
+    fn zoom_toward_keeps_the_anchor_point_fixed() {
+        let current = region(-0.5, 0.0, 3.0, 2.0);
+        let anchor = (0.25, -0.5);
+        let mut animator = ZoomAnimator::default();
+
+        animator.zoom_toward(&current, anchor, 0.5);
+        let target = animator.target.as_ref().unwrap();
+
+        let (anchor_re, anchor_im) = (-0.5 + 0.25 * 3.0, -1.0);
+        let (target_re, target_im) = target.centre().to_f64();
+        assert!((target_re + anchor.0 * target.width() - anchor_re).abs() < 1e-12);
+        assert!((target_im + anchor.1 * target.height() - anchor_im).abs() < 1e-12);
+        assert_eq!((target.width(), target.height()), (1.5, 1.0));
+    }
+
+    #[test]
+    fn repeated_zooms_compound_onto_the_pending_target() {
+        let current = region(0.0, 0.0, 4.0, 4.0);
+        let mut animator = ZoomAnimator::default();
+
+        animator.zoom_toward(&current, (0.0, 0.0), 0.5);
+        animator.zoom_toward(&current, (0.0, 0.0), 0.5);
+
+        assert_eq!(animator.target.as_ref().unwrap().width(), 1.0);
+    }
+
+    #[test]
+    fn step_converges_monotonically_to_the_target() {
+        let mut current = region(-0.75, 0.1, 3.0, 2.0);
+        let mut animator = ZoomAnimator::default();
+        animator.zoom_toward(&current, (0.3, -0.2), WHEEL_ZOOM_FACTOR.powi(6));
+        let target = animator.target.as_ref().unwrap().clone();
+
+        let mut previous_gap = gap(&current, &target);
+        let mut frames = 0;
+
+        while let Some(next) = animator.step(&current, Duration::from_millis(16)) {
+            let next_gap = gap(&next, &target);
+            assert!(
+                next_gap < previous_gap,
+                "gap grew from {previous_gap} to {next_gap}"
+            );
+
+            previous_gap = next_gap;
+            current = next;
+            frames += 1;
+            assert!(frames < 200, "did not converge");
+        }
+
+        assert!(frames > 1, "expected several frames of easing");
+        assert!(!animator.is_animating());
+        assert_eq!(current, target);
+    }
+
+    #[test]
+    fn pan_toward_centres_the_anchor_point_at_the_same_extent() {
+        let current = region(-0.5, 0.0, 3.0, 2.0);
+        let mut animator = ZoomAnimator::default();
+
+        animator.pan_toward(&current, (0.25, -0.5));
+        let target = animator.target.as_ref().unwrap();
+
+        let (target_re, target_im) = target.centre().to_f64();
+        assert!((target_re - 0.25).abs() < 1e-12);
+        assert!((target_im - -1.0).abs() < 1e-12);
+        assert_eq!((target.width(), target.height()), (3.0, 2.0));
+    }
+
+    #[test]
+    fn zero_time_constant_jumps_straight_to_the_target() {
+        let current = region(0.0, 0.0, 2.0, 2.0);
+        let mut animator = ZoomAnimator {
+            time_constant: Duration::ZERO,
+            ..ZoomAnimator::default()
+        };
+        animator.zoom_toward(&current, (0.5, 0.5), 0.5);
+        let target = animator.target.as_ref().unwrap().clone();
+
+        assert_eq!(animator.step(&current, Duration::from_millis(1)), Some(target));
+        assert!(!animator.is_animating());
+    }
+
+    #[test]
+    fn cancel_drops_the_target() {
+        let current = region(0.0, 0.0, 2.0, 2.0);
+        let mut animator = ZoomAnimator::default();
+        animator.zoom_toward(&current, (0.0, 0.0), 0.5);
+
+        animator.cancel();
+
+        assert!(!animator.is_animating());
+    }
+
+    #[test]
+    fn rejects_non_positive_factor() {
+        let current = region(0.0, 0.0, 2.0, 2.0);
+        let mut animator = ZoomAnimator::default();
+
+        animator.zoom_toward(&current, (0.0, 0.0), 0.0);
+        animator.zoom_toward(&current, (0.0, 0.0), f64::NAN);
+
+        assert!(!animator.is_animating());
+    }
+}
@@ -1,7 +1,8 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
-use winit::{dpi::LogicalSize, event_loop::EventLoopBuilder, window::{Window, WindowBuilder}};
-use crate::{controllers::interactive::InteractiveController, input::gui::{app::{events::gui::GuiEvent, gui_app::GuiApp, ports::presenter::GuiPresenterPort}, commands::ports::presenter_factory::GuiPresenterFactoryPort}};
+use winit::{dpi::LogicalSize, event::Event, event_loop::{EventLoop, EventLoopBuilder}, window::{Window, WindowBuilder, WindowId}};
+use crate::{controllers::{config::explore_config::ExploreConfig, interactive::{detail_budget::DetailBudget, InteractiveController}}, input::gui::{app::{events::gui::GuiEvent, gui_app::{AppControl, GuiApp}, iteration_ceiling::DEFAULT_MAX_ITERATIONS_CEILING, ports::presenter::GuiPresenterPort, render_size::DEFAULT_MAX_BUFFER_DIMENSION, window_router::WindowRouter}, commands::ports::presenter_factory::GuiPresenterFactoryPort}};
 
 pub struct RunGuiCommand<F, P>
 where
@@ -9,6 +10,16 @@ where
     F: GuiPresenterFactoryPort<P>,
 {
     presenter_factory: F,
+    continuous: bool,
+    max_buffer_dimension: u32,
+    even_buffer: bool,
+    max_iterations_ceiling: u32,
+    idle_render_delay: Option<Duration>,
+    pipelined_colouring: bool,
+    gpu_direct_rendering: bool,
+    frame_budget: Option<Duration>,
+    explore_config: Option<ExploreConfig>,
+    window_count: usize,
     _phantom: PhantomData<fn() -> P>,
 }
 
@@ -18,7 +29,104 @@ where
     F: GuiPresenterFactoryPort<P>,
 {
     pub fn new(presenter_factory: F) -> Self {
-        Self { presenter_factory, _phantom: PhantomData }
+        Self {
+            presenter_factory,
+            continuous: true,
+            max_buffer_dimension: DEFAULT_MAX_BUFFER_DIMENSION,
+            even_buffer: false,
+            max_iterations_ceiling: DEFAULT_MAX_ITERATIONS_CEILING,
+            idle_render_delay: None,
+            pipelined_colouring: false,
+            gpu_direct_rendering: false,
+            frame_budget: None,
+            explore_config: None,
+            window_count: 1,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// See [`GuiApp::with_continuous`]; `false` renders on demand only.
+    #[must_use]
+    pub fn with_continuous(mut self, continuous: bool) -> Self {
+        self.continuous = continuous;
+        self
+    }
+
+    /// See [`GuiApp::with_max_buffer_dimension`].
+    #[must_use]
+    pub fn with_max_buffer_dimension(mut self, max_buffer_dimension: u32) -> Self {
+        self.max_buffer_dimension = max_buffer_dimension;
+        self
+    }
+
+    /// See [`GuiApp::with_even_buffer`].
+    #[must_use]
+    pub fn with_even_buffer(mut self, even_buffer: bool) -> Self {
+        self.even_buffer = even_buffer;
+        self
+    }
+
+    /// See [`GuiApp::with_max_iterations_ceiling`].
+    #[must_use]
+    pub fn with_max_iterations_ceiling(mut self, max_iterations_ceiling: u32) -> Self {
+        self.max_iterations_ceiling = max_iterations_ceiling;
+        self
+    }
+
+    /// See [`GuiApp::with_idle_render_delay`].
+    #[must_use]
+    pub fn with_idle_render_delay(mut self, delay: Duration) -> Self {
+        self.idle_render_delay = Some(delay);
+        self
+    }
+
+    /// See [`InteractiveController::with_pipelined_colouring`].
+    #[must_use]
+    pub fn with_pipelined_colouring(mut self, pipelined_colouring: bool) -> Self {
+        self.pipelined_colouring = pipelined_colouring;
+        self
+    }
+
+    /// Starts with shallow Mandelbrot views rendered on the GPU; the panel
+    /// can switch it at runtime. See
+    /// [`InteractiveController::set_gpu_direct_rendering`].
+    #[must_use]
+    pub fn with_gpu_direct_rendering(mut self, gpu_direct_rendering: bool) -> Self {
+        self.gpu_direct_rendering = gpu_direct_rendering;
+        self
+    }
+
+    /// Tunes each window's iteration count so frames render in about
+    /// `target`, starting from 256; see [`DetailBudget`]. The budget
+    /// replaces the iteration setting in the side panel while it is on.
+    #[must_use]
+    pub fn with_frame_budget(mut self, target: Duration) -> Self {
+        self.frame_budget = Some(target);
+        self
+    }
+
+    /// Starts from a loaded config file: its view, flight limits and window
+    /// size, plus any window options it sets. Builders called afterwards
+    /// override those options.
+    #[must_use]
+    pub fn with_explore_config(mut self, config: ExploreConfig) -> Self {
+        if let Some(continuous) = config.window.continuous {
+            self.continuous = continuous;
+        }
+        if let Some(max_buffer_dimension) = config.window.max_buffer_dimension {
+            self.max_buffer_dimension = max_buffer_dimension;
+        }
+        self.explore_config = Some(config);
+        self
+    }
+
+    /// Opens `window_count` windows (at least one), each with its own app,
+    /// controller and presenter, so each explores independently. The event
+    /// loop exits once the last window closes.
+    #[must_use]
+    pub fn with_window_count(mut self, window_count: usize) -> Self {
+        self.window_count = window_count.max(1);
+        self
     }
 
     pub fn execute(&self) {
@@ -26,22 +134,102 @@ where
             .build()
             .expect("Failed to create event loop");
 
-        let event_loop_proxy = event_loop.create_proxy();
+        let window_options = self.explore_config.as_ref().map(|config| config.window).unwrap_or_default();
+        let inner_size = LogicalSize::new(
+            f64::from(window_options.width.unwrap_or(800)),
+            f64::from(window_options.height.unwrap_or(600)),
+        );
+
+        let mut router = WindowRouter::<WindowId, GuiApp<P>>::new();
+        for index in 0..self.window_count {
+            let title = if self.window_count == 1 {
+                "Fractal Explorer".to_string()
+            } else {
+                format!("Fractal Explorer ({})", index + 1)
+            };
 
-        let window: &'static Window = Box::leak(Box::new(
-            WindowBuilder::new()
-                .with_title("Fractal Explorer")
-                .with_inner_size(LogicalSize::new(800.0, 600.0))
-                .with_min_inner_size(LogicalSize::new(200.0, 200.0))
-                .build(&event_loop)
-                .expect("Failed to create window"),
-        ));
+            // Leaked so presenters can hold a `'static` window. A closed
+            // window is only hidden; its memory goes when the process exits.
+            let window: &'static Window = Box::leak(Box::new(
+                WindowBuilder::new()
+                    .with_title(title)
+                    .with_inner_size(inner_size)
+                    .with_min_inner_size(LogicalSize::new(200.0, 200.0))
+                    .build(&event_loop)
+                    .expect("Failed to create window"),
+            ));
+
+            router.register(window.id(), self.build_app(window, &event_loop));
+        }
+
+        Self::run_windows(router, event_loop);
+    }
+
+    /// Drives every app in `router` from one event loop: window events and
+    /// wakes go to the app owning the window, a plain `GuiEvent::Wake` to all
+    /// of them. An app that asks to exit is dropped (shutting down its
+    /// controller) and the loop ends with the last one.
+    fn run_windows(mut router: WindowRouter<WindowId, GuiApp<P>>, event_loop: EventLoop<GuiEvent>) {
+        event_loop
+            .run(move |event, elwt| match event {
+                Event::UserEvent(GuiEvent::Wake) => router.apps_mut().for_each(GuiApp::on_wake),
+                Event::UserEvent(GuiEvent::WakeWindow(window_id)) => {
+                    if let Some(app) = router.get_mut(window_id) {
+                        app.on_wake();
+                    }
+                }
+                Event::WindowEvent { ref event, window_id } => {
+                    let Some(app) = router.get_mut(window_id) else {
+                        return;
+                    };
+
+                    if app.on_window_event(event) == AppControl::Exit {
+                        if let Some(app) = router.remove(window_id) {
+                            app.window().set_visible(false);
+                        }
+                        if router.is_empty() {
+                            elwt.exit();
+                        }
+                    }
+                }
+                Event::AboutToWait => {
+                    for app in router.apps_mut() {
+                        if app.needs_redraw() {
+                            app.window().request_redraw();
+                        }
+                    }
+                }
+                _ => {}
+            })
+            .expect("Event loop error");
+    }
 
-        let presenter: P = self.presenter_factory.build(window, event_loop_proxy);
+    /// Builds the app `execute` runs, for an event loop owned elsewhere:
+    /// forward `window`'s events to [`GuiApp::on_window_event`] and
+    /// `GuiEvent::Wake`, or `GuiEvent::WakeWindow` for `window`, to
+    /// [`GuiApp::on_wake`], and request a redraw
+    /// whenever [`GuiApp::needs_redraw`].
+    pub fn build_app(&self, window: &'static Window, event_loop: &EventLoop<GuiEvent>) -> GuiApp<P> {
+        let presenter: P = self.presenter_factory.build(window, event_loop.create_proxy());
         let gpu_renderer = Box::new(crate::gpu::perturbation_renderer::WgpuPerturbationRenderer::new());
-        let controller = InteractiveController::new(presenter.share_adapter(), Some(gpu_renderer));
-        let app = GuiApp::new(window, &event_loop, presenter, controller);
+        let mut controller = InteractiveController::new(presenter.share_adapter(), Some(gpu_renderer))
+            .with_pipelined_colouring(self.pipelined_colouring)
+            .with_gpu_direct_rendering(self.gpu_direct_rendering);
+        if let Some(target) = self.frame_budget {
+            controller = controller.with_detail_budget(DetailBudget::new(target, 256));
+        }
+        let mut app = GuiApp::new(window, event_loop, presenter, controller)
+            .with_continuous(self.continuous)
+            .with_max_buffer_dimension(self.max_buffer_dimension)
+            .with_even_buffer(self.even_buffer)
+            .with_max_iterations_ceiling(self.max_iterations_ceiling);
+        if let Some(delay) = self.idle_render_delay {
+            app = app.with_idle_render_delay(delay);
+        }
+        if let Some(config) = &self.explore_config {
+            app = app.with_explore_config(config);
+        }
 
-        app.run(event_loop);
+        app
     }
 }
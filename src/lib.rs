@@ -6,9 +6,33 @@ pub mod gpu;
 #[cfg(feature = "gui")]
 mod input;
 
+pub use controllers::cli::batch_sweep::{BatchSweep, BatchSweepError, SweepAxis};
+pub use controllers::cli::oneshot::{render_oneshot, FileFrameSink, OneshotError, DEFAULT_ONESHOT_TIMEOUT};
+pub use controllers::cli::self_test::{run_self_test, SelfTestError};
+pub use controllers::config::explore_config::ExploreConfig;
+#[cfg(feature = "config")]
+pub use controllers::config::explore_config_file::ExploreConfigError;
+#[cfg(feature = "serde")]
+pub use controllers::config::flight_profile::{FlightProfileError, load_flight_profile, save_flight_profile};
+pub use controllers::interactive::flight::simulator::{FlightSimulator, SimulationResult};
+pub use controllers::interactive::flight::ScriptedControls;
+pub use controllers::interactive::data::fractal_config::{FractalConfig, FractalConfigError};
+pub use core::actions::generate_fractal::generate_fractal_serial::generate_fractal_serial;
+pub use core::fractals::catalog::{available_colour_maps, available_fractals};
+pub use core::fractals::fractal_kinds::FractalKinds;
 pub use controllers::cli::test::cli_test::CliTestController;
+pub use presenters::file::bmp::BmpFilePresenter;
+pub use presenters::file::png::PngFilePresenter;
 pub use presenters::file::ppm::PpmFilePresenter;
 #[cfg(feature = "gui")]
+pub use input::gui::app::events::gui::GuiEvent;
+#[cfg(feature = "gui")]
+pub use input::gui::app::gui_app::{AppControl, GuiApp};
+#[cfg(feature = "gui")]
+pub use input::gui::app::window_router::WindowRouter;
+#[cfg(feature = "gui")]
 pub use input::gui::commands::run_gui::RunGuiCommand;
 #[cfg(feature = "gui")]
 pub use presenters::pixels::factory::PixelsPresenterFactory;
+#[cfg(feature = "gui")]
+pub use presenters::pixels::present_mode::PresentMode;
@@ -1,13 +1,142 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    if std::env::args().skip(1).any(|arg| arg == "--self-test") {
+        fractal_explorer::run_self_test()?;
+        println!("Self-test passed");
+        return Ok(());
+    }
+
     let presenter = fractal_explorer::PpmFilePresenter::new();
     let mut controller = fractal_explorer::CliTestController::new(presenter);
 
+    if std::env::args().skip(1).any(|arg| arg == "--palette-strip") {
+        controller.generate_palette_strip()?;
+        controller.write("output/palette.ppm")?;
+        return Ok(());
+    }
+
+    if std::env::args().skip(1).any(|arg| arg == "--antialiased") {
+        controller.generate_antialiased()?;
+        controller.write("output/mandelbrot_aa.ppm")?;
+        return Ok(());
+    }
+
+    if std::env::args().skip(1).any(|arg| arg == "--boundary") {
+        controller.write_boundary_pam("output/boundary.pam")?;
+        return Ok(());
+    }
+
+    if std::env::args().skip(1).any(|arg| arg == "--iterations-pgm") {
+        controller.write_iterations_pgm("output/iterations.pgm")?;
+        return Ok(());
+    }
+
+    if std::env::args().skip(1).any(|arg| arg == "--cost-heatmap") {
+        controller.write_cost_heatmap("output/cost_heatmap.ppm")?;
+        return Ok(());
+    }
+
+    if std::env::args().skip(1).any(|arg| arg == "--streaming-ppm") {
+        controller.write_streaming_ppm("output/mandelbrot_streamed.ppm")?;
+        return Ok(());
+    }
+
+    if std::env::args().skip(1).any(|arg| arg == "--contours") {
+        controller.write_contours_svg("output/contours.svg")?;
+        return Ok(());
+    }
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let config = explore_config_from_args(&args)?;
+
+    if let Some(position) = args.iter().position(|arg| arg == "--sweep-iterations") {
+        let usage = "--sweep-iterations expects <start> <end> <steps>";
+        let values = args
+            .get(position + 1..position + 4)
+            .ok_or(usage)?
+            .iter()
+            .map(|value| value.parse::<u32>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| usage)?;
+        let sweep = fractal_explorer::BatchSweep::new(
+            config.unwrap_or_default(),
+            fractal_explorer::SweepAxis::MaxIterations {
+                start: values[0],
+                end: values[1],
+            },
+            values[2],
+            fractal_explorer::PpmFilePresenter::new(),
+        )?;
+        let written = sweep.run("output/series")?;
+        println!("Wrote {} frames to output/series", written.len());
+        return Ok(());
+    }
+
+    if args.iter().any(|arg| arg == "--oneshot") {
+        let written = fractal_explorer::render_oneshot(
+            &config.unwrap_or_default(),
+            fractal_explorer::PpmFilePresenter::new(),
+            "output/oneshot.ppm",
+            fractal_explorer::DEFAULT_ONESHOT_TIMEOUT,
+        )?;
+        println!("Wrote {}", written.display());
+        return Ok(());
+    }
+
+    if let Some(config) = config {
+        controller.generate_from_config(&config)?;
+        controller.write("output/config.ppm")?;
+        return Ok(());
+    }
+
+    if let Some(position) = args.iter().position(|arg| arg == "--power") {
+        use fractal_explorer::core::fractals::mandelbrot::algorithm::{DEFAULT_POWER, MAX_POWER};
+
+        let power = args
+            .get(position + 1)
+            .and_then(|value| value.parse::<u32>().ok())
+            .filter(|power| (DEFAULT_POWER..=MAX_POWER).contains(power))
+            .ok_or("--power expects a whole number from 2 to 8")?;
+        controller.generate_with_power(power)?;
+        controller.write("output/multibrot.ppm")?;
+        return Ok(());
+    }
+
     controller.generate()?;
     controller.write("output/mandelbrot.ppm")?;
 
     Ok(())
 }
 
+/// Reads `--config <path>`. An invalid file exits with its error.
+#[cfg(feature = "config")]
+fn explore_config_from_args(
+    args: &[String],
+) -> Result<Option<fractal_explorer::ExploreConfig>, Box<dyn std::error::Error>> {
+    let Some(position) = args.iter().position(|arg| arg == "--config") else {
+        return Ok(None);
+    };
+
+    let path = args.get(position + 1).ok_or("--config needs a file path")?;
+    match fractal_explorer::ExploreConfig::load(path) {
+        Ok(config) => Ok(Some(config)),
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(not(feature = "config"))]
+fn explore_config_from_args(
+    args: &[String],
+) -> Result<Option<fractal_explorer::ExploreConfig>, Box<dyn std::error::Error>> {
+    if args.iter().any(|arg| arg == "--config") {
+        return Err("--config needs the `config` feature".into());
+    }
+
+    Ok(None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -18,4 +147,11 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_self_test_returns_ok() {
+        let result = fractal_explorer::run_self_test();
+
+        assert!(result.is_ok());
+    }
 }
@@ -0,0 +1,162 @@
+use crate::controllers::ports::file_presenter::FilePresenterPort;
+use crate::core::data::pixel_buffer::PixelBuffer;
+use std::io::Write;
+use std::path::Path;
+
+/// Size of the file header plus the `BITMAPINFOHEADER`, where the pixels
+/// start.
+const BMP_HEADER_BYTES: u32 = 14 + 40;
+
+/// Writes a pixel buffer as an uncompressed 24-bit BMP; alpha is dropped,
+/// as for PPM.
+///
+/// All header fields are little-endian. Rows are stored bottom-up, so the
+/// buffer's top row is written last, each as blue, green, red bytes per
+/// pixel and padded with zeros to a multiple of 4 bytes.
+pub struct BmpFilePresenter {}
+
+impl FilePresenterPort for BmpFilePresenter {
+    fn present(&self, buffer: &PixelBuffer, filepath: impl AsRef<Path>) -> std::io::Result<()> {
+        let mut file = std::io::BufWriter::new(std::fs::File::create(filepath)?);
+        let pixel_rect = buffer.pixel_rect();
+        let width = pixel_rect.width();
+        let height = pixel_rect.height();
+
+        let row_bytes = width as usize * 3;
+        let padded_row_bytes = row_bytes.next_multiple_of(4);
+        let image_bytes = u32::try_from(padded_row_bytes * height as usize)
+            .ok()
+            .filter(|bytes| bytes.checked_add(BMP_HEADER_BYTES).is_some())
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "image too large for BMP"))?;
+
+        // File header.
+        file.write_all(b"BM")?;
+        file.write_all(&(BMP_HEADER_BYTES + image_bytes).to_le_bytes())?;
+        file.write_all(&[0; 4])?; // reserved
+        file.write_all(&BMP_HEADER_BYTES.to_le_bytes())?;
+
+        // BITMAPINFOHEADER: a positive height means bottom-up rows.
+        file.write_all(&40u32.to_le_bytes())?;
+        file.write_all(&(width as i32).to_le_bytes())?;
+        file.write_all(&(height as i32).to_le_bytes())?;
+        file.write_all(&1u16.to_le_bytes())?; // colour planes
+        file.write_all(&24u16.to_le_bytes())?; // bits per pixel
+        file.write_all(&0u32.to_le_bytes())?; // BI_RGB, uncompressed
+        file.write_all(&image_bytes.to_le_bytes())?;
+        file.write_all(&2835i32.to_le_bytes())?; // 72 dpi horizontally
+        file.write_all(&2835i32.to_le_bytes())?; // and vertically
+        file.write_all(&0u32.to_le_bytes())?; // palette colours
+        file.write_all(&0u32.to_le_bytes())?; // important colours
+
+        let rgb = buffer.to_rgb();
+        let mut row_out = vec![0u8; padded_row_bytes];
+        for row in rgb.chunks_exact(row_bytes).rev() {
+            for (out, pixel) in row_out.chunks_exact_mut(3).zip(row.chunks_exact(3)) {
+                out.copy_from_slice(&[pixel[2], pixel[1], pixel[0]]);
+            }
+            file.write_all(&row_out)?;
+        }
+
+        file.flush()
+    }
+}
+
+impl Default for BmpFilePresenter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BmpFilePresenter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::util::temp_file_path::temp_file_path;
+    use crate::core::data::pixel_rect::PixelRect;
+    use crate::core::data::point::Point;
+    use std::fs;
+
+    fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes(bytes[offset..offset + 2].try_into().unwrap())
+    }
+
+    fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    fn i32_at(bytes: &[u8], offset: usize) -> i32 {
+        i32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    /// A 2x2 image: red, green on top; blue, white below.
+    fn two_by_two() -> PixelBuffer {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 1, y: 1 }).unwrap();
+        PixelBuffer::from_data(
+            pixel_rect,
+            vec![
+                255, 0, 0, 255, // top left
+                0, 255, 0, 255, // top right
+                0, 0, 255, 255, // bottom left
+                255, 255, 255, 255, // bottom right
+            ],
+        )
+        .unwrap()
+    }
+
+    fn present(buffer: &PixelBuffer, test_name: &str) -> Vec<u8> {
+        let output_path = temp_file_path(test_name, "bmp");
+        BmpFilePresenter::new().present(buffer, &output_path).unwrap();
+        let output = fs::read(&output_path).unwrap();
+        fs::remove_file(&output_path).unwrap();
+        output
+    }
+
+    #[test]
+    fn test_present_writes_the_54_byte_header() {
+        let output = present(&two_by_two(), "header");
+
+        // Two 6-byte rows, each padded to 8.
+        assert_eq!(output.len(), 54 + 16);
+        assert_eq!(&output[0..2], b"BM");
+        assert_eq!(u32_at(&output, 2), 70);
+        assert_eq!(u32_at(&output, 10), 54);
+        assert_eq!(u32_at(&output, 14), 40);
+        assert_eq!(i32_at(&output, 18), 2);
+        assert_eq!(i32_at(&output, 22), 2);
+        assert_eq!(u16_at(&output, 26), 1);
+        assert_eq!(u16_at(&output, 28), 24);
+        assert_eq!(u32_at(&output, 30), 0);
+        assert_eq!(u32_at(&output, 34), 16);
+    }
+
+    #[test]
+    fn test_present_writes_bgr_rows_bottom_up_with_padding() {
+        let output = present(&two_by_two(), "rows");
+
+        assert_eq!(
+            &output[54..],
+            [
+                255, 0, 0, 255, 255, 255, 0, 0, // blue, white
+                0, 0, 255, 0, 255, 0, 0, 0, // red, green
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rows_already_on_a_4_byte_boundary_are_not_padded() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 0 }).unwrap();
+        let buffer = PixelBuffer::from_data(pixel_rect, vec![7; 16]).unwrap();
+
+        let output = present(&buffer, "unpadded");
+
+        assert_eq!(output.len(), 54 + 12);
+        assert_eq!(u32_at(&output, 34), 12);
+        assert_eq!(i32_at(&output, 18), 4);
+        assert_eq!(i32_at(&output, 22), 1);
+    }
+}
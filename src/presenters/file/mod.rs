@@ -1 +1,7 @@
+pub mod bmp;
+pub mod pam;
+pub mod pgm;
+pub mod png;
 pub mod ppm;
+pub mod streaming_ppm;
+pub mod svg;
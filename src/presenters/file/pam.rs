@@ -0,0 +1,78 @@
+use crate::controllers::ports::file_presenter::FilePresenterPort;
+use crate::core::data::pixel_buffer::PixelBuffer;
+use std::io::Write;
+use std::path::Path;
+
+/// Writes a pixel buffer as a Netpbm PAM (`P7`) with an alpha channel,
+/// for renders with transparent parts that a PPM would flatten.
+///
+/// The header is `P7` and the `WIDTH`, `HEIGHT`, `DEPTH 4`, `MAXVAL 255`
+/// and `TUPLTYPE RGB_ALPHA` lines, closed by `ENDHDR`, each ended by a
+/// single `\n`. Pixels follow in row-major order as one byte each of red,
+/// green, blue and straight (unpremultiplied) alpha.
+pub struct PamFilePresenter {}
+
+impl FilePresenterPort for PamFilePresenter {
+    fn present(&self, buffer: &PixelBuffer, filepath: impl AsRef<Path>) -> std::io::Result<()> {
+        let mut file = std::fs::File::create(filepath)?;
+        let width = buffer.pixel_rect().width();
+        let height = buffer.pixel_rect().height();
+
+        writeln!(file, "P7")?;
+        writeln!(file, "WIDTH {width}")?;
+        writeln!(file, "HEIGHT {height}")?;
+        writeln!(file, "DEPTH 4")?;
+        writeln!(file, "MAXVAL 255")?;
+        writeln!(file, "TUPLTYPE RGB_ALPHA")?;
+        writeln!(file, "ENDHDR")?;
+
+        file.write_all(buffer.as_rgba())?;
+
+        Ok(())
+    }
+}
+
+impl Default for PamFilePresenter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PamFilePresenter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::util::temp_file_path::temp_file_path;
+    use crate::core::data::pixel_rect::PixelRect;
+    use crate::core::data::point::Point;
+    use std::fs;
+
+    #[test]
+    fn test_present_writes_exact_file_bytes_keeping_alpha() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 1, y: 0 }).unwrap();
+        let buffer = PixelBuffer::from_data_rgba(
+            pixel_rect,
+            vec![
+                10, 20, 30, 0, // transparent
+                40, 50, 60, 255, // opaque
+            ],
+        )
+        .unwrap();
+
+        let output_path = temp_file_path("pam_exact_bytes", "pam");
+        PamFilePresenter::new().present(&buffer, &output_path).unwrap();
+
+        let output = fs::read(&output_path).unwrap();
+        fs::remove_file(&output_path).unwrap();
+
+        let mut expected =
+            b"P7\nWIDTH 2\nHEIGHT 1\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n".to_vec();
+        expected.extend_from_slice(&[10, 20, 30, 0, 40, 50, 60, 255]);
+        assert_eq!(output, expected);
+    }
+}
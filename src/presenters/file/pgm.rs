@@ -0,0 +1,144 @@
+use crate::core::data::pixel_rect::PixelRect;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Largest maxval a PGM file can declare.
+pub const PGM_MAX_MAXVAL: u16 = u16::MAX;
+
+/// Writes an iteration field as a greyscale binary PGM (`P5`), one sample
+/// per pixel in row-major order, so raw counts can be inspected or
+/// post-processed without a colour map in the way.
+///
+/// The header is `P5`, then width and height, then maxval, each line ended
+/// by a single `\n`. Counts are clamped to maxval, which is the
+/// `max_iterations` passed in (at least 1, at most [`PGM_MAX_MAXVAL`]). As
+/// the format requires, a maxval below 256 gives one byte per sample, and
+/// anything larger two bytes per sample, most significant byte first
+/// (big-endian) whatever the host's byte order.
+pub struct PgmFilePresenter {}
+
+impl Default for PgmFilePresenter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PgmFilePresenter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn present(
+        &self,
+        field: &[u32],
+        pixel_rect: PixelRect,
+        max_iterations: u32,
+        filepath: impl AsRef<Path>,
+    ) -> io::Result<()> {
+        let mut file = io::BufWriter::new(std::fs::File::create(filepath)?);
+        self.write(field, pixel_rect, max_iterations, &mut file)?;
+
+        file.flush()
+    }
+
+    pub fn write(
+        &self,
+        field: &[u32],
+        pixel_rect: PixelRect,
+        max_iterations: u32,
+        out: &mut impl Write,
+    ) -> io::Result<()> {
+        if field.len() as u64 != pixel_rect.size() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "iteration field has {} values but the pixel rect holds {}",
+                    field.len(),
+                    pixel_rect.size()
+                ),
+            ));
+        }
+
+        let maxval = max_iterations.clamp(1, u32::from(PGM_MAX_MAXVAL));
+        writeln!(out, "P5")?;
+        writeln!(out, "{} {}", pixel_rect.width(), pixel_rect.height())?;
+        writeln!(out, "{maxval}")?;
+
+        let samples = field.iter().map(|&iterations| iterations.min(maxval) as u16);
+        let payload: Vec<u8> = if maxval < 256 {
+            samples.map(|sample| sample as u8).collect()
+        } else {
+            samples.flat_map(u16::to_be_bytes).collect()
+        };
+
+        out.write_all(&payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::point::Point;
+
+    fn pixel_rect(width: i32, height: i32) -> PixelRect {
+        PixelRect::new(
+            Point { x: 0, y: 0 },
+            Point {
+                x: width - 1,
+                y: height - 1,
+            },
+        )
+        .unwrap()
+    }
+
+    fn write(field: &[u32], pixel_rect: PixelRect, max_iterations: u32) -> io::Result<Vec<u8>> {
+        let mut out = Vec::new();
+        PgmFilePresenter::new().write(field, pixel_rect, max_iterations, &mut out)?;
+
+        Ok(out)
+    }
+
+    #[test]
+    fn test_write_8_bit_samples_are_single_bytes() {
+        let output = write(&[0, 1, 254, 255, 300, 7], pixel_rect(3, 2), 255).unwrap();
+
+        let mut expected = b"P5\n3 2\n255\n".to_vec();
+        expected.extend_from_slice(&[0x00, 0x01, 0xfe, 0xff, 0xff, 0x07]);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_write_16_bit_samples_are_big_endian() {
+        let output = write(&[0x0102, 0, 256, 1000, 70_000, 0xabcd], pixel_rect(2, 3), 65_535).unwrap();
+
+        let mut expected = b"P5\n2 3\n65535\n".to_vec();
+        expected.extend_from_slice(&[
+            0x01, 0x02, // 258
+            0x00, 0x00, // 0
+            0x01, 0x00, // 256
+            0x03, 0xe8, // 1000
+            0xff, 0xff, // 70000, clamped to maxval
+            0xab, 0xcd, // 43981
+        ]);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_write_maxval_follows_max_iterations_within_the_format_range() {
+        let output = write(&[300, 0], pixel_rect(2, 1), 256).unwrap();
+        assert_eq!(output, b"P5\n2 1\n256\n\x01\x00\x00\x00");
+
+        let output = write(&[5], pixel_rect(1, 1), 100_000).unwrap();
+        assert_eq!(output, b"P5\n1 1\n65535\n\x00\x05");
+
+        let output = write(&[5], pixel_rect(1, 1), 0).unwrap();
+        assert_eq!(output, b"P5\n1 1\n1\n\x01");
+    }
+
+    #[test]
+    fn test_write_rejects_a_field_of_the_wrong_size() {
+        let error = write(&[1, 2, 3], pixel_rect(2, 2), 255).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,262 @@
+use crate::controllers::ports::file_presenter::FilePresenterPort;
+use crate::core::data::pixel_buffer::PixelBuffer;
+use std::io::Write;
+use std::path::Path;
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Largest payload of one stored (uncompressed) deflate block.
+const MAX_STORED_BLOCK: usize = 0xffff;
+
+/// Writes a pixel buffer as an 8-bit RGB PNG; alpha is dropped, as for
+/// PPM.
+///
+/// The image data is zlib-wrapped deflate using stored blocks only, so the
+/// file is valid for any decoder but no smaller than the raw pixels. Each
+/// row is written with filter type 0 (none).
+pub struct PngFilePresenter {}
+
+impl FilePresenterPort for PngFilePresenter {
+    fn present(&self, buffer: &PixelBuffer, filepath: impl AsRef<Path>) -> std::io::Result<()> {
+        let mut file = std::io::BufWriter::new(std::fs::File::create(filepath)?);
+        let pixel_rect = buffer.pixel_rect();
+
+        file.write_all(&PNG_SIGNATURE)?;
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&pixel_rect.width().to_be_bytes());
+        header.extend_from_slice(&pixel_rect.height().to_be_bytes());
+        // Bit depth 8, colour type 2 (RGB), deflate, adaptive filtering, no
+        // interlace.
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+        write_chunk(&mut file, b"IHDR", &header)?;
+
+        let row_bytes = pixel_rect.width() as usize * 3;
+        let rgb = buffer.to_rgb();
+        let mut scanlines = Vec::with_capacity(rgb.len() + pixel_rect.height() as usize);
+        for row in rgb.chunks_exact(row_bytes) {
+            scanlines.push(0);
+            scanlines.extend_from_slice(row);
+        }
+        write_chunk(&mut file, b"IDAT", &zlib_stored(&scanlines))?;
+
+        write_chunk(&mut file, b"IEND", &[])?;
+        file.flush()
+    }
+}
+
+impl Default for PngFilePresenter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PngFilePresenter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Writes one chunk: big-endian length, type, data, then the CRC of type
+/// and data.
+fn write_chunk(out: &mut impl Write, chunk_type: &[u8; 4], data: &[u8]) -> std::io::Result<()> {
+    let length = u32::try_from(data.len())
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidInput, "PNG chunk too large"))?;
+
+    out.write_all(&length.to_be_bytes())?;
+    out.write_all(chunk_type)?;
+    out.write_all(data)?;
+
+    let crc = crc32(crc32_update(0xffff_ffff, chunk_type), data);
+    out.write_all(&crc.to_be_bytes())
+}
+
+/// `data` as a zlib stream of stored deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let blocks = data.len().div_ceil(MAX_STORED_BLOCK).max(1);
+    let mut out = Vec::with_capacity(data.len() + blocks * 5 + 6);
+
+    // CMF: deflate with a 32K window; FLG: no dictionary, check bits making
+    // the pair a multiple of 31.
+    out.extend_from_slice(&[0x78, 0x01]);
+
+    let mut chunks = data.chunks(MAX_STORED_BLOCK).peekable();
+    if chunks.peek().is_none() {
+        out.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = chunks.next() {
+        let last = u8::from(chunks.peek().is_none());
+        let length = block.len() as u16;
+        out.push(last);
+        out.extend_from_slice(&length.to_le_bytes());
+        out.extend_from_slice(&(!length).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 { 0xedb8_8320 ^ (c >> 1) } else { c >> 1 };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    table
+};
+
+fn crc32_update(mut crc: u32, data: &[u8]) -> u32 {
+    for &byte in data {
+        crc = CRC32_TABLE[((crc ^ u32::from(byte)) & 0xff) as usize] ^ (crc >> 8);
+    }
+    crc
+}
+
+/// Finishes a CRC begun with [`crc32_update`] over `data`.
+fn crc32(crc: u32, data: &[u8]) -> u32 {
+    crc32_update(crc, data) ^ 0xffff_ffff
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MODULUS: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    // 5552 bytes is the most that can be summed before `b` could overflow.
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= MODULUS;
+        b %= MODULUS;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::util::temp_file_path::temp_file_path;
+    use crate::controllers::cli::test::cli_test::CliTestController;
+    use crate::core::data::pixel_rect::PixelRect;
+    use crate::core::data::point::Point;
+    use std::fs;
+
+    /// A decoded file: its `IHDR` fields and the inflated `IDAT` data.
+    struct DecodedPng {
+        width: u32,
+        height: u32,
+        bit_depth: u8,
+        colour_type: u8,
+        scanlines: Vec<u8>,
+    }
+
+    /// Reads back what `PngFilePresenter` writes, checking the signature,
+    /// every chunk CRC and the zlib checksum along the way. Only stored
+    /// deflate blocks are understood.
+    fn decode(bytes: &[u8]) -> DecodedPng {
+        assert_eq!(bytes[..8], PNG_SIGNATURE);
+
+        let mut chunks = Vec::new();
+        let mut offset = 8;
+        while offset < bytes.len() {
+            let length = u32::from_be_bytes(bytes[offset..offset + 4].try_into().unwrap()) as usize;
+            let chunk_type: [u8; 4] = bytes[offset + 4..offset + 8].try_into().unwrap();
+            let data = &bytes[offset + 8..offset + 8 + length];
+            let crc = u32::from_be_bytes(bytes[offset + 8 + length..offset + 12 + length].try_into().unwrap());
+            assert_eq!(crc, crc32(crc32_update(0xffff_ffff, &chunk_type), data));
+            chunks.push((chunk_type, data));
+            offset += 12 + length;
+        }
+
+        let types: Vec<&[u8; 4]> = chunks.iter().map(|(chunk_type, _)| chunk_type).collect();
+        assert_eq!(types, [b"IHDR", b"IDAT", b"IEND"]);
+
+        let header = chunks[0].1;
+        let zlib = chunks[1].1;
+        assert_eq!(zlib[..2], [0x78, 0x01]);
+        assert_eq!((u16::from(zlib[0]) << 8 | u16::from(zlib[1])) % 31, 0);
+
+        let mut scanlines = Vec::new();
+        let mut at = 2;
+        loop {
+            let last = zlib[at] & 1 == 1;
+            assert_eq!(zlib[at] >> 1, 0, "only stored blocks are expected");
+            let length = u16::from_le_bytes([zlib[at + 1], zlib[at + 2]]);
+            let complement = u16::from_le_bytes([zlib[at + 3], zlib[at + 4]]);
+            assert_eq!(length, !complement);
+            at += 5;
+            scanlines.extend_from_slice(&zlib[at..at + length as usize]);
+            at += length as usize;
+            if last {
+                break;
+            }
+        }
+        assert_eq!(zlib[at..], adler32(&scanlines).to_be_bytes());
+
+        DecodedPng {
+            width: u32::from_be_bytes(header[0..4].try_into().unwrap()),
+            height: u32::from_be_bytes(header[4..8].try_into().unwrap()),
+            bit_depth: header[8],
+            colour_type: header[9],
+            scanlines,
+        }
+    }
+
+    #[test]
+    fn test_present_round_trips_dimensions_and_rgb_rows() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 1, y: 1 }).unwrap();
+        let buffer = PixelBuffer::from_data(
+            pixel_rect,
+            vec![
+                10, 20, 30, 255, // row 0
+                40, 50, 60, 255, //
+                70, 80, 90, 255, // row 1
+                100, 110, 120, 255, //
+            ],
+        )
+        .unwrap();
+
+        let output_path = temp_file_path("round_trip", "png");
+        PngFilePresenter::new().present(&buffer, &output_path).unwrap();
+        let output = fs::read(&output_path).unwrap();
+        fs::remove_file(&output_path).unwrap();
+
+        let decoded = decode(&output);
+        assert_eq!((decoded.width, decoded.height), (2, 2));
+        assert_eq!((decoded.bit_depth, decoded.colour_type), (8, 2));
+        assert_eq!(
+            decoded.scanlines,
+            [0, 10, 20, 30, 40, 50, 60, 0, 70, 80, 90, 100, 110, 120]
+        );
+    }
+
+    #[test]
+    fn test_cli_controller_writes_png_through_the_same_port() {
+        let mut controller = CliTestController::new(PngFilePresenter::new());
+        controller.generate().unwrap();
+
+        let output_path = temp_file_path("cli_controller", "png");
+        controller.write(&output_path).unwrap();
+        let output = fs::read(&output_path).unwrap();
+        fs::remove_file(&output_path).unwrap();
+
+        // 800x600 spans several stored blocks.
+        let decoded = decode(&output);
+        assert_eq!((decoded.width, decoded.height), (800, 600));
+        assert_eq!(decoded.scanlines.len(), 600 * (1 + 800 * 3));
+    }
+
+    #[test]
+    fn test_checksums_match_known_values() {
+        assert_eq!(crc32(crc32_update(0xffff_ffff, b"IEND"), &[]), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+}
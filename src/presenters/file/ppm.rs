@@ -1,35 +1,36 @@
 use crate::controllers::ports::file_presenter::FilePresenterPort;
 use crate::core::data::pixel_buffer::PixelBuffer;
+use crate::core::data::pixel_rect::PixelRect;
 use std::io::Write;
 use std::path::Path;
 
-const PPM_BYTES_PER_PIXEL: usize = 3;
-
+/// Writes a pixel buffer as a binary PPM (`P6`).
+///
+/// The header is `P6`, then width and height, then the maxval `255`, each
+/// line ended by a single `\n`. Pixels follow in row-major order as one
+/// byte each of red, green and blue; alpha is dropped. With maxval below
+/// 256 every sample is a single byte, so byte order never comes into it.
 pub struct PpmFilePresenter {}
 
 impl FilePresenterPort for PpmFilePresenter {
     fn present(&self, buffer: &PixelBuffer, filepath: impl AsRef<Path>) -> std::io::Result<()> {
         let mut file = std::fs::File::create(filepath)?;
-        let width = buffer.pixel_rect().width();
-        let height = buffer.pixel_rect().height();
-
-        // PPM header: P6 means binary RGB, then width, height and max_colour
-        writeln!(file, "P6")?;
-        writeln!(file, "{} {}", width, height)?;
-        writeln!(file, "255")?;
-
-        let pixel_count = (width * height) as usize;
-        let mut rgb_data = Vec::with_capacity(pixel_count * PPM_BYTES_PER_PIXEL);
-        for pixel in buffer.buffer().chunks_exact(PixelBuffer::BYTES_PER_PIXEL) {
-            rgb_data.extend_from_slice(&pixel[..PPM_BYTES_PER_PIXEL]);
-        }
+        write_ppm_header(&mut file, buffer.pixel_rect())?;
 
-        file.write_all(&rgb_data)?;
+        file.write_all(&buffer.to_rgb())?;
 
         Ok(())
     }
 }
 
+/// Writes the `P6` header for an image the size of `pixel_rect`.
+pub(crate) fn write_ppm_header(out: &mut impl Write, pixel_rect: PixelRect) -> std::io::Result<()> {
+    // PPM header: P6 means binary RGB, then width, height and max_colour
+    writeln!(out, "P6")?;
+    writeln!(out, "{} {}", pixel_rect.width(), pixel_rect.height())?;
+    writeln!(out, "255")
+}
+
 impl Default for PpmFilePresenter {
     fn default() -> Self {
         Self::new()
@@ -45,11 +46,15 @@ impl PpmFilePresenter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::data::pixel_rect::PixelRect;
+    use crate::core::util::temp_file_path::temp_file_path;
+    use crate::core::actions::render_pixel_buffer::render_pixel_buffer_parallel_rayon;
+    use crate::core::data::complex::Complex;
+    use crate::core::data::complex_rect::ComplexRect;
     use crate::core::data::point::Point;
+    use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
+    use crate::core::fractals::mandelbrot::colour_mapping::maps::fire::MandelbrotFireColourMap;
     use std::fs;
     use std::path::PathBuf;
-    use std::time::{SystemTime, UNIX_EPOCH};
 
     fn create_pixel_rect(width: i32, height: i32) -> PixelRect {
         PixelRect::new(
@@ -62,17 +67,50 @@ mod tests {
         .unwrap()
     }
 
-    fn temp_file_path(test_name: &str) -> PathBuf {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_nanos();
-        std::env::temp_dir().join(format!(
-            "fractal_explorer_{}_{}_{}.ppm",
-            test_name,
-            std::process::id(),
-            timestamp
-        ))
+    const PPM_BYTES_PER_PIXEL: usize = 3;
+
+    /// Set to regenerate the golden fixtures instead of comparing against them.
+    const UPDATE_GOLDEN_ENV: &str = "FRACTAL_EXPLORER_UPDATE_GOLDEN";
+
+    fn golden_path(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("golden")
+            .join(name)
+    }
+
+    fn assert_matches_golden(name: &str, actual: &[u8]) {
+        let path = golden_path(name);
+
+        if std::env::var_os(UPDATE_GOLDEN_ENV).is_some() {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, actual).unwrap();
+            return;
+        }
+
+        let expected = fs::read(&path).unwrap_or_else(|e| {
+            panic!(
+                "failed to read golden {}: {e} (run with {UPDATE_GOLDEN_ENV}=1 to create it)",
+                path.display()
+            )
+        });
+
+        assert_eq!(
+            actual.len(),
+            expected.len(),
+            "output length differs from golden {}",
+            path.display()
+        );
+
+        if let Some(offset) = actual.iter().zip(&expected).position(|(a, e)| a != e) {
+            panic!(
+                "output differs from golden {} at byte {offset}: got {}, expected {} \
+                 (run with {UPDATE_GOLDEN_ENV}=1 to regenerate if the change is intended)",
+                path.display(),
+                actual[offset],
+                expected[offset]
+            );
+        }
     }
 
     #[test]
@@ -87,7 +125,7 @@ mod tests {
         )
         .unwrap();
 
-        let output_path = temp_file_path("header_and_payload");
+        let output_path = temp_file_path("header_and_payload", "ppm");
         PpmFilePresenter::new().present(&buffer, &output_path).unwrap();
 
         let output = fs::read(&output_path).unwrap();
@@ -99,6 +137,34 @@ mod tests {
         assert_eq!(&output[expected_header.len()..], expected_payload.as_slice());
     }
 
+    #[test]
+    fn test_present_writes_exact_file_bytes() {
+        let pixel_rect = create_pixel_rect(2, 2);
+        let buffer = PixelBuffer::from_data(
+            pixel_rect,
+            vec![
+                0x00, 0x01, 0x02, 0xff, // (0, 0)
+                0x10, 0x20, 0x30, 0x00, // (1, 0)
+                0x80, 0x7f, 0xfe, 0x80, // (0, 1)
+                0xff, 0xff, 0xff, 0xff, // (1, 1)
+            ],
+        )
+        .unwrap();
+
+        let output_path = temp_file_path("exact_bytes", "ppm");
+        PpmFilePresenter::new().present(&buffer, &output_path).unwrap();
+
+        let output = fs::read(&output_path).unwrap();
+        fs::remove_file(&output_path).unwrap();
+
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend_from_slice(&[
+            0x00, 0x01, 0x02, 0x10, 0x20, 0x30, // row 0
+            0x80, 0x7f, 0xfe, 0xff, 0xff, 0xff, // row 1
+        ]);
+        assert_eq!(output, expected);
+    }
+
     #[test]
     fn test_present_strips_alpha_bytes_from_output_payload() {
         let pixel_rect = create_pixel_rect(1, 2);
@@ -111,7 +177,7 @@ mod tests {
         )
         .unwrap();
 
-        let output_path = temp_file_path("strips_alpha");
+        let output_path = temp_file_path("strips_alpha", "ppm");
         PpmFilePresenter::new().present(&buffer, &output_path).unwrap();
 
         let output = fs::read(&output_path).unwrap();
@@ -125,4 +191,33 @@ mod tests {
         assert_eq!(payload.len(), expected_payload_len);
         assert_eq!(payload, &[1, 2, 3, 4, 5, 6]);
     }
+
+    #[test]
+    fn test_present_matches_golden_mandelbrot_fire_image() {
+        let max_iterations = 100;
+        let pixel_rect = create_pixel_rect(32, 24);
+        let complex_rect = ComplexRect::new(
+            Complex {
+                real: -2.5,
+                imag: -1.0,
+            },
+            Complex {
+                real: 1.0,
+                imag: 1.0,
+            },
+        )
+        .unwrap();
+
+        let algorithm = MandelbrotAlgorithm::new(pixel_rect, complex_rect, max_iterations).unwrap();
+        let colour_map = MandelbrotFireColourMap::new(max_iterations);
+        let buffer = render_pixel_buffer_parallel_rayon(pixel_rect, &algorithm, &colour_map).unwrap();
+
+        let output_path = temp_file_path("golden_mandelbrot_fire", "ppm");
+        PpmFilePresenter::new().present(&buffer, &output_path).unwrap();
+
+        let output = fs::read(&output_path).unwrap();
+        fs::remove_file(&output_path).unwrap();
+
+        assert_matches_golden("mandelbrot_32x24_fire_100.ppm", &output);
+    }
 }
@@ -0,0 +1,188 @@
+use crate::controllers::ports::streaming_file_presenter::StreamingFilePresenterPort;
+use crate::core::data::pixel_buffer::PixelBuffer;
+use crate::core::data::pixel_rect::PixelRect;
+use crate::presenters::file::ppm::write_ppm_header;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Writes a binary PPM (`P6`) band by band: the header when created, then
+/// each band's pixels as it is passed in, so only one band is ever held.
+/// The finished file is byte-for-byte what [`PpmFilePresenter`] writes for
+/// the whole image.
+///
+/// [`PpmFilePresenter`]: crate::presenters::file::ppm::PpmFilePresenter
+pub struct StreamingPpmPresenter<W: Write> {
+    out: W,
+    pixel_rect: PixelRect,
+    next_row: i32,
+}
+
+impl StreamingPpmPresenter<BufWriter<File>> {
+    /// Creates the file at `filepath` for an image the size of `pixel_rect`.
+    pub fn create(pixel_rect: PixelRect, filepath: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(pixel_rect, BufWriter::new(File::create(filepath)?))
+    }
+}
+
+impl<W: Write> StreamingPpmPresenter<W> {
+    /// Starts an image the size of `pixel_rect` on `out`, writing its header.
+    pub fn new(pixel_rect: PixelRect, mut out: W) -> io::Result<Self> {
+        write_ppm_header(&mut out, pixel_rect)?;
+
+        Ok(Self {
+            out,
+            pixel_rect,
+            next_row: pixel_rect.top_left().y,
+        })
+    }
+
+    fn rows_left(&self) -> i64 {
+        i64::from(self.pixel_rect.bottom_right().y) - i64::from(self.next_row) + 1
+    }
+}
+
+impl<W: Write> StreamingFilePresenterPort for StreamingPpmPresenter<W> {
+    fn write_band(&mut self, band: &PixelBuffer) -> io::Result<()> {
+        let band_rect = band.pixel_rect();
+        let spans_width = band_rect.top_left().x == self.pixel_rect.top_left().x
+            && band_rect.bottom_right().x == self.pixel_rect.bottom_right().x;
+        let follows_on = band_rect.top_left().y == self.next_row
+            && i64::from(band_rect.height()) <= self.rows_left();
+
+        if !spans_width || !follows_on {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "band (x: {}, y: {})-(x: {}, y: {}) does not continue the image at row {}",
+                    band_rect.top_left().x,
+                    band_rect.top_left().y,
+                    band_rect.bottom_right().x,
+                    band_rect.bottom_right().y,
+                    self.next_row
+                ),
+            ));
+        }
+
+        self.out.write_all(&band.to_rgb())?;
+        self.next_row = band_rect.bottom_right().y + 1;
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> io::Result<()> {
+        if self.rows_left() > 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("image finished with {} rows unwritten", self.rows_left()),
+            ));
+        }
+
+        self.out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::util::temp_file_path::temp_file_path;
+    use crate::controllers::ports::file_presenter::FilePresenterPort;
+    use crate::core::actions::render_pixel_buffer::render_pixel_buffer_parallel_rayon;
+    use crate::core::actions::render_tile::{TileRequest, render_tile};
+    use crate::core::data::complex::Complex;
+    use crate::core::data::complex_rect::ComplexRect;
+    use crate::core::data::point::Point;
+    use crate::core::fractals::mandelbrot::algorithm::MandelbrotAlgorithm;
+    use crate::core::fractals::mandelbrot::colour_mapping::maps::fire::MandelbrotFireColourMap;
+    use crate::presenters::file::ppm::PpmFilePresenter;
+    use std::fs;
+
+    const MAX_ITERATIONS: u32 = 100;
+
+    fn rect(x0: i32, y0: i32, x1: i32, y1: i32) -> PixelRect {
+        PixelRect::new(Point { x: x0, y: y0 }, Point { x: x1, y: y1 }).unwrap()
+    }
+
+    fn region() -> ComplexRect {
+        ComplexRect::new(
+            Complex {
+                real: -2.5,
+                imag: -1.0,
+            },
+            Complex {
+                real: 1.0,
+                imag: 1.0,
+            },
+        )
+        .unwrap()
+    }
+
+    /// Rows `y0..=y1` of the 32x24 test image.
+    fn band(y0: i32, y1: i32) -> PixelBuffer {
+        let request = TileRequest::new(rect(0, 0, 31, 23), rect(0, y0, 31, y1), region(), MAX_ITERATIONS).unwrap();
+
+        render_tile(&request, MandelbrotAlgorithm::new, &MandelbrotFireColourMap::new(MAX_ITERATIONS)).unwrap()
+    }
+
+    fn one_shot_ppm() -> Vec<u8> {
+        let pixel_rect = rect(0, 0, 31, 23);
+        let algorithm = MandelbrotAlgorithm::new(pixel_rect, region(), MAX_ITERATIONS).unwrap();
+        let colour_map = MandelbrotFireColourMap::new(MAX_ITERATIONS);
+        let buffer = render_pixel_buffer_parallel_rayon(pixel_rect, &algorithm, &colour_map).unwrap();
+
+        let path = temp_file_path("streaming_one_shot", "ppm");
+        PpmFilePresenter::new().present(&buffer, &path).unwrap();
+        let output = fs::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        output
+    }
+
+    fn stream(bands: &[(i32, i32)]) -> Vec<u8> {
+        let mut output = Vec::new();
+        let mut presenter = StreamingPpmPresenter::new(rect(0, 0, 31, 23), &mut output).unwrap();
+        for &(y0, y1) in bands {
+            presenter.write_band(&band(y0, y1)).unwrap();
+        }
+        presenter.finish().unwrap();
+
+        output
+    }
+
+    #[test]
+    fn ordered_bands_match_a_one_shot_ppm() {
+        let expected = one_shot_ppm();
+
+        assert_eq!(stream(&[(0, 7), (8, 15), (16, 23)]), expected);
+        assert_eq!(stream(&[(0, 0), (1, 22), (23, 23)]), expected);
+        assert_eq!(stream(&[(0, 23)]), expected);
+    }
+
+    #[test]
+    fn bands_out_of_order_or_short_of_the_width_are_rejected() {
+        let mut output = Vec::new();
+        let mut presenter = StreamingPpmPresenter::new(rect(0, 0, 31, 23), &mut output).unwrap();
+
+        let skipped = presenter.write_band(&band(8, 15)).unwrap_err();
+        assert_eq!(skipped.kind(), io::ErrorKind::InvalidInput);
+
+        let request = TileRequest::new(rect(0, 0, 31, 23), rect(0, 0, 15, 7), region(), MAX_ITERATIONS).unwrap();
+        let narrow = render_tile(&request, MandelbrotAlgorithm::new, &MandelbrotFireColourMap::new(MAX_ITERATIONS)).unwrap();
+        let error = presenter.write_band(&narrow).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+        presenter.write_band(&band(0, 7)).unwrap();
+        let repeated = presenter.write_band(&band(0, 7)).unwrap_err();
+        assert_eq!(repeated.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn finishing_early_is_an_error() {
+        let mut output = Vec::new();
+        let mut presenter = StreamingPpmPresenter::new(rect(0, 0, 31, 23), &mut output).unwrap();
+        presenter.write_band(&band(0, 15)).unwrap();
+
+        let error = presenter.finish().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::core::actions::extract_contours::Contour;
+use crate::core::data::pixel_rect::PixelRect;
+use std::io::Write;
+use std::path::Path;
+
+/// Writes contours as an SVG the size of the field they were traced from,
+/// one `<g>` per level. Closed contours become `<polygon>`s and open ones
+/// `<polyline>`s, all stroked in black with no fill.
+pub struct SvgContourPresenter {}
+
+impl Default for SvgContourPresenter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SvgContourPresenter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn present(
+        &self,
+        contours: &[Contour],
+        pixel_rect: PixelRect,
+        filepath: impl AsRef<Path>,
+    ) -> std::io::Result<()> {
+        let mut file = std::io::BufWriter::new(std::fs::File::create(filepath)?);
+        self.write(contours, pixel_rect, &mut file)?;
+
+        file.flush()
+    }
+
+    pub fn write(
+        &self,
+        contours: &[Contour],
+        pixel_rect: PixelRect,
+        out: &mut impl Write,
+    ) -> std::io::Result<()> {
+        let width = pixel_rect.width();
+        let height = pixel_rect.height();
+
+        // Contour points sit on pixel centres, so shift them into the middle
+        // of the pixels the viewBox spans.
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="-0.5 -0.5 {width} {height}">"#
+        )?;
+
+        let mut open_level = None;
+        for contour in contours {
+            if open_level != Some(contour.level) {
+                if open_level.is_some() {
+                    writeln!(out, "</g>")?;
+                }
+                writeln!(
+                    out,
+                    r#"<g data-level="{}" fill="none" stroke="black" stroke-width="0.5">"#,
+                    contour.level
+                )?;
+                open_level = Some(contour.level);
+            }
+
+            let element = if contour.closed { "polygon" } else { "polyline" };
+            let points: Vec<String> = contour
+                .points
+                .iter()
+                .map(|(x, y)| format!("{x:.2},{y:.2}"))
+                .collect();
+            writeln!(out, r#"<{element} points="{}"/>"#, points.join(" "))?;
+        }
+
+        if open_level.is_some() {
+            writeln!(out, "</g>")?;
+        }
+        writeln!(out, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::point::Point;
+
+    #[test]
+    fn test_write_groups_contours_by_level() {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 1 }).unwrap();
+        let contours = [
+            Contour {
+                level: 2,
+                points: vec![(0.5, 0.0), (1.0, 0.5), (0.5, 1.0)],
+                closed: true,
+            },
+            Contour {
+                level: 2,
+                points: vec![(2.0, 0.0), (2.25, 1.0)],
+                closed: false,
+            },
+            Contour {
+                level: 5,
+                points: vec![(3.0, 0.5), (2.5, 1.0)],
+                closed: false,
+            },
+        ];
+
+        let mut out = Vec::new();
+        SvgContourPresenter::new().write(&contours, pixel_rect, &mut out).unwrap();
+
+        let expected = r#"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="2" viewBox="-0.5 -0.5 4 2">
+<g data-level="2" fill="none" stroke="black" stroke-width="0.5">
+<polygon points="0.50,0.00 1.00,0.50 0.50,1.00"/>
+<polyline points="2.00,0.00 2.25,1.00"/>
+</g>
+<g data-level="5" fill="none" stroke="black" stroke-width="0.5">
+<polyline points="3.00,0.50 2.50,1.00"/>
+</g>
+</svg>
+"#;
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+}
@@ -1,18 +1,37 @@
+use std::time::Duration;
 use winit::{event_loop::EventLoopProxy, window::Window};
 
-use crate::{input::gui::{app::{events::gui::GuiEvent, ports::presenter::GuiPresenterPort}, commands::ports::presenter_factory::GuiPresenterFactoryPort}, presenters::pixels::presenter::PixelsPresenter};
+use crate::{input::gui::{app::events::gui::GuiEvent, commands::ports::presenter_factory::GuiPresenterFactoryPort}, presenters::pixels::{present_mode::PresentMode, presenter::PixelsPresenter}};
 
-pub struct PixelsPresenterFactory {}
+pub struct PixelsPresenterFactory {
+    present_mode: PresentMode,
+    max_frame_age: Option<Duration>,
+}
 
 impl GuiPresenterFactoryPort<PixelsPresenter> for PixelsPresenterFactory {
     fn build(&self, window: &'static Window, event_loop_proxy: EventLoopProxy<GuiEvent>) -> PixelsPresenter {
-        PixelsPresenter::new(window, event_loop_proxy)
+        PixelsPresenter::with_present_mode(window, event_loop_proxy, self.present_mode)
+            .with_max_frame_age(self.max_frame_age)
     }
 }
 
 impl PixelsPresenterFactory {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            present_mode: PresentMode::default(),
+            max_frame_age: None,
+        }
+    }
+
+    pub fn with_present_mode(mut self, present_mode: PresentMode) -> Self {
+        self.present_mode = present_mode;
+        self
+    }
+
+    /// See [`PixelsPresenter::with_max_frame_age`].
+    pub fn with_max_frame_age(mut self, max_frame_age: Option<Duration>) -> Self {
+        self.max_frame_age = max_frame_age;
+        self
     }
 }
 
@@ -1,4 +1,5 @@
 pub mod paused_overlay;
+pub mod present_mode;
 pub mod presenter;
 pub mod adapter;
 pub mod factory;
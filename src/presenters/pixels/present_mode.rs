@@ -0,0 +1,58 @@
+use pixels::wgpu;
+
+/// How the GUI surface paces presentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PresentMode {
+    /// Wait for vertical blank (Fifo). Always supported; the default.
+    #[default]
+    Vsync,
+    /// Present as soon as a frame is ready (Immediate), for uncapped-FPS
+    /// benchmarking. Falls back to Mailbox, then Fifo, where the surface
+    /// does not support it.
+    NoVsync,
+}
+
+impl PresentMode {
+    #[must_use]
+    pub fn to_wgpu(self) -> wgpu::PresentMode {
+        match self {
+            Self::Vsync => wgpu::PresentMode::AutoVsync,
+            Self::NoVsync => wgpu::PresentMode::AutoNoVsync,
+        }
+    }
+
+    /// Parses a command-line value: `vsync`/`fifo` or `no-vsync`/`immediate`.
+    #[must_use]
+    pub fn from_arg(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "vsync" | "fifo" => Some(Self::Vsync),
+            "no-vsync" | "immediate" => Some(Self::NoVsync),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_to_wgpu_present_modes() {
+        assert_eq!(PresentMode::Vsync.to_wgpu(), wgpu::PresentMode::AutoVsync);
+        assert_eq!(PresentMode::NoVsync.to_wgpu(), wgpu::PresentMode::AutoNoVsync);
+    }
+
+    #[test]
+    fn default_matches_pixels_default() {
+        assert_eq!(PresentMode::default().to_wgpu(), wgpu::PresentMode::AutoVsync);
+    }
+
+    #[test]
+    fn parses_command_line_values() {
+        assert_eq!(PresentMode::from_arg("vsync"), Some(PresentMode::Vsync));
+        assert_eq!(PresentMode::from_arg("Fifo"), Some(PresentMode::Vsync));
+        assert_eq!(PresentMode::from_arg("no-vsync"), Some(PresentMode::NoVsync));
+        assert_eq!(PresentMode::from_arg("IMMEDIATE"), Some(PresentMode::NoVsync));
+        assert_eq!(PresentMode::from_arg("mailbox"), None);
+    }
+}
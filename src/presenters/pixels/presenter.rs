@@ -1,19 +1,22 @@
 use crate::controllers::interactive::data::frame_data::FrameData;
 use crate::controllers::interactive::events::render::RenderEvent;
+use crate::controllers::interactive::generation::is_newer;
 use crate::controllers::interactive::ports::presenter::InteractiveControllerPresenterPort;
 use crate::core::data::pixel_buffer::PixelBuffer;
+use crate::core::util::upsample_nearest::upsample_nearest;
 use crate::input::gui::app::frame_overlay::FrameOverlay;
 use crate::input::gui::app::events::gui::GuiEvent;
 use crate::input::gui::app::ports::presenter::GuiPresenterPort;
 use crate::presenters::pixels::adapter::PixelsAdapter;
 use crate::presenters::pixels::paused_overlay;
+use crate::presenters::pixels::present_mode::PresentMode;
 use egui::Context as EguiContext;
 use egui_wgpu::Renderer as EguiRenderer;
-use pixels::Pixels;
+use pixels::{Pixels, PixelsBuilder};
 use pixels::SurfaceTexture;
 use pixels::wgpu;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use winit::event_loop::EventLoopProxy;
 use winit::window::Window;
 
@@ -21,42 +24,25 @@ pub struct PixelsPresenter {
     pixels: Pixels<'static>,
     egui_renderer: EguiRenderer,
     adapter: Arc<PixelsAdapter>,
+    surface_width: u32,
+    surface_height: u32,
     width: u32,
     height: u32,
     has_frame: bool,
     base_frame_rgba: Vec<u8>,
-    last_presented_generation: u64,
+    frame_gate: FrameGate,
     last_error_message: Option<String>,
     last_render_duration: Option<Duration>,
+    /// From `submit_request` to the draw, for the last frame presented.
+    last_present_latency: Option<Duration>,
+    last_frame_single_colour: bool,
+    /// The last frame drawn, kept for screenshots.
+    last_frame: Option<Arc<PixelBuffer>>,
 }
 
 impl GuiPresenterPort for PixelsPresenter {
     fn new(window: &'static Window, event_loop_proxy: EventLoopProxy<GuiEvent>) -> Self {
-        let size = window.inner_size();
-        let surface_texture = SurfaceTexture::new(size.width, size.height, window);
-
-        let pixels = Pixels::new(size.width, size.height, surface_texture)
-            .expect("Failed to create pixels surface");
-
-        let egui_renderer = EguiRenderer::new(
-            pixels.device(),
-            pixels.render_texture_format(),
-            None, // depth format
-            1,    // msaa samples
-        );
-
-        Self {
-            pixels,
-            egui_renderer,
-            adapter: Arc::new(PixelsAdapter::new(event_loop_proxy)),
-            width: size.width,
-            height: size.height,
-            has_frame: false,
-            base_frame_rgba: Vec::new(),
-            last_presented_generation: 0,
-            last_error_message: None,
-            last_render_duration: None,
-        }
+        Self::with_present_mode(window, event_loop_proxy, PresentMode::default())
     }
 
     fn share_adapter(&self) -> Arc<dyn InteractiveControllerPresenterPort> {
@@ -67,14 +53,14 @@ impl GuiPresenterPort for PixelsPresenter {
         &mut self,
         egui_output: egui::FullOutput,
         egui_ctx: &EguiContext,
-        _requested_generation: u64,
+        requested_generation: u64,
         frame_overlay: &FrameOverlay,
     ) -> Result<(), pixels::Error> {
         if self.width == 0 || self.height == 0 {
             return Ok(());
         }
 
-        self.maybe_draw_frame();
+        self.maybe_draw_frame(requested_generation);
         self.redraw_base_layer();
         paused_overlay::draw_frame_overlay(
             self.pixels.frame_mut(),
@@ -91,7 +77,7 @@ impl GuiPresenterPort for PixelsPresenter {
                 egui_ctx.tessellate(egui_output.shapes, egui_ctx.pixels_per_point());
 
             let screen_descriptor = egui_wgpu::ScreenDescriptor {
-                size_in_pixels: [self.width, self.height],
+                size_in_pixels: [self.surface_width, self.surface_height],
                 pixels_per_point: egui_ctx.pixels_per_point(),
             };
 
@@ -144,24 +130,119 @@ impl GuiPresenterPort for PixelsPresenter {
         })
     }
 
-    fn resize(&mut self, width: u32, height: u32) {
+    fn resize(&mut self, surface_size: (u32, u32), buffer_size: (u32, u32)) {
+        let (width, height) = buffer_size;
+        let preview = self.resized_base_frame(width, height);
+
+        (self.surface_width, self.surface_height) = surface_size;
         self.width = width;
         self.height = height;
 
         self.pixels
-            .resize_surface(width, height)
+            .resize_surface(self.surface_width, self.surface_height)
             .expect("Failed to resize surface");
 
         self.pixels
             .resize_buffer(width, height)
             .expect("Failed to resize buffer");
 
-        self.has_frame = false;
-        self.base_frame_rgba.clear();
+        // Until the render at the new size arrives, show the previous frame
+        // stretched to fit rather than a blank placeholder.
+        self.last_frame_single_colour = false;
+        match preview {
+            Some(rgba) => self.base_frame_rgba = rgba,
+            None => {
+                self.has_frame = false;
+                self.base_frame_rgba.clear();
+            }
+        }
+    }
+
+    fn last_frame_single_colour(&self) -> bool {
+        self.has_frame && self.last_frame_single_colour
+    }
+
+    fn last_frame(&self) -> Option<&PixelBuffer> {
+        self.last_frame.as_deref()
+    }
+
+    fn last_present_latency(&self) -> Option<Duration> {
+        self.last_present_latency
+    }
+
+    fn last_presented_generation(&self) -> u64 {
+        self.frame_gate.last_presented_generation
     }
 }
 
 impl PixelsPresenter {
+    pub fn with_present_mode(
+        window: &'static Window,
+        event_loop_proxy: EventLoopProxy<GuiEvent>,
+        present_mode: PresentMode,
+    ) -> Self {
+        let size = window.inner_size();
+        let surface_texture = SurfaceTexture::new(size.width, size.height, window);
+
+        let pixels = PixelsBuilder::new(size.width, size.height, surface_texture)
+            .present_mode(present_mode.to_wgpu())
+            .build()
+            .expect("Failed to create pixels surface");
+
+        let egui_renderer = EguiRenderer::new(
+            pixels.device(),
+            pixels.render_texture_format(),
+            None, // depth format
+            1,    // msaa samples
+        );
+
+        Self {
+            pixels,
+            egui_renderer,
+            adapter: Arc::new(PixelsAdapter::new(event_loop_proxy, window.id())),
+            surface_width: size.width,
+            surface_height: size.height,
+            width: size.width,
+            height: size.height,
+            has_frame: false,
+            base_frame_rgba: Vec::new(),
+            frame_gate: FrameGate::default(),
+            last_error_message: None,
+            last_render_duration: None,
+            last_present_latency: None,
+            last_frame_single_colour: false,
+            last_frame: None,
+        }
+    }
+
+    /// Drops frames that waited longer than `max_frame_age` between the
+    /// controller submitting them and the next draw once a newer request has
+    /// been submitted, rather than showing a view the user has already
+    /// moved on from. The newest request's frame is always drawn, however
+    /// late. `None`, the default, keeps every frame.
+    #[must_use]
+    pub fn with_max_frame_age(mut self, max_frame_age: Option<Duration>) -> Self {
+        self.frame_gate.max_frame_age = max_frame_age;
+        self
+    }
+
+    /// The cached frame resampled (nearest-neighbour) to `width`x`height`,
+    /// or `None` if there is no frame to resample.
+    fn resized_base_frame(&self, width: u32, height: u32) -> Option<Vec<u8>> {
+        if !self.has_frame || width == 0 || height == 0 {
+            return None;
+        }
+
+        let pixels: Vec<[u8; 4]> = self
+            .base_frame_rgba
+            .chunks_exact(PixelBuffer::BYTES_PER_PIXEL)
+            .map(|p| [p[0], p[1], p[2], p[3]])
+            .collect();
+
+        upsample_nearest(&pixels, self.width, self.height, width, height)
+            .map(|resized| resized.into_flattened())
+    }
+
     fn draw_placeholder(&mut self) {
         let frame = self.pixels.frame_mut();
         for pixel in frame.chunks_exact_mut(PixelBuffer::BYTES_PER_PIXEL) {
@@ -204,25 +285,28 @@ impl PixelsPresenter {
         }
     }
 
-    pub fn maybe_draw_frame(&mut self) {
+    /// Draws the controller's latest frame, if the gate admits it;
+    /// `requested_generation` is the newest generation submitted.
+    pub fn maybe_draw_frame(&mut self, requested_generation: u64) {
         if let Some(event) = self.adapter.render_event() {
             match event {
                 RenderEvent::Frame(frame) => {
-                    let pixel_rect = frame.pixel_buffer.pixel_rect();
-
-                    if frame.generation > self.last_presented_generation
-                        && pixel_rect.width() == self.width
-                        && pixel_rect.height() == self.height
+                    let now = Instant::now();
+                    if self
+                        .frame_gate
+                        .admit(&frame, (self.width, self.height), requested_generation, now)
                     {
                         self.copy_pixel_buffer_into_base_frame(&frame);
                         self.has_frame = true;
-                        self.last_presented_generation = frame.generation;
                         self.last_render_duration = Some(frame.render_duration);
+                        self.last_present_latency = Some(present_latency(frame.requested_at, now));
+                        self.last_frame_single_colour = frame.single_colour;
                         self.last_error_message = None;
+                        self.last_frame = Some(frame.pixel_buffer);
                     }
                 }
                 RenderEvent::Error(error) => {
-                    if error.generation >= self.last_presented_generation {
+                    if !is_newer(self.frame_gate.last_presented_generation, error.generation) {
                         self.last_error_message = Some(error.message);
                     }
                 }
@@ -235,7 +319,7 @@ impl PixelsPresenter {
         let width = pixel_rect.width();
         let height = pixel_rect.height();
         let expected_rgba_len = (width * height) as usize * PixelBuffer::BYTES_PER_PIXEL;
-        let src = frame.pixel_buffer.buffer();
+        let src = frame.pixel_buffer.as_rgba();
 
         assert_eq!(
             src.len(),
@@ -251,3 +335,179 @@ impl PixelsPresenter {
         self.base_frame_rgba.extend_from_slice(src);
     }
 }
+
+/// Decides which frames reach the screen, remembering the generation of
+/// the last one let through.
+#[derive(Debug, Clone, Copy, Default)]
+struct FrameGate {
+    last_presented_generation: u64,
+    max_frame_age: Option<Duration>,
+}
+
+impl FrameGate {
+    /// Whether to present `frame` on a `size` buffer at `now`, with
+    /// `requested_generation` the newest submitted; see
+    /// [`should_present_frame`]. An admitted frame becomes the last
+    /// presented.
+    fn admit(&mut self, frame: &FrameData, size: (u32, u32), requested_generation: u64, now: Instant) -> bool {
+        let superseded = is_newer(requested_generation, frame.generation);
+        let admitted = should_present_frame(
+            frame,
+            self.last_presented_generation,
+            size,
+            self.max_frame_age.filter(|_| superseded),
+            now,
+        );
+        if admitted {
+            self.last_presented_generation = frame.generation;
+        }
+        admitted
+    }
+}
+
+/// True if `frame` is newer than the last one presented, matches the buffer
+/// size, and has not waited longer than `max_frame_age` since the
+/// controller submitted it.
+fn should_present_frame(
+    frame: &FrameData,
+    last_presented_generation: u64,
+    (width, height): (u32, u32),
+    max_frame_age: Option<Duration>,
+    now: Instant,
+) -> bool {
+    let pixel_rect = frame.pixel_buffer.pixel_rect();
+    let fresh = max_frame_age
+        .is_none_or(|max_age| now.saturating_duration_since(frame.submitted_at) <= max_age);
+
+    is_newer(frame.generation, last_presented_generation)
+        && pixel_rect.width() == width
+        && pixel_rect.height() == height
+        && fresh
+}
+
+/// End-to-end latency of a frame: from its request's `submit_request` to
+/// `presented_at`.
+fn present_latency(requested_at: Instant, presented_at: Instant) -> Duration {
+    presented_at.saturating_duration_since(requested_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::pixel_rect::PixelRect;
+    use crate::core::data::point::Point;
+
+    const MAX_AGE: Duration = Duration::from_millis(100);
+
+    fn frame(generation: u64, submitted_at: Instant) -> FrameData {
+        let pixel_rect = PixelRect::new(Point { x: 0, y: 0 }, Point { x: 3, y: 1 }).unwrap();
+        let pixel_buffer = PixelBuffer::new(pixel_rect);
+
+        FrameData {
+            generation,
+            #[cfg(feature = "frame-checksum")]
+            checksum: crate::controllers::interactive::data::frame_data::buffer_checksum(&pixel_buffer),
+            pixel_buffer: Arc::new(pixel_buffer),
+            render_duration: Duration::ZERO,
+            single_colour: false,
+            requested_at: submitted_at,
+            submitted_at,
+        }
+    }
+
+    #[test]
+    fn present_latency_runs_from_request_to_draw() {
+        let requested_at = Instant::now();
+
+        assert_eq!(
+            present_latency(requested_at, requested_at + Duration::from_millis(42)),
+            Duration::from_millis(42)
+        );
+        assert_eq!(present_latency(requested_at, requested_at), Duration::ZERO);
+    }
+
+    #[test]
+    fn present_latency_is_zero_for_a_draw_before_the_request() {
+        let presented_at = Instant::now();
+
+        assert_eq!(
+            present_latency(presented_at + Duration::from_millis(5), presented_at),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn a_frame_older_than_the_max_age_is_not_presented() {
+        let submitted_at = Instant::now();
+        let frame = frame(2, submitted_at);
+        let now = submitted_at + MAX_AGE + Duration::from_millis(1);
+
+        assert!(!should_present_frame(&frame, 1, (4, 2), Some(MAX_AGE), now));
+    }
+
+    #[test]
+    fn a_frame_within_the_max_age_is_presented() {
+        let submitted_at = Instant::now();
+        let frame = frame(2, submitted_at);
+
+        assert!(should_present_frame(&frame, 1, (4, 2), Some(MAX_AGE), submitted_at + MAX_AGE));
+    }
+
+    #[test]
+    fn without_a_max_age_old_frames_are_presented() {
+        let submitted_at = Instant::now();
+        let frame = frame(2, submitted_at);
+
+        assert!(should_present_frame(&frame, 1, (4, 2), None, submitted_at + Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn stale_generations_and_mismatched_sizes_are_not_presented() {
+        let submitted_at = Instant::now();
+        let frame = frame(2, submitted_at);
+
+        assert!(!should_present_frame(&frame, 2, (4, 2), None, submitted_at));
+        assert!(!should_present_frame(&frame, 1, (4, 3), None, submitted_at));
+    }
+
+    #[test]
+    fn the_gate_tracks_the_last_presented_generation_in_order() {
+        let now = Instant::now();
+        let mut gate = FrameGate::default();
+        assert_eq!(gate.last_presented_generation, 0);
+
+        for generation in 1..=3 {
+            assert!(gate.admit(&frame(generation, now), (4, 2), generation, now));
+            assert_eq!(gate.last_presented_generation, generation);
+        }
+
+        // A late frame from an older generation leaves it alone.
+        assert!(!gate.admit(&frame(2, now), (4, 2), 3, now));
+        assert_eq!(gate.last_presented_generation, 3);
+    }
+
+    #[test]
+    fn a_rejected_frame_does_not_advance_the_gate() {
+        let submitted_at = Instant::now();
+        let mut gate = FrameGate {
+            max_frame_age: Some(MAX_AGE),
+            ..FrameGate::default()
+        };
+
+        assert!(!gate.admit(&frame(1, submitted_at), (4, 3), 3, submitted_at));
+        assert!(!gate.admit(&frame(2, submitted_at), (4, 2), 3, submitted_at + 2 * MAX_AGE));
+        assert_eq!(gate.last_presented_generation, 0);
+    }
+
+    #[test]
+    fn a_late_frame_is_presented_when_nothing_newer_was_requested() {
+        let submitted_at = Instant::now();
+        let mut gate = FrameGate {
+            max_frame_age: Some(MAX_AGE),
+            ..FrameGate::default()
+        };
+
+        assert!(gate.admit(&frame(2, submitted_at), (4, 2), 2, submitted_at + 2 * MAX_AGE));
+        assert_eq!(gate.last_presented_generation, 2);
+    }
+}